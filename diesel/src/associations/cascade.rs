@@ -0,0 +1,56 @@
+use connection::Connection;
+use result::QueryResult;
+
+/// Declares the rows which have to be deleted along with a record, for
+/// tables which don't have `ON DELETE CASCADE` foreign keys. Used by
+/// [`delete_cascade`](../fn.delete_cascade.html).
+///
+/// `delete_dependents` should delete the rows which reference this record,
+/// running the delete statements for the most deeply nested tables first. A
+/// dependent type which implements `Cascade` itself can be deleted with
+/// `delete_cascade`, so its own dependents are deleted as well.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate diesel;
+/// # #[macro_use] extern crate diesel_codegen;
+/// # include!("../doctest_setup.rs");
+/// use diesel::associations::Cascade;
+/// use schema::{posts, users};
+///
+/// #[derive(Identifiable, Queryable)]
+/// pub struct User {
+///     id: i32,
+///     name: String,
+/// }
+///
+/// #[derive(Identifiable, Queryable, Associations)]
+/// #[belongs_to(User)]
+/// pub struct Post {
+///     id: i32,
+///     user_id: i32,
+///     title: String,
+/// }
+///
+/// impl<Conn: Connection<Backend = DB>> Cascade<Conn> for User {
+///     fn delete_dependents(&self, conn: &Conn) -> QueryResult<()> {
+///         diesel::delete(Post::belonging_to(self)).execute(conn)?;
+///         Ok(())
+///     }
+/// }
+///
+/// # fn main() {
+/// #     let connection = establish_connection();
+/// let sean = users::table.find(1).first::<User>(&connection).unwrap();
+/// diesel::delete_cascade(&sean, &connection).unwrap();
+///
+/// let remaining_posts = posts::table.select(posts::user_id).load(&connection);
+/// assert_eq!(Ok(vec![2]), remaining_posts);
+/// # }
+/// ```
+pub trait Cascade<Conn: Connection> {
+    /// Deletes the rows which depend on this record. This is called within
+    /// the transaction of `delete_cascade`, before the record is deleted.
+    fn delete_dependents(&self, conn: &Conn) -> QueryResult<()>;
+}
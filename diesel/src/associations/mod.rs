@@ -128,12 +128,14 @@
 //! by a user in a single query). However, the goal is to provide simple building blocks which can
 //! be used to construct the complex behavior applications need.
 mod belongs_to;
+mod cascade;
 
 use std::hash::Hash;
 
 use query_source::Table;
 
 pub use self::belongs_to::{BelongsTo, GroupedBy};
+pub use self::cascade::Cascade;
 
 pub trait HasTable {
     type Table: Table;
@@ -1,12 +1,17 @@
+mod retry;
 mod statement_cache;
 mod transaction_manager;
 
+use std::error::Error as StdError;
+
 use backend::Backend;
-use query_builder::{AsQuery, QueryFragment, QueryId};
+use query_builder::{AsQuery, Query, QueryFragment, QueryId};
 use query_source::Queryable;
 use result::*;
-use types::HasSqlType;
+use row::Row;
+use types::{FromSqlRow, HasSqlType};
 
+pub use self::retry::insert_with_retry;
 pub use self::transaction_manager::{AnsiTransactionManager, TransactionManager};
 #[doc(hidden)]
 pub use self::statement_cache::{MaybeCached, StatementCache, StatementCacheKey};
@@ -114,6 +119,25 @@ pub trait Connection: SimpleConnection + Sized + Send {
 
     /// Creates a transaction that will never be committed. This is useful for
     /// tests. Panics if called while inside of a transaction.
+    ///
+    /// Any call to [`transaction`](#method.transaction) made afterwards
+    /// creates a savepoint instead, so code which uses transactions can be
+    /// tested as is. Nothing is written to the database: the transaction is
+    /// rolled back when the connection is dropped at the end of the test.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # extern crate diesel;
+    /// # use diesel::prelude::*;
+    /// // In a module shared by your tests
+    /// fn connection<Conn: Connection>(database_url: &str) -> Conn {
+    ///     let connection = Conn::establish(database_url).unwrap();
+    ///     connection.begin_test_transaction().unwrap();
+    ///     connection
+    /// }
+    /// # fn main() {}
+    /// ```
     fn begin_test_transaction(&self) -> QueryResult<()> {
         let transaction_manager = self.transaction_manager();
         assert_eq!(transaction_manager.get_transaction_depth(), 0);
@@ -134,6 +158,38 @@ pub trait Connection: SimpleConnection + Sized + Send {
         user_result.expect("Transaction did not succeed")
     }
 
+    /// Runs a query for its side effects, such as taking a lock, and returns
+    /// the number of rows it returned. The rows are discarded without being
+    /// deserialized.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate diesel;
+    /// # include!("../doctest_setup.rs");
+    /// #
+    /// # table! {
+    /// #     users {
+    /// #         id -> Integer,
+    /// #         name -> VarChar,
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     use self::users::dsl::*;
+    /// #     let connection = establish_connection();
+    /// let row_count = connection.execute_query(&users.select(id));
+    /// assert_eq!(Ok(2), row_count);
+    /// # }
+    /// ```
+    fn execute_query<T>(&self, query: &T) -> QueryResult<usize>
+    where
+        T: Query + QueryFragment<Self::Backend> + QueryId,
+        Self::Backend: HasSqlType<T::SqlType>,
+    {
+        self.query_by_index::<_, DiscardedRow>(query).map(|rows| rows.len())
+    }
+
     #[doc(hidden)]
     fn execute(&self, query: &str) -> QueryResult<usize>;
 
@@ -145,6 +201,22 @@ pub trait Connection: SimpleConnection + Sized + Send {
         Self::Backend: HasSqlType<T::SqlType>,
         U: Queryable<T::SqlType, Self::Backend>;
 
+    #[doc(hidden)]
+    fn query_by_index_iter<'a, T, U>(
+        &'a self,
+        source: T,
+    ) -> QueryResult<Box<Iterator<Item = QueryResult<U>> + 'a>>
+    where
+        T: AsQuery,
+        T::Query: QueryFragment<Self::Backend> + QueryId,
+        T::SqlType: 'a,
+        Self::Backend: HasSqlType<T::SqlType>,
+        U: Queryable<T::SqlType, Self::Backend> + 'a,
+    {
+        let rows = try!(self.query_by_index(source));
+        Ok(Box::new(rows.into_iter().map(Ok)))
+    }
+
     #[doc(hidden)]
     fn execute_returning_count<T>(&self, source: &T) -> QueryResult<usize>
     where
@@ -155,3 +227,27 @@ pub trait Connection: SimpleConnection + Sized + Send {
     #[doc(hidden)]
     fn transaction_manager(&self) -> &Self::TransactionManager;
 }
+
+/// A row which is skipped without reading any of its fields, used to count
+/// the rows returned by a query.
+struct DiscardedRow;
+
+impl<ST, DB> FromSqlRow<ST, DB> for DiscardedRow
+where
+    DB: Backend + HasSqlType<ST>,
+{
+    fn build_from_row<R: Row<DB>>(_: &mut R) -> Result<Self, Box<StdError + Send + Sync>> {
+        Ok(DiscardedRow)
+    }
+}
+
+impl<ST, DB> Queryable<ST, DB> for DiscardedRow
+where
+    DB: Backend + HasSqlType<ST>,
+{
+    type Row = Self;
+
+    fn build(row: Self) -> Self {
+        row
+    }
+}
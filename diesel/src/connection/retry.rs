@@ -0,0 +1,80 @@
+use connection::Connection;
+use result::{DatabaseErrorKind, Error, QueryResult};
+
+/// Runs an insert, retrying if it fails with a unique violation.
+///
+/// This is intended for records with a randomly generated key, such as a
+/// token. `gen_record` should generate a new record and insert it, returning
+/// the result of the insert. If the insert fails because of a unique
+/// constraint, `gen_record` is called again, up to `max_attempts` times in
+/// total. Any other error is returned immediately. If every attempt results in
+/// a unique violation, the error from the last attempt is returned.
+///
+/// Each attempt is run in its own transaction (or savepoint, if a transaction
+/// is already open), so a failed attempt does not abort an enclosing
+/// transaction on PostgreSQL.
+///
+/// # Panics
+///
+/// Panics if `max_attempts` is 0.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate diesel_codegen;
+/// # #[macro_use] extern crate diesel;
+/// # include!("../doctest_setup.rs");
+/// #
+/// # table! {
+/// #     users {
+/// #         id -> Integer,
+/// #         name -> Text,
+/// #     }
+/// # }
+/// #
+/// #[derive(Insertable)]
+/// #[table_name="users"]
+/// struct UserWithId {
+///     id: i32,
+///     name: String,
+/// }
+///
+/// # fn main() {
+/// #     use self::users::dsl::*;
+/// #     let connection = establish_connection();
+/// // Ids 1 and 2 are already taken
+/// let mut candidate_ids = vec![2, 1, 3].into_iter();
+/// let inserted_id = diesel::connection::insert_with_retry(5, || {
+///     let new_user = UserWithId {
+///         id: candidate_ids.next().unwrap(),
+///         name: "Ruby".into(),
+///     };
+///     diesel::insert(&new_user)
+///         .into(users)
+///         .execute(&connection)
+///         .map(|_| new_user.id)
+/// }, &connection);
+///
+/// assert_eq!(Ok(3), inserted_id);
+/// # }
+/// ```
+pub fn insert_with_retry<T, F, Conn>(
+    max_attempts: usize,
+    mut gen_record: F,
+    conn: &Conn,
+) -> QueryResult<T>
+where
+    F: FnMut() -> QueryResult<T>,
+    Conn: Connection,
+{
+    assert!(max_attempts > 0, "insert_with_retry needs at least one attempt");
+    let mut attempts = 0;
+    loop {
+        attempts += 1;
+        match conn.transaction(&mut gen_record) {
+            Err(Error::DatabaseError(DatabaseErrorKind::UniqueViolation, _))
+                if attempts < max_attempts => {}
+            result => return result,
+        }
+    }
+}
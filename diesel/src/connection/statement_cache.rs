@@ -82,6 +82,16 @@
 //! the only type which is safe to cache as a prepared statement but does not
 //! have a static query ID is something which has been boxed.
 //!
+//! The cache holds a limited number of statements, so that applications which
+//! generate many distinct boxed queries do not grow it without bound. When it
+//! is full, the least recently used statement is evicted to make room for a
+//! new one, once the new statement has been prepared successfully. The
+//! statements are also kept in a map ordered by when they were last used, so
+//! finding the least recently used one does not require a scan of the cache.
+//! Backends which need to release the statement on the server (such as PG,
+//! where a named prepared statement lives until it is deallocated) do so as
+//! part of the eviction.
+//!
 //! One potential optimization that we don't perform is storing the queries
 //! which are cached by type ID in a separate map. Since a type ID is a u64,
 //! this would allow us to use a specialized map which knows that there will
@@ -92,8 +102,8 @@
 
 use std::any::TypeId;
 use std::borrow::Cow;
-use std::cell::{RefCell, RefMut};
-use std::collections::HashMap;
+use std::cell::{Cell, RefCell, RefMut};
+use std::collections::{BTreeMap, HashMap};
 use std::hash::Hash;
 use std::ops::{Deref, DerefMut};
 
@@ -101,10 +111,24 @@ use backend::Backend;
 use query_builder::*;
 use result::QueryResult;
 
+/// The number of statements which are cached by default before the least
+/// recently used statement is evicted.
+const DEFAULT_CAPACITY: usize = 500;
+
 #[doc(hidden)]
 #[allow(missing_debug_implementations)]
 pub struct StatementCache<DB: Backend, Statement> {
-    pub cache: RefCell<HashMap<StatementCacheKey<DB>, Statement>>,
+    pub cache: RefCell<HashMap<StatementCacheKey<DB>, CachedStatement<Statement>>>,
+    recently_used: RefCell<BTreeMap<u64, StatementCacheKey<DB>>>,
+    capacity: Cell<usize>,
+    clock: Cell<u64>,
+}
+
+#[doc(hidden)]
+#[allow(missing_debug_implementations)]
+pub struct CachedStatement<Statement> {
+    statement: Statement,
+    last_used: u64,
 }
 
 #[cfg_attr(feature = "clippy", allow(len_without_is_empty))]
@@ -119,6 +143,9 @@ where
     pub fn new() -> Self {
         StatementCache {
             cache: RefCell::new(HashMap::new()),
+            recently_used: RefCell::new(BTreeMap::new()),
+            capacity: Cell::new(DEFAULT_CAPACITY),
+            clock: Cell::new(0),
         }
     }
 
@@ -126,6 +153,31 @@ where
         self.cache.borrow().len()
     }
 
+    pub fn capacity(&self) -> usize {
+        self.capacity.get()
+    }
+
+    /// Sets the maximum number of cached statements, immediately evicting the
+    /// least recently used statements if there are more than that. Each
+    /// evicted statement is passed to `release_fn`.
+    ///
+    /// # Panics
+    ///
+    /// If `capacity` is zero.
+    pub fn set_capacity<R>(&self, capacity: usize, release_fn: R) -> QueryResult<()>
+    where
+        R: FnMut(Statement) -> QueryResult<()>,
+    {
+        assert!(capacity > 0, "The statement cache capacity must be at least 1");
+        self.capacity.set(capacity);
+        let mut cache = self.cache.borrow_mut();
+        let mut recently_used = self.recently_used.borrow_mut();
+        evict_least_recently_used(&mut cache, &mut recently_used, capacity, release_fn)
+    }
+
+    /// Returns the statement for the given query, calling `prepare_fn` if it
+    /// is not already cached. Statements which are evicted to make room for a
+    /// new one are dropped.
     pub fn cached_statement<T, F>(
         &self,
         source: &T,
@@ -136,8 +188,24 @@ where
         T: QueryFragment<DB> + QueryId,
         F: FnOnce(&str) -> QueryResult<Statement>,
     {
-        use std::collections::hash_map::Entry::{Occupied, Vacant};
+        self.cached_statement_with_release(source, bind_types, prepare_fn, |_| Ok(()))
+    }
 
+    /// The same as `cached_statement`, but statements which are evicted to
+    /// make room for a new one are passed to `release_fn`. This is used by
+    /// backends which must explicitly release a statement on the server.
+    pub fn cached_statement_with_release<T, F, R>(
+        &self,
+        source: &T,
+        bind_types: &[DB::TypeMetadata],
+        prepare_fn: F,
+        release_fn: R,
+    ) -> QueryResult<MaybeCached<Statement>>
+    where
+        T: QueryFragment<DB> + QueryId,
+        F: FnOnce(&str) -> QueryResult<Statement>,
+        R: FnMut(Statement) -> QueryResult<()>,
+    {
         let cache_key = try!(StatementCacheKey::for_source(source, bind_types));
 
         if !source.is_safe_to_cache_prepared()? {
@@ -145,22 +213,73 @@ where
             return prepare_fn(&sql).map(MaybeCached::CannotCache);
         }
 
-        refmut_map_result(self.cache.borrow_mut(), |cache| {
-            match cache.entry(cache_key) {
-                Occupied(entry) => Ok(entry.into_mut()),
-                Vacant(entry) => {
-                    let statement = {
-                        let sql = try!(entry.key().sql(source));
-                        prepare_fn(&sql)
-                    };
+        let now = self.clock.get() + 1;
+        self.clock.set(now);
+        let capacity = self.capacity.get();
+        let mut recently_used = self.recently_used.borrow_mut();
+
+        let mut cache = self.cache.borrow_mut();
+        if !cache.contains_key(&cache_key) {
+            let statement = {
+                let sql = try!(cache_key.sql(source));
+                try!(prepare_fn(&sql))
+            };
+            // A statement is only evicted once the new one could be prepared
+            let evicted =
+                evict_least_recently_used(&mut cache, &mut recently_used, capacity - 1, release_fn);
+            recently_used.insert(now, cache_key.clone());
+            cache.insert(
+                cache_key.clone(),
+                CachedStatement {
+                    statement: statement,
+                    last_used: now,
+                },
+            );
+            try!(evicted);
+        }
 
-                    Ok(entry.insert(try!(statement)))
+        let statement = RefMut::map(cache, |cache| {
+            let cached = cache
+                .get_mut(&cache_key)
+                .expect("The statement was just inserted");
+            if cached.last_used != now {
+                if let Some(key) = recently_used.remove(&cached.last_used) {
+                    recently_used.insert(now, key);
                 }
+                cached.last_used = now;
             }
-        }).map(MaybeCached::Cached)
+            &mut cached.statement
+        });
+        Ok(MaybeCached::Cached(statement))
     }
 }
 
+/// Removes the least recently used statements until there are at most
+/// `max_len` left.
+fn evict_least_recently_used<DB, Statement, R>(
+    cache: &mut HashMap<StatementCacheKey<DB>, CachedStatement<Statement>>,
+    recently_used: &mut BTreeMap<u64, StatementCacheKey<DB>>,
+    max_len: usize,
+    mut release_fn: R,
+) -> QueryResult<()>
+where
+    DB: Backend,
+    StatementCacheKey<DB>: Hash + Eq,
+    R: FnMut(Statement) -> QueryResult<()>,
+{
+    while cache.len() > max_len {
+        let least_recently_used = recently_used.keys().next().cloned();
+        let key = match least_recently_used.and_then(|last_used| recently_used.remove(&last_used)) {
+            Some(key) => key,
+            None => break,
+        };
+        if let Some(evicted) = cache.remove(&key) {
+            try!(release_fn(evicted.statement));
+        }
+    }
+    Ok(())
+}
+
 #[doc(hidden)]
 #[allow(missing_debug_implementations)]
 pub enum MaybeCached<'a, T: 'a> {
@@ -199,6 +318,25 @@ pub enum StatementCacheKey<DB: Backend> {
     },
 }
 
+impl<DB> Clone for StatementCacheKey<DB>
+where
+    DB: Backend,
+    DB::TypeMetadata: Clone,
+{
+    fn clone(&self) -> Self {
+        match *self {
+            StatementCacheKey::Type(id) => StatementCacheKey::Type(id),
+            StatementCacheKey::Sql {
+                ref sql,
+                ref bind_types,
+            } => StatementCacheKey::Sql {
+                sql: sql.clone(),
+                bind_types: bind_types.clone(),
+            },
+        }
+    }
+}
+
 impl<DB> StatementCacheKey<DB>
 where
     DB: Backend,
@@ -234,27 +372,3 @@ where
         Ok(query_builder.finish())
     }
 }
-
-/// Similar to `RefMut::map`, but for functions which return `Result`
-///
-/// If we were in Haskell (and if `RefMut` were a functor), this would just be
-/// `sequenceA`.
-fn refmut_map_result<T, U, F>(refmut: RefMut<T>, mapper: F) -> QueryResult<RefMut<U>>
-where
-    F: FnOnce(&mut T) -> QueryResult<&mut U>,
-{
-    use std::mem;
-
-    let mut error = None;
-    let result = RefMut::map(refmut, |mutref| match mapper(mutref) {
-        Ok(x) => x,
-        Err(e) => {
-            error = Some(e);
-            unsafe { mem::uninitialized() }
-        }
-    });
-    match error {
-        Some(e) => Err(e),
-        None => Ok(result),
-    }
-}
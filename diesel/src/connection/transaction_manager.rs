@@ -91,17 +91,26 @@ where
 
     fn commit_transaction(&self, conn: &Conn) -> QueryResult<()> {
         let transaction_depth = self.transaction_depth.get();
-        self.change_transaction_depth(
-            -1,
-            if transaction_depth <= 1 {
-                conn.batch_execute("COMMIT")
-            } else {
+        if transaction_depth <= 1 {
+            let result = conn.batch_execute("COMMIT");
+            if result.is_err() {
+                // `COMMIT` can fail, e.g. because of a deferred constraint.
+                // PostgreSQL ends the transaction anyway, but other backends
+                // may leave it open, so it is rolled back to be sure.
+                let _ = conn.batch_execute("ROLLBACK");
+                self.transaction_depth.set(0);
+                return result;
+            }
+            self.change_transaction_depth(-1, result)
+        } else {
+            self.change_transaction_depth(
+                -1,
                 conn.batch_execute(&format!(
                     "RELEASE SAVEPOINT diesel_savepoint_{}",
                     transaction_depth - 1
-                ))
-            },
-        )
+                )),
+            )
+        }
     }
 
     fn get_transaction_depth(&self) -> u32 {
@@ -0,0 +1,136 @@
+use std::marker::PhantomData;
+
+use backend::Backend;
+use expression::*;
+use query_builder::*;
+use result::QueryResult;
+
+/// An expression given a name in the select clause (`expr AS alias`).
+/// Constructed by
+/// [`ExpressionMethods::aliased`](../expression_methods/trait.ExpressionMethods.html#method.aliased).
+#[derive(Debug, Clone, Copy)]
+pub struct Aliased<'a, T> {
+    expr: T,
+    alias: &'a str,
+}
+
+impl<'a, T> Aliased<'a, T> {
+    pub fn new(expr: T, alias: &'a str) -> Self {
+        Aliased {
+            expr: expr,
+            alias: alias,
+        }
+    }
+
+    /// Refers to this expression by its alias. This is intended to be passed
+    /// to `.order`, where it renders only the alias, rather than repeating the
+    /// whole expression.
+    ///
+    /// PostgreSQL, MySQL, and SQLite all accept an alias from the select
+    /// clause in `ORDER BY`. PostgreSQL requires the alias to appear on its
+    /// own, so the result of this method should not be used as part of a
+    /// larger expression. Aliases cannot be referenced from `WHERE` on any
+    /// backend, so the result cannot be passed to `.filter`. It can be used in
+    /// `.order`, `.group_by` and `.having`.
+    ///
+    /// Diesel rejects an alias which is passed to `.filter`, or compared with
+    /// an operator such as `.eq` or `.eq_any`. It cannot detect every
+    /// expression which wraps an alias, such as a SQL function call, so a
+    /// `.filter` containing one is only rejected by the database. Diesel also
+    /// does not check that the aliased expression is part of the select
+    /// clause of the query. The alias is quoted as an identifier, so it is
+    /// case sensitive on PostgreSQL.
+    ///
+    /// The aliased expression itself can be used in `.having`, where it is
+    /// written as a reference to the alias on backends which allow it.
+    pub fn alias(&self) -> Alias<'a, T::SqlType>
+    where
+        T: Expression,
+    {
+        Alias {
+            name: self.alias,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, T: Expression> Expression for Aliased<'a, T> {
+    type SqlType = T::SqlType;
+}
+
+impl<'a, T, DB> QueryFragment<DB> for Aliased<'a, T>
+where
+    DB: Backend,
+    T: QueryFragment<DB>,
+{
+    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+        match out.alias_reference() {
+            None => {
+                self.expr.walk_ast(out.reborrow())?;
+                out.push_sql(" AS ");
+                out.push_identifier(self.alias)?;
+            }
+            Some(AliasReference::Name) => out.push_identifier(self.alias)?,
+            Some(AliasReference::Expression) => self.expr.walk_ast(out.reborrow())?,
+        }
+        Ok(())
+    }
+}
+
+// The alias is part of the SQL, so the type alone does not identify the query
+impl<'a, T> QueryId for Aliased<'a, T> {
+    type QueryId = ();
+
+    const HAS_STATIC_QUERY_ID: bool = false;
+}
+
+impl<'a, T, QS> SelectableExpression<QS> for Aliased<'a, T>
+where
+    T: SelectableExpression<QS>,
+{
+}
+
+impl<'a, T, QS> AppearsOnTable<QS> for Aliased<'a, T>
+where
+    T: AppearsOnTable<QS>,
+{
+}
+
+impl<'a, T: NonAggregate> NonAggregate for Aliased<'a, T> {}
+
+// `SELECT DISTINCT expr AS alias` can still be ordered by `expr`
+impl<'a, T, C> SelectsColumn<C> for Aliased<'a, T>
+where
+    T: SelectsColumn<C>,
+{
+    type Count = T::Count;
+}
+
+impl<'a, T, Selection> ValidOrderTermForDistinct<Selection> for Aliased<'a, T> {}
+
+/// A reference to an aliased expression. See
+/// [`Aliased::alias`](struct.Aliased.html#method.alias).
+#[derive(Debug, Clone, Copy)]
+pub struct Alias<'a, ST> {
+    name: &'a str,
+    _marker: PhantomData<ST>,
+}
+
+impl<'a, ST> Expression for Alias<'a, ST> {
+    type SqlType = ST;
+}
+
+impl<'a, ST, DB: Backend> QueryFragment<DB> for Alias<'a, ST> {
+    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+        out.push_identifier(self.name)
+    }
+}
+
+impl<'a, ST> QueryId for Alias<'a, ST> {
+    type QueryId = ();
+
+    const HAS_STATIC_QUERY_ID: bool = false;
+}
+
+// Deliberately not `NonAggregate`, which keeps it out of `WHERE`
+impl<'a, ST, QS> AppearsOnTable<QS> for Alias<'a, ST> {}
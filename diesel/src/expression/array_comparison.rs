@@ -58,12 +58,14 @@ where
 
 impl<T, U> NonAggregate for In<T, U>
 where
+    T: NonAggregate,
     In<T, U>: Expression,
 {
 }
 
 impl<T, U> NonAggregate for NotIn<T, U>
 where
+    T: NonAggregate,
     NotIn<T, U>: Expression,
 {
 }
@@ -85,6 +87,10 @@ where
         }
         Ok(())
     }
+
+    fn is_known_empty(&self) -> bool {
+        self.values.is_empty()
+    }
 }
 
 impl<T, U, DB> QueryFragment<DB> for NotIn<T, U>
@@ -186,6 +192,8 @@ where
 {
 }
 
+impl_unchecked_distinct_ordering!(Many<T>);
+
 impl<T, QS> AppearsOnTable<QS> for Many<T>
 where
     Many<T>: Expression,
@@ -238,6 +246,8 @@ where
 {
 }
 
+impl_unchecked_distinct_ordering!(Subselect<T, ST>);
+
 impl<T, ST, QS> AppearsOnTable<QS> for Subselect<T, ST>
 where
     Subselect<T, ST>: Expression,
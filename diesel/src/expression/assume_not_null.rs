@@ -0,0 +1,61 @@
+use backend::Backend;
+use expression::*;
+use query_builder::*;
+use result::QueryResult;
+use types::{NotNull, Nullable};
+
+#[derive(Debug, Copy, Clone)]
+pub struct AssumeNotNull<T>(T);
+
+impl<T> AssumeNotNull<T> {
+    pub fn new(expr: T) -> Self {
+        AssumeNotNull(expr)
+    }
+}
+
+impl<T, ST> Expression for AssumeNotNull<T>
+where
+    T: Expression<SqlType = Nullable<ST>>,
+    ST: NotNull,
+{
+    type SqlType = ST;
+}
+
+impl<T, DB> QueryFragment<DB> for AssumeNotNull<T>
+where
+    DB: Backend,
+    T: QueryFragment<DB>,
+{
+    fn walk_ast(&self, pass: AstPass<DB>) -> QueryResult<()> {
+        self.0.walk_ast(pass)
+    }
+}
+
+impl<T, QS> AppearsOnTable<QS> for AssumeNotNull<T>
+where
+    T: AppearsOnTable<QS>,
+    AssumeNotNull<T>: Expression,
+{
+}
+
+impl<T, QS> SelectableExpression<QS> for AssumeNotNull<T>
+where
+    T: SelectableExpression<QS>,
+    AssumeNotNull<T>: AppearsOnTable<QS>,
+{
+}
+
+impl_unchecked_distinct_ordering!(AssumeNotNull<T>);
+
+impl<T: QueryId> QueryId for AssumeNotNull<T> {
+    type QueryId = T::QueryId;
+
+    const HAS_STATIC_QUERY_ID: bool = T::HAS_STATIC_QUERY_ID;
+}
+
+impl<T> NonAggregate for AssumeNotNull<T>
+where
+    T: NonAggregate,
+    AssumeNotNull<T>: Expression,
+{
+}
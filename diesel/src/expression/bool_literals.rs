@@ -0,0 +1,139 @@
+use backend::Backend;
+use expression::{AppearsOnTable, Expression, NonAggregate, SelectableExpression};
+use query_builder::*;
+use result::QueryResult;
+use types::Bool;
+
+/// Creates an expression which is always true. It is rendered as `1=1`, which
+/// is supported by every backend.
+///
+/// This is the starting point when combining a dynamic list of conditions
+/// with `.and`, as `true_expr().and(condition)` is the same as `condition`.
+/// When the list is empty, the result matches every row.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate diesel;
+/// # include!("../doctest_setup.rs");
+/// #
+/// # table! {
+/// #     users {
+/// #         id -> Integer,
+/// #         name -> VarChar,
+/// #     }
+/// # }
+/// #
+/// # fn main() {
+/// #     use self::users::dsl::*;
+/// #     let connection = establish_connection();
+/// use diesel::dsl::true_expr;
+/// use diesel::types::Bool;
+///
+/// type Condition = Box<BoxableExpression<users, DB, SqlType = Bool>>;
+///
+/// fn all_of(conditions: Vec<Condition>) -> Condition {
+///     conditions
+///         .into_iter()
+///         .fold(Box::new(true_expr()), |all, condition| Box::new(all.and(condition)))
+/// }
+///
+/// let all_users = users.select(name).filter(all_of(Vec::new()));
+/// assert_eq!(Ok(vec!["Sean".to_string(), "Tess".to_string()]), all_users.load(&connection));
+///
+/// let conditions: Vec<Condition> = vec![Box::new(id.gt(1)), Box::new(name.ne("Sean"))];
+/// let some_users = users.select(name).filter(all_of(conditions));
+/// assert_eq!(Ok(vec!["Tess".to_string()]), some_users.load(&connection));
+/// # }
+/// ```
+pub fn true_expr() -> TrueExpr {
+    TrueExpr
+}
+
+/// Creates an expression which is always false. It is rendered as `1=0`,
+/// which is supported by every backend.
+///
+/// This is the starting point when combining a dynamic list of conditions
+/// with `.or`, as `false_expr().or(condition)` is the same as `condition`.
+/// When the list is empty, the result matches no rows.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate diesel;
+/// # include!("../doctest_setup.rs");
+/// #
+/// # table! {
+/// #     users {
+/// #         id -> Integer,
+/// #         name -> VarChar,
+/// #     }
+/// # }
+/// #
+/// # fn main() {
+/// #     use self::users::dsl::*;
+/// #     let connection = establish_connection();
+/// use diesel::dsl::false_expr;
+///
+/// let no_users = users.select(name).filter(false_expr());
+/// assert_eq!(Ok(Vec::<String>::new()), no_users.load(&connection));
+///
+/// let tess = users.select(name).filter(false_expr().or(name.eq("Tess")));
+/// assert_eq!(Ok(vec!["Tess".to_string()]), tess.load(&connection));
+/// # }
+/// ```
+pub fn false_expr() -> FalseExpr {
+    FalseExpr
+}
+
+#[derive(Debug, Clone, Copy)]
+/// An expression which is always true. Constructed by
+/// [`true_expr`](../dsl/fn.true_expr.html).
+pub struct TrueExpr;
+
+impl Expression for TrueExpr {
+    type SqlType = Bool;
+}
+
+impl<QS> SelectableExpression<QS> for TrueExpr {}
+
+impl_unchecked_distinct_ordering!(TrueExpr);
+
+impl<QS> AppearsOnTable<QS> for TrueExpr {}
+
+impl NonAggregate for TrueExpr {}
+
+impl<DB: Backend> QueryFragment<DB> for TrueExpr {
+    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+        out.push_sql("1=1");
+        Ok(())
+    }
+}
+
+impl_query_id!(TrueExpr);
+
+#[derive(Debug, Clone, Copy)]
+/// An expression which is always false. Constructed by
+/// [`false_expr`](../dsl/fn.false_expr.html).
+pub struct FalseExpr;
+
+impl Expression for FalseExpr {
+    type SqlType = Bool;
+}
+
+impl<QS> SelectableExpression<QS> for FalseExpr {}
+
+impl_unchecked_distinct_ordering!(FalseExpr);
+
+impl<QS> AppearsOnTable<QS> for FalseExpr {}
+
+impl NonAggregate for FalseExpr {}
+
+impl<DB: Backend> QueryFragment<DB> for FalseExpr {
+    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+        out.push_sql("1=0");
+        Ok(())
+    }
+}
+
+impl_query_id!(FalseExpr);
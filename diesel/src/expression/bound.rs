@@ -48,6 +48,8 @@ where
 {
 }
 
+impl_unchecked_distinct_ordering!(Bound<T, U>);
+
 impl<T, U, QS> AppearsOnTable<QS> for Bound<T, U>
 where
     Bound<T, U>: Expression,
@@ -59,3 +61,51 @@ where
     Bound<T, U>: Expression,
 {
 }
+
+#[derive(Debug, Clone, Copy)]
+/// A bind parameter which carries its SQL type explicitly. On PostgreSQL this
+/// is rendered with a cast (e.g. `$1::integer`), so it can be used in
+/// positions where the server is unable to infer the type of a parameter,
+/// such as a select clause with no `FROM`. Other backends render a plain bind
+/// parameter.
+pub struct TypedBound<T, U> {
+    pub(crate) bound: Bound<T, U>,
+}
+
+impl<T, U> TypedBound<T, U> {
+    pub fn new(item: U) -> Self {
+        TypedBound {
+            bound: Bound::new(item),
+        }
+    }
+}
+
+impl<T, U> Expression for TypedBound<T, U> {
+    type SqlType = T;
+}
+
+impl<T: QueryId, U> QueryId for TypedBound<T, U> {
+    type QueryId = TypedBound<T::QueryId, ()>;
+
+    const HAS_STATIC_QUERY_ID: bool = T::HAS_STATIC_QUERY_ID;
+}
+
+impl<T, U, QS> SelectableExpression<QS> for TypedBound<T, U>
+where
+    TypedBound<T, U>: AppearsOnTable<QS>,
+{
+}
+
+impl_unchecked_distinct_ordering!(TypedBound<T, U>);
+
+impl<T, U, QS> AppearsOnTable<QS> for TypedBound<T, U>
+where
+    TypedBound<T, U>: Expression,
+{
+}
+
+impl<T, U> NonAggregate for TypedBound<T, U>
+where
+    TypedBound<T, U>: Expression,
+{
+}
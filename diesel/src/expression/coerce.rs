@@ -45,6 +45,8 @@ where
 {
 }
 
+impl_unchecked_distinct_ordering!(Coerce<T, ST>);
+
 impl<T, ST, QS> AppearsOnTable<QS> for Coerce<T, ST>
 where
     T: AppearsOnTable<QS>,
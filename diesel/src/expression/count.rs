@@ -1,11 +1,17 @@
 use backend::Backend;
 use query_builder::*;
 use result::QueryResult;
-use super::Expression;
+use super::{AppearsOnTable, Expression, SelectableExpression};
+use super::window::{WindowFunction, WindowFunctionAppearsOnTable};
 use types::BigInt;
 
 /// Creates a SQL `COUNT` expression
 ///
+/// `NULL` values are not counted, so the result is always a `BigInt`. This
+/// also means that columns from the right side of a left join can be counted
+/// without calling `.nullable()` on them. Groups with no matching rows will
+/// have a count of 0.
+///
 /// As with most bare functions, this is not exported by default. You can import
 /// it specifically as `diesel::expression::count`, or glob import
 /// `diesel::dsl::*`
@@ -88,7 +94,27 @@ impl<T: QueryFragment<DB>, DB: Backend> QueryFragment<DB> for Count<T> {
 }
 
 impl_query_id!(Count<T>);
-impl_selectable_expression!(Count<T>);
+
+// `COUNT` skips `NULL` values, so the count of a column from the right side of
+// a left join is still a `BigInt`, and does not need to be made nullable.
+impl<T, QS> SelectableExpression<QS> for Count<T>
+where
+    Count<T>: AppearsOnTable<QS>,
+{
+}
+
+impl_unchecked_distinct_ordering!(Count<T>);
+
+impl<T, QS> AppearsOnTable<QS> for Count<T>
+where
+    Count<T>: Expression,
+    T: AppearsOnTable<QS>,
+{
+}
+
+impl<T> WindowFunction for Count<T> where Count<T>: Expression {}
+
+impl<T, QS> WindowFunctionAppearsOnTable<QS> for Count<T> where Count<T>: AppearsOnTable<QS> {}
 
 #[derive(Debug, Clone, Copy)]
 #[doc(hidden)]
@@ -107,3 +133,7 @@ impl<DB: Backend> QueryFragment<DB> for CountStar {
 
 impl_query_id!(CountStar);
 impl_selectable_expression!(CountStar);
+
+impl WindowFunction for CountStar {}
+
+impl<QS> WindowFunctionAppearsOnTable<QS> for CountStar {}
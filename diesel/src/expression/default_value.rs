@@ -0,0 +1,85 @@
+use backend::{Backend, SupportsDefaultKeyword};
+use query_builder::{AsChangeset, AstPass, Changeset};
+use query_source::Column;
+use result::QueryResult;
+
+/// Marks a column which has a default value in the database, so that it can
+/// be reset with [`set_default`](dsl/fn.set_default.html).
+///
+/// `table!` does not know which columns have a default, so this has to be
+/// implemented for each of them, e.g.
+/// `impl HasDefault for users::created_at {}`.
+pub trait HasDefault: Column {}
+
+/// Sets a column to its default value, rendering `column = DEFAULT` in the
+/// `SET` clause of an `UPDATE`. The result is a changeset, which can be passed
+/// to `.set` on its own or as part of a tuple.
+///
+/// Only columns which implement [`HasDefault`](../trait.HasDefault.html) can
+/// be reset. SQLite does not support `DEFAULT` in updates, so queries using
+/// this will not compile against `SqliteConnection`.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate diesel;
+/// # include!("../doctest_setup.rs");
+/// #
+/// # table! {
+/// #     users {
+/// #         id -> Integer,
+/// #         name -> VarChar,
+/// #     }
+/// # }
+/// #
+/// impl diesel::expression::HasDefault for users::name {}
+///
+/// # #[cfg(feature = "postgres")]
+/// # fn main() {
+/// #     use self::users::dsl::*;
+/// #     use diesel::dsl::set_default;
+/// #     let connection = establish_connection();
+/// #     connection.execute("ALTER TABLE users ALTER COLUMN name SET DEFAULT 'Anonymous'").unwrap();
+/// diesel::update(users.filter(id.eq(1)))
+///     .set(set_default(name))
+///     .execute(&connection)
+///     .unwrap();
+///
+/// let names = users.select(name).order(id).load::<String>(&connection);
+/// assert_eq!(Ok(vec!["Anonymous".to_string(), "Tess".to_string()]), names);
+/// # }
+/// # #[cfg(not(feature = "postgres"))]
+/// # fn main() {}
+/// ```
+pub fn set_default<C: HasDefault>(column: C) -> SetDefault<C> {
+    SetDefault(column)
+}
+
+#[derive(Debug, Clone, Copy)]
+/// The return type of `set_default`.
+pub struct SetDefault<C>(C);
+
+impl<C: HasDefault> AsChangeset for SetDefault<C> {
+    type Target = C::Table;
+    type Changeset = Self;
+
+    fn as_changeset(self) -> Self {
+        self
+    }
+}
+
+impl<C, DB> Changeset<DB> for SetDefault<C>
+where
+    C: Column,
+    DB: Backend + SupportsDefaultKeyword,
+{
+    fn is_noop(&self) -> bool {
+        false
+    }
+
+    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+        try!(out.push_identifier(C::NAME));
+        out.push_sql(" = DEFAULT");
+        Ok(())
+    }
+}
@@ -0,0 +1,62 @@
+use backend::Backend;
+use expression::{Expression, NonAggregate};
+use query_builder::*;
+use result::QueryResult;
+use types::Bool;
+
+/// Renders `left = right` when `right` is present, or `left IS NULL`
+/// otherwise. Constructed by
+/// [`ExpressionMethods::eq_opt`](../expression_methods/trait.ExpressionMethods.html#method.eq_opt).
+#[derive(Debug, Clone, Copy)]
+pub struct EqOpt<T, U> {
+    left: T,
+    right: Option<U>,
+}
+
+impl<T, U> EqOpt<T, U> {
+    pub fn new(left: T, right: Option<U>) -> Self {
+        EqOpt {
+            left: left,
+            right: right,
+        }
+    }
+}
+
+impl<T, U> Expression for EqOpt<T, U>
+where
+    T: Expression,
+    U: Expression,
+{
+    type SqlType = Bool;
+}
+
+impl<T, U> NonAggregate for EqOpt<T, U>
+where
+    T: NonAggregate,
+    U: NonAggregate,
+{
+}
+
+impl<T, U, DB> QueryFragment<DB> for EqOpt<T, U>
+where
+    DB: Backend,
+    T: QueryFragment<DB>,
+    U: QueryFragment<DB>,
+{
+    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+        self.left.walk_ast(out.reborrow())?;
+        match self.right {
+            Some(ref right) => {
+                out.push_sql(" = ");
+                right.walk_ast(out.reborrow())?;
+            }
+            None => out.push_sql(" IS NULL"),
+        }
+        Ok(())
+    }
+}
+
+// The SQL depends on whether `right` is present, so the type alone does not
+// identify the query
+impl_query_id!(noop: EqOpt<T, U>);
+impl_selectable_expression!(EqOpt<T, U>);
@@ -0,0 +1,124 @@
+use backend::Backend;
+use expression::{AppearsOnTable, Expression};
+use expression::window::{WindowFunction, WindowFunctionAppearsOnTable};
+use query_builder::*;
+use result::QueryResult;
+use types::{Bool, IntoNullable, Nullable};
+
+macro_rules! bool_function {
+    ($fn_name:ident, $type_name:ident, $docs:expr) => {
+        #[doc=$docs]
+        pub fn $fn_name<T>(t: T) -> $type_name<T> where
+            T: Expression,
+            T::SqlType: IntoNullable<Nullable=Nullable<Bool>>,
+        {
+            $type_name {
+                target: t,
+            }
+        }
+
+        #[derive(Debug, Clone, Copy)]
+        #[doc(hidden)]
+        pub struct $type_name<T> {
+            target: T,
+        }
+
+        impl<T> $type_name<T> {
+            pub(crate) fn walk_with_function<DB>(
+                &self,
+                mut out: AstPass<DB>,
+                function_name: &str,
+            ) -> QueryResult<()>
+            where
+                DB: Backend,
+                T: QueryFragment<DB>,
+            {
+                out.push_sql(function_name);
+                out.push_sql("(");
+                self.target.walk_ast(out.reborrow())?;
+                out.push_sql(")");
+                Ok(())
+            }
+        }
+
+        impl<T: Expression> Expression for $type_name<T> {
+            type SqlType = Nullable<Bool>;
+        }
+
+        impl_query_id!($type_name<T>);
+        impl_selectable_expression!($type_name<T>);
+
+        impl<T> WindowFunction for $type_name<T> where $type_name<T>: Expression {}
+
+        impl<T, QS> WindowFunctionAppearsOnTable<QS> for $type_name<T> where
+            $type_name<T>: AppearsOnTable<QS>,
+        {
+        }
+    }
+}
+
+bool_function!(
+    bool_and,
+    BoolAnd,
+    "Represents the SQL `BOOL_AND` aggregate function, which is true if all
+values in the group are true. `NULL` values are skipped. The result is `NULL`
+if there are no non-null values.
+
+MySQL and SQLite store booleans as integers, so this is rendered as `MIN` on
+those backends.
+
+# Examples
+
+```rust
+# #[macro_use] extern crate diesel;
+# include!(\"../../doctest_setup.rs\");
+# use diesel::dsl::*;
+#
+# table! {
+#     users {
+#         id -> Integer,
+#         name -> VarChar,
+#     }
+# }
+#
+# fn main() {
+#     use self::users::dsl::*;
+#     let connection = establish_connection();
+let all_named_sean = users.select(bool_and(name.eq(\"Sean\"))).get_result(&connection);
+assert_eq!(Ok(Some(false)), all_named_sean);
+# }
+```"
+);
+
+bool_function!(
+    bool_or,
+    BoolOr,
+    "Represents the SQL `BOOL_OR` aggregate function, which is true if any
+value in the group is true. `NULL` values are skipped. The result is `NULL` if
+there are no non-null values.
+
+MySQL and SQLite store booleans as integers, so this is rendered as `MAX` on
+those backends.
+
+# Examples
+
+```rust
+# #[macro_use] extern crate diesel;
+# include!(\"../../doctest_setup.rs\");
+# use diesel::dsl::*;
+#
+# table! {
+#     users {
+#         id -> Integer,
+#         name -> VarChar,
+#     }
+# }
+#
+# fn main() {
+#     use self::users::dsl::*;
+#     let connection = establish_connection();
+let any_named_sean = users.select(bool_or(name.eq(\"Sean\"))).get_result(&connection);
+assert_eq!(Ok(Some(true)), any_named_sean);
+# }
+```"
+);
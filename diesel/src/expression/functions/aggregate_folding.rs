@@ -1,5 +1,6 @@
 use backend::Backend;
-use expression::Expression;
+use expression::{AppearsOnTable, Expression};
+use expression::window::{WindowFunction, WindowFunctionAppearsOnTable};
 use query_builder::*;
 use result::QueryResult;
 use types::{Foldable, HasSqlType};
@@ -43,6 +44,13 @@ macro_rules! fold_function {
 
         impl_query_id!($type_name<T>);
         impl_selectable_expression!($type_name<T>);
+
+        impl<T> WindowFunction for $type_name<T> where $type_name<T>: Expression {}
+
+        impl<T, QS> WindowFunctionAppearsOnTable<QS> for $type_name<T> where
+            $type_name<T>: AppearsOnTable<QS>,
+        {
+        }
     }
 }
 
@@ -53,6 +61,18 @@ fold_function!(
     "Represents a SQL `SUM` function. This function can only take types which are
 Foldable.
 
+The result type follows the rules of PostgreSQL, which widens integers so
+that the sum cannot overflow. The result is always nullable, as the sum of
+no rows is `NULL`.
+
+| Argument | Result |
+|----------|--------|
+| `SmallInt`, `Integer` | `BigInt` |
+| `BigInt`, `Numeric` | `Numeric` |
+| `Float` | `Float` |
+| `Double` | `Double` |
+| `Interval` | `Interval` |
+
 # Examples
 
 ```rust
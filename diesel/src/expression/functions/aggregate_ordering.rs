@@ -1,5 +1,6 @@
 use backend::Backend;
-use expression::Expression;
+use expression::{AppearsOnTable, Expression};
+use expression::window::{WindowFunction, WindowFunctionAppearsOnTable};
 use query_builder::*;
 use result::QueryResult;
 use types::{HasSqlType, IntoNullable, SqlOrd};
@@ -42,6 +43,13 @@ macro_rules! ord_function {
 
         impl_query_id!($type_name<T>);
         impl_selectable_expression!($type_name<T>);
+
+        impl<T> WindowFunction for $type_name<T> where $type_name<T>: Expression {}
+
+        impl<T, QS> WindowFunctionAppearsOnTable<QS> for $type_name<T> where
+            $type_name<T>: AppearsOnTable<QS>,
+        {
+        }
     }
 }
 
@@ -52,6 +60,8 @@ ord_function!(
     "Represents a SQL `MAX` function. This function can only take types which are
 ordered.
 
+The result is nullable, as it is `NULL` when there are no rows.
+
 # Examples
 
 ```rust
@@ -81,6 +91,8 @@ ord_function!(
     "Represents a SQL `MIN` function. This function can only take types which are
 ordered.
 
+The result is nullable, as it is `NULL` when there are no rows.
+
 # Examples
 
 ```rust
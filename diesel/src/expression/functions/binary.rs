@@ -0,0 +1,137 @@
+use backend::Backend;
+use expression::{AsExpression, Expression, NonAggregate};
+use query_builder::*;
+use result::QueryResult;
+use types::*;
+
+sql_function!(octet_length, octet_length_t, (x: Binary) -> Integer,
+"Represents the SQL `OCTET_LENGTH` function. Returns the number of bytes in
+the given binary value.
+
+This function is not available on SQLite before 3.43. On older versions,
+`LENGTH` returns the number of bytes in a binary value.
+
+# Examples
+
+```rust
+# #[macro_use] extern crate diesel;
+# include!(\"../../doctest_setup.rs\");
+# use diesel::dsl::*;
+# use diesel::types::Binary;
+#
+# table! {
+#     users {
+#         id -> Integer,
+#         name -> VarChar,
+#     }
+# }
+#
+# #[cfg(not(feature = \"sqlite\"))]
+# fn main() {
+#     let connection = establish_connection();
+let data = vec![0u8; 1024];
+let len = diesel::select(octet_length(data.into_sql::<Binary>())).get_result(&connection);
+assert_eq!(Ok(1024), len);
+# }
+# #[cfg(feature = \"sqlite\")]
+# fn main() {}
+```
+");
+
+/// Represents the SQL `SUBSTR` function for text and binary values. Returns
+/// `len` characters (or bytes, for binary values) of the given value, starting
+/// from the one at position `start`. The first one is at position 1.
+///
+/// `SUBSTR` is used rather than `SUBSTRING`, since older versions of SQLite
+/// only support the former.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate diesel;
+/// # include!("../../doctest_setup.rs");
+/// # use diesel::dsl::*;
+/// # use diesel::types::Binary;
+/// #
+/// # table! {
+/// #     users {
+/// #         id -> Integer,
+/// #         name -> VarChar,
+/// #     }
+/// # }
+/// #
+/// # fn main() {
+/// #     use self::users::dsl::*;
+/// #     let connection = establish_connection();
+/// let data = vec![1u8, 2, 3, 4];
+/// let bytes = diesel::select(substring(data.into_sql::<Binary>(), 2, 2)).get_result(&connection);
+/// assert_eq!(Ok(vec![2u8, 3]), bytes);
+///
+/// let initials = users.select(substring(name, 1, 1)).order(id).load(&connection);
+/// assert_eq!(Ok(vec!["S".to_string(), "T".to_string()]), initials);
+/// # }
+/// ```
+pub fn substring<X, S, L>(x: X, start: S, len: L) -> Substring<X, S::Expression, L::Expression>
+where
+    X: Expression,
+    X::SqlType: SubstringType,
+    S: AsExpression<Integer>,
+    L: AsExpression<Integer>,
+{
+    Substring {
+        x: x,
+        start: start.as_expression(),
+        len: len.as_expression(),
+    }
+}
+
+/// The SQL types which [`substring`](fn.substring.html) can be used
+/// with.
+pub trait SubstringType {}
+
+impl SubstringType for Text {}
+impl SubstringType for Binary {}
+impl<T: SubstringType + NotNull> SubstringType for Nullable<T> {}
+
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct Substring<X, S, L> {
+    x: X,
+    start: S,
+    len: L,
+}
+
+impl<X: Expression, S, L> Expression for Substring<X, S, L> {
+    type SqlType = X::SqlType;
+}
+
+impl<X, S, L, DB> QueryFragment<DB> for Substring<X, S, L>
+where
+    DB: Backend,
+    X: QueryFragment<DB>,
+    S: QueryFragment<DB>,
+    L: QueryFragment<DB>,
+{
+    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+        out.push_sql("substr(");
+        self.x.walk_ast(out.reborrow())?;
+        out.push_sql(", ");
+        self.start.walk_ast(out.reborrow())?;
+        out.push_sql(", ");
+        self.len.walk_ast(out.reborrow())?;
+        out.push_sql(")");
+        Ok(())
+    }
+}
+
+impl_query_id!(Substring<X, S, L>);
+impl_selectable_expression!(Substring<X, S, L>);
+
+impl<X, S, L> NonAggregate for Substring<X, S, L>
+where
+    X: NonAggregate,
+    S: NonAggregate,
+    L: NonAggregate,
+    Self: Expression,
+{
+}
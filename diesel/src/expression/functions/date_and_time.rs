@@ -2,6 +2,7 @@ use backend::Backend;
 use expression::{Expression, NonAggregate};
 use query_builder::*;
 use result::QueryResult;
+use result::Error::QueryBuilderError;
 use types::*;
 
 /// Represents the SQL `CURRENT_TIMESTAMP` constant. This is equivalent to the
@@ -77,3 +78,169 @@ impl AsExpression<Nullable<Timestamptz>> for now {
         Coerce::new(self)
     }
 }
+
+/// A part of a date or time which can be retrieved with
+/// [`extract`](fn.extract.html).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DatePart {
+    /// The year
+    Year,
+    /// The month, from 1 to 12
+    Month,
+    /// The day of the month, from 1 to 31
+    Day,
+    /// The hour, from 0 to 23
+    Hour,
+    /// The minute, from 0 to 59
+    Minute,
+    /// The seconds, including fractional seconds
+    Second,
+    /// The day of the week, from 0 (Sunday) to 6 (Saturday)
+    DayOfWeek,
+    /// The day of the year, from 1 to 366
+    DayOfYear,
+    /// The number of seconds since 1970-01-01 00:00:00 UTC
+    Epoch,
+}
+
+/// Types which [`extract`](fn.extract.html) can retrieve a part of.
+pub trait ExtractFrom {
+    /// The type of the extracted part. This is `Nullable<Double>` when the
+    /// argument is nullable, and `Double` otherwise.
+    type Output;
+
+    /// Whether `part` can be retrieved from this type.
+    fn can_extract(_part: DatePart) -> bool {
+        true
+    }
+}
+
+impl ExtractFrom for Date {
+    type Output = Double;
+}
+
+/// Only the hour, minute and second can be retrieved from a time.
+impl ExtractFrom for Time {
+    type Output = Double;
+
+    fn can_extract(part: DatePart) -> bool {
+        match part {
+            DatePart::Hour | DatePart::Minute | DatePart::Second => true,
+            _ => false,
+        }
+    }
+}
+
+impl ExtractFrom for Timestamp {
+    type Output = Double;
+}
+
+#[cfg(feature = "postgres")]
+impl ExtractFrom for Timestamptz {
+    type Output = Double;
+}
+
+impl<T> ExtractFrom for Nullable<T>
+where
+    T: ExtractFrom + NotNull,
+{
+    type Output = Nullable<Double>;
+
+    fn can_extract(part: DatePart) -> bool {
+        T::can_extract(part)
+    }
+}
+
+/// Retrieves a part of a date or time, such as its year. This is mostly used
+/// to group rows by month or year.
+///
+/// This is rendered as `EXTRACT(part FROM expr)` on MySQL. On PostgreSQL it
+/// is rendered as the equivalent `date_part('part', expr)`, which returns a
+/// `double precision` on every version. On SQLite, `strftime` is used. The
+/// part is written into the query, so the same expression can be used in the
+/// select clause and in the group by clause. On SQLite the epoch does not
+/// include fractional seconds.
+///
+/// Only the hour, minute and second can be retrieved from a `Time`. Running a
+/// query which retrieves another part of one returns a `QueryBuilderError`.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate diesel;
+/// # include!("../../doctest_setup.rs");
+/// # use diesel::dsl::*;
+/// # use diesel::types::Timestamp;
+/// #
+/// # table! {
+/// #     users {
+/// #         id -> Integer,
+/// #         name -> VarChar,
+/// #     }
+/// # }
+/// #
+/// # fn main() {
+/// #     let connection = establish_connection();
+/// # #[cfg(feature = "sqlite")]
+/// # let timestamp = sql::<Timestamp>("'2017-07-23 12:34:56'");
+/// # #[cfg(not(feature = "sqlite"))]
+/// # let timestamp = sql::<Timestamp>("TIMESTAMP '2017-07-23 12:34:56'");
+/// // `timestamp` is 2017-07-23 12:34:56, which was a Sunday
+/// let parts = diesel::select((
+///     extract(DatePart::Year, timestamp.clone()),
+///     extract(DatePart::Month, timestamp.clone()),
+///     extract(DatePart::DayOfWeek, timestamp),
+/// )).get_result(&connection);
+/// assert_eq!(Ok((2017.0, 7.0, 0.0)), parts);
+/// # }
+/// ```
+pub fn extract<T>(part: DatePart, expr: T) -> Extract<T>
+where
+    T: Expression,
+    T::SqlType: ExtractFrom,
+{
+    Extract {
+        part: part,
+        expr: expr,
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+/// The return type of [`extract`](fn.extract.html).
+pub struct Extract<T> {
+    pub(crate) part: DatePart,
+    pub(crate) expr: T,
+}
+
+impl<T> Extract<T>
+where
+    T: Expression,
+    T::SqlType: ExtractFrom,
+{
+    pub(crate) fn check_part(&self) -> QueryResult<()> {
+        if <T::SqlType as ExtractFrom>::can_extract(self.part) {
+            Ok(())
+        } else {
+            Err(QueryBuilderError(
+                format!("{:?} can not be extracted from this type", self.part).into(),
+            ))
+        }
+    }
+}
+
+impl<T> Expression for Extract<T>
+where
+    T: Expression,
+    T::SqlType: ExtractFrom,
+{
+    type SqlType = <T::SqlType as ExtractFrom>::Output;
+}
+
+impl<T: NonAggregate> NonAggregate for Extract<T>
+where
+    Extract<T>: Expression,
+{
+}
+
+impl_query_id!(noop: Extract<T>);
+impl_selectable_expression!(Extract<T>);
@@ -69,6 +69,8 @@ macro_rules! sql_function_body {
             $struct_name<$($arg_name),*>: $crate::expression::Expression,
         {
         }
+
+        impl_unchecked_distinct_ordering!($struct_name<$($arg_name),+>);
     }
 }
 
@@ -137,6 +139,7 @@ macro_rules! no_arg_sql_function_body_except_to_sql {
         }
 
         impl_query_id!($type_name);
+        impl_unchecked_distinct_ordering!($type_name);
     }
 }
 
@@ -199,6 +202,10 @@ macro_rules! no_arg_sql_function {
     };
 }
 
+pub mod aggregate_boolean;
 pub mod aggregate_ordering;
 pub mod aggregate_folding;
+pub mod binary;
 pub mod date_and_time;
+pub mod string;
+pub mod string_agg;
@@ -0,0 +1,126 @@
+use types::*;
+
+sql_function!(lower, lower_t, (x: Text) -> Text,
+"Represents the SQL `LOWER` function. Converts the given string to lower
+case.
+
+# Examples
+
+```rust
+# #[macro_use] extern crate diesel;
+# include!(\"../../doctest_setup.rs\");
+# use diesel::dsl::*;
+#
+# table! {
+#     users {
+#         id -> Integer,
+#         name -> VarChar,
+#     }
+# }
+#
+# fn main() {
+#     let connection = establish_connection();
+let sean = users::table
+    .select(users::name)
+    .filter(lower(users::name).eq(\"sean\"))
+    .get_result(&connection);
+assert_eq!(Ok(String::from(\"Sean\")), sean);
+# }
+```
+");
+
+sql_function!(upper, upper_t, (x: Text) -> Text,
+"Represents the SQL `UPPER` function. Converts the given string to upper
+case.
+
+# Examples
+
+```rust
+# #[macro_use] extern crate diesel;
+# include!(\"../../doctest_setup.rs\");
+# use diesel::dsl::*;
+#
+# table! {
+#     users {
+#         id -> Integer,
+#         name -> VarChar,
+#     }
+# }
+#
+# fn main() {
+#     let connection = establish_connection();
+let names = users::table
+    .select(upper(users::name))
+    .order(users::id)
+    .load(&connection);
+assert_eq!(Ok(vec![String::from(\"SEAN\"), String::from(\"TESS\")]), names);
+# }
+```
+");
+
+sql_function!(trim, trim_t, (x: Text) -> Text,
+"Represents the SQL `TRIM` function. Removes whitespace from both ends of
+the given string.
+
+# Examples
+
+```rust
+# #[macro_use] extern crate diesel;
+# include!(\"../../doctest_setup.rs\");
+# use diesel::dsl::*;
+#
+# table! {
+#     users {
+#         id -> Integer,
+#         name -> VarChar,
+#     }
+# }
+#
+# fn main() {
+#     let connection = establish_connection();
+let trimmed = diesel::select(trim(\"  Sean  \")).get_result(&connection);
+assert_eq!(Ok(String::from(\"Sean\")), trimmed);
+# }
+```
+");
+
+sql_function!(ltrim, ltrim_t, (x: Text) -> Text,
+"Represents the SQL `LTRIM` function. Removes whitespace from the start of
+the given string.");
+
+sql_function!(rtrim, rtrim_t, (x: Text) -> Text,
+"Represents the SQL `RTRIM` function. Removes whitespace from the end of
+the given string.");
+
+sql_function!(length, length_t, (x: Text) -> Integer,
+"Represents the SQL `LENGTH` function. Returns the length of the given
+string.
+
+On PostgreSQL and SQLite this is the number of characters in the string. On
+MySQL, `LENGTH` returns the number of bytes, so strings containing multibyte
+characters will have a larger length than expected.
+
+# Examples
+
+```rust
+# #[macro_use] extern crate diesel;
+# include!(\"../../doctest_setup.rs\");
+# use diesel::dsl::*;
+#
+# table! {
+#     users {
+#         id -> Integer,
+#         name -> VarChar,
+#     }
+# }
+#
+# fn main() {
+#     let connection = establish_connection();
+let lengths = users::table
+    .select(length(users::name))
+    .order(users::id)
+    .load(&connection);
+assert_eq!(Ok(vec![4, 4]), lengths);
+# }
+```
+");
@@ -0,0 +1,162 @@
+use expression::{AppearsOnTable, Expression, SelectableExpression};
+use expression::window::{WindowFunction, WindowFunctionAppearsOnTable};
+use query_builder::{QueryId, SelectsColumn, ValidOrderTermForDistinct};
+use query_builder::order_clause::{NoOrderClause, OrderClause};
+use query_source::Never;
+use types::{IntoNullable, Nullable, Text};
+
+/// Represents the SQL `STRING_AGG` function, which concatenates the values of
+/// a text expression in a group, separated by `delimiter`. `NULL` values are
+/// skipped. The result is `NULL` if there are no non-null values.
+///
+/// On MySQL and SQLite this is rendered as `GROUP_CONCAT`. The delimiter is
+/// always sent as a bind parameter. MySQL does not allow a parameter as the
+/// `SEPARATOR` of `GROUP_CONCAT`, so there each value is prefixed with the
+/// delimiter instead, and the leading one is removed from the result.
+///
+/// The order of the values can be specified with
+/// [`.order_by`](struct.StringAgg.html#method.order_by). This is not
+/// supported on SQLite.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[macro_use] extern crate diesel;
+/// # include!("../../doctest_setup.rs");
+/// # use diesel::dsl::*;
+/// #
+/// # table! {
+/// #     users {
+/// #         id -> Integer,
+/// #         name -> VarChar,
+/// #     }
+/// # }
+/// #
+/// # fn main() {
+/// #     use self::users::dsl::*;
+/// #     let connection = establish_connection();
+/// let names = users
+///     .select(string_agg(name, ", "))
+///     .filter(id.eq(1))
+///     .get_result(&connection);
+/// assert_eq!(Ok(Some(String::from("Sean"))), names);
+/// # }
+/// ```
+pub fn string_agg<T>(expr: T, delimiter: &str) -> StringAgg<T, NoOrderClause>
+where
+    T: Expression,
+    T::SqlType: IntoNullable<Nullable = Nullable<Text>>,
+{
+    StringAgg {
+        expr: expr,
+        delimiter: delimiter,
+        order: NoOrderClause,
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+/// The return type of [`string_agg`](fn.string_agg.html).
+pub struct StringAgg<'a, T, O> {
+    pub(crate) expr: T,
+    pub(crate) delimiter: &'a str,
+    pub(crate) order: O,
+}
+
+impl<'a, T> StringAgg<'a, T, NoOrderClause> {
+    /// Concatenates the values in the given order, rendered as
+    /// `STRING_AGG(expr, delimiter ORDER BY order)`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate diesel;
+    /// # include!("../../doctest_setup.rs");
+    /// # use diesel::dsl::*;
+    /// #
+    /// # table! {
+    /// #     users {
+    /// #         id -> Integer,
+    /// #         name -> VarChar,
+    /// #     }
+    /// # }
+    /// #
+    /// # #[cfg(not(feature = "sqlite"))]
+    /// # fn main() {
+    /// #     use self::users::dsl::*;
+    /// #     let connection = establish_connection();
+    /// let names = users
+    ///     .select(string_agg(name, ", ").order_by(name.desc()))
+    ///     .get_result(&connection);
+    /// assert_eq!(Ok(Some(String::from("Tess, Sean"))), names);
+    /// # }
+    /// # #[cfg(feature = "sqlite")]
+    /// # fn main() {}
+    /// ```
+    pub fn order_by<O: Expression>(self, order: O) -> StringAgg<'a, T, OrderClause<O>> {
+        StringAgg {
+            expr: self.expr,
+            delimiter: self.delimiter,
+            order: OrderClause(order),
+        }
+    }
+}
+
+impl<'a, T: Expression, O> Expression for StringAgg<'a, T, O> {
+    type SqlType = Nullable<Text>;
+}
+
+impl<'a, T: QueryId, O: QueryId> QueryId for StringAgg<'a, T, O> {
+    type QueryId = StringAgg<'static, T::QueryId, O::QueryId>;
+
+    const HAS_STATIC_QUERY_ID: bool = T::HAS_STATIC_QUERY_ID && O::HAS_STATIC_QUERY_ID;
+}
+
+impl<'a, T, QS> SelectableExpression<QS> for StringAgg<'a, T, NoOrderClause>
+where
+    T: SelectableExpression<QS>,
+    Self: AppearsOnTable<QS>,
+{
+}
+
+impl<'a, T, QS> AppearsOnTable<QS> for StringAgg<'a, T, NoOrderClause>
+where
+    T: AppearsOnTable<QS>,
+    Self: Expression,
+{
+}
+
+// PostgreSQL does not support `ORDER BY` in an aggregate used as a window
+// function
+impl<'a, T> WindowFunction for StringAgg<'a, T, NoOrderClause>
+where
+    Self: Expression,
+{
+}
+
+impl<'a, T, QS> WindowFunctionAppearsOnTable<QS> for StringAgg<'a, T, NoOrderClause>
+where
+    Self: AppearsOnTable<QS>,
+{
+}
+
+impl<'a, T, O, QS> SelectableExpression<QS> for StringAgg<'a, T, OrderClause<O>>
+where
+    T: SelectableExpression<QS>,
+    O: SelectableExpression<QS>,
+    Self: AppearsOnTable<QS>,
+{
+}
+
+impl<'a, T, O, QS> AppearsOnTable<QS> for StringAgg<'a, T, OrderClause<O>>
+where
+    T: AppearsOnTable<QS>,
+    O: AppearsOnTable<QS>,
+    Self: Expression,
+{
+}
+
+impl<'a, T, O, C> SelectsColumn<C> for StringAgg<'a, T, O> {
+    type Count = Never;
+}
+
+impl<'a, T, O, Selection> ValidOrderTermForDistinct<Selection> for StringAgg<'a, T, O> {}
@@ -17,6 +17,10 @@ impl<T: QueryFragment<DB>, DB: Backend> QueryFragment<DB> for Grouped<T> {
         out.push_sql(")");
         Ok(())
     }
+
+    fn is_known_empty(&self) -> bool {
+        self.0.is_known_empty()
+    }
 }
 
 impl_query_id!(Grouped<T>);
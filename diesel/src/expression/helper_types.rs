@@ -17,6 +17,9 @@ pub type AsExprOf<Item, Type> = <Item as AsExpression<Type>>::Expression;
 /// The return type of `lhs.eq(rhs)`
 pub type Eq<Lhs, Rhs> = super::operators::Eq<Lhs, AsExpr<Rhs, Lhs>>;
 
+/// The return type of `lhs.eq_opt(Some(rhs))`
+pub type EqOpt<Lhs, Rhs> = super::eq_opt::EqOpt<Lhs, AsExpr<Rhs, Lhs>>;
+
 /// The return type of `lhs.ne(rhs)`
 pub type NotEq<Lhs, Rhs> = super::operators::NotEq<Lhs, AsExpr<Rhs, Lhs>>;
 
@@ -35,12 +38,30 @@ pub type LtEq<Lhs, Rhs> = super::operators::LtEq<Lhs, AsExpr<Rhs, Lhs>>;
 /// The return type of `lhs.and(rhs)`
 pub type And<Lhs, Rhs> = super::operators::And<Lhs, AsExprOf<Rhs, types::Bool>>;
 
+/// The return type of `lhs.bitand(rhs)`
+pub type BitAnd<Lhs, Rhs> = super::ops::BitAnd<Lhs, AsExpr<Rhs, Lhs>>;
+
+/// The return type of `lhs.bitor(rhs)`
+pub type BitOr<Lhs, Rhs> = super::ops::BitOr<Lhs, AsExpr<Rhs, Lhs>>;
+
+/// The return type of `lhs.bitxor(rhs)`
+pub type BitXor<Lhs, Rhs> = super::ops::BitXor<Lhs, AsExpr<Rhs, Lhs>>;
+
+/// The return type of `lhs.bitshift_left(bits)`
+pub type BitShiftLeft<Lhs, Rhs> = super::ops::BitShiftLeft<Lhs, AsExprOf<Rhs, types::Integer>>;
+
+/// The return type of `lhs.bitshift_right(bits)`
+pub type BitShiftRight<Lhs, Rhs> = super::ops::BitShiftRight<Lhs, AsExprOf<Rhs, types::Integer>>;
+
 /// The return type of `lhs.like(rhs)`
 pub type Like<Lhs, Rhs> = super::operators::Like<Lhs, AsExprOf<Rhs, types::VarChar>>;
 
 /// The return type of `lhs.not_like(rhs)`
 pub type NotLike<Lhs, Rhs> = super::operators::NotLike<Lhs, AsExprOf<Rhs, types::VarChar>>;
 
+/// The return type of `expr.icontains(substring)`
+pub type IContains<Expr> = super::icontains::IContains<Expr>;
+
 /// The return type of `lhs.between(rhs..rhs)`
 pub type Between<Lhs, Rhs> = super::operators::Between<
     Lhs,
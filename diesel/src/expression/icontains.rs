@@ -0,0 +1,45 @@
+use expression::{Expression, NonAggregate};
+use types::Bool;
+
+/// Checks whether a text expression contains a substring, ignoring case.
+/// Constructed by
+/// [`TextExpressionMethods::icontains`](../expression_methods/trait.TextExpressionMethods.html#method.icontains).
+///
+/// This is rendered as `ILIKE` on PostgreSQL, and by comparing both sides in
+/// lower case with `LIKE` on other backends.
+#[derive(Debug, Clone)]
+pub struct IContains<T> {
+    pub(crate) expr: T,
+    pub(crate) pattern: String,
+}
+
+impl<T> IContains<T> {
+    pub fn new(expr: T, substring: &str) -> Self {
+        IContains {
+            expr: expr,
+            pattern: format!("%{}%", escape_like_pattern(substring)),
+        }
+    }
+}
+
+impl<T: Expression> Expression for IContains<T> {
+    type SqlType = Bool;
+}
+
+impl<T: NonAggregate> NonAggregate for IContains<T> {}
+
+impl_query_id!(IContains<T>);
+impl_selectable_expression!(IContains<T>);
+
+/// Escapes `%`, `_`, and the escape character `\` itself, so that `LIKE`
+/// matches them literally.
+fn escape_like_pattern(substring: &str) -> String {
+    let mut escaped = String::with_capacity(substring.len());
+    for c in substring.chars() {
+        if c == '%' || c == '_' || c == '\\' {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
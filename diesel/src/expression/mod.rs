@@ -17,15 +17,25 @@
 #[doc(hidden)]
 pub mod ops;
 
+#[doc(hidden)]
+pub mod aliased;
 #[doc(hidden)]
 pub mod array_comparison;
 #[doc(hidden)]
+pub mod assume_not_null;
+#[doc(hidden)]
+pub mod bool_literals;
+#[doc(hidden)]
 pub mod bound;
 #[doc(hidden)]
 pub mod coerce;
 #[doc(hidden)]
 pub mod count;
 #[doc(hidden)]
+pub mod default_value;
+#[doc(hidden)]
+pub mod eq_opt;
+#[doc(hidden)]
 pub mod exists;
 #[doc(hidden)]
 #[macro_use]
@@ -34,37 +44,63 @@ pub mod functions;
 pub mod grouped;
 #[macro_use]
 pub mod helper_types;
+#[doc(hidden)]
+pub mod icontains;
 mod not;
 #[doc(hidden)]
+pub mod null;
+#[doc(hidden)]
 pub mod nullable;
 #[doc(hidden)]
 #[macro_use]
 pub mod operators;
 #[doc(hidden)]
+pub mod order_by_values;
+#[doc(hidden)]
 pub mod sql_literal;
+mod named_bind;
 mod unchecked_bind;
+pub mod window;
 
 #[doc(hidden)]
 pub mod dsl {
+    #[doc(inline)]
+    pub use super::bool_literals::{false_expr, true_expr};
     #[doc(inline)]
     pub use super::count::{count, count_star};
     #[doc(inline)]
+    pub use super::default_value::set_default;
+    #[doc(inline)]
     pub use super::exists::exists;
     #[doc(inline)]
+    pub use super::functions::aggregate_boolean::*;
+    #[doc(inline)]
     pub use super::functions::aggregate_folding::*;
     #[doc(inline)]
     pub use super::functions::aggregate_ordering::*;
     #[doc(inline)]
+    pub use super::functions::binary::*;
+    #[doc(inline)]
     pub use super::functions::date_and_time::*;
     #[doc(inline)]
+    pub use super::functions::string::*;
+    #[doc(inline)]
+    pub use super::functions::string_agg::string_agg;
+    #[doc(inline)]
     pub use super::not::not;
     #[doc(inline)]
+    pub use super::null::null;
+    #[doc(inline)]
     pub use super::sql_literal::sql;
+    #[doc(inline)]
+    pub use super::window::{dense_rank, rank, row_number, window};
 
     #[cfg(feature = "postgres")]
     pub use pg::expression::dsl::*;
 }
 
+#[doc(inline)]
+pub use self::default_value::HasDefault;
 #[doc(inline)]
 pub use self::sql_literal::SqlLiteral;
 
@@ -112,6 +148,47 @@ impl<T: Expression> AsExpression<T::SqlType> for T {
     }
 }
 
+/// Converts a Rust value to a bind parameter with an explicit SQL type.
+///
+/// This trait is automatically implemented for all types. It is useful when
+/// the SQL type of a value can't be inferred from the expression it is used
+/// in, such as when passing it to [`select`](../fn.select.html) with no table.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate diesel;
+/// # include!("../doctest_setup.rs");
+/// # use diesel::types::Integer;
+/// #
+/// # table! {
+/// #     users {
+/// #         id -> Integer,
+/// #         name -> VarChar,
+/// #     }
+/// # }
+/// #
+/// # fn main() {
+/// #     let connection = establish_connection();
+/// let one = diesel::select(1.into_sql::<Integer>()).get_result(&connection);
+/// assert_eq!(Ok(1), one);
+/// # }
+/// ```
+pub trait IntoSql {
+    /// Convert `self` to a bind parameter of the SQL type `T`.
+    ///
+    /// On PostgreSQL the bind parameter is rendered with an explicit cast to
+    /// `T` (e.g. `$1::integer`). Other backends do not require the cast.
+    fn into_sql<T>(self) -> bound::TypedBound<T, Self>
+    where
+        Self: Sized,
+    {
+        bound::TypedBound::new(self)
+    }
+}
+
+impl<T> IntoSql for T {}
+
 /// Indicates that all elements of an expression are valid given a from clause.
 /// This is used to ensure that `users.filter(posts::id.eq(1))` fails to
 /// compile. This constraint is only used in places where the nullability of a
@@ -0,0 +1,324 @@
+use std::marker::PhantomData;
+
+use backend::Backend;
+use query_builder::*;
+use result::Error::QueryBuilderError;
+use result::QueryResult;
+use types::{HasSqlType, ToSql};
+
+/// Raw SQL with a value bound to one of its named parameters. Created by
+/// [`SqlLiteral::bind_named`](../sql_literal/struct.SqlLiteral.html#method.bind_named).
+#[derive(Debug, Clone, Copy)]
+#[must_use = "Queries are only executed when calling `load`, `get_result` or similar."]
+pub struct NamedBind<Query, Value, ST> {
+    query: Query,
+    name: &'static str,
+    value: Value,
+    _marker: PhantomData<ST>,
+}
+
+impl<Query, Value, ST> NamedBind<Query, Value, ST> {
+    pub fn new(query: Query, name: &'static str, value: Value) -> Self {
+        NamedBind {
+            query: query,
+            name: name,
+            value: value,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Bind a value to another named parameter of this SQL query. See
+    /// [`SqlLiteral::bind_named`](../sql_literal/struct.SqlLiteral.html#method.bind_named).
+    pub fn bind_named<ST2, Value2>(
+        self,
+        name: &'static str,
+        value: Value2,
+    ) -> NamedBind<Self, Value2, ST2> {
+        NamedBind::new(self, name, value)
+    }
+}
+
+/// The SQL of a query with named parameters, and the values bound to them.
+#[doc(hidden)]
+pub trait NamedBindValues<DB: Backend> {
+    fn sql(&self) -> &str;
+
+    /// Pushes the value bound to `name`, and returns whether there was one.
+    /// `repeated` is true when the name was already used in the query.
+    fn walk_named_bind(&self, name: &str, repeated: bool, out: AstPass<DB>) -> QueryResult<bool>;
+}
+
+impl<Query, Value, ST, DB> NamedBindValues<DB> for NamedBind<Query, Value, ST>
+where
+    DB: Backend + HasSqlType<ST>,
+    Query: NamedBindValues<DB>,
+    Value: ToSql<ST, DB>,
+{
+    fn sql(&self) -> &str {
+        self.query.sql()
+    }
+
+    fn walk_named_bind(
+        &self,
+        name: &str,
+        repeated: bool,
+        mut out: AstPass<DB>,
+    ) -> QueryResult<bool> {
+        if name != self.name {
+            self.query.walk_named_bind(name, repeated, out)
+        } else if repeated {
+            out.push_repeated_named_bind_param::<ST, _>(name, &self.value)?;
+            Ok(true)
+        } else {
+            out.push_named_bind_param::<ST, _>(name, &self.value)?;
+            Ok(true)
+        }
+    }
+}
+
+impl<Query, Value, ST> QueryId for NamedBind<Query, Value, ST>
+where
+    Query: QueryId,
+    ST: QueryId,
+{
+    type QueryId = NamedBind<Query::QueryId, (), ST::QueryId>;
+
+    const HAS_STATIC_QUERY_ID: bool = Query::HAS_STATIC_QUERY_ID && ST::HAS_STATIC_QUERY_ID;
+}
+
+impl<Query, Value, ST, DB> QueryFragment<DB> for NamedBind<Query, Value, ST>
+where
+    DB: Backend,
+    Self: NamedBindValues<DB>,
+{
+    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+        out.unsafe_to_cache_prepared();
+        let mut bound_names = Vec::new();
+        for part in split_named_params(self.sql()) {
+            match part {
+                SqlPart::Sql(sql) => out.push_sql_verbatim(sql),
+                SqlPart::Param(name) => {
+                    let repeated = bound_names.contains(&name);
+                    if !self.walk_named_bind(name, repeated, out.reborrow())? {
+                        let message = format!("No value was bound to the parameter `:{}`", name);
+                        return Err(QueryBuilderError(message.into()));
+                    }
+                    if !repeated {
+                        bound_names.push(name);
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<Q, Value, ST> Query for NamedBind<Q, Value, ST>
+where
+    Q: Query,
+{
+    type SqlType = Q::SqlType;
+}
+
+#[derive(Debug, PartialEq)]
+enum SqlPart<'a> {
+    Sql(&'a str),
+    Param(&'a str),
+}
+
+/// Splits SQL at each `:name` parameter. Casts written as `::type`, array
+/// slices such as `[a:b]`, comments, and anything inside quotes, backticks or
+/// dollar quotes are left as they are.
+fn split_named_params(sql: &str) -> Vec<SqlPart> {
+    let mut parts = Vec::new();
+    let mut sql_start = 0;
+    let mut bracket_depth = 0;
+    let mut i = 0;
+    while let Some(c) = sql[i..].chars().next() {
+        let rest = &sql[i..];
+        let skip_to = match c {
+            '\'' | '"' | '`' => Some(end_of_quoted(sql, i, false)),
+            'E' | 'e' if rest[1..].starts_with('\'') && !follows_identifier(sql, i) => {
+                Some(end_of_quoted(sql, i + 1, true))
+            }
+            '-' if rest.starts_with("--") => Some(rest.find('\n').map_or(sql.len(), |n| i + n)),
+            '/' if rest.starts_with("/*") => Some(end_of_block_comment(sql, i)),
+            '$' if !follows_identifier(sql, i) => end_of_dollar_quoted(sql, i),
+            '[' => {
+                bracket_depth += 1;
+                None
+            }
+            ']' if bracket_depth > 0 => {
+                bracket_depth -= 1;
+                None
+            }
+            ':' if rest.starts_with("::") => Some(i + 2),
+            ':' if bracket_depth == 0 => {
+                let name_len = identifier_len(&rest[1..]);
+                if name_len > 0 {
+                    if sql_start < i {
+                        parts.push(SqlPart::Sql(&sql[sql_start..i]));
+                    }
+                    sql_start = i + 1 + name_len;
+                    parts.push(SqlPart::Param(&sql[i + 1..sql_start]));
+                    Some(sql_start)
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        };
+        i = skip_to.unwrap_or(i + c.len_utf8());
+    }
+    if sql_start < sql.len() {
+        parts.push(SqlPart::Sql(&sql[sql_start..]));
+    }
+    parts
+}
+
+/// The length of the identifier at the start of `sql`, which is 0 if it
+/// doesn't start with one.
+fn identifier_len(sql: &str) -> usize {
+    let mut len = 0;
+    for c in sql.chars() {
+        let is_valid = if len == 0 {
+            c.is_alphabetic() || c == '_'
+        } else {
+            c.is_alphanumeric() || c == '_'
+        };
+        if !is_valid {
+            break;
+        }
+        len += c.len_utf8();
+    }
+    len
+}
+
+/// Whether the character at `i` continues an identifier or a keyword.
+fn follows_identifier(sql: &str, i: usize) -> bool {
+    sql[..i]
+        .chars()
+        .next_back()
+        .map_or(false, |c| c.is_alphanumeric() || c == '_' || c == '$')
+}
+
+/// Returns the index after the quote which closes the one at `start`. A
+/// doubled quote is read as two quoted strings next to each other, which
+/// gives the same result. In `E''` strings a backslash escapes the next
+/// character.
+fn end_of_quoted(sql: &str, start: usize, backslash_escapes: bool) -> usize {
+    let quote = sql[start..].chars().next().unwrap_or('\'');
+    let mut chars = sql[start + 1..].char_indices();
+    while let Some((j, c)) = chars.next() {
+        if c == '\\' && backslash_escapes {
+            chars.next();
+        } else if c == quote {
+            return start + 1 + j + 1;
+        }
+    }
+    sql.len()
+}
+
+/// Returns the index after the `*/` which closes the comment at `start`.
+/// Comments can be nested.
+fn end_of_block_comment(sql: &str, start: usize) -> usize {
+    let mut depth = 0;
+    let mut i = start;
+    while i < sql.len() {
+        let rest = &sql[i..];
+        if rest.starts_with("/*") {
+            depth += 1;
+            i += 2;
+        } else if rest.starts_with("*/") {
+            depth -= 1;
+            i += 2;
+            if depth == 0 {
+                return i;
+            }
+        } else {
+            i += rest.chars().next().map_or(1, char::len_utf8);
+        }
+    }
+    sql.len()
+}
+
+/// Returns the index after the end of the dollar quoted string at `start`,
+/// such as `$$text$$` or `$tag$text$tag$`, or `None` if there is no dollar
+/// quote at `start`.
+fn end_of_dollar_quoted(sql: &str, start: usize) -> Option<usize> {
+    let rest = &sql[start + 1..];
+    let tag_len = identifier_len(rest);
+    if !rest[tag_len..].starts_with('$') {
+        return None;
+    }
+    let delimiter = &sql[start..start + tag_len + 2];
+    let body_start = start + delimiter.len();
+    let end = sql[body_start..]
+        .find(delimiter)
+        .map_or(sql.len(), |n| body_start + n + delimiter.len());
+    Some(end)
+}
+
+#[test]
+fn named_params_are_split_from_sql() {
+    use self::SqlPart::*;
+
+    let parts = split_named_params("SELECT :a::int + :b_2 WHERE x = :a");
+    let expected = vec![
+        Sql("SELECT "),
+        Param("a"),
+        Sql("::int + "),
+        Param("b_2"),
+        Sql(" WHERE x = "),
+        Param("a"),
+    ];
+    assert_eq!(expected, parts);
+}
+
+#[test]
+fn named_params_are_not_split_inside_quotes() {
+    let sql = "SELECT ':a', \":b\" FROM t";
+    assert_eq!(vec![SqlPart::Sql(sql)], split_named_params(sql));
+}
+
+#[test]
+fn named_params_are_not_split_inside_comments() {
+    use self::SqlPart::*;
+
+    let sql = "SELECT 1 -- :a\n/* :b /* :c */ :d */ + :e";
+    let expected = vec![Sql("SELECT 1 -- :a\n/* :b /* :c */ :d */ + "), Param("e")];
+    assert_eq!(expected, split_named_params(sql));
+    let sql = "SELECT 1 -- :a";
+    assert_eq!(vec![Sql(sql)], split_named_params(sql));
+}
+
+#[test]
+fn named_params_are_not_split_inside_dollar_quotes() {
+    use self::SqlPart::*;
+
+    let sql = "SELECT $$ :a $$, $tag$ $$ :b $tag$, :c, $1";
+    let expected = vec![
+        Sql("SELECT $$ :a $$, $tag$ $$ :b $tag$, "),
+        Param("c"),
+        Sql(", $1"),
+    ];
+    assert_eq!(expected, split_named_params(sql));
+}
+
+#[test]
+fn named_params_are_not_split_inside_escaped_strings() {
+    use self::SqlPart::*;
+
+    let sql = r"SELECT E'\' :a', 'it''s :b', :c";
+    let expected = vec![Sql(r"SELECT E'\' :a', 'it''s :b', "), Param("c")];
+    assert_eq!(expected, split_named_params(sql));
+}
+
+#[test]
+fn named_params_are_not_split_inside_array_slices_or_backticks() {
+    use self::SqlPart::*;
+
+    let sql = "SELECT arr[a:b], `:c` FROM t WHERE x = :d";
+    let expected = vec![Sql("SELECT arr[a:b], `:c` FROM t WHERE x = "), Param("d")];
+    assert_eq!(expected, split_named_params(sql));
+}
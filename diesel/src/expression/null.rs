@@ -0,0 +1,65 @@
+use std::marker::PhantomData;
+
+use expression::{AppearsOnTable, Expression, NonAggregate, SelectableExpression};
+use types::{NotNull, Nullable};
+
+/// Represents a `NULL` literal of type `Nullable<ST>`.
+///
+/// On PostgreSQL this is rendered with a cast (e.g. `NULL::integer`), so it
+/// can be used where the server is unable to infer the type of `NULL`, such
+/// as a select clause with no `FROM`. Other backends render a plain `NULL`.
+///
+/// This can be used to explicitly set a column to `NULL` in an insert or
+/// update, or as part of a select clause.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate diesel;
+/// # include!("../doctest_setup.rs");
+/// #
+/// # table! {
+/// #     users {
+/// #         id -> Integer,
+/// #         name -> VarChar,
+/// #     }
+/// # }
+/// #
+/// # fn main() {
+/// #     use self::users::dsl::*;
+/// #     use diesel::dsl::null;
+/// #     use diesel::types::Integer;
+/// #     let connection = establish_connection();
+/// let data = users
+///     .select((name, null::<Integer>()))
+///     .order(id)
+///     .load::<(String, Option<i32>)>(&connection);
+/// let expected = vec![("Sean".to_string(), None), ("Tess".to_string(), None)];
+/// assert_eq!(Ok(expected), data);
+/// # }
+/// ```
+pub fn null<ST: NotNull>() -> Null<ST> {
+    Null {
+        _marker: PhantomData,
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+/// The `NULL` literal. Constructed by [`null`](../dsl/fn.null.html).
+pub struct Null<ST> {
+    _marker: PhantomData<ST>,
+}
+
+impl<ST: NotNull> Expression for Null<ST> {
+    type SqlType = Nullable<ST>;
+}
+
+impl<ST: NotNull, QS> SelectableExpression<QS> for Null<ST> {}
+
+impl_unchecked_distinct_ordering!(Null<ST>);
+
+impl<ST: NotNull, QS> AppearsOnTable<QS> for Null<ST> {}
+
+impl<ST> NonAggregate for Null<ST> {}
+
+impl_query_id!(Null<ST>);
@@ -45,6 +45,7 @@ macro_rules! __diesel_operator_body {
             fn walk_ast(&self, mut out: $crate::query_builder::AstPass<$backend_ty>) -> $crate::result::QueryResult<()> {
                 __diesel_operator_to_sql!(
                     notation = $notation,
+                    out = out,
                     operator_expr = out.push_sql($operator),
                     field_exprs = ($(self.$field_name.walk_ast(out.reborrow())?),+),
                 );
@@ -59,6 +60,7 @@ macro_rules! __diesel_operator_body {
 macro_rules! __diesel_operator_to_sql {
     (
         notation = infix,
+        out = $out:ident,
         operator_expr = $op:expr,
         field_exprs = ($left:expr, $right:expr),
     ) => {
@@ -67,8 +69,22 @@ macro_rules! __diesel_operator_to_sql {
         $right;
     };
 
+    (
+        notation = grouped_infix,
+        out = $out:ident,
+        operator_expr = $op:expr,
+        field_exprs = ($left:expr, $right:expr),
+    ) => {
+        $out.push_sql("(");
+        $left;
+        $op;
+        $right;
+        $out.push_sql(")");
+    };
+
     (
         notation = postfix,
+        out = $out:ident,
         operator_expr = $op:expr,
         field_exprs = ($expr:expr),
     ) => {
@@ -78,6 +94,7 @@ macro_rules! __diesel_operator_to_sql {
 
     (
         notation = prefix,
+        out = $out:ident,
         operator_expr = $op:expr,
         field_exprs = ($expr:expr),
     ) => {
@@ -98,9 +115,25 @@ macro_rules! __diesel_operator_to_sql {
 /// If the operator is specific to a single backend, you can specify this by
 /// adding `backend: Pg` or similar as the last argument.
 ///
+/// The arguments are, in order:
+///
+/// - The name of the generated type. It has a `new(left, right)`
+///   constructor, which takes two expressions.
+/// - The SQL placed between the two expressions, including any surrounding
+///   whitespace (e.g. `" ->> "`).
+/// - Optionally, the SQL type of the result.
+/// - Optionally, `backend: SomeBackend`.
+///
 /// It should be noted that the generated impls will not constrain the SQL
 /// types of the arguments. You should ensure that they are of the right
-/// type in your function which constructs the operator.
+/// type in your function which constructs the operator. That function is
+/// also the place to turn Rust values into bind parameters, by taking
+/// arguments which implement
+/// [`AsExpression`](expression/trait.AsExpression.html).
+///
+/// The generated SQL is not wrapped in parentheses. If the operator may be
+/// combined with operators of a different precedence, use
+/// [`infix_operator!`](macro.infix_operator.html) instead.
 ///
 /// Typically you would not expose the type that this generates directly. You'd
 /// expose a function (or trait) used to construct the expression, and a helper
@@ -202,6 +235,120 @@ macro_rules! diesel_infix_operator {
     };
 }
 
+/// Defines a custom binary operator, such as `->>` on JSON or `<@` on ranges,
+/// along with a method to construct it.
+///
+/// Unlike [`diesel_infix_operator!`], the generated SQL is always wrapped in
+/// parentheses (`(left ->> right)`), so the operator can be combined with
+/// other operators regardless of its precedence.
+///
+/// The arguments are, in order:
+///
+/// - The name of the generated type. It has a `new(left, right)`
+///   constructor, which takes two expressions.
+/// - The SQL placed between the two expressions, including any surrounding
+///   whitespace (e.g. `" ->> "`).
+/// - The SQL type of the result.
+/// - Optionally, `fn method(LeftType, RightType) in TraitName`. This generates
+///   a public trait called `TraitName`, implemented for every expression of
+///   type `LeftType`, with a method called `method`. The method takes any
+///   value which implements `AsExpression<RightType>`, so Rust values are
+///   sent as bind parameters.
+///   Doc comments and other attributes written before `fn` are added to the
+///   method.
+/// - Optionally, `backend: SomeBackend`, if the operator only exists on a
+///   single backend.
+///
+/// As with [`diesel_infix_operator!`], the generated type does not constrain
+/// the SQL types of its arguments. Only the generated method does.
+///
+/// [`diesel_infix_operator!`]: macro.diesel_infix_operator.html
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate diesel;
+/// # include!("../doctest_setup.rs");
+/// # use diesel::types::Integer;
+/// #
+/// # table! {
+/// #     users {
+/// #         id -> Integer,
+/// #         name -> VarChar,
+/// #     }
+/// # }
+/// #
+/// infix_operator!(
+///     Modulo,
+///     " % ",
+///     Integer,
+///     /// Creates a SQL `%` expression, the remainder of `self` divided by
+///     /// `other`.
+///     fn modulo(Integer, Integer) in ModuloMethods
+/// );
+///
+/// # fn main() {
+/// #     use self::users::dsl::*;
+/// #     let connection = establish_connection();
+/// let even_ids = users.select(id).filter(id.modulo(2).eq(0));
+/// assert_eq!(Ok(vec![2]), even_ids.load(&connection));
+/// # }
+/// ```
+#[macro_export]
+macro_rules! infix_operator {
+    ($name:ident, $operator:expr, $return_ty:ty) => {
+        __diesel_operator_body!(
+            notation = grouped_infix,
+            struct_name = $name,
+            operator = $operator,
+            return_ty = $return_ty,
+            ty_params = (T, U,),
+            field_names = (left, right,),
+            backend_ty_params = (DB,),
+            backend_ty = DB,
+        );
+    };
+
+    ($name:ident, $operator:expr, $return_ty:ty, backend: $backend:ty) => {
+        __diesel_operator_body!(
+            notation = grouped_infix,
+            struct_name = $name,
+            operator = $operator,
+            return_ty = $return_ty,
+            ty_params = (T, U,),
+            field_names = (left, right,),
+            backend_ty_params = (),
+            backend_ty = $backend,
+        );
+    };
+
+    (
+        $name:ident,
+        $operator:expr,
+        $return_ty:ty,
+        $(#[$meta:meta])*
+        fn $method:ident($left_ty:ty, $right_ty:ty) in $trait_name:ident
+        $(, backend: $backend:ty)*
+    ) => {
+        infix_operator!($name, $operator, $return_ty $(, backend: $backend)*);
+
+        /// Provides the method of a custom operator defined with
+        /// `infix_operator!`. This trait is implemented for every expression
+        /// of the left SQL type of the operator.
+        pub trait $trait_name: $crate::expression::Expression<SqlType = $left_ty> + Sized {
+            $(#[$meta])*
+            fn $method<T>(self, other: T) -> $name<Self, T::Expression>
+            where
+                T: $crate::expression::AsExpression<$right_ty>,
+            {
+                $name::new(self, $crate::expression::AsExpression::as_expression(other))
+            }
+        }
+
+        impl<T: $crate::expression::Expression<SqlType = $left_ty>> $trait_name for T {}
+    };
+}
+
 /// Useful for libraries adding support for new SQL types. Apps should never
 /// need to call this.
 ///
@@ -293,7 +440,6 @@ macro_rules! diesel_prefix_operator {
 }
 
 diesel_infix_operator!(Concat, " || ", ::types::Text);
-diesel_infix_operator!(And, " AND ");
 diesel_infix_operator!(Between, " BETWEEN ");
 diesel_infix_operator!(Escape, " ESCAPE ");
 diesel_infix_operator!(Eq, " = ");
@@ -309,17 +455,83 @@ diesel_infix_operator!(Or, " OR ");
 
 diesel_postfix_operator!(IsNull, " IS NULL");
 diesel_postfix_operator!(IsNotNull, " IS NOT NULL");
-diesel_postfix_operator!(Asc, " ASC", ());
-diesel_postfix_operator!(Desc, " DESC", ());
 
 diesel_prefix_operator!(Not, "NOT ");
 
+// `Asc` and `Desc` are written out rather than generated by
+// `diesel_postfix_operator!`, so that a `SELECT DISTINCT` query ordered by them
+// checks the column they wrap.
+macro_rules! sort_direction {
+    ($name:ident, $operator:expr) => {
+        #[derive(Debug, Clone, Copy)]
+        #[doc(hidden)]
+        pub struct $name<Expr> {
+            expr: Expr,
+        }
+
+        impl<Expr> $name<Expr> {
+            pub fn new(expr: Expr) -> Self {
+                $name { expr: expr }
+            }
+
+            #[cfg(any(feature = "sqlite", feature = "mysql"))]
+            pub(crate) fn expr(&self) -> &Expr {
+                &self.expr
+            }
+        }
+
+        impl_query_id!($name<Expr>);
+
+        impl<Expr, QS> SelectableExpression<QS> for $name<Expr>
+        where
+            $name<Expr>: AppearsOnTable<QS>,
+            Expr: SelectableExpression<QS>,
+        {
+        }
+
+        impl<Expr, QS> AppearsOnTable<QS> for $name<Expr>
+        where
+            $name<Expr>: Expression,
+            Expr: AppearsOnTable<QS>,
+        {
+        }
+
+        impl<Expr: Expression> Expression for $name<Expr> {
+            type SqlType = ();
+        }
+
+        impl<Expr: NonAggregate> NonAggregate for $name<Expr> {}
+
+        impl<Expr, DB> QueryFragment<DB> for $name<Expr>
+        where
+            DB: Backend,
+            Expr: QueryFragment<DB>,
+        {
+            fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+                self.expr.walk_ast(out.reborrow())?;
+                out.push_sql($operator);
+                Ok(())
+            }
+        }
+    }
+}
+
+sort_direction!(Asc, " ASC");
+sort_direction!(Desc, " DESC");
+
 use backend::Backend;
 use insertable::{ColumnInsertValue, InsertValues, Insertable};
 use query_source::Column;
 use query_builder::*;
 use result::QueryResult;
-use super::AppearsOnTable;
+use super::{AppearsOnTable, Expression, NonAggregate, SelectableExpression};
+
+impl<T, U> Eq<T, U> {
+    #[cfg(feature = "postgres")]
+    pub(crate) fn left(&self) -> &T {
+        &self.left
+    }
+}
 
 impl<T, U, DB> Changeset<DB> for Eq<T, U>
 where
@@ -363,3 +575,57 @@ where
         (ColumnInsertValue::Expression(self.left, &self.right),)
     }
 }
+
+// `And` is written out rather than generated by `diesel_infix_operator!`, so
+// that it can report when either side is known to match no rows.
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct And<T, U> {
+    left: T,
+    right: U,
+}
+
+impl<T, U> And<T, U> {
+    pub fn new(left: T, right: U) -> Self {
+        And {
+            left: left,
+            right: right,
+        }
+    }
+}
+
+impl_query_id!(And<T, U>);
+impl_selectable_expression!(And<T, U>);
+
+impl<T, U> Expression for And<T, U>
+where
+    T: Expression,
+    U: Expression,
+{
+    type SqlType = ::types::Bool;
+}
+
+impl<T, U> NonAggregate for And<T, U>
+where
+    T: NonAggregate,
+    U: NonAggregate,
+{
+}
+
+impl<T, U, DB> QueryFragment<DB> for And<T, U>
+where
+    DB: Backend,
+    T: QueryFragment<DB>,
+    U: QueryFragment<DB>,
+{
+    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+        self.left.walk_ast(out.reborrow())?;
+        out.push_sql(" AND ");
+        self.right.walk_ast(out.reborrow())?;
+        Ok(())
+    }
+
+    fn is_known_empty(&self) -> bool {
+        self.left.is_known_empty() || self.right.is_known_empty()
+    }
+}
@@ -0,0 +1,69 @@
+use backend::Backend;
+use expression::{Expression, NonAggregate};
+use query_builder::*;
+use result::QueryResult;
+use types;
+
+macro_rules! bitwise_operation {
+    ($name:ident, $op:expr) => {
+        bitwise_operation!($name);
+
+        impl<Lhs, Rhs, DB> QueryFragment<DB> for $name<Lhs, Rhs> where
+            DB: Backend,
+            Lhs: QueryFragment<DB>,
+            Rhs: QueryFragment<DB>,
+        {
+            fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+                out.push_sql("(");
+                self.lhs.walk_ast(out.reborrow())?;
+                out.push_sql($op);
+                self.rhs.walk_ast(out.reborrow())?;
+                out.push_sql(")");
+                Ok(())
+            }
+        }
+    };
+
+    ($name:ident) => {
+        #[derive(Debug, Copy, Clone)]
+        pub struct $name<Lhs, Rhs> {
+            pub(crate) lhs: Lhs,
+            pub(crate) rhs: Rhs,
+        }
+
+        impl<Lhs, Rhs> $name<Lhs, Rhs> {
+            pub fn new(left: Lhs, right: Rhs) -> Self {
+                $name {
+                    lhs: left,
+                    rhs: right,
+                }
+            }
+        }
+
+        impl<Lhs, Rhs> Expression for $name<Lhs, Rhs> where
+            Lhs: Expression,
+            Lhs::SqlType: types::ops::Bitwise,
+            Rhs: Expression,
+        {
+            type SqlType = Lhs::SqlType;
+        }
+
+        impl_query_id!($name<Lhs, Rhs>);
+        impl_selectable_expression!($name<Lhs, Rhs>);
+
+        impl<Lhs, Rhs> NonAggregate for $name<Lhs, Rhs> where
+            Lhs: NonAggregate,
+            Rhs: NonAggregate,
+            $name<Lhs, Rhs>: Expression,
+        {
+        }
+    }
+}
+
+bitwise_operation!(BitAnd, " & ");
+bitwise_operation!(BitOr, " | ");
+bitwise_operation!(BitShiftLeft, " << ");
+bitwise_operation!(BitShiftRight, " >> ");
+// The operator for exclusive or differs between backends, so it is rendered
+// by each backend.
+bitwise_operation!(BitXor);
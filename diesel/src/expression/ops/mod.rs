@@ -25,6 +25,8 @@ macro_rules! generic_numeric_expr {
     }
 }
 
+mod bitwise;
 mod numeric;
 
+pub use self::bitwise::{BitAnd, BitOr, BitShiftLeft, BitShiftRight, BitXor};
 pub use self::numeric::{Add, Div, Mul, Sub};
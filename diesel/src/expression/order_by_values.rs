@@ -0,0 +1,51 @@
+use expression::*;
+use types::{Integer, Nullable};
+
+/// The position of the value of a column in a list of values, used to sort
+/// rows in the order of the list. Constructed by
+/// [`.order_by_values`](../query_dsl/trait.OrderByValuesDsl.html).
+///
+/// This is rendered as `array_position` on PostgreSQL, `FIELD` on MySQL, and
+/// a `CASE` expression on SQLite.
+#[derive(Debug, Clone)]
+pub struct OrderByValues<T, U> {
+    pub(crate) column: T,
+    pub(crate) values: Vec<U>,
+}
+
+impl<T, U> OrderByValues<T, U> {
+    pub fn new(column: T, values: Vec<U>) -> Self {
+        OrderByValues {
+            column: column,
+            values: values,
+        }
+    }
+}
+
+impl<T: Expression, U> Expression for OrderByValues<T, U> {
+    type SqlType = Nullable<Integer>;
+}
+
+impl<T: NonAggregate, U> NonAggregate for OrderByValues<T, U>
+where
+    OrderByValues<T, U>: Expression,
+{
+}
+
+impl<T, U, QS> SelectableExpression<QS> for OrderByValues<T, U>
+where
+    OrderByValues<T, U>: AppearsOnTable<QS>,
+    T: SelectableExpression<QS>,
+{
+}
+
+impl_unchecked_distinct_ordering!(OrderByValues<T, U>);
+
+impl<T, U, QS> AppearsOnTable<QS> for OrderByValues<T, U>
+where
+    OrderByValues<T, U>: Expression,
+    T: AppearsOnTable<QS>,
+{
+}
+
+impl_query_id!(noop: OrderByValues<T, U>);
@@ -4,6 +4,7 @@ use backend::Backend;
 use expression::*;
 use query_builder::*;
 use result::QueryResult;
+use super::named_bind::{NamedBind, NamedBindValues};
 use super::unchecked_bind::UncheckedBind;
 use types::HasSqlType;
 
@@ -102,6 +103,66 @@ impl<ST> SqlLiteral<ST> {
     pub fn bind<BindST, T>(self, bind_value: T) -> UncheckedBind<Self, T, BindST> {
         UncheckedBind::new(self, bind_value)
     }
+
+    /// Bind a value to a named parameter of this SQL query. Parameters are
+    /// written as `:name`, and are replaced by the placeholders of the
+    /// backend when the query is run. A name can be used more than once. On
+    /// PostgreSQL each use refers to the same placeholder, such as `$1`, so
+    /// the value is only sent once. Other backends send the value for each
+    /// use.
+    ///
+    /// Casts written as `::type`, array slices such as `[a:b]`, `--` and
+    /// `/* */` comments, and text inside quotes, backticks or dollar quotes
+    /// are not treated as parameters. Running a query which uses a name
+    /// without a value returns a `QueryBuilderError`. Named and positional
+    /// parameters cannot be mixed.
+    ///
+    /// # Safety
+    ///
+    /// As with [`bind`](#method.bind), Diesel cannot validate that the value
+    /// is of the right type.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate diesel;
+    /// # include!("../doctest_setup.rs");
+    /// #
+    /// # table! {
+    /// #     users {
+    /// #         id -> Integer,
+    /// #         name -> VarChar,
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     use diesel::dsl::sql;
+    /// #     use diesel::types::{Integer, Text};
+    /// #     let connection = establish_connection();
+    /// let query = sql::<Text>("SELECT name FROM users WHERE id > :min_id AND name <> :name")
+    ///     .bind_named::<Integer, _>("min_id", 0)
+    ///     .bind_named::<Text, _>("name", "Sean");
+    /// let expected = vec!["Tess".to_string()];
+    /// assert_eq!(Ok(expected), query.load(&connection));
+    /// # }
+    /// ```
+    pub fn bind_named<BindST, T>(
+        self,
+        name: &'static str,
+        bind_value: T,
+    ) -> NamedBind<Self, T, BindST> {
+        NamedBind::new(self, name, bind_value)
+    }
+}
+
+impl<ST, DB: Backend> NamedBindValues<DB> for SqlLiteral<ST> {
+    fn sql(&self) -> &str {
+        &self.sql
+    }
+
+    fn walk_named_bind(&self, _: &str, _: bool, _: AstPass<DB>) -> QueryResult<bool> {
+        Ok(false)
+    }
 }
 
 impl<ST> Expression for SqlLiteral<ST> {
@@ -114,7 +175,7 @@ where
 {
     fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
         out.unsafe_to_cache_prepared();
-        out.push_sql(&self.sql);
+        out.push_sql_verbatim(&self.sql);
         Ok(())
     }
 }
@@ -127,6 +188,8 @@ impl<ST> Query for SqlLiteral<ST> {
 
 impl<QS, ST> SelectableExpression<QS> for SqlLiteral<ST> {}
 
+impl_unchecked_distinct_ordering!(SqlLiteral<ST>);
+
 impl<QS, ST> AppearsOnTable<QS> for SqlLiteral<ST> {}
 
 impl<ST> NonAggregate for SqlLiteral<ST> {}
@@ -0,0 +1,445 @@
+//! Types for the `OVER` clause of a window function call. A window is
+//! constructed with [`window`](fn.window.html), and applied to an aggregate
+//! function, or to any other [`WindowFunction`](trait.WindowFunction.html),
+//! with
+//! [`.over`](../../expression_methods/global_expression_methods/trait.ExpressionMethods.html#method.over).
+//!
+//! SQLite only supports window functions since version 3.25, so the example
+//! below is only run against PostgreSQL.
+//!
+//! # Example
+//!
+//! ```rust
+//! # #[macro_use] extern crate diesel;
+//! # include!("../doctest_setup.rs");
+//! #
+//! # table! {
+//! #     users {
+//! #         id -> Integer,
+//! #         name -> VarChar,
+//! #     }
+//! # }
+//! #
+//! # #[cfg(feature = "postgres")]
+//! # fn main() {
+//! #     use self::users::dsl::*;
+//! #     let connection = establish_connection();
+//! use diesel::dsl::{sum, window};
+//! use diesel::expression::window::*;
+//!
+//! let running_total = sum(id).over(
+//!     window()
+//!         .order_by(id)
+//!         .frame(Rows::between(Preceding(1), CurrentRow)),
+//! );
+//! let data = users
+//!     .select((name, running_total))
+//!     .order(id)
+//!     .load::<(String, Option<i64>)>(&connection);
+//! assert_eq!(Ok(vec![("Sean".to_string(), Some(1)), ("Tess".to_string(), Some(3))]), data);
+//! # }
+//! # #[cfg(not(feature = "postgres"))]
+//! # fn main() {}
+//! ```
+//!
+//! Besides aggregate functions, the ranking functions
+//! [`row_number`](fn.row_number.html), [`rank`](fn.rank.html) and
+//! [`dense_rank`](fn.dense_rank.html) can be evaluated over a window.
+use backend::Backend;
+use expression::{AppearsOnTable, Expression, NonAggregate, SelectableExpression};
+use query_builder::{AstPass, QueryFragment, QueryId};
+use query_builder::order_clause::{NoOrderClause, OrderClause};
+use result::QueryResult;
+use types::BigInt;
+
+pub use self::FrameBound::*;
+
+/// Creates an empty window definition, which is rendered as `OVER ()`. The
+/// window can be restricted with `partition_by`, `order_by` and `frame`.
+pub fn window() -> Window<NoPartitionClause, NoOrderClause, NoFrameClause> {
+    Window {
+        partition: NoPartitionClause,
+        order: NoOrderClause,
+        frame: NoFrameClause,
+    }
+}
+
+/// A window definition. Constructed by [`window`](fn.window.html).
+#[derive(Debug, Clone, Copy)]
+pub struct Window<Partition, Order, Frame> {
+    partition: Partition,
+    order: Order,
+    frame: Frame,
+}
+
+impl<Partition, Order, Frame> Window<Partition, Order, Frame> {
+    /// Adds `PARTITION BY` to the window. Multiple expressions can be given as
+    /// a tuple.
+    pub fn partition_by<Expr>(self, expr: Expr) -> Window<PartitionClause<Expr>, Order, Frame>
+    where
+        Expr: Expression,
+    {
+        Window {
+            partition: PartitionClause(expr),
+            order: self.order,
+            frame: self.frame,
+        }
+    }
+
+    /// Adds `ORDER BY` to the window.
+    pub fn order_by<Expr>(self, expr: Expr) -> Window<Partition, OrderClause<Expr>, Frame>
+    where
+        Expr: Expression,
+    {
+        Window {
+            partition: self.partition,
+            order: OrderClause(expr),
+            frame: self.frame,
+        }
+    }
+
+    /// Sets the frame of the window, which is placed after the `PARTITION BY`
+    /// and `ORDER BY` clauses. Frames are constructed with
+    /// [`Rows::between`](struct.Rows.html#method.between) or
+    /// [`Range::between`](struct.Range.html#method.between).
+    pub fn frame(self, frame: FrameClause) -> Window<Partition, Order, FrameClause> {
+        Window {
+            partition: self.partition,
+            order: self.order,
+            frame: frame,
+        }
+    }
+}
+
+/// The frame is defined by a number of rows before or after the current row.
+#[derive(Debug, Clone, Copy)]
+pub struct Rows;
+
+impl Rows {
+    /// `ROWS BETWEEN start AND end`
+    pub fn between(start: FrameBound, end: FrameBound) -> FrameClause {
+        FrameClause {
+            mode: "ROWS",
+            start: start,
+            end: end,
+        }
+    }
+}
+
+/// The frame is defined by a range of values of the `ORDER BY` expression.
+/// `Preceding` and `Following` bounds require the window to be ordered by a
+/// single numeric or date expression.
+#[derive(Debug, Clone, Copy)]
+pub struct Range;
+
+impl Range {
+    /// `RANGE BETWEEN start AND end`
+    pub fn between(start: FrameBound, end: FrameBound) -> FrameClause {
+        FrameClause {
+            mode: "RANGE",
+            start: start,
+            end: end,
+        }
+    }
+}
+
+/// The start or the end of a window frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FrameBound {
+    /// `UNBOUNDED PRECEDING`
+    UnboundedPreceding,
+    /// `n PRECEDING`
+    Preceding(u64),
+    /// `CURRENT ROW`
+    CurrentRow,
+    /// `n FOLLOWING`
+    Following(u64),
+    /// `UNBOUNDED FOLLOWING`
+    UnboundedFollowing,
+}
+
+impl FrameBound {
+    fn walk_ast<DB: Backend>(&self, mut out: AstPass<DB>) {
+        match *self {
+            UnboundedPreceding => out.push_sql("UNBOUNDED PRECEDING"),
+            Preceding(n) => {
+                out.push_sql(&n.to_string());
+                out.push_sql(" PRECEDING");
+            }
+            CurrentRow => out.push_sql("CURRENT ROW"),
+            Following(n) => {
+                out.push_sql(&n.to_string());
+                out.push_sql(" FOLLOWING");
+            }
+            UnboundedFollowing => out.push_sql("UNBOUNDED FOLLOWING"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct NoPartitionClause;
+
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct PartitionClause<Expr>(Expr);
+
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct NoFrameClause;
+
+/// A window frame. Constructed by [`Rows::between`](struct.Rows.html#method.between)
+/// or [`Range::between`](struct.Range.html#method.between).
+#[derive(Debug, Clone, Copy)]
+pub struct FrameClause {
+    mode: &'static str,
+    start: FrameBound,
+    end: FrameBound,
+}
+
+/// A part of a window definition, which is separated from the other parts by
+/// a space.
+#[doc(hidden)]
+pub trait WindowClause<DB: Backend> {
+    fn is_noop(&self) -> bool;
+
+    fn walk_ast(&self, out: AstPass<DB>) -> QueryResult<()>;
+}
+
+impl<DB: Backend> WindowClause<DB> for NoPartitionClause {
+    fn is_noop(&self) -> bool {
+        true
+    }
+
+    fn walk_ast(&self, _: AstPass<DB>) -> QueryResult<()> {
+        Ok(())
+    }
+}
+
+impl<Expr: QueryFragment<DB>, DB: Backend> WindowClause<DB> for PartitionClause<Expr> {
+    fn is_noop(&self) -> bool {
+        false
+    }
+
+    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+        out.push_sql("PARTITION BY ");
+        self.0.walk_ast(out.reborrow())
+    }
+}
+
+impl<DB: Backend> WindowClause<DB> for NoOrderClause {
+    fn is_noop(&self) -> bool {
+        true
+    }
+
+    fn walk_ast(&self, _: AstPass<DB>) -> QueryResult<()> {
+        Ok(())
+    }
+}
+
+impl<Expr: QueryFragment<DB>, DB: Backend> WindowClause<DB> for OrderClause<Expr> {
+    fn is_noop(&self) -> bool {
+        false
+    }
+
+    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+        out.push_sql("ORDER BY ");
+        self.0.walk_ast(out.reborrow())
+    }
+}
+
+impl<DB: Backend> WindowClause<DB> for NoFrameClause {
+    fn is_noop(&self) -> bool {
+        true
+    }
+
+    fn walk_ast(&self, _: AstPass<DB>) -> QueryResult<()> {
+        Ok(())
+    }
+}
+
+impl<DB: Backend> WindowClause<DB> for FrameClause {
+    fn is_noop(&self) -> bool {
+        false
+    }
+
+    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+        out.push_sql(self.mode);
+        out.push_sql(" BETWEEN ");
+        self.start.walk_ast(out.reborrow());
+        out.push_sql(" AND ");
+        self.end.walk_ast(out.reborrow());
+        Ok(())
+    }
+}
+
+/// An aggregate function, or one of the ranking functions, which can be
+/// evaluated over a window with
+/// [`.over`](../../expression_methods/global_expression_methods/trait.ExpressionMethods.html#method.over).
+pub trait WindowFunction: Expression {}
+
+/// Whether the arguments of a window function are valid for the query source
+/// `QS`.
+#[doc(hidden)]
+pub trait WindowFunctionAppearsOnTable<QS>: WindowFunction {}
+
+/// Whether the expressions used in a part of a window definition are valid
+/// for the query source `QS`.
+#[doc(hidden)]
+pub trait WindowClauseAppearsOnTable<QS> {}
+
+impl<QS> WindowClauseAppearsOnTable<QS> for NoPartitionClause {}
+
+impl<Expr: AppearsOnTable<QS>, QS> WindowClauseAppearsOnTable<QS> for PartitionClause<Expr> {}
+
+impl<QS> WindowClauseAppearsOnTable<QS> for NoOrderClause {}
+
+impl<Expr: AppearsOnTable<QS>, QS> WindowClauseAppearsOnTable<QS> for OrderClause<Expr> {}
+
+macro_rules! ranking_function {
+    ($(#[$doc:meta])* fn $fn_name:ident -> $type_name:ident, $sql:expr) => {
+        $(#[$doc])*
+        ///
+        /// The result has the SQL type `BigInt`, and is never `NULL`. This
+        /// function is only valid with a window, applied with
+        /// [`.over`](../../expression_methods/global_expression_methods/trait.ExpressionMethods.html#method.over).
+        pub fn $fn_name() -> $type_name {
+            $type_name
+        }
+
+        #[derive(Debug, Clone, Copy)]
+        #[doc(hidden)]
+        pub struct $type_name;
+
+        impl Expression for $type_name {
+            type SqlType = BigInt;
+        }
+
+        impl<DB: Backend> QueryFragment<DB> for $type_name {
+            fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+                out.push_sql($sql);
+                Ok(())
+            }
+        }
+
+        impl_query_id!($type_name);
+
+        // Deliberately not `AppearsOnTable`, as it is only valid with `OVER`
+        impl WindowFunction for $type_name {}
+
+        impl<QS> WindowFunctionAppearsOnTable<QS> for $type_name {}
+    };
+}
+
+ranking_function! {
+    /// Numbers the rows of each partition, starting at 1. Rows which are
+    /// equal according to the `ORDER BY` of the window get different numbers.
+    fn row_number -> RowNumber, "ROW_NUMBER()"
+}
+
+ranking_function! {
+    /// The rank of the row within its partition, with gaps. Rows which are
+    /// equal according to the `ORDER BY` of the window get the same rank.
+    fn rank -> Rank, "RANK()"
+}
+
+ranking_function! {
+    /// The rank of the row within its partition, without gaps. Rows which are
+    /// equal according to the `ORDER BY` of the window get the same rank.
+    fn dense_rank -> DenseRank, "DENSE_RANK()"
+}
+
+/// A window function evaluated over a window, `expr OVER (window)`. The
+/// result has the same SQL type as the function, so `sum(x).over(...)` is
+/// nullable like `sum(x)`, since the frame of a row can be empty.
+/// Constructed by
+/// [`.over`](../../expression_methods/global_expression_methods/trait.ExpressionMethods.html#method.over).
+#[derive(Debug, Clone, Copy)]
+pub struct Over<Expr, W> {
+    expr: Expr,
+    window: W,
+}
+
+impl<Expr, W> Over<Expr, W> {
+    #[doc(hidden)]
+    pub fn new(expr: Expr, window: W) -> Self {
+        Over {
+            expr: expr,
+            window: window,
+        }
+    }
+}
+
+impl<Expr, Partition, Order, Frame> Expression for Over<Expr, Window<Partition, Order, Frame>>
+where
+    Expr: Expression,
+{
+    type SqlType = Expr::SqlType;
+}
+
+impl<Expr, Partition, Order, Frame, DB> QueryFragment<DB>
+    for Over<Expr, Window<Partition, Order, Frame>>
+where
+    DB: Backend,
+    Expr: QueryFragment<DB>,
+    Partition: WindowClause<DB>,
+    Order: WindowClause<DB>,
+    Frame: WindowClause<DB>,
+{
+    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+        self.expr.walk_ast(out.reborrow())?;
+        out.push_sql(" OVER (");
+        let clauses: [&WindowClause<DB>; 3] = [
+            &self.window.partition,
+            &self.window.order,
+            &self.window.frame,
+        ];
+        let mut needs_space = false;
+        for clause in clauses.iter().filter(|c| !c.is_noop()) {
+            if needs_space {
+                out.push_sql(" ");
+            }
+            clause.walk_ast(out.reborrow())?;
+            needs_space = true;
+        }
+        out.push_sql(")");
+        Ok(())
+    }
+}
+
+// The frame offsets are part of the SQL, so the type alone does not identify
+// the query
+impl<Expr, W> QueryId for Over<Expr, W> {
+    type QueryId = ();
+
+    const HAS_STATIC_QUERY_ID: bool = false;
+}
+
+impl<Expr, Partition, Order, Frame, QS> AppearsOnTable<QS>
+    for Over<Expr, Window<Partition, Order, Frame>>
+where
+    Expr: WindowFunctionAppearsOnTable<QS>,
+    Partition: WindowClauseAppearsOnTable<QS>,
+    Order: WindowClauseAppearsOnTable<QS>,
+{
+}
+
+impl<Expr, Partition, Order, Frame, QS> SelectableExpression<QS>
+    for Over<Expr, Window<Partition, Order, Frame>>
+where
+    Self: AppearsOnTable<QS>,
+{
+}
+
+impl_unchecked_distinct_ordering!(
+    ty_params = (Expr, Partition, Order, Frame),
+    struct_ty = Over<Expr, Window<Partition, Order, Frame>>,
+);
+
+// A window function returns one value per row, so it can be selected
+// alongside columns
+impl<Expr, Partition, Order, Frame> NonAggregate for Over<Expr, Window<Partition, Order, Frame>>
+where
+    Self: Expression,
+{
+}
@@ -0,0 +1,105 @@
+use expression::{AsExpression, Expression};
+use expression::ops::{BitAnd, BitOr, BitShiftLeft, BitShiftRight, BitXor};
+use types::Integer;
+use types::ops::Bitwise;
+
+/// Methods for the bitwise operators of integer expressions. The result has
+/// the type of this expression.
+///
+/// Each operation is wrapped in parentheses, as the precedence of bitwise
+/// operators differs between backends.
+pub trait BitwiseExpressionMethods: Expression + Sized {
+    /// Creates a SQL `&` expression, which keeps the bits set in both values.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate diesel;
+    /// # include!("../doctest_setup.rs");
+    /// #
+    /// # table! {
+    /// #     users {
+    /// #         id -> Integer,
+    /// #         name -> VarChar,
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     use self::users::dsl::*;
+    /// #     let connection = establish_connection();
+    /// let odd_ids = users.select(id).filter(id.bitand(1).eq(1)).load(&connection);
+    /// assert_eq!(Ok(vec![1]), odd_ids);
+    /// # }
+    /// ```
+    fn bitand<T>(self, other: T) -> BitAnd<Self, T::Expression>
+    where
+        T: AsExpression<Self::SqlType>,
+    {
+        BitAnd::new(self, other.as_expression())
+    }
+
+    /// Creates a SQL `|` expression, which keeps the bits set in either
+    /// value.
+    fn bitor<T>(self, other: T) -> BitOr<Self, T::Expression>
+    where
+        T: AsExpression<Self::SqlType>,
+    {
+        BitOr::new(self, other.as_expression())
+    }
+
+    /// Creates an exclusive or expression, which keeps the bits set in only
+    /// one of the values. This is `#` on PostgreSQL, and `^` on MySQL. SQLite
+    /// has no operator for it, so it is computed from `|`, `&` and `~`.
+    fn bitxor<T>(self, other: T) -> BitXor<Self, T::Expression>
+    where
+        T: AsExpression<Self::SqlType>,
+    {
+        BitXor::new(self, other.as_expression())
+    }
+
+    /// Creates a SQL `<<` expression, which shifts the bits of this value to
+    /// the left.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate diesel;
+    /// # include!("../doctest_setup.rs");
+    /// #
+    /// # table! {
+    /// #     users {
+    /// #         id -> Integer,
+    /// #         name -> VarChar,
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     use self::users::dsl::*;
+    /// #     let connection = establish_connection();
+    /// let flags = users.select(id.bitshift_left(4)).order(id).load(&connection);
+    /// assert_eq!(Ok(vec![16, 32]), flags);
+    /// # }
+    /// ```
+    fn bitshift_left<T>(self, bits: T) -> BitShiftLeft<Self, T::Expression>
+    where
+        T: AsExpression<Integer>,
+    {
+        BitShiftLeft::new(self, bits.as_expression())
+    }
+
+    /// Creates a SQL `>>` expression, which shifts the bits of this value to
+    /// the right.
+    fn bitshift_right<T>(self, bits: T) -> BitShiftRight<Self, T::Expression>
+    where
+        T: AsExpression<Integer>,
+    {
+        BitShiftRight::new(self, bits.as_expression())
+    }
+}
+
+impl<T> BitwiseExpressionMethods for T
+where
+    T: Expression,
+    T::SqlType: Bitwise,
+{
+}
@@ -1,7 +1,10 @@
-use expression::{nullable, AsExpression, Expression};
+use expression::{assume_not_null, nullable, AsExpression, Expression};
+use expression::aliased::Aliased;
 use expression::array_comparison::{AsInExpression, In, NotIn};
+use expression::eq_opt::EqOpt;
 use expression::operators::*;
-use types::SingleValue;
+use expression::window::{Over, Window, WindowFunction};
+use types::{NotNull, Nullable, SingleValue};
 
 pub trait ExpressionMethods: Expression + Sized {
     /// Creates a SQL `=` expression.
@@ -30,6 +33,47 @@ pub trait ExpressionMethods: Expression + Sized {
         Eq::new(self, other.as_expression())
     }
 
+    /// Creates a SQL `=` expression when `other` is `Some`, or a SQL `IS NULL`
+    /// expression when it is `None`. Unlike `.eq(None)`, which will never match
+    /// any rows, this will find rows where the value is `NULL`.
+    ///
+    /// This method is only available on nullable expressions.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate diesel;
+    /// # include!("../doctest_setup.rs");
+    /// #
+    /// # table! {
+    /// #     users {
+    /// #         id -> Integer,
+    /// #         name -> VarChar,
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     use self::animals::dsl::*;
+    /// #     let connection = establish_connection();
+    /// let jack: Option<&str> = Some("Jack");
+    /// let no_name: Option<&str> = None;
+    ///
+    /// let dog = animals.select(species).filter(name.eq_opt(jack));
+    /// let spider = animals.select(species).filter(name.eq_opt(no_name));
+    ///
+    /// assert_eq!(Ok("dog".to_string()), dog.first(&connection));
+    /// assert_eq!(Ok("spider".to_string()), spider.first(&connection));
+    /// # }
+    /// ```
+    fn eq_opt<ST, T>(self, other: Option<T>) -> EqOpt<Self, T::Expression>
+    where
+        Self: Expression<SqlType = Nullable<ST>>,
+        ST: NotNull,
+        T: AsExpression<Nullable<ST>>,
+    {
+        EqOpt::new(self, other.map(AsExpression::as_expression))
+    }
+
     /// Creates a SQL `!=` expression.
     ///
     /// # Example
@@ -147,6 +191,11 @@ pub trait ExpressionMethods: Expression + Sized {
 
     /// Creates a SQL `>` expression.
     ///
+    /// As with the other comparison methods, `other` can be a Rust value, or
+    /// another expression with the same SQL type, such as a column. Expressions
+    /// are written into the query directly, rather than being sent as bind
+    /// parameters.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -310,6 +359,67 @@ pub trait ExpressionMethods: Expression + Sized {
     fn asc(self) -> Asc<Self> {
         Asc::new(self)
     }
+
+    /// Gives this expression a name in the select clause (`expr AS alias`).
+    /// The alias can then be used in `.order` by calling `.alias()` on the
+    /// result, which avoids repeating the whole expression.
+    ///
+    /// Constant values can be given a name as well, by converting them to an
+    /// expression with [`.into_sql`](../expression/trait.IntoSql.html) first.
+    /// `"pending".into_sql::<Text>().aliased("status")` is written as
+    /// `$1::text AS "status"` on PostgreSQL, and is loaded like any other
+    /// column.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate diesel;
+    /// # include!("../doctest_setup.rs");
+    /// # use diesel::dsl::*;
+    /// #
+    /// # table! {
+    /// #     users {
+    /// #         id -> Integer,
+    /// #         name -> VarChar,
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     use schema::posts::dsl::*;
+    /// #     let connection = establish_connection();
+    /// let post_count = count_star().aliased("post_count");
+    /// // SELECT COUNT(*) AS "post_count" FROM "posts"
+    /// //     GROUP BY "posts"."user_id" ORDER BY "post_count" DESC
+    /// let post_counts = posts
+    ///     .group_by(user_id)
+    ///     .select(post_count)
+    ///     .order(post_count.alias().desc())
+    ///     .load(&connection);
+    /// assert_eq!(Ok(vec![2, 1]), post_counts);
+    /// # }
+    /// ```
+    fn aliased(self, alias: &str) -> Aliased<Self> {
+        Aliased::new(self, alias)
+    }
+
+    /// Evaluates this aggregate function over a window of rows, instead of
+    /// over the whole group (`expr OVER (window)`). The result has one value
+    /// per row, so it can be selected alongside columns. See the
+    /// [`window`](../../expression/window/index.html) module for how to define
+    /// the window.
+    ///
+    /// Only aggregate functions and the ranking functions, which implement
+    /// [`WindowFunction`](../../expression/window/trait.WindowFunction.html),
+    /// can be used with `OVER`.
+    fn over<Partition, Order, Frame>(
+        self,
+        window: Window<Partition, Order, Frame>,
+    ) -> Over<Self, Window<Partition, Order, Frame>>
+    where
+        Self: WindowFunction,
+    {
+        Over::new(self, window)
+    }
 }
 
 impl<T> ExpressionMethods for T
@@ -373,6 +483,61 @@ pub trait NullableExpressionMethods: Expression + Sized {
     fn nullable(self) -> nullable::Nullable<Self> {
         nullable::Nullable::new(self)
     }
+
+    /// Converts a nullable expression into one which is treated as not null.
+    /// This method has no impact on the generated SQL, and can be used when a
+    /// column is known not to be null, such as the right side of a left join
+    /// which is filtered to rows that have a match.
+    ///
+    /// If the database does return `NULL`, loading the value will fail with a
+    /// `DeserializationError`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate diesel;
+    /// # include!("../doctest_setup.rs");
+    /// #
+    /// # table! {
+    /// #     users {
+    /// #         id -> Integer,
+    /// #         name -> VarChar,
+    /// #     }
+    /// # }
+    /// #
+    /// # table! {
+    /// #     posts {
+    /// #         id -> Integer,
+    /// #         user_id -> Integer,
+    /// #         title -> VarChar,
+    /// #     }
+    /// # }
+    /// #
+    /// # joinable!(posts -> users (user_id));
+    /// #
+    /// # fn main() {
+    /// #     let connection = establish_connection();
+    /// let data = users::table
+    ///     .left_join(posts::table)
+    ///     .filter(posts::id.nullable().is_not_null())
+    ///     .select((users::name, posts::title.nullable().assume_not_null()))
+    ///     .order(posts::id.nullable())
+    ///     .load::<(String, String)>(&connection);
+    /// let expected = vec![
+    ///     ("Sean".to_string(), "My first post".to_string()),
+    ///     ("Sean".to_string(), "About Rust".to_string()),
+    ///     ("Tess".to_string(), "My first post too".to_string()),
+    /// ];
+    /// assert_eq!(Ok(expected), data);
+    /// # }
+    /// ```
+    fn assume_not_null<ST>(self) -> assume_not_null::AssumeNotNull<Self>
+    where
+        Self: Expression<SqlType = Nullable<ST>>,
+        ST: NotNull,
+    {
+        assume_not_null::AssumeNotNull::new(self)
+    }
 }
 
 impl<T: Expression> NullableExpressionMethods for T {}
@@ -4,13 +4,17 @@
 //! You can rely on the methods provided by this trait existing on any
 //! `Expression` of the appropriate type. You should not rely on the specific
 //! traits existing, their names, or their organization.
+pub mod bitwise_expression_methods;
 pub mod bool_expression_methods;
 pub mod escape_expression_methods;
 pub mod global_expression_methods;
 pub mod text_expression_methods;
+pub mod tuple_expression_methods;
 #[doc(hidden)]
 pub mod eq_all;
 
+#[doc(inline)]
+pub use self::bitwise_expression_methods::BitwiseExpressionMethods;
 #[doc(inline)]
 pub use self::bool_expression_methods::BoolExpressionMethods;
 #[doc(inline)]
@@ -19,6 +23,8 @@ pub use self::escape_expression_methods::EscapeExpressionMethods;
 pub use self::global_expression_methods::{ExpressionMethods, NullableExpressionMethods};
 #[doc(inline)]
 pub use self::text_expression_methods::TextExpressionMethods;
+#[doc(inline)]
+pub use self::tuple_expression_methods::TupleExpressionMethods;
 #[doc(hidden)]
 pub use self::eq_all::EqAll;
 
@@ -1,4 +1,5 @@
 use expression::{AsExpression, Expression};
+use expression::icontains::IContains;
 use expression::operators::{Concat, Like, NotLike};
 use types::Text;
 
@@ -43,6 +44,42 @@ pub trait TextExpressionMethods: Expression<SqlType = Text> + Sized {
     fn not_like<T: AsExpression<Text>>(self, other: T) -> NotLike<Self, T::Expression> {
         NotLike::new(self.as_expression(), other.as_expression())
     }
+
+    /// Checks whether the string contains `substring`, ignoring case.
+    ///
+    /// `%`, `_`, and `\` in `substring` are escaped, so they only match
+    /// themselves. On PostgreSQL this is rendered as
+    /// `expr ILIKE '%substring%'`. Other backends have no `ILIKE`, so both
+    /// sides are converted to lower case instead, as in
+    /// `lower(expr) LIKE lower('%substring%')`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate diesel;
+    /// # include!("../doctest_setup.rs");
+    /// #
+    /// # table! {
+    /// #     users {
+    /// #         id -> Integer,
+    /// #         name -> VarChar,
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     use self::users::dsl::*;
+    /// #     let connection = establish_connection();
+    /// #
+    /// let names = users.select(name).filter(name.icontains("EA")).load(&connection);
+    /// assert_eq!(Ok(vec!["Sean".to_string()]), names);
+    ///
+    /// let names = users.select(name).filter(name.icontains("%")).load::<String>(&connection);
+    /// assert_eq!(Ok(vec![]), names);
+    /// # }
+    /// ```
+    fn icontains(self, substring: &str) -> IContains<Self> {
+        IContains::new(self, substring)
+    }
 }
 
 impl<T: Expression<SqlType = Text>> TextExpressionMethods for T {}
@@ -0,0 +1,85 @@
+use expression::Expression;
+use expression::array_comparison::{AsInExpression, In, Subselect};
+use expression::grouped::Grouped;
+
+/// Methods present on tuples of expressions, such as the list of columns
+/// passed to `order`.
+pub trait TupleExpressionMethods: Expression + Sized {
+    /// The type returned by `desc`
+    type Desc;
+    /// The type returned by `asc`
+    type Asc;
+
+    /// Places `DESC` after every element of the tuple, so that
+    /// `(created_at, id).desc()` is the same as
+    /// `(created_at.desc(), id.desc())`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate diesel;
+    /// # include!("../doctest_setup.rs");
+    /// #
+    /// # table! {
+    /// #     users {
+    /// #         id -> Integer,
+    /// #         name -> VarChar,
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     use self::users::dsl::*;
+    /// #     let connection = establish_connection();
+    /// #     connection.execute("INSERT INTO users (name) VALUES ('Sean')").unwrap();
+    /// let data = users
+    ///     .select((id, name))
+    ///     .order((name, id).desc())
+    ///     .load::<(i32, String)>(&connection);
+    /// let expected = vec![
+    ///     (2, "Tess".to_string()),
+    ///     (3, "Sean".to_string()),
+    ///     (1, "Sean".to_string()),
+    /// ];
+    /// assert_eq!(Ok(expected), data);
+    /// # }
+    /// ```
+    fn desc(self) -> Self::Desc;
+
+    /// Places `ASC` after every element of the tuple, so that
+    /// `(created_at, id).asc()` is the same as
+    /// `(created_at.asc(), id.asc())`.
+    fn asc(self) -> Self::Asc;
+
+    /// Creates a SQL `IN` expression which checks whether the values of this
+    /// tuple appear together in a row of a subquery, generating
+    /// `(a, b) IN (SELECT x, y ...)`. The subquery has to select as many
+    /// columns as the tuple has, of the same SQL types.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate diesel;
+    /// # include!("../doctest_setup.rs");
+    /// # use schema::{posts, users};
+    /// #
+    /// # fn main() {
+    /// #     let connection = establish_connection();
+    /// let first_posts = posts::table
+    ///     .filter(posts::title.like("My first post%"))
+    ///     .select((posts::user_id, posts::id));
+    /// let titles = posts::table
+    ///     .select(posts::title)
+    ///     .filter((posts::user_id, posts::id).eq_any(first_posts))
+    ///     .order(posts::id)
+    ///     .load::<String>(&connection);
+    /// let expected = vec!["My first post".to_string(), "My first post too".to_string()];
+    /// assert_eq!(Ok(expected), titles);
+    /// # }
+    /// ```
+    fn eq_any<Q>(self, subquery: Q) -> In<Grouped<Self>, Subselect<Q, Self::SqlType>>
+    where
+        Q: AsInExpression<Self::SqlType, InExpression = Subselect<Q, Self::SqlType>>,
+    {
+        In::new(Grouped(self), subquery.as_in_expression())
+    }
+}
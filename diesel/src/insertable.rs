@@ -16,7 +16,21 @@ use sqlite::Sqlite;
 /// `#[derive(Insertable)]` to your struct. Structs which derive this trait must
 /// also be annotated with `#[table_name = "some_table_name"]`. If the field
 /// name of your struct differs from the name of the column, you can annotate
-/// the field with `#[column_name = "some_column_name"]`.
+/// the field with `#[column_name = "some_column_name"]`. Fields annotated with
+/// `#[diesel(skip_insertion)]` are not inserted, so that the database fills
+/// in the default of their column. This allows the same struct to be used for
+/// loading and inserting rows, leaving out an `id` column of type `SERIAL`.
+///
+/// The derived implementation is for `&YourStruct`, and binds each field by
+/// reference. Inserting `&new_user` does not clone the values of its fields, so
+/// there is no need to use `&str` instead of `String` to avoid copying data.
+///
+/// A field annotated with `#[diesel(serialize_as = "SomeType")]` is converted
+/// to `SomeType` before it is inserted, using `SomeType: From<FieldType>`.
+/// This allows a field to be stored differently from how it is used in Rust,
+/// such as an enum stored as an integer. The field is cloned to convert it.
+/// For an `Option` field, the value inside of it is converted, and `None`
+/// still inserts the default of the column.
 pub trait Insertable<T: Table, DB: Backend> {
     type Values: InsertValues<T, DB>;
 
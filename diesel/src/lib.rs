@@ -100,21 +100,84 @@ pub mod helper_types {
     /// Represents the return type of `.filter(lhs.eq(rhs))`
     pub type FindBy<Source, Column, Value> = Filter<Source, Eq<Column, Value>>;
 
+    /// Represents the return type of `.count()`
+    pub type Count<Source> = <Source as CountDsl>::Output;
+
     /// Represents the return type of `.for_update()`
     pub type ForUpdate<Source> = <Source as ForUpdateDsl>::Output;
 
+    /// Represents the return type of `.for_update_of(tables)`
+    pub type ForUpdateOf<Source, Tables> = <Source as ForUpdateOfDsl<Tables>>::Output;
+
+    /// Represents the return type of `.skip_locked()`
+    pub type SkipLocked<Source> = <Source as SkipLockedDsl>::Output;
+
+    /// Represents the return type of `.having(predicate)`
+    pub type Having<Source, Predicate> = <Source as HavingDsl<Predicate>>::Output;
+
     /// Represents the return type of `.find(pk)`
     pub type Find<Source, PK> = <Source as FindDsl<PK>>::Output;
 
     /// Represents the return type of `.order(ordering)`
     pub type Order<Source, Ordering> = <Source as OrderDsl<Ordering>>::Output;
 
+    /// Represents the return type of `.order_stable_by(ordering)`
+    pub type OrderStableBy<Source, Ordering> = <Source as StableOrderDsl<Ordering>>::Output;
+
+    /// Represents the return type of `.order_by_values(column, values)`
+    pub type OrderByValues<Source, Column, Value> =
+        <Source as OrderByValuesDsl<Column, Value>>::Output;
+
+    /// Represents the return type of `.nulls_last_everywhere()`
+    pub type NullsLastEverywhere<Source> = <Source as NullsLastEverywhereDsl>::Output;
+
+    /// Represents the return type of `.short_circuit_empty_in()`
+    pub type ShortCircuitEmptyIn<Source> = <Source as ShortCircuitEmptyInDsl>::Output;
+
     /// Represents the return type of `.limit()`
     pub type Limit<Source> = <Source as LimitDsl>::Output;
 
     /// Represents the return type of `.offset()`
     pub type Offset<Source> = <Source as OffsetDsl>::Output;
 
+    #[doc(hidden)]
+    pub type WithTotal<Source> = <Source as WithTotalDsl>::Output;
+
+    use super::query_builder::combination_clause::{self, All, Distinct};
+    use super::query_builder::{AsQuery, CombinationClause};
+
+    /// Represents the return type of `.union(rhs)`
+    pub type Union<Source, Rhs> = CombinationClause<
+        combination_clause::Union,
+        Distinct,
+        <Source as AsQuery>::Query,
+        <Rhs as AsQuery>::Query,
+    >;
+
+    /// Represents the return type of `.union_all(rhs)`
+    pub type UnionAll<Source, Rhs> = CombinationClause<
+        combination_clause::Union,
+        All,
+        <Source as AsQuery>::Query,
+        <Rhs as AsQuery>::Query,
+    >;
+
+    /// Represents the return type of `.intersect(rhs)`
+    pub type Intersect<Source, Rhs> = CombinationClause<
+        combination_clause::Intersect,
+        Distinct,
+        <Source as AsQuery>::Query,
+        <Rhs as AsQuery>::Query,
+    >;
+
+    /// Represents the return type of `.except(rhs)`
+    pub type Except<Source, Rhs> = CombinationClause<
+        combination_clause::Except,
+        Distinct,
+        <Source as AsQuery>::Query,
+        <Rhs as AsQuery>::Query,
+    >;
+
     /// Represents the return type of `.inner_join(rhs)`
     pub type InnerJoin<Source, Rhs> = <Source as JoinWithImplicitOnClause<
         Rhs,
@@ -141,7 +204,8 @@ pub mod prelude {
     //! Re-exports important traits and types. Meant to be glob imported when using Diesel.
     pub use associations::{GroupedBy, Identifiable};
     pub use connection::Connection;
-    pub use expression::{AppearsOnTable, BoxableExpression, Expression, SelectableExpression};
+    pub use expression::{AppearsOnTable, BoxableExpression, Expression, IntoSql,
+                         SelectableExpression};
     pub use expression_methods::*;
     #[doc(inline)]
     pub use insertable::Insertable;
@@ -161,7 +225,10 @@ pub use prelude::*;
 #[doc(inline)]
 pub use query_builder::debug_query;
 #[doc(inline)]
-pub use query_builder::functions::{default_values, delete, insert, select, update};
+pub use query_source::dynamic_table;
+#[doc(inline)]
+pub use query_builder::functions::{default_values, delete, delete_cascade, insert, select,
+                                   truncate, update};
 #[cfg(feature = "with-deprecated")]
 #[doc(inline)]
 #[allow(deprecated)]
@@ -163,7 +163,8 @@ macro_rules! impl_AsChangeset {
                 column_name: $column_name:ident,
                 field_ty: $field_ty:ty,
                 field_kind: $field_kind:ident,
-                $($rest:tt)*
+                inner_field_ty: $inner_field_ty:ty,
+                $(serialize_as: $serialize_as:ty,)*
             })+],
             struct_name = $struct_name:ident,
             $($headers:tt)*
@@ -173,7 +174,7 @@ macro_rules! impl_AsChangeset {
         impl_AsChangeset! {
             $($headers)*
             self_to_columns = $struct_name($(ref $column_name),+),
-            columns = ($($column_name, $field_kind),+),
+            columns = ($($column_name, $field_kind, ($($serialize_as)*)),+),
             field_names = [],
             changeset_ty = $changeset_ty,
         }
@@ -187,7 +188,8 @@ macro_rules! impl_AsChangeset {
                 column_name: $column_name:ident,
                 field_ty: $field_ty:ty,
                 field_kind: $field_kind:ident,
-                $($rest:tt)*
+                inner_field_ty: $inner_field_ty:ty,
+                $(serialize_as: $serialize_as:ty,)*
             })+],
             struct_name = $struct_name:ident,
             $($headers:tt)*
@@ -197,7 +199,7 @@ macro_rules! impl_AsChangeset {
         impl_AsChangeset! {
             $($headers)*
             self_to_columns = $struct_name { $($field_name: ref $column_name,)+ ..},
-            columns = ($($column_name, $field_kind),+),
+            columns = ($($column_name, $field_kind, ($($serialize_as)*)),+),
             field_names = [$($field_name)+],
             changeset_ty = $changeset_ty,
         }
@@ -210,7 +212,7 @@ macro_rules! impl_AsChangeset {
         struct_ty = $struct_ty:ty,
         lifetimes = ($($lifetime:tt),*),
         self_to_columns = $self_to_columns:pat,
-        columns = ($($column_name:ident, $field_kind:ident),+),
+        columns = ($($column_name:ident, $field_kind:ident, $serialize_as:tt),+),
         field_names = $field_names:tt,
         changeset_ty = $changeset_ty:ty,
     ) => {
@@ -231,6 +233,7 @@ macro_rules! impl_AsChangeset {
                             $column_name,
                             none_as_null = $treat_none_as_null,
                             field_kind = $field_kind,
+                            serialize_as = $serialize_as,
                         )
                     ,)+)
                 }
@@ -242,6 +245,26 @@ macro_rules! impl_AsChangeset {
 #[doc(hidden)]
 #[macro_export]
 macro_rules! AsChangeset_changeset_ty {
+    // Handle option field converted with serialize_as when treat none as null
+    // is false
+    (
+        table_name = $table_name:ident,
+        treat_none_as_null = "false",
+        field = {
+            $(field_name: $field_name:ident,)*
+            column_name: $column_name:ident,
+            field_ty: $ignore:ty,
+            field_kind: option,
+            inner_field_ty: $inner_field_ty:ty,
+            serialize_as: $serialize_as:ty,
+        },
+    ) => {
+        Option<$crate::dsl::Eq<
+            $table_name::$column_name,
+            $serialize_as,
+        >>
+    };
+
     // Handle option field when treat none as null is false
     (
         table_name = $table_name:ident,
@@ -261,6 +284,45 @@ macro_rules! AsChangeset_changeset_ty {
         >>
     };
 
+    // Handle option field converted with serialize_as when treat none as null
+    // is true
+    (
+        table_name = $table_name:ident,
+        treat_none_as_null = $treat_none_as_null:tt,
+        field = {
+            $(field_name: $field_name:ident,)*
+            column_name: $column_name:ident,
+            field_ty: $ignore:ty,
+            field_kind: option,
+            inner_field_ty: $inner_field_ty:ty,
+            serialize_as: $serialize_as:ty,
+        },
+    ) => {
+        $crate::dsl::Eq<
+            $table_name::$column_name,
+            Option<$serialize_as>,
+        >
+    };
+
+    // Handle normal field converted with serialize_as
+    (
+        table_name = $table_name:ident,
+        treat_none_as_null = $treat_none_as_null:tt,
+        field = {
+            $(field_name: $field_name:ident,)*
+            column_name: $column_name:ident,
+            field_ty: $ignore:ty,
+            field_kind: $field_kind:ident,
+            inner_field_ty: $inner_field_ty:ty,
+            serialize_as: $serialize_as:ty,
+        },
+    ) => {
+        $crate::dsl::Eq<
+            $table_name::$column_name,
+            $serialize_as,
+        >
+    };
+
     // Handle normal field or option when treat none as null is true
     (
         table_name = $table_name:ident,
@@ -279,6 +341,9 @@ macro_rules! AsChangeset_changeset_ty {
     };
 }
 
+/// Fields annotated with `#[diesel(serialize_as = "Type")]` are cloned and
+/// converted to `Type` with `From`. For option fields, `Type` is what the
+/// value inside of the option is converted to.
 #[doc(hidden)]
 #[macro_export]
 macro_rules! AsChangeset_column_expr {
@@ -288,18 +353,58 @@ macro_rules! AsChangeset_column_expr {
         $field_access:expr,
         none_as_null = "false",
         field_kind = option,
+        serialize_as = (),
     ) => {
         $field_access.as_ref().map(|f| $column.eq(f))
     };
 
+    (
+        $column:expr,
+        $field_access:expr,
+        none_as_null = "false",
+        field_kind = option,
+        serialize_as = ($serialize_as:ty),
+    ) => {
+        $field_access.as_ref().map(|f| {
+            $column.eq(<$serialize_as as ::std::convert::From<_>>::from(
+                ::std::clone::Clone::clone(f),
+            ))
+        })
+    };
+
     // If none_as_null is true, or the field kind isn't option, assign blindly
     (
         $column:expr,
         $field_access:expr,
-        $($args:tt)*
+        none_as_null = $none_as_null:tt,
+        field_kind = $field_kind:ident,
+        serialize_as = (),
     ) => {
         $column.eq($field_access)
     };
+
+    (
+        $column:expr,
+        $field_access:expr,
+        none_as_null = $none_as_null:tt,
+        field_kind = option,
+        serialize_as = ($serialize_as:ty),
+    ) => {
+        $column.eq(::std::clone::Clone::clone($field_access)
+            .map(<$serialize_as as ::std::convert::From<_>>::from))
+    };
+
+    (
+        $column:expr,
+        $field_access:expr,
+        none_as_null = $none_as_null:tt,
+        field_kind = $field_kind:ident,
+        serialize_as = ($serialize_as:ty),
+    ) => {
+        $column.eq(<$serialize_as as ::std::convert::From<_>>::from(
+            ::std::clone::Clone::clone($field_access),
+        ))
+    };
 }
 
 #[cfg(test)]
@@ -120,13 +120,13 @@ macro_rules! impl_Insertable {
             field_ty: $ignore:ty,
             field_kind: $field_kind:ident,
             inner_field_ty: $field_ty:ty,
-            $($rest:tt)*
+            $(serialize_as: $serialize_as:ty,)*
         })+],
     ) => {
         impl_Insertable! {
             $($headers)*
             self_to_columns = $struct_name($(ref $column_name),+),
-            columns = ($($column_name, $field_ty, $field_kind),+),
+            columns = ($($column_name, $field_ty, $field_kind, ($($serialize_as)*)),+),
         }
     };
 
@@ -142,13 +142,13 @@ macro_rules! impl_Insertable {
             field_ty: $ignore:ty,
             field_kind: $field_kind:ident,
             inner_field_ty: $field_ty:ty,
-            $($rest:tt)*
+            $(serialize_as: $serialize_as:ty,)*
         })+],
     ) => {
         impl_Insertable! {
             $($headers)*
-            self_to_columns = $struct_name { $($field_name: ref $column_name),+ },
-            columns = ($($column_name, $field_ty, $field_kind),+),
+            self_to_columns = $struct_name { $($field_name: ref $column_name,)+ .. },
+            columns = ($($column_name, $field_ty, $field_kind, ($($serialize_as)*)),+),
         }
     };
 
@@ -157,7 +157,7 @@ macro_rules! impl_Insertable {
         struct_ty = $struct_ty:ty,
         lifetimes = ($($lifetime:tt),*),
         self_to_columns = $self_to_columns:pat,
-        columns = ($($column_name:ident, $field_ty:ty, $field_kind:ident),+),
+        columns = ($($column_name:ident, $field_ty:ty, $field_kind:ident, $serialize_as:tt),+),
     ) => { __diesel_parse_as_item! {
         impl<$($lifetime,)* 'insert, DB> $crate::insertable::Insertable<$table_name::table, DB>
             for &'insert $struct_ty where
@@ -166,7 +166,7 @@ macro_rules! impl_Insertable {
                     $crate::insertable::ColumnInsertValue<
                         $table_name::$column_name,
                         $crate::dsl::AsExpr<
-                            &'insert $field_ty,
+                            Insertable_value_ty!('insert, $field_ty, $serialize_as),
                             $table_name::$column_name,
                         >,
                     >
@@ -176,7 +176,7 @@ macro_rules! impl_Insertable {
                 $crate::insertable::ColumnInsertValue<
                     $table_name::$column_name,
                     $crate::dsl::AsExpr<
-                        &'insert $field_ty,
+                        Insertable_value_ty!('insert, $field_ty, $serialize_as),
                         $table_name::$column_name,
                     >,
                 >
@@ -188,7 +188,12 @@ macro_rules! impl_Insertable {
                 use $crate::insertable::ColumnInsertValue;
                 let $self_to_columns = *self;
                 ($(
-                    Insertable_column_expr!($table_name::$column_name, $column_name, $field_kind)
+                    Insertable_column_expr!(
+                        $table_name::$column_name,
+                        $column_name,
+                        $field_kind,
+                        $serialize_as
+                    )
                 ,)+)
             }
         }
@@ -215,9 +220,41 @@ macro_rules! impl_Insertable {
     }};
 }
 
+/// The type of the value which is bound for a field. Fields are bound by
+/// reference, unless they are converted to another type with
+/// `#[diesel(serialize_as = "Type")]`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! Insertable_value_ty {
+    ($lifetime:tt, $field_ty:ty, ()) => { &$lifetime $field_ty };
+    ($lifetime:tt, $field_ty:ty, ($serialize_as:ty)) => { $serialize_as };
+}
+
 #[doc(hidden)]
 #[macro_export]
 macro_rules! Insertable_column_expr {
+    ($column:path, $field_access:expr, $field_kind:ident, ()) => {
+        Insertable_column_expr!($column, $field_access, $field_kind)
+    };
+
+    ($column:path, $field_access:expr, option, ($serialize_as:ty)) => {
+        match *$field_access {
+            Some(ref value) => Insertable_column_expr!($column, value, regular, ($serialize_as)),
+            None => ColumnInsertValue::Default($column),
+        }
+    };
+
+    ($column:path, $field_access:expr, $field_kind:ident, ($serialize_as:ty)) => {
+        ColumnInsertValue::Expression(
+            $column,
+            <$serialize_as as AsExpression<<$column as Expression>::SqlType>>::as_expression(
+                <$serialize_as as ::std::convert::From<_>>::from(
+                    ::std::clone::Clone::clone($field_access),
+                ),
+            ),
+        )
+    };
+
     ($column:path, $field_access:expr, option) => {
         match *$field_access {
             Some(ref value) => Insertable_column_expr!($column, value, regular),
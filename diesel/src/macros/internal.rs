@@ -1,6 +1,7 @@
 /// This will implement `SelectableExpression` and `AppearsOnTable` for "simple"
 /// composite nodes where the where clause is roughly `AllTyParams:
-/// SelectableExpression<QS>, Self: Expression`.
+/// SelectableExpression<QS>, Self: Expression`. These nodes are also never
+/// columns, so this calls `impl_unchecked_distinct_ordering!` as well.
 ///
 /// This macro is exported because we want to be able to call it from other
 /// macros that are exported, but it is not part of our public API.
@@ -32,5 +33,42 @@ macro_rules! impl_selectable_expression {
                 $($ty_params: $crate::expression::AppearsOnTable<QS>,)*
         {
         }
+
+        impl_unchecked_distinct_ordering!(ty_params = ($($ty_params),*), struct_ty = $struct_ty,);
+    };
+}
+
+/// This will implement the traits which check the `ORDER BY` clause of a
+/// `SELECT DISTINCT` query for an expression which is not a column. It is
+/// never counted as a selected column, and is not checked when a query is
+/// ordered by it.
+///
+/// This macro is exported because we want to be able to call it from other
+/// macros that are exported, but it is not part of our public API.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_unchecked_distinct_ordering {
+    ($struct_name:ident) => {
+        impl_unchecked_distinct_ordering!(ty_params = (), struct_ty = $struct_name,);
+    };
+
+    ($struct_name:ident<$($ty_params:ident),+>) => {
+        impl_unchecked_distinct_ordering!(
+            ty_params = ($($ty_params),+),
+            struct_ty = $struct_name<$($ty_params),+>,
+        );
+    };
+
+    (ty_params = ($($ty_params:ident),*), struct_ty = $struct_ty:ty,) => {
+        #[allow(non_camel_case_types)]
+        impl<$($ty_params,)* Col> $crate::query_builder::SelectsColumn<Col> for $struct_ty {
+            type Count = $crate::query_source::Never;
+        }
+
+        #[allow(non_camel_case_types)]
+        impl<$($ty_params,)* Selection> $crate::query_builder::ValidOrderTermForDistinct<Selection>
+            for $struct_ty
+        {
+        }
     };
 }
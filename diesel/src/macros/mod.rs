@@ -29,6 +29,16 @@ macro_rules! __diesel_column {
         impl SelectableExpression<$($table)::*> for $column_name {
         }
 
+        impl<'a> SelectableExpression<$crate::query_source::DynamicTable<'a, $($table)::*>>
+            for $column_name
+        {
+        }
+
+        impl<Q> SelectableExpression<$crate::query_source::Lateral<Q, $($table)::*>>
+            for $column_name
+        {
+        }
+
         impl<QS> AppearsOnTable<QS> for $column_name where
             QS: AppearsInFromClause<$($table)::*, Count=Once>,
         {
@@ -87,6 +97,38 @@ macro_rules! __diesel_column {
     }
 }
 
+/// Implements `IsSameColumn` for every pair of columns of a table, by pairing
+/// each column with itself and with all of the columns before it.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __diesel_impl_is_same_column {
+    (previous_columns = [$($previous:ident,)*], columns = []) => {};
+
+    (
+        previous_columns = [$($previous:ident,)*],
+        columns = [$column:ident, $($rest:ident,)*]
+    ) => {
+        impl $crate::query_builder::IsSameColumn<$column> for $column {
+            type Count = $crate::query_source::Once;
+        }
+
+        $(
+            impl $crate::query_builder::IsSameColumn<$previous> for $column {
+                type Count = $crate::query_source::Never;
+            }
+
+            impl $crate::query_builder::IsSameColumn<$column> for $previous {
+                type Count = $crate::query_source::Never;
+            }
+        )*
+
+        __diesel_impl_is_same_column!(
+            previous_columns = [$($previous,)* $column,],
+            columns = [$($rest,)*]
+        );
+    };
+}
+
 /// Specifies that a table exists, and what columns it has. This will create a
 /// new public module, with the same name, as the name of the table. In this
 /// module, you'll find a unit struct named `table`, and a unit struct with the
@@ -825,6 +867,8 @@ macro_rules! table_body {
                 }
 
                 $(__diesel_column!(table, $column_name -> ($($column_ty)*), $sql_name, $($doc),*);)+
+
+                __diesel_impl_is_same_column!(previous_columns = [], columns = [$($column_name,)+]);
             }
         }
     }
@@ -2,6 +2,8 @@ use std::collections::HashSet;
 use std::iter::FromIterator;
 
 use prelude::*;
+use query_builder::QueryFragment;
+use query_builder::insert_statement::DefaultValuesClause;
 use super::schema::NewMigration;
 use super::schema::__diesel_schema_migrations::dsl::*;
 use types::{FromSql, VarChar};
@@ -21,6 +23,7 @@ where
     T: Connection,
     String: FromSql<VarChar, T::Backend>,
     for<'a> &'a NewMigration<'a>: Insertable<__diesel_schema_migrations, T::Backend>,
+    DefaultValuesClause: QueryFragment<T::Backend>,
 {
     fn previously_run_migration_versions(&self) -> QueryResult<HashSet<String>> {
         __diesel_schema_migrations
@@ -17,6 +17,12 @@ use types::HasSqlType;
 #[allow(missing_debug_implementations, missing_copy_implementations)]
 /// A connection to a MySQL database. Connection URLs should be in the form
 /// `mysql://[user[:password]@]host/database_name`
+///
+/// By default, the number of rows returned from `execute` for an `UPDATE` is
+/// the number of rows which were actually changed. Rows which matched the
+/// `WHERE` clause but already had the new values are not counted, which
+/// differs from PostgreSQL and SQLite. Add `?found_rows=true` to the
+/// connection URL to have MySQL return the number of matched rows instead.
 pub struct MysqlConnection {
     raw_connection: RawConnection,
     transaction_manager: AnsiTransactionManager,
@@ -85,6 +91,14 @@ impl Connection for MysqlConnection {
         f()
     }
 
+    fn execute_query<T>(&self, query: &T) -> QueryResult<usize>
+    where
+        T: Query + QueryFragment<Self::Backend> + QueryId,
+    {
+        let stmt = try!(self.prepare_query(query));
+        unsafe { stmt.execute_and_count_rows() }
+    }
+
     #[doc(hidden)]
     fn execute_returning_count<T>(&self, source: &T) -> QueryResult<usize>
     where
@@ -7,7 +7,11 @@ use std::sync::{Once, ONCE_INIT};
 
 use result::{ConnectionError, ConnectionResult, QueryResult};
 use super::url::ConnectionOptions;
-use super::stmt::Statement;
+use super::stmt::{connection_was_lost, Statement};
+
+// Defined in mysql_com.h. Makes the affected row count of an `UPDATE`
+// include rows which were matched but already had the new values.
+const CLIENT_FOUND_ROWS: libc::c_ulong = 2;
 
 pub struct RawConnection(*mut ffi::MYSQL);
 
@@ -47,6 +51,11 @@ impl RawConnection {
         let password = connection_options.password();
         let database = connection_options.database();
         let port = connection_options.port();
+        let client_flags = if connection_options.found_rows() {
+            CLIENT_FOUND_ROWS
+        } else {
+            0
+        };
 
         unsafe {
             // Make sure you don't use the fake one!
@@ -62,7 +71,7 @@ impl RawConnection {
                     .unwrap_or_else(|| ptr::null_mut()),
                 u32::from(port.unwrap_or(0)),
                 ptr::null_mut(),
-                0,
+                client_flags,
             )
         };
 
@@ -137,11 +146,13 @@ impl RawConnection {
 
     fn did_an_error_occur(&self) -> QueryResult<()> {
         use result::DatabaseErrorKind;
-        use result::Error::DatabaseError;
+        use result::Error::{ConnectionClosed, DatabaseError};
 
         let error_message = self.last_error_message();
         if error_message.is_empty() {
             Ok(())
+        } else if connection_was_lost(unsafe { ffi::mysql_errno(self.0) }) {
+            Err(ConnectionClosed(Box::new(error_message)))
         } else {
             Err(DatabaseError(
                 DatabaseErrorKind::__Unknown,
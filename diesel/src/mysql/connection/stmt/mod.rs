@@ -58,6 +58,19 @@ impl Statement {
         self.did_an_error_occur()
     }
 
+    /// Executes a query which returns rows, and discards the rows without
+    /// fetching them into output binds. Like `execute`, this should never be
+    /// called on a statement on which `results` has previously been called.
+    pub unsafe fn execute_and_count_rows(&self) -> QueryResult<usize> {
+        ffi::mysql_stmt_execute(self.stmt);
+        try!(self.did_an_error_occur());
+        ffi::mysql_stmt_store_result(self.stmt);
+        try!(self.did_an_error_occur());
+        let row_count = ffi::mysql_stmt_num_rows(self.stmt);
+        ffi::mysql_stmt_free_result(self.stmt);
+        Ok(row_count as usize)
+    }
+
     pub fn affected_rows(&self) -> usize {
         let affected_rows = unsafe { ffi::mysql_stmt_affected_rows(self.stmt) };
         affected_rows as usize
@@ -99,11 +112,13 @@ impl Statement {
     }
 
     fn did_an_error_occur(&self) -> QueryResult<()> {
-        use result::Error::DatabaseError;
+        use result::Error::{ConnectionClosed, DatabaseError};
 
         let error_message = self.last_error_message();
         if error_message.is_empty() {
             Ok(())
+        } else if connection_was_lost(unsafe { ffi::mysql_stmt_errno(self.stmt) }) {
+            Err(ConnectionClosed(Box::new(error_message)))
         } else {
             Err(DatabaseError(
                 self.last_error_type(),
@@ -125,6 +140,13 @@ impl Statement {
     }
 }
 
+/// Whether the error number is `CR_SERVER_GONE_ERROR` or `CR_SERVER_LOST`,
+/// which are returned by the client library when the server closed the
+/// connection.
+pub(crate) fn connection_was_lost(error_number: libc::c_uint) -> bool {
+    error_number == 2006 || error_number == 2013
+}
+
 impl Drop for Statement {
     fn drop(&mut self) {
         unsafe { ffi::mysql_stmt_close(self.stmt) };
@@ -11,6 +11,7 @@ pub struct ConnectionOptions {
     password: Option<CString>,
     database: Option<CString>,
     port: Option<u16>,
+    found_rows: bool,
 }
 
 impl ConnectionOptions {
@@ -41,6 +42,16 @@ impl ConnectionOptions {
             Some("") | None => None,
             Some(segment) => Some(try!(CString::new(segment.as_bytes()))),
         };
+        let mut found_rows = false;
+        for (key, value) in url.query_pairs() {
+            if key == "found_rows" {
+                found_rows = match &*value {
+                    "true" => true,
+                    "false" => false,
+                    _ => return Err(connection_url_error()),
+                };
+            }
+        }
 
         Ok(ConnectionOptions {
             host: host,
@@ -48,6 +59,7 @@ impl ConnectionOptions {
             password: password,
             database: database,
             port: url.port(),
+            found_rows: found_rows,
         })
     }
 
@@ -70,11 +82,15 @@ impl ConnectionOptions {
     pub fn port(&self) -> Option<u16> {
         self.port
     }
+
+    pub fn found_rows(&self) -> bool {
+        self.found_rows
+    }
 }
 
 fn connection_url_error() -> ConnectionError {
     let msg = "MySQL connection URLs must be in the form \
-               `mysql://[[user]:[password]@]host[:port][/database]`";
+               `mysql://[[user]:[password]@]host[:port][/database][?found_rows=true]`";
     ConnectionError::InvalidConnectionUrl(msg.into())
 }
 
@@ -116,3 +132,17 @@ fn first_path_segment_is_treated_as_database() {
             .database()
     );
 }
+
+#[test]
+fn found_rows_is_read_from_the_query_string() {
+    assert!(!ConnectionOptions::parse("mysql://localhost/foo")
+        .unwrap()
+        .found_rows());
+    assert!(ConnectionOptions::parse("mysql://localhost/foo?found_rows=true")
+        .unwrap()
+        .found_rows());
+    assert!(!ConnectionOptions::parse("mysql://localhost/foo?found_rows=false")
+        .unwrap()
+        .found_rows());
+    assert!(ConnectionOptions::parse("mysql://localhost/foo?found_rows=yes").is_err());
+}
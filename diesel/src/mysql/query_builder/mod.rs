@@ -1,5 +1,5 @@
 use super::backend::Mysql;
-use query_builder::QueryBuilder;
+use query_builder::{check_identifier, escape_identifier, QueryBuilder, SqlBuffer};
 use result::QueryResult;
 
 mod query_fragment_impls;
@@ -7,7 +7,7 @@ mod query_fragment_impls;
 #[allow(missing_debug_implementations)]
 #[derive(Default)]
 pub struct MysqlQueryBuilder {
-    sql: String,
+    sql: SqlBuffer,
 }
 
 impl MysqlQueryBuilder {
@@ -18,12 +18,17 @@ impl MysqlQueryBuilder {
 
 impl QueryBuilder<Mysql> for MysqlQueryBuilder {
     fn push_sql(&mut self, sql: &str) {
-        self.sql.push_str(sql);
+        self.sql.push_fragment(sql);
+    }
+
+    fn push_sql_verbatim(&mut self, sql: &str) {
+        self.sql.push_verbatim(sql);
     }
 
     fn push_identifier(&mut self, identifier: &str) -> QueryResult<()> {
+        check_identifier(identifier)?;
         self.push_sql("`");
-        self.push_sql(&identifier.replace("`", "``"));
+        self.push_sql(&escape_identifier(identifier, '`'));
         self.push_sql("`");
         Ok(())
     }
@@ -33,6 +38,6 @@ impl QueryBuilder<Mysql> for MysqlQueryBuilder {
     }
 
     fn finish(self) -> String {
-        self.sql
+        self.sql.finish()
     }
 }
@@ -1,7 +1,26 @@
+use expression::Expression;
+use expression::bound::TypedBound;
+use expression::functions::aggregate_boolean::{BoolAnd, BoolOr};
+use expression::functions::date_and_time::{DatePart, Extract, ExtractFrom};
+use expression::functions::string_agg::StringAgg;
+use expression::icontains::IContains;
+use expression::null::Null;
+use expression::ops::BitXor;
+use expression::order_by_values::OrderByValues;
+use expression::operators::{Asc, Desc};
 use mysql::Mysql;
-use query_builder::{AstPass, QueryFragment};
+use query_builder::{AliasReference, AstPass, QueryFragment, ValidDistinctOrdering,
+                    ValidOrderingForDistinct};
+use query_builder::distinct_clause::DistinctClause;
+use query_builder::group_by_clause::HavingClause;
+use query_builder::insert_statement::DefaultValuesClause;
 use query_builder::for_update_clause::ForUpdateClause;
+use query_builder::order_clause::NullsLastOrder;
+use query_builder::truncate_statement::{NoCascadeClause, TruncateStatement};
+use query_builder::update_statement::{UpdateLimitClause, UpdateOrderClause};
+use query_source::{Column, Table};
 use result::QueryResult;
+use types::{HasSqlType, ToSql, VarChar};
 
 impl QueryFragment<Mysql> for ForUpdateClause {
     fn walk_ast(&self, mut out: AstPass<Mysql>) -> QueryResult<()> {
@@ -9,3 +28,242 @@ impl QueryFragment<Mysql> for ForUpdateClause {
         Ok(())
     }
 }
+
+impl<T, U> QueryFragment<Mysql> for TypedBound<T, U>
+where
+    Mysql: HasSqlType<T>,
+    U: ToSql<T, Mysql>,
+{
+    fn walk_ast(&self, out: AstPass<Mysql>) -> QueryResult<()> {
+        self.bound.walk_ast(out)
+    }
+}
+
+/// MySQL always resets the auto increment counter when truncating a table, so
+/// `RESTART IDENTITY` is never rendered.
+impl<T, Identity> QueryFragment<Mysql> for TruncateStatement<T, Identity, NoCascadeClause>
+where
+    T: Table,
+    T::FromClause: QueryFragment<Mysql>,
+{
+    fn walk_ast(&self, mut out: AstPass<Mysql>) -> QueryResult<()> {
+        out.push_sql("TRUNCATE TABLE ");
+        self.table.from_clause().walk_ast(out.reborrow())?;
+        Ok(())
+    }
+}
+
+impl<ST> QueryFragment<Mysql> for Null<ST> {
+    fn walk_ast(&self, mut out: AstPass<Mysql>) -> QueryResult<()> {
+        out.push_sql("NULL");
+        Ok(())
+    }
+}
+
+/// MySQL only accepts a string literal as the separator, so the delimiter is
+/// escaped and written into the query.
+impl<'a, T, O> QueryFragment<Mysql> for StringAgg<'a, T, O>
+where
+    T: QueryFragment<Mysql>,
+    O: QueryFragment<Mysql>,
+{
+    fn walk_ast(&self, mut out: AstPass<Mysql>) -> QueryResult<()> {
+        // `SEPARATOR` only accepts a string literal, so the delimiter is
+        // prepended to each value and stripped from the start of the result
+        out.push_sql("SUBSTRING(GROUP_CONCAT(CONCAT(");
+        out.push_bind_param::<VarChar, _>(&self.delimiter)?;
+        out.push_sql(", ");
+        self.expr.walk_ast(out.reborrow())?;
+        out.push_sql(")");
+        self.order.walk_ast(out.reborrow())?;
+        out.push_sql(" SEPARATOR ''), CHAR_LENGTH(");
+        out.push_bind_param::<VarChar, _>(&self.delimiter)?;
+        out.push_sql(") + 1)");
+        Ok(())
+    }
+}
+
+impl<GroupBy, Predicate> QueryFragment<Mysql> for HavingClause<GroupBy, Predicate>
+where
+    GroupBy: QueryFragment<Mysql>,
+    Predicate: QueryFragment<Mysql>,
+{
+    fn walk_ast(&self, out: AstPass<Mysql>) -> QueryResult<()> {
+        self.walk_with_aliases(out, AliasReference::Name)
+    }
+}
+
+/// MySQL has no boolean aggregates, but stores booleans as integers, so the
+/// smallest and largest values give the same result.
+impl<T> QueryFragment<Mysql> for BoolAnd<T>
+where
+    T: QueryFragment<Mysql>,
+{
+    fn walk_ast(&self, out: AstPass<Mysql>) -> QueryResult<()> {
+        self.walk_with_function(out, "MIN")
+    }
+}
+
+impl<T> QueryFragment<Mysql> for BoolOr<T>
+where
+    T: QueryFragment<Mysql>,
+{
+    fn walk_ast(&self, out: AstPass<Mysql>) -> QueryResult<()> {
+        self.walk_with_function(out, "MAX")
+    }
+}
+
+/// MySQL has no `ILIKE`, so both sides are compared in lower case.
+impl<T> QueryFragment<Mysql> for IContains<T>
+where
+    T: QueryFragment<Mysql>,
+{
+    fn walk_ast(&self, mut out: AstPass<Mysql>) -> QueryResult<()> {
+        out.push_sql("lower(");
+        self.expr.walk_ast(out.reborrow())?;
+        out.push_sql(") LIKE lower(");
+        out.push_bind_param::<VarChar, _>(&self.pattern)?;
+        out.push_sql(") ESCAPE ");
+        out.push_bind_param::<VarChar, _>(&"\\")
+    }
+}
+
+impl QueryFragment<Mysql> for DefaultValuesClause {
+    fn walk_ast(&self, mut out: AstPass<Mysql>) -> QueryResult<()> {
+        out.push_sql(" () VALUES ()");
+        Ok(())
+    }
+}
+
+/// `FIELD` returns 0 for values which are not in the list, so rows with those
+/// values come first.
+impl<T, U> QueryFragment<Mysql> for OrderByValues<T, U>
+where
+    Mysql: HasSqlType<T::SqlType>,
+    T: Expression + QueryFragment<Mysql>,
+    U: ToSql<T::SqlType, Mysql>,
+{
+    fn walk_ast(&self, mut out: AstPass<Mysql>) -> QueryResult<()> {
+        out.unsafe_to_cache_prepared();
+        if self.values.is_empty() {
+            out.push_sql("NULL");
+            return Ok(());
+        }
+
+        out.push_sql("FIELD(");
+        self.column.walk_ast(out.reborrow())?;
+        for value in &self.values {
+            out.push_sql(", ");
+            out.push_bind_param::<T::SqlType, _>(value)?;
+        }
+        out.push_sql(")");
+        Ok(())
+    }
+}
+
+impl<Expr> QueryFragment<Mysql> for UpdateOrderClause<Expr>
+where
+    Expr: QueryFragment<Mysql>,
+{
+    fn walk_ast(&self, out: AstPass<Mysql>) -> QueryResult<()> {
+        self.0.walk_ast(out)
+    }
+}
+
+impl<Expr> QueryFragment<Mysql> for UpdateLimitClause<Expr>
+where
+    Expr: QueryFragment<Mysql>,
+{
+    fn walk_ast(&self, out: AstPass<Mysql>) -> QueryResult<()> {
+        self.0.walk_ast(out)
+    }
+}
+
+/// `EXTRACT` has no unit for the day of the week, the day of the year, or the
+/// epoch, so the equivalent functions are used for those. Fractional seconds
+/// are included by extracting the seconds together with the microseconds.
+impl<T> QueryFragment<Mysql> for Extract<T>
+where
+    T: Expression + QueryFragment<Mysql>,
+    T::SqlType: ExtractFrom,
+{
+    fn walk_ast(&self, mut out: AstPass<Mysql>) -> QueryResult<()> {
+        self.check_part()?;
+        let (prefix, suffix) = match self.part {
+            DatePart::Year => ("EXTRACT(YEAR FROM ", ")"),
+            DatePart::Month => ("EXTRACT(MONTH FROM ", ")"),
+            DatePart::Day => ("EXTRACT(DAY FROM ", ")"),
+            DatePart::Hour => ("EXTRACT(HOUR FROM ", ")"),
+            DatePart::Minute => ("EXTRACT(MINUTE FROM ", ")"),
+            DatePart::Second => ("EXTRACT(SECOND_MICROSECOND FROM ", ") / 1000000"),
+            DatePart::DayOfWeek => ("(DAYOFWEEK(", ") - 1)"),
+            DatePart::DayOfYear => ("DAYOFYEAR(", ")"),
+            DatePart::Epoch => ("UNIX_TIMESTAMP(", ")"),
+        };
+        out.push_sql(prefix);
+        self.expr.walk_ast(out.reborrow())?;
+        out.push_sql(suffix);
+        Ok(())
+    }
+}
+
+impl<Lhs, Rhs> QueryFragment<Mysql> for BitXor<Lhs, Rhs>
+where
+    Lhs: QueryFragment<Mysql>,
+    Rhs: QueryFragment<Mysql>,
+{
+    fn walk_ast(&self, mut out: AstPass<Mysql>) -> QueryResult<()> {
+        out.push_sql("(");
+        self.lhs.walk_ast(out.reborrow())?;
+        out.push_sql(" ^ ");
+        self.rhs.walk_ast(out.reborrow())?;
+        out.push_sql(")");
+        Ok(())
+    }
+}
+
+/// MySQL has no `NULLS LAST`, so nulls are sorted last by ordering by
+/// `expr IS NULL` first.
+impl<T> NullsLastOrder<Mysql> for T
+where
+    T: Column + QueryFragment<Mysql>,
+{
+    fn walk_nulls_last(&self, mut out: AstPass<Mysql>) -> QueryResult<()> {
+        self.walk_ast(out.reborrow())?;
+        out.push_sql(" IS NULL, ");
+        self.walk_ast(out.reborrow())?;
+        Ok(())
+    }
+}
+
+impl<T> NullsLastOrder<Mysql> for Asc<T>
+where
+    T: QueryFragment<Mysql>,
+{
+    fn walk_nulls_last(&self, mut out: AstPass<Mysql>) -> QueryResult<()> {
+        self.expr().walk_ast(out.reborrow())?;
+        out.push_sql(" IS NULL, ");
+        self.walk_ast(out.reborrow())?;
+        Ok(())
+    }
+}
+
+impl<T> NullsLastOrder<Mysql> for Desc<T>
+where
+    T: QueryFragment<Mysql>,
+{
+    fn walk_nulls_last(&self, mut out: AstPass<Mysql>) -> QueryResult<()> {
+        self.expr().walk_ast(out.reborrow())?;
+        out.push_sql(" IS NULL, ");
+        self.walk_ast(out.reborrow())?;
+        Ok(())
+    }
+}
+
+/// MySQL 5.7 and later reject ordering a `SELECT DISTINCT` query by a column
+/// which is not selected, like PostgreSQL.
+impl<O, Selection> ValidDistinctOrdering<Mysql, O, Selection> for DistinctClause
+where
+    O: ValidOrderingForDistinct<Selection>,
+{
+}
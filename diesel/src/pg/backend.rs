@@ -16,6 +16,14 @@ pub struct PgTypeMetadata {
     pub array_oid: u32,
 }
 
+/// The name PostgreSQL uses to refer to a SQL type. This is used when Diesel
+/// needs to render an explicit cast, such as for bind parameters created with
+/// [`into_sql`](../expression/trait.IntoSql.html).
+pub trait PgTypeName {
+    /// The name of this type, as it would be written in `expr::type_name`
+    const TYPE_NAME: &'static str;
+}
+
 impl Queryable<(Oid, Oid), Pg> for PgTypeMetadata {
     type Row = (u32, u32);
 
@@ -0,0 +1,86 @@
+use std::fmt;
+use std::mem;
+
+use result::QueryResult;
+use super::PgConnection;
+
+/// The key of an advisory lock. PostgreSQL identifies advisory locks either
+/// by a single 64-bit integer, or by a pair of 32-bit integers. The two forms
+/// never conflict with each other.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AdvisoryLockKey {
+    /// A key given as a single `bigint`
+    Single(i64),
+    /// A key given as two `integer`s
+    Pair(i32, i32),
+}
+
+impl From<i64> for AdvisoryLockKey {
+    fn from(key: i64) -> Self {
+        AdvisoryLockKey::Single(key)
+    }
+}
+
+impl From<i32> for AdvisoryLockKey {
+    fn from(key: i32) -> Self {
+        AdvisoryLockKey::Single(i64::from(key))
+    }
+}
+
+impl From<(i32, i32)> for AdvisoryLockKey {
+    fn from((key1, key2): (i32, i32)) -> Self {
+        AdvisoryLockKey::Pair(key1, key2)
+    }
+}
+
+/// Formats the key as the arguments of an advisory lock function.
+impl fmt::Display for AdvisoryLockKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            AdvisoryLockKey::Single(key) => write!(f, "{}", key),
+            AdvisoryLockKey::Pair(key1, key2) => write!(f, "{}, {}", key1, key2),
+        }
+    }
+}
+
+/// An advisory lock which is released when the guard is dropped. Constructed
+/// by
+/// [`PgConnection::advisory_lock_guard`](struct.PgConnection.html#method.advisory_lock_guard)
+/// or
+/// [`PgConnection::try_advisory_lock_guard`](struct.PgConnection.html#method.try_advisory_lock_guard).
+///
+/// Errors which occur while the lock is released on drop are ignored. Use
+/// [`unlock`](#method.unlock) to handle them.
+#[allow(missing_debug_implementations)]
+#[must_use = "The lock is released as soon as the guard is dropped"]
+pub struct AdvisoryLockGuard<'a> {
+    connection: &'a PgConnection,
+    key: AdvisoryLockKey,
+}
+
+impl<'a> AdvisoryLockGuard<'a> {
+    pub(crate) fn new(connection: &'a PgConnection, key: AdvisoryLockKey) -> Self {
+        AdvisoryLockGuard {
+            connection: connection,
+            key: key,
+        }
+    }
+
+    /// The key of the lock held by this guard.
+    pub fn key(&self) -> AdvisoryLockKey {
+        self.key
+    }
+
+    /// Releases the lock, returning whether it was still held.
+    pub fn unlock(self) -> QueryResult<bool> {
+        let result = self.connection.advisory_unlock(self.key);
+        mem::forget(self);
+        result
+    }
+}
+
+impl<'a> Drop for AdvisoryLockGuard<'a> {
+    fn drop(&mut self) {
+        let _ = self.connection.advisory_unlock(self.key);
+    }
+}
@@ -0,0 +1,72 @@
+use std::ffi::CStr;
+use std::os::raw as libc;
+
+use result::*;
+use super::raw::RawConnection;
+use super::raw::pq_sys::*;
+
+/// Cancels the query which a connection is running. Constructed by
+/// [`PgConnection::cancel_token`](struct.PgConnection.html#method.cancel_token).
+///
+/// The token holds the process id and secret key which the server sent when
+/// the connection was established. It can be sent to another thread, which can
+/// cancel a long running query while the thread which owns the connection is
+/// waiting for its result.
+#[allow(missing_debug_implementations)]
+pub struct CancelToken {
+    internal_cancel: *mut PGcancel,
+}
+
+// libpq documents `PQcancel` as safe to call from any thread
+unsafe impl Send for CancelToken {}
+unsafe impl Sync for CancelToken {}
+
+impl CancelToken {
+    pub(crate) fn new(raw_connection: &RawConnection) -> QueryResult<Self> {
+        let internal_cancel = unsafe { PQgetCancel(raw_connection.as_ptr()) };
+        if internal_cancel.is_null() {
+            Err(Error::DatabaseError(
+                DatabaseErrorKind::UnableToSendCommand,
+                Box::new(raw_connection.last_error_message()),
+            ))
+        } else {
+            Ok(CancelToken {
+                internal_cancel: internal_cancel,
+            })
+        }
+    }
+
+    /// Asks the server to cancel the query which the connection is currently
+    /// running. This opens a new connection to the server to send the
+    /// request.
+    ///
+    /// A successful return only means that the request was sent. If the
+    /// query was cancelled, it returns an error from the connection which ran
+    /// it. If the connection was idle, or the query had already finished,
+    /// nothing happens.
+    pub fn cancel(&self) -> QueryResult<()> {
+        let mut error_buffer = [0 as libc::c_char; 256];
+        let sent = unsafe {
+            PQcancel(
+                self.internal_cancel,
+                error_buffer.as_mut_ptr(),
+                error_buffer.len() as libc::c_int,
+            )
+        };
+        if sent == 1 {
+            Ok(())
+        } else {
+            let message = unsafe { CStr::from_ptr(error_buffer.as_ptr()) };
+            Err(Error::DatabaseError(
+                DatabaseErrorKind::UnableToSendCommand,
+                Box::new(message.to_string_lossy().into_owned()),
+            ))
+        }
+    }
+}
+
+impl Drop for CancelToken {
+    fn drop(&mut self) {
+        unsafe { PQfreeCancel(self.internal_cancel) }
+    }
+}
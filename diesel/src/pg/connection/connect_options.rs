@@ -0,0 +1,60 @@
+use std::time::Duration;
+
+/// Additional options which can be passed to
+/// [`PgConnection::establish_with_options`](struct.PgConnection.html#method.establish_with_options).
+///
+/// Any option which is set overrides the corresponding parameter of the
+/// connection string.
+///
+/// # Example
+///
+/// ```no_run
+/// # extern crate diesel;
+/// # use std::time::Duration;
+/// # use diesel::pg::{ConnectOptions, PgConnection};
+/// # fn main() {
+/// let options = ConnectOptions {
+///     connect_timeout: Some(Duration::from_secs(5)),
+///     application_name: Some("my_app".into()),
+///     ..Default::default()
+/// };
+/// let connection = PgConnection::establish_with_options("postgres://localhost/my_app", options);
+/// # }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConnectOptions {
+    /// The maximum time to wait while connecting. If the server cannot be
+    /// reached in time, an error is returned. PostgreSQL only supports whole
+    /// seconds, so this is rounded up.
+    pub connect_timeout: Option<Duration>,
+    /// Sets the `application_name` of the connection, which is shown in
+    /// `pg_stat_activity`.
+    pub application_name: Option<String>,
+    /// Command line options sent to the server when the connection starts.
+    /// For example `-c search_path=my_schema`.
+    pub options: Option<String>,
+    /// Runs each query as the unnamed statement, instead of preparing and
+    /// caching named statements. See
+    /// [`PgConnection::set_prepared_statements`](struct.PgConnection.html#method.set_prepared_statements).
+    pub disable_prepared_statements: bool,
+}
+
+impl ConnectOptions {
+    pub(crate) fn connection_params(&self) -> Vec<(&'static str, String)> {
+        let mut params = Vec::new();
+        if let Some(timeout) = self.connect_timeout {
+            let mut seconds = timeout.as_secs();
+            if timeout.subsec_nanos() > 0 {
+                seconds += 1;
+            }
+            params.push(("connect_timeout", seconds.to_string()));
+        }
+        if let Some(ref application_name) = self.application_name {
+            params.push(("application_name", application_name.clone()));
+        }
+        if let Some(ref options) = self.options {
+            params.push(("options", options.clone()));
+        }
+        params
+    }
+}
@@ -0,0 +1,149 @@
+use std::io::{self, Read};
+use std::ffi::CString;
+use std::os::raw as libc;
+use std::{cmp, ptr, slice};
+
+use result::*;
+use super::cancel::CancelToken;
+use super::raw::RawConnection;
+use super::raw::pq_sys::*;
+use super::result::PgResult;
+
+/// The format of the rows which
+/// [`PgConnection::copy_out`](struct.PgConnection.html#method.copy_out)
+/// returns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CopyFormat {
+    /// PostgreSQL's text format, with values separated by tabs, and `\N`
+    /// for `NULL`.
+    Text,
+    /// Comma separated values, with `NULL` written as an unquoted empty value.
+    Csv,
+}
+
+impl CopyFormat {
+    pub(crate) fn option_name(&self) -> &'static str {
+        match *self {
+            CopyFormat::Text => "text",
+            CopyFormat::Csv => "csv",
+        }
+    }
+}
+
+/// Reads the rows of a `COPY ... TO STDOUT` command. Constructed by
+/// [`PgConnection::copy_out`](struct.PgConnection.html#method.copy_out).
+///
+/// Each row is read from the server once the previous row has been consumed.
+/// Every row ends with a newline. Errors which the server reports while
+/// sending the rows are returned from `read`, wrapped in an `io::Error`.
+///
+/// The connection cannot run other queries until all rows have been read.
+/// If the reader is dropped before that, the command is cancelled and the
+/// rows which were already sent are discarded. As the cancelled command
+/// fails, a transaction which it was run in can't be committed.
+#[allow(missing_debug_implementations)]
+pub struct CopyOut<'a> {
+    raw_connection: &'a RawConnection,
+    row: Vec<u8>,
+    position: usize,
+    finished: bool,
+}
+
+impl<'a> CopyOut<'a> {
+    pub(crate) fn new(raw_connection: &'a RawConnection, sql: &str) -> QueryResult<Self> {
+        let sql = try!(CString::new(sql));
+        let result = unsafe { raw_connection.exec(sql.as_ptr())? };
+        let status = unsafe { PQresultStatus(result.as_ptr()) };
+        if status == ExecStatusType::PGRES_COPY_OUT {
+            Ok(CopyOut {
+                raw_connection: raw_connection,
+                row: Vec::new(),
+                position: 0,
+                finished: false,
+            })
+        } else {
+            try!(PgResult::new(result));
+            Err(Error::DatabaseError(
+                DatabaseErrorKind::__Unknown,
+                Box::new("The command did not start a copy".to_string()),
+            ))
+        }
+    }
+
+    /// Reads the next row into `self.row`. Returns `false` once all rows
+    /// have been read.
+    fn read_row(&mut self) -> QueryResult<bool> {
+        if self.finished {
+            return Ok(false);
+        }
+
+        let mut buffer = ptr::null_mut();
+        let length = unsafe { PQgetCopyData(self.raw_connection.as_ptr(), &mut buffer, 0) };
+        match length {
+            -1 => {
+                self.finished = true;
+                self.finish_command().map(|_| false)
+            }
+            -2 => {
+                self.finished = true;
+                let error = Error::DatabaseError(
+                    DatabaseErrorKind::UnableToSendCommand,
+                    Box::new(self.raw_connection.last_error_message()),
+                );
+                let _ = self.finish_command();
+                Err(error)
+            }
+            _ => {
+                self.row.clear();
+                unsafe {
+                    let data = slice::from_raw_parts(buffer as *const u8, length as usize);
+                    self.row.extend_from_slice(data);
+                    PQfreemem(buffer as *mut libc::c_void);
+                }
+                self.position = 0;
+                Ok(true)
+            }
+        }
+    }
+
+    /// Returns the error of the command if it failed while sending rows, and
+    /// leaves the connection ready for the next query.
+    fn finish_command(&self) -> QueryResult<()> {
+        let mut outcome = Ok(());
+        while let Some(result) = self.raw_connection.get_result() {
+            outcome = outcome.and(PgResult::new(result).map(|_| ()));
+        }
+        outcome
+    }
+}
+
+impl<'a> Read for CopyOut<'a> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.position == self.row.len() {
+            let has_row = self.read_row()
+                .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+            if !has_row {
+                return Ok(0);
+            }
+        }
+
+        let remaining = &self.row[self.position..];
+        let length = cmp::min(buf.len(), remaining.len());
+        buf[..length].copy_from_slice(&remaining[..length]);
+        self.position += length;
+        Ok(length)
+    }
+}
+
+impl<'a> Drop for CopyOut<'a> {
+    fn drop(&mut self) {
+        if !self.finished {
+            // The cancelled command fails, so its error is discarded along
+            // with the rows which were sent before the server stopped
+            if let Ok(token) = CancelToken::new(self.raw_connection) {
+                let _ = token.cancel();
+            }
+            while let Ok(true) = self.read_row() {}
+        }
+    }
+}
@@ -2,6 +2,7 @@ use pg::Pg;
 use query_source::Queryable;
 use result::QueryResult;
 use result::Error::DeserializationError;
+use super::raw::RawConnection;
 use super::result::PgResult;
 use types::{FromSqlRow, HasSqlType};
 
@@ -46,3 +47,78 @@ where
         }
     }
 }
+
+/// Iterates over the rows of a command which was sent in single row mode,
+/// receiving each row from the server only once it is needed.
+///
+/// The connection can't run anything else until all results have been
+/// received, so dropping the iterator early still waits for the command to
+/// finish, discarding the remaining rows.
+pub struct StreamingCursor<'a, ST, T> {
+    conn: &'a RawConnection,
+    done: bool,
+    _marker: PhantomData<(ST, T)>,
+}
+
+impl<'a, ST, T> StreamingCursor<'a, ST, T> {
+    pub fn new(conn: &'a RawConnection) -> Self {
+        StreamingCursor {
+            conn: conn,
+            done: false,
+            _marker: PhantomData,
+        }
+    }
+
+    fn finish(&mut self) {
+        while self.conn.get_result().is_some() {}
+        self.done = true;
+    }
+}
+
+impl<'a, ST, T> Iterator for StreamingCursor<'a, ST, T>
+where
+    Pg: HasSqlType<ST>,
+    T: Queryable<ST, Pg>,
+{
+    type Item = QueryResult<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let result = match self.conn.get_result() {
+            Some(result) => PgResult::new(result),
+            None => {
+                self.done = true;
+                return None;
+            }
+        };
+        match result {
+            // Each row comes in its own result, followed by an empty one
+            // once the command has finished
+            Ok(ref result) if result.num_rows() == 0 => {
+                self.finish();
+                None
+            }
+            Ok(result) => {
+                let mut row = result.get_row(0);
+                let value = T::Row::build_from_row(&mut row)
+                    .map(T::build)
+                    .map_err(DeserializationError);
+                Some(value)
+            }
+            Err(e) => {
+                self.finish();
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+impl<'a, ST, T> Drop for StreamingCursor<'a, ST, T> {
+    fn drop(&mut self) {
+        if !self.done {
+            self.finish();
+        }
+    }
+}
@@ -1,25 +1,43 @@
+mod advisory_lock;
+mod cancel;
+mod connect_options;
+mod copy;
 mod cursor;
+mod notice;
+mod notification;
+mod prepared;
 pub mod raw;
 mod row;
 #[doc(hidden)]
 pub mod result;
 mod stmt;
 
-use std::ffi::{CStr, CString};
+use std::cell::{Cell, RefCell};
+use std::ffi::CString;
 use std::os::raw as libc;
+use std::ptr;
+use std::time::Duration;
 
 use connection::*;
-use pg::{Pg, PgMetadataLookup};
+use pg::{Pg, PgMetadataLookup, PgQueryBuilder};
 use query_builder::*;
 use query_builder::bind_collector::RawBytesBindCollector;
-use query_source::Queryable;
+use query_source::{Queryable, Table};
 use result::*;
 use result::ConnectionError::CouldntSetupConfiguration;
-use self::cursor::Cursor;
+pub use self::advisory_lock::{AdvisoryLockGuard, AdvisoryLockKey};
+pub use self::cancel::CancelToken;
+pub use self::connect_options::ConnectOptions;
+pub use self::copy::{CopyFormat, CopyOut};
+use self::cursor::{Cursor, StreamingCursor};
+pub use self::notice::Notice;
+use self::notice::NoticeHandler;
+pub use self::notification::{Notification, Notifications};
+pub use self::prepared::PreparedQuery;
 use self::raw::RawConnection;
 use self::result::PgResult;
 use self::stmt::Statement;
-use types::HasSqlType;
+use types::{BigInt, Bool, FromSql, HasSqlType, Text};
 
 /// The connection string expected by `PgConnection::establish`
 /// should be a PostgreSQL connection string, as documented at
@@ -29,6 +47,11 @@ pub struct PgConnection {
     raw_connection: RawConnection,
     transaction_manager: AnsiTransactionManager,
     statement_cache: StatementCache<Pg, Statement>,
+    next_statement_id: Cell<usize>,
+    prepared_statements: Cell<bool>,
+    // Declared after `raw_connection`, so that the handler is dropped after
+    // the connection is closed
+    notice_handler: RefCell<Option<Box<NoticeHandler>>>,
 }
 
 unsafe impl Send for PgConnection {}
@@ -47,15 +70,7 @@ impl Connection for PgConnection {
     type TransactionManager = AnsiTransactionManager;
 
     fn establish(database_url: &str) -> ConnectionResult<PgConnection> {
-        RawConnection::establish(database_url).and_then(|raw_conn| {
-            let conn = PgConnection {
-                raw_connection: raw_conn,
-                transaction_manager: AnsiTransactionManager::new(),
-                statement_cache: StatementCache::new(),
-            };
-            conn.set_config_options().map_err(CouldntSetupConfiguration)?;
-            Ok(conn)
-        })
+        RawConnection::establish(database_url).and_then(PgConnection::from_raw_connection)
     }
 
     #[doc(hidden)]
@@ -77,6 +92,33 @@ impl Connection for PgConnection {
             .and_then(|r| Cursor::new(r).collect())
     }
 
+    #[doc(hidden)]
+    fn query_by_index_iter<'a, T, U>(
+        &'a self,
+        source: T,
+    ) -> QueryResult<Box<Iterator<Item = QueryResult<U>> + 'a>>
+    where
+        T: AsQuery,
+        T::Query: QueryFragment<Pg> + QueryId,
+        T::SqlType: 'a,
+        Pg: HasSqlType<T::SqlType>,
+        U: Queryable<T::SqlType, Pg> + 'a,
+    {
+        let (query, params) = try!(self.prepare_query(&source.as_query()));
+        try!(query.send(&self.raw_connection, &params));
+        Ok(Box::new(StreamingCursor::new(&self.raw_connection)))
+    }
+
+    fn execute_query<T>(&self, query: &T) -> QueryResult<usize>
+    where
+        T: Query + QueryFragment<Pg> + QueryId,
+    {
+        let (query, params) = try!(self.prepare_query(query));
+        query
+            .execute(&self.raw_connection, &params)
+            .map(|r| r.num_rows())
+    }
+
     #[doc(hidden)]
     fn execute_returning_count<T>(&self, source: &T) -> QueryResult<usize>
     where
@@ -91,10 +133,9 @@ impl Connection for PgConnection {
     #[doc(hidden)]
     fn silence_notices<F: FnOnce() -> T, T>(&self, f: F) -> T {
         self.raw_connection
-            .set_notice_processor(noop_notice_processor);
+            .set_notice_receiver(notice::noop_notice_receiver, ptr::null_mut());
         let result = f();
-        self.raw_connection
-            .set_notice_processor(default_notice_processor);
+        self.install_notice_receiver();
         result
     }
 
@@ -105,6 +146,720 @@ impl Connection for PgConnection {
 }
 
 impl PgConnection {
+    /// Establishes a new connection to the database at the given URL, with
+    /// additional options such as a connect timeout. Options which are set
+    /// override the parameters given in `database_url`.
+    ///
+    /// See [`ConnectOptions`](struct.ConnectOptions.html) for the available
+    /// options.
+    pub fn establish_with_options(
+        database_url: &str,
+        options: ConnectOptions,
+    ) -> ConnectionResult<Self> {
+        let conn = try!(
+            RawConnection::establish_with_params(database_url, &options.connection_params())
+                .and_then(PgConnection::from_raw_connection)
+        );
+        conn.set_prepared_statements(!options.disable_prepared_statements);
+        Ok(conn)
+    }
+
+    fn from_raw_connection(raw_connection: RawConnection) -> ConnectionResult<Self> {
+        let conn = PgConnection {
+            raw_connection: raw_connection,
+            transaction_manager: AnsiTransactionManager::new(),
+            statement_cache: StatementCache::new(),
+            next_statement_id: Cell::new(0),
+            prepared_statements: Cell::new(true),
+            notice_handler: RefCell::new(None),
+        };
+        conn.set_config_options().map_err(CouldntSetupConfiguration)?;
+        Ok(conn)
+    }
+
+    /// Sets the maximum number of prepared statements which are cached by
+    /// this connection. The default is 500.
+    ///
+    /// When the cache is full, the least recently used statement is
+    /// deallocated on the server to make room for a new one. If the cache
+    /// currently holds more statements than `capacity`, the least recently
+    /// used statements are deallocated immediately.
+    ///
+    /// # Panics
+    ///
+    /// If `capacity` is zero.
+    pub fn set_statement_cache_capacity(&self, capacity: usize) -> QueryResult<()> {
+        self.statement_cache.set_capacity(capacity, |evicted| {
+            evicted.deallocate(&self.raw_connection)
+        })
+    }
+
+    /// Sets whether queries are run as named prepared statements, which are
+    /// placed in the statement cache. This is enabled by default.
+    ///
+    /// When disabled, each query is sent with its binds as the unnamed
+    /// statement, which is parsed and run in a single round trip. This is
+    /// required behind connection poolers such as PgBouncer in transaction
+    /// pooling mode, where consecutive transactions may run on different
+    /// server connections, which don't have the statements prepared by this
+    /// one. Statements which were already cached stay prepared until the
+    /// connection is closed, but are no longer used. While prepared statements
+    /// are disabled, [`prepare_cached`](#method.prepare_cached) and
+    /// [`prepare`](#method.prepare) return an error.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate diesel;
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # table! {
+    /// #     users {
+    /// #         id -> Integer,
+    /// #         name -> VarChar,
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     use self::users::dsl::*;
+    /// #     let connection = establish_connection();
+    /// connection.set_prepared_statements(false);
+    /// let names = users.select(name).filter(id.eq(2)).load(&connection);
+    /// assert_eq!(Ok(vec!["Tess".to_string()]), names);
+    /// # }
+    /// ```
+    pub fn set_prepared_statements(&self, enabled: bool) {
+        self.prepared_statements.set(enabled);
+    }
+
+    /// Whether the connection to the server has been lost, for example
+    /// because the server was shut down while a query was running. Queries
+    /// which fail for this reason return
+    /// [`Error::ConnectionClosed`](../result/enum.Error.html#variant.ConnectionClosed).
+    ///
+    /// A broken connection cannot be used anymore. Connection pools should
+    /// check this before giving a connection out again, and discard it
+    /// instead.
+    pub fn is_broken(&self) -> bool {
+        self.raw_connection.is_broken()
+    }
+
+    /// Sets the `search_path` of the connection, which determines the schemas
+    /// searched for tables which are not qualified with a schema. Each schema
+    /// name is quoted, so it is used exactly as given.
+    ///
+    /// PostgreSQL plans prepared statements again when the `search_path`
+    /// changes, so cached statements refer to the tables of the new schemas.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # extern crate diesel;
+    /// # use diesel::prelude::*;
+    /// # use diesel::pg::PgConnection;
+    /// # fn main() {
+    /// let connection = PgConnection::establish("postgres://localhost/my_app").unwrap();
+    /// // SET search_path TO "tenant_1", "public"
+    /// connection.set_search_path(&["tenant_1", "public"]).unwrap();
+    /// # }
+    /// ```
+    pub fn set_search_path(&self, schemas: &[&str]) -> QueryResult<()> {
+        let mut query_builder = PgQueryBuilder::new();
+        query_builder.push_sql("SET search_path TO ");
+        if schemas.is_empty() {
+            query_builder.push_sql("''");
+        }
+        for (i, schema) in schemas.iter().enumerate() {
+            if i != 0 {
+                query_builder.push_sql(", ");
+            }
+            try!(query_builder.push_identifier(schema));
+        }
+        self.execute(&query_builder.finish()).map(|_| ())
+    }
+
+    /// Sets the `search_path` to `schemas` while `f` is run, and restores the
+    /// previous `search_path` afterwards, even if `f` returns an error.
+    pub fn with_search_path<T, E, F>(&self, schemas: &[&str], f: F) -> Result<T, E>
+    where
+        F: FnOnce() -> Result<T, E>,
+        E: From<Error>,
+    {
+        use dsl::sql;
+        use query_dsl::{ExecuteDsl, LoadDsl};
+
+        let previous = try!(
+            ::select(sql::<Text>("current_setting('search_path')")).get_result::<String>(self)
+        );
+        try!(self.set_search_path(schemas));
+        let result = f();
+        // The previous value is already formatted as a list of schemas
+        try!(
+            sql::<Text>("SELECT set_config('search_path', $1, false)")
+                .bind::<Text, _>(previous)
+                .execute(self)
+        );
+        result
+    }
+
+    /// Runs `SET CONSTRAINTS ALL DEFERRED`, which postpones checking
+    /// constraints declared as `DEFERRABLE` until the current transaction is
+    /// committed. This allows inserting rows which reference each other in a
+    /// single transaction.
+    ///
+    /// If a constraint is violated, the error is returned when committing the
+    /// transaction, so it is returned from
+    /// [`transaction`](../connection/trait.Connection.html#method.transaction)
+    /// instead of from the query which caused it. This only affects the
+    /// current transaction. Outside of a transaction, PostgreSQL ignores it.
+    pub fn defer_constraints(&self) -> QueryResult<()> {
+        self.execute("SET CONSTRAINTS ALL DEFERRED").map(|_| ())
+    }
+
+    /// Returns the number of rows in `table` as estimated by PostgreSQL, which
+    /// is read from `reltuples` in `pg_class`. This is much faster than
+    /// `COUNT(*)` on large tables, and is intended for cases where an
+    /// approximate count is enough, such as showing "about N results".
+    ///
+    /// The estimate is only updated by `VACUUM`, `ANALYZE`, and some DDL
+    /// commands such as `CREATE INDEX`, so it can be far off after many rows
+    /// have been inserted or deleted. It is 0 for tables which have never been
+    /// analyzed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate diesel;
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # table! {
+    /// #     users {
+    /// #         id -> Integer,
+    /// #         name -> VarChar,
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     let connection = establish_connection();
+    /// connection.execute("ANALYZE users").unwrap();
+    /// let estimate = connection.estimated_row_count(users::table);
+    /// assert_eq!(Ok(2), estimate);
+    /// # }
+    /// ```
+    pub fn estimated_row_count<T>(&self, table: T) -> QueryResult<i64>
+    where
+        T: Table,
+        T::FromClause: QueryFragment<Pg>,
+    {
+        let mut query_builder = PgQueryBuilder::new();
+        try!(table.from_clause().to_sql(&mut query_builder));
+        let table_name = query_builder.finish();
+
+        let text = <Pg as HasSqlType<Text>>::metadata(PgMetadataLookup::new(self));
+        let query = try!(Statement::prepare(
+            &self.raw_connection,
+            "SELECT GREATEST(reltuples, 0)::bigint FROM pg_class WHERE oid = $1::regclass",
+            None,
+            &[text],
+        ));
+        let binds = vec![Some(table_name.into_bytes())];
+        let result = try!(query.execute(&self.raw_connection, &binds));
+        <i64 as FromSql<BigInt, Pg>>::from_sql(result.get(0, 0))
+            .map_err(Error::DeserializationError)
+    }
+
+    /// Runs `LISTEN channel`, so that notifications sent to `channel` are
+    /// returned by [`notifications`](#method.notifications).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # extern crate diesel;
+    /// # use std::time::Duration;
+    /// # use diesel::prelude::*;
+    /// # use diesel::pg::PgConnection;
+    /// # fn main() {
+    /// let connection = PgConnection::establish("postgres://localhost/my_app").unwrap();
+    /// connection.listen("new_posts").unwrap();
+    ///
+    /// for notification in connection.notifications(Some(Duration::from_secs(30))) {
+    ///     let notification = notification.unwrap();
+    ///     println!("post {} was created", notification.payload);
+    /// }
+    /// // No notification arrived for 30 seconds
+    /// # }
+    /// ```
+    pub fn listen(&self, channel: &str) -> QueryResult<()> {
+        self.execute_with_channel("LISTEN ", channel)
+    }
+
+    /// Runs `UNLISTEN channel`. Notifications sent to `channel` afterwards
+    /// are no longer received by this connection.
+    pub fn unlisten(&self, channel: &str) -> QueryResult<()> {
+        self.execute_with_channel("UNLISTEN ", channel)
+    }
+
+    /// Sends a notification to `channel` using `pg_notify`. When called
+    /// inside of a transaction, the notification is only delivered once the
+    /// transaction is committed.
+    pub fn notify(&self, channel: &str, payload: &str) -> QueryResult<()> {
+        let text = <Pg as HasSqlType<Text>>::metadata(PgMetadataLookup::new(self));
+        let query = try!(Statement::prepare(
+            &self.raw_connection,
+            "SELECT pg_notify($1, $2)",
+            None,
+            &[text, text],
+        ));
+        let binds = vec![Some(channel.as_bytes().to_vec()), Some(payload.as_bytes().to_vec())];
+        query.execute(&self.raw_connection, &binds).map(|_| ())
+    }
+
+    /// Returns an iterator over the notifications sent to the channels this
+    /// connection is listening on. Each call to `next` blocks until a
+    /// notification arrives. If `timeout` is given, `next` returns `None` when
+    /// no notification arrived in time.
+    ///
+    /// Notifications which arrived while other queries were run are returned
+    /// first.
+    pub fn notifications(&self, timeout: Option<Duration>) -> Notifications {
+        Notifications::new(&self.raw_connection, timeout)
+    }
+
+    /// Runs `pg_advisory_lock`, which waits until the advisory lock `key`
+    /// is available and takes it. The key is either an `i64`, or a pair of
+    /// `i32`s.
+    ///
+    /// Advisory locks are held until they are released with
+    /// [`advisory_unlock`](#method.advisory_unlock) or the connection is
+    /// closed, even if the current transaction is rolled back. A connection
+    /// can take the same lock several times, and must release it as many
+    /// times.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # extern crate diesel;
+    /// # use diesel::prelude::*;
+    /// # use diesel::pg::PgConnection;
+    /// # fn main() {
+    /// let connection = PgConnection::establish("postgres://localhost/my_app").unwrap();
+    /// connection.advisory_lock(42).unwrap();
+    /// // Only one connection runs this at a time
+    /// connection.advisory_unlock(42).unwrap();
+    /// # }
+    /// ```
+    pub fn advisory_lock<K: Into<AdvisoryLockKey>>(&self, key: K) -> QueryResult<()> {
+        let query = format!("SELECT pg_advisory_lock({})", key.into());
+        self.execute(&query).map(|_| ())
+    }
+
+    /// Runs `pg_try_advisory_lock`, which takes the advisory lock `key` if it
+    /// is available without waiting. Returns whether the lock was taken.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # extern crate diesel;
+    /// # use diesel::prelude::*;
+    /// # use diesel::pg::PgConnection;
+    /// # fn main() {
+    /// let connection = PgConnection::establish("postgres://localhost/my_app").unwrap();
+    /// if connection.try_advisory_lock((1, 7)).unwrap() {
+    ///     // This connection is the leader
+    /// }
+    /// # }
+    /// ```
+    pub fn try_advisory_lock<K: Into<AdvisoryLockKey>>(&self, key: K) -> QueryResult<bool> {
+        self.advisory_lock_function("pg_try_advisory_lock", key.into())
+    }
+
+    /// Runs `pg_advisory_unlock`, which releases the advisory lock `key`
+    /// once. Returns `false` if the lock was not held by this connection, in
+    /// which case PostgreSQL also sends a warning.
+    pub fn advisory_unlock<K: Into<AdvisoryLockKey>>(&self, key: K) -> QueryResult<bool> {
+        self.advisory_lock_function("pg_advisory_unlock", key.into())
+    }
+
+    /// Takes the advisory lock `key` like
+    /// [`advisory_lock`](#method.advisory_lock), and returns a guard which
+    /// releases it when dropped.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # extern crate diesel;
+    /// # use diesel::prelude::*;
+    /// # use diesel::pg::PgConnection;
+    /// # fn main() {
+    /// let connection = PgConnection::establish("postgres://localhost/my_app").unwrap();
+    /// {
+    ///     let _lock = connection.advisory_lock_guard(42).unwrap();
+    ///     // Only one connection runs this at a time
+    /// }
+    /// // The lock has been released
+    /// # }
+    /// ```
+    pub fn advisory_lock_guard<K: Into<AdvisoryLockKey>>(
+        &self,
+        key: K,
+    ) -> QueryResult<AdvisoryLockGuard> {
+        let key = key.into();
+        try!(self.advisory_lock(key));
+        Ok(AdvisoryLockGuard::new(self, key))
+    }
+
+    /// Takes the advisory lock `key` like
+    /// [`try_advisory_lock`](#method.try_advisory_lock), and returns a guard
+    /// which releases it when dropped. Returns `None` if the lock is held by
+    /// another connection.
+    pub fn try_advisory_lock_guard<K: Into<AdvisoryLockKey>>(
+        &self,
+        key: K,
+    ) -> QueryResult<Option<AdvisoryLockGuard>> {
+        let key = key.into();
+        let locked = try!(self.try_advisory_lock(key));
+        Ok(if locked {
+            Some(AdvisoryLockGuard::new(self, key))
+        } else {
+            None
+        })
+    }
+
+    // The key is part of the SQL rather than a bind, and the statement is not
+    // cached, so that a statement is not cached for each key.
+    fn advisory_lock_function(&self, function: &str, key: AdvisoryLockKey) -> QueryResult<bool> {
+        let sql = format!("SELECT {}({})", function, key);
+        let query = try!(Statement::prepare(&self.raw_connection, &sql, None, &[]));
+        let result = try!(query.execute(&self.raw_connection, &Vec::new()));
+        <bool as FromSql<Bool, Pg>>::from_sql(result.get(0, 0)).map_err(Error::DeserializationError)
+    }
+
+    /// Calls `handler` with each notice or warning sent by the server while
+    /// this connection runs queries, such as the messages of `RAISE NOTICE`
+    /// in a function. The handler is called even if the query succeeds. It
+    /// replaces the previous handler, if any.
+    ///
+    /// Without a handler, notices are written to `stderr`. If the handler
+    /// panics, the panic is caught once it reaches libpq, and the query
+    /// continues as if the notice had been handled.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # extern crate diesel;
+    /// # use diesel::prelude::*;
+    /// # use diesel::pg::PgConnection;
+    /// # fn main() {
+    /// let connection = PgConnection::establish("postgres://localhost/my_app").unwrap();
+    /// connection.set_notice_handler(|notice| {
+    ///     println!("{}: {}", notice.severity, notice.message);
+    /// });
+    /// // Prints "NOTICE: table "users" does not exist, skipping"
+    /// connection.execute("DROP TABLE IF EXISTS users").unwrap();
+    /// # }
+    /// ```
+    pub fn set_notice_handler<F>(&self, handler: F)
+    where
+        F: FnMut(&Notice) + Send + 'static,
+    {
+        let mut handler: Box<NoticeHandler> = Box::new(Box::new(handler));
+        let arg = &mut *handler as *mut NoticeHandler as *mut libc::c_void;
+        self.raw_connection
+            .set_notice_receiver(notice::handler_notice_receiver, arg);
+        *self.notice_handler.borrow_mut() = Some(handler);
+    }
+
+    /// Removes the handler given to
+    /// [`set_notice_handler`](#method.set_notice_handler), so that notices
+    /// are written to `stderr` again.
+    pub fn remove_notice_handler(&self) {
+        self.raw_connection
+            .set_notice_receiver(notice::default_notice_receiver, ptr::null_mut());
+        *self.notice_handler.borrow_mut() = None;
+    }
+
+    fn install_notice_receiver(&self) {
+        match *self.notice_handler.borrow_mut() {
+            Some(ref mut handler) => {
+                let arg = &mut **handler as *mut NoticeHandler as *mut libc::c_void;
+                self.raw_connection
+                    .set_notice_receiver(notice::handler_notice_receiver, arg);
+            }
+            None => self.raw_connection
+                .set_notice_receiver(notice::default_notice_receiver, ptr::null_mut()),
+        }
+    }
+
+    /// Returns a token which cancels the query this connection is running,
+    /// from another thread.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # extern crate diesel;
+    /// # use diesel::prelude::*;
+    /// # use diesel::pg::PgConnection;
+    /// use std::thread;
+    /// use std::time::Duration;
+    ///
+    /// # fn main() {
+    /// let connection = PgConnection::establish("postgres://localhost/my_app").unwrap();
+    /// let cancel_token = connection.cancel_token().unwrap();
+    /// thread::spawn(move || {
+    ///     thread::sleep(Duration::from_secs(1));
+    ///     cancel_token.cancel().unwrap();
+    /// });
+    /// assert!(connection.execute("SELECT pg_sleep(10)").is_err());
+    /// # }
+    /// ```
+    pub fn cancel_token(&self) -> QueryResult<CancelToken> {
+        CancelToken::new(&self.raw_connection)
+    }
+
+    /// Returns the libpq connection handle of this connection, for calling
+    /// libpq functions which Diesel does not wrap. The bindings are available
+    /// as [`diesel::pg::pq_sys`](pq_sys/index.html).
+    ///
+    /// # Safety
+    ///
+    /// The handle is owned by this connection. It must not be closed with
+    /// `PQfinish` or reset, and it must not be used after the connection has
+    /// been dropped. Diesel assumes it is the only user of the connection, so
+    /// the connection must be left idle, without an unfinished query or
+    /// unread results, and its settings must not be changed in ways Diesel
+    /// does not expect, such as the notice receiver.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # extern crate diesel;
+    /// # use diesel::prelude::*;
+    /// # use diesel::pg::PgConnection;
+    /// use diesel::pg::pq_sys::PQbackendPID;
+    ///
+    /// # fn main() {
+    /// let connection = PgConnection::establish("postgres://localhost/my_app").unwrap();
+    /// let backend_pid = unsafe { PQbackendPID(connection.raw_connection()) };
+    /// # }
+    /// ```
+    pub unsafe fn raw_connection(&self) -> *mut raw::pq_sys::PGconn {
+        self.raw_connection.as_ptr()
+    }
+
+    /// Prepares `query` and adds it to the statement cache, without running
+    /// it. Running the same query later reuses the prepared statement, so
+    /// this can be used to warm up a connection before it serves requests.
+    ///
+    /// Only the type of the query and the types of its binds identify the
+    /// cached statement, so the values of the binds given here don't matter.
+    /// Methods which change the query before running it change its type as
+    /// well. For example, `.first` runs `query.limit(1)`, which is the query
+    /// that has to be prepared. Queries which are never cached, such as
+    /// queries with SQL literals, are only checked by the server.
+    ///
+    /// Returns an error if prepared statements were disabled with
+    /// [`set_prepared_statements`](#method.set_prepared_statements).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate diesel;
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # table! {
+    /// #     users {
+    /// #         id -> Integer,
+    /// #         name -> VarChar,
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     use self::users::dsl::*;
+    /// #     let connection = establish_connection();
+    /// use diesel::dsl::{Eq, Filter, Select};
+    ///
+    /// fn user_ids_named(user_name: &str) -> Filter<Select<users, id>, Eq<name, &str>> {
+    ///     users.select(id).filter(name.eq(user_name))
+    /// }
+    ///
+    /// connection.prepare_cached(user_ids_named("")).unwrap();
+    /// // Reuses the statement which was prepared above
+    /// let ids = user_ids_named("Tess").load(&connection);
+    /// assert_eq!(Ok(vec![2]), ids);
+    /// # }
+    /// ```
+    pub fn prepare_cached<T>(&self, query: T) -> QueryResult<()>
+    where
+        T: AsQuery,
+        T::Query: QueryFragment<Pg> + QueryId,
+    {
+        try!(self.check_prepared_statements_enabled());
+        self.prepare_query(&query.as_query()).map(|_| ())
+    }
+
+    /// Prepares `query` as a named statement, which can be run any number of
+    /// times with new values for its binds. See
+    /// [`PreparedQuery`](struct.PreparedQuery.html) for details.
+    ///
+    /// Unlike [`prepare_cached`](#method.prepare_cached), the statement is
+    /// not placed in the statement cache, and is not evicted from it. It is
+    /// deallocated when the returned value is dropped.
+    ///
+    /// Returns an error if prepared statements were disabled with
+    /// [`set_prepared_statements`](#method.set_prepared_statements).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate diesel;
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # table! {
+    /// #     users {
+    /// #         id -> Integer,
+    /// #         name -> VarChar,
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     use self::users::dsl::*;
+    /// #     let connection = establish_connection();
+    /// use diesel::types::{Integer, VarChar};
+    ///
+    /// let rename = connection
+    ///     .prepare(diesel::update(users.find(0)).set(name.eq("")))
+    ///     .unwrap();
+    /// for (user_id, new_name) in vec![(1, "Sean Griffin"), (2, "Tess Griffin")] {
+    ///     let binds = (new_name.into_sql::<VarChar>(), user_id.into_sql::<Integer>());
+    ///     assert_eq!(Ok(1), rename.execute_with(binds));
+    /// }
+    ///
+    /// let find_name = connection.prepare(users.select(name).find(0)).unwrap();
+    /// let tess = find_name.load_with::<_, String>(2.into_sql::<Integer>());
+    /// assert_eq!(Ok(vec!["Tess Griffin".to_string()]), tess);
+    /// # }
+    /// ```
+    pub fn prepare<T>(&self, query: T) -> QueryResult<PreparedQuery<T>>
+    where
+        T: QueryFragment<Pg>,
+    {
+        try!(self.check_prepared_statements_enabled());
+        PreparedQuery::new(self, &query)
+    }
+
+    /// Runs `COPY (query) TO STDOUT`, and returns a reader over the rows of
+    /// the query in the given format. This is much faster than loading the
+    /// rows when exporting a large number of them, as the values are
+    /// formatted by the server, and nothing is deserialized.
+    ///
+    /// `COPY` does not accept bind parameters. Instead, the server quotes the
+    /// values of the binds of `query` as literals, which are written into the
+    /// command before it is run.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate diesel;
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # table! {
+    /// #     users {
+    /// #         id -> Integer,
+    /// #         name -> VarChar,
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     use self::users::dsl::*;
+    /// #     let connection = establish_connection();
+    /// use diesel::pg::CopyFormat;
+    /// use std::io::Read;
+    ///
+    /// let mut csv = String::new();
+    /// connection
+    ///     .copy_out(users.filter(name.eq("Tess")), CopyFormat::Csv)
+    ///     .unwrap()
+    ///     .read_to_string(&mut csv)
+    ///     .unwrap();
+    /// assert_eq!("2,Tess\n", csv);
+    /// # }
+    /// ```
+    pub fn copy_out<T>(&self, query: T, format: CopyFormat) -> QueryResult<CopyOut>
+    where
+        T: AsQuery,
+        T::Query: QueryFragment<Pg>,
+    {
+        let query = query.as_query();
+        let mut bind_collector = RawBytesBindCollector::<Pg>::new();
+        try!(query.collect_binds(&mut bind_collector, PgMetadataLookup::new(self)));
+        let literals = try!(self.bind_literals(&bind_collector));
+        let mut query_builder = PgQueryBuilder::with_inlined_binds(literals);
+        try!(query.to_sql(&mut query_builder));
+
+        let command = format!(
+            "COPY ({}) TO STDOUT WITH (FORMAT {})",
+            query_builder.finish(),
+            format.option_name()
+        );
+        CopyOut::new(&self.raw_connection, &command)
+    }
+
+    /// Returns the value of each bind as a literal, quoted by the server and
+    /// cast to the type of the bind.
+    fn bind_literals(
+        &self,
+        bind_collector: &RawBytesBindCollector<Pg>,
+    ) -> QueryResult<Vec<String>> {
+        let metadata = &bind_collector.metadata;
+        if metadata.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let literals_query = metadata
+            .iter()
+            .enumerate()
+            .map(|(i, tpe)| {
+                format!(
+                    "'CAST(' || quote_nullable(${}) || ' AS ' || format_type({}, NULL) || ')'",
+                    i + 1,
+                    tpe.oid
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        let statement = try!(Statement::prepare(
+            &self.raw_connection,
+            &format!("SELECT {}", literals_query),
+            None,
+            metadata,
+        ));
+        let result = try!(statement.execute(&self.raw_connection, &bind_collector.binds));
+        let literals = (0..metadata.len())
+            .map(|i| {
+                let bytes = result.get(0, i).unwrap_or(b"NULL");
+                String::from_utf8_lossy(bytes).into_owned()
+            })
+            .collect();
+        Ok(literals)
+    }
+
+    fn execute_with_channel(&self, command: &str, channel: &str) -> QueryResult<()> {
+        let mut query_builder = PgQueryBuilder::new();
+        query_builder.push_sql(command);
+        try!(query_builder.push_identifier(channel));
+        self.execute(&query_builder.finish()).map(|_| ())
+    }
+
+    fn check_prepared_statements_enabled(&self) -> QueryResult<()> {
+        if self.prepared_statements.get() {
+            Ok(())
+        } else {
+            Err(Error::QueryBuilderError(
+                "Prepared statements are disabled for this connection".into(),
+            ))
+        }
+    }
+
     #[cfg_attr(feature = "clippy", allow(type_complexity))]
     fn prepare_query<T: QueryFragment<Pg> + QueryId>(
         &self,
@@ -115,11 +870,26 @@ impl PgConnection {
         let binds = bind_collector.binds;
         let metadata = bind_collector.metadata;
 
-        let cache_len = self.statement_cache.len();
-        let query = self.statement_cache
-            .cached_statement(source, &metadata, |sql| {
+        if !self.prepared_statements.get() {
+            let mut query_builder = PgQueryBuilder::new();
+            try!(source.to_sql(&mut query_builder));
+            let query = try!(Statement::prepare(
+                &self.raw_connection,
+                &query_builder.finish(),
+                None,
+                &metadata,
+            ));
+            return Ok((MaybeCached::CannotCache(query), binds));
+        }
+
+        let query = self.statement_cache.cached_statement_with_release(
+            source,
+            &metadata,
+            |sql| {
                 let query_name = if source.is_safe_to_cache_prepared()? {
-                    Some(format!("__diesel_stmt_{}", cache_len))
+                    let id = self.next_statement_id.get();
+                    self.next_statement_id.set(id + 1);
+                    Some(format!("__diesel_stmt_{}", id))
                 } else {
                     None
                 };
@@ -129,7 +899,9 @@ impl PgConnection {
                     query_name.as_ref().map(|s| &**s),
                     &metadata,
                 )
-            });
+            },
+            |evicted| evicted.deallocate(&self.raw_connection),
+        );
 
         Ok((query?, binds))
     }
@@ -146,16 +918,6 @@ impl PgConnection {
     }
 }
 
-extern "C" fn noop_notice_processor(_: *mut libc::c_void, _message: *const libc::c_char) {}
-
-extern "C" fn default_notice_processor(_: *mut libc::c_void, message: *const libc::c_char) {
-    use std::io::Write;
-    let c_str = unsafe { CStr::from_ptr(message) };
-    ::std::io::stderr()
-        .write_all(c_str.to_bytes())
-        .expect("Error writing to `stderr`");
-}
-
 #[cfg(test)]
 mod tests {
     extern crate dotenv;
@@ -167,7 +929,7 @@ mod tests {
     use dsl::sql;
     use prelude::*;
     use super::*;
-    use types::{Integer, VarChar};
+    use types::{BigInt, Double, Integer, VarChar};
 
     #[test]
     fn prepared_statements_are_cached() {
@@ -180,6 +942,18 @@ mod tests {
         assert_eq!(1, connection.statement_cache.len());
     }
 
+    #[test]
+    fn statements_are_not_cached_when_prepared_statements_are_disabled() {
+        let connection = connection();
+        connection.set_prepared_statements(false);
+
+        let query = ::select(AsExpression::<Integer>::as_expression(1));
+
+        assert_eq!(Ok(1), query.get_result(&connection));
+        assert_eq!(Ok(1), query.get_result(&connection));
+        assert_eq!(0, connection.statement_cache.len());
+    }
+
     #[test]
     fn queries_with_identical_sql_but_different_types_are_cached_separately() {
         let connection = connection();
@@ -229,11 +1003,188 @@ mod tests {
         assert_eq!(0, connection.statement_cache.len());
     }
 
+    #[test]
+    fn least_recently_used_statement_is_evicted_when_cache_is_full() {
+        let connection = connection();
+        connection.set_statement_cache_capacity(2).unwrap();
+        let prepared_statement_count = || {
+            ::select(sql::<BigInt>("COUNT(*) FROM pg_prepared_statements"))
+                .get_result::<i64>(&connection)
+        };
+
+        let one = ::select(AsExpression::<Integer>::as_expression(1));
+        let hi = ::select(AsExpression::<VarChar>::as_expression("hi"));
+        let one_point_five = ::select(AsExpression::<Double>::as_expression(1.5));
+
+        assert_eq!(Ok(1), one.get_result(&connection));
+        assert_eq!(Ok("hi".to_string()), hi.get_result(&connection));
+        assert_eq!(Ok(1), one.get_result(&connection));
+        assert_eq!(2, connection.next_statement_id.get());
+
+        // `hi` is the least recently used statement, so it is evicted
+        assert_eq!(Ok(1.5), one_point_five.get_result(&connection));
+        assert_eq!(2, connection.statement_cache.len());
+        assert_eq!(Ok(2), prepared_statement_count());
+        assert_eq!(Ok(1), one.get_result(&connection));
+        assert_eq!(3, connection.next_statement_id.get());
+
+        // `hi` is prepared again, evicting `one_point_five`
+        assert_eq!(Ok("hi".to_string()), hi.get_result(&connection));
+        assert_eq!(4, connection.next_statement_id.get());
+        assert_eq!(Ok(1), one.get_result(&connection));
+        assert_eq!(4, connection.next_statement_id.get());
+        assert_eq!(Ok(2), prepared_statement_count());
+    }
+
+    #[test]
+    fn lowering_statement_cache_capacity_deallocates_statements() {
+        let connection = connection();
+        let prepared_statement_count = || {
+            ::select(sql::<BigInt>("COUNT(*) FROM pg_prepared_statements"))
+                .get_result::<i64>(&connection)
+        };
+
+        let one = ::select(AsExpression::<Integer>::as_expression(1));
+        let hi = ::select(AsExpression::<VarChar>::as_expression("hi"));
+        assert_eq!(Ok(1), one.get_result(&connection));
+        assert_eq!(Ok("hi".to_string()), hi.get_result(&connection));
+        assert_eq!(Ok(2), prepared_statement_count());
+
+        connection.set_statement_cache_capacity(1).unwrap();
+        assert_eq!(1, connection.statement_cache.len());
+        assert_eq!(Ok(1), prepared_statement_count());
+        assert_eq!(Ok("hi".to_string()), hi.get_result(&connection));
+        assert_eq!(2, connection.next_statement_id.get());
+    }
+
+    #[test]
+    fn statements_which_fail_to_prepare_do_not_evict_cached_statements() {
+        let connection = connection();
+        sql_function!(no_such_function, no_such_function_t, (x: Integer) -> Integer);
+        connection.set_statement_cache_capacity(1).unwrap();
+
+        let one = ::select(AsExpression::<Integer>::as_expression(1));
+        assert_eq!(Ok(1), one.get_result(&connection));
+
+        let invalid = ::select(no_such_function(1));
+        assert!(invalid.get_result::<i32>(&connection).is_err());
+        assert_eq!(1, connection.statement_cache.len());
+
+        // `one` is still cached, so it is not prepared again
+        let next_statement_id = connection.next_statement_id.get();
+        assert_eq!(Ok(1), one.get_result(&connection));
+        assert_eq!(next_statement_id, connection.next_statement_id.get());
+    }
+
+    #[test]
+    fn queries_prepared_ahead_of_time_are_not_prepared_again() {
+        let connection = connection();
+        let query = |value| ::select(AsExpression::<Integer>::as_expression(value));
+
+        connection.prepare_cached(query(0)).unwrap();
+        assert_eq!(1, connection.statement_cache.len());
+        assert_eq!(1, connection.next_statement_id.get());
+
+        assert_eq!(Ok(1), query(1).get_result(&connection));
+        assert_eq!(Ok(2), query(2).get_result(&connection));
+        assert_eq!(1, connection.statement_cache.len());
+        assert_eq!(1, connection.next_statement_id.get());
+    }
+
+    #[test]
+    fn prepared_queries_are_prepared_once_and_run_with_new_binds() {
+        let connection = connection();
+        let query = |value| ::select(AsExpression::<Integer>::as_expression(value));
+        let prepared_statement_count = || {
+            ::select(sql::<BigInt>("COUNT(*) FROM pg_prepared_statements"))
+                .get_result::<i64>(&connection)
+        };
+
+        let prepared = connection.prepare(query(0)).unwrap();
+        for i in 0..1000 {
+            let bind = AsExpression::<Integer>::as_expression(i);
+            assert_eq!(Ok(vec![i]), prepared.load_with(bind));
+        }
+        assert_eq!(1, connection.next_statement_id.get());
+        assert_eq!(0, connection.statement_cache.len());
+        assert_eq!(Ok(1), prepared_statement_count());
+
+        drop(prepared);
+        assert_eq!(Ok(0), prepared_statement_count());
+    }
+
+    #[test]
+    fn prepared_queries_reject_binds_of_other_types() {
+        let connection = connection();
+        let one = AsExpression::<Integer>::as_expression(1);
+        let prepared = connection.prepare(::select(one).into_boxed::<Pg>()).unwrap();
+
+        let wrong_type = prepared.load_with::<_, i32>(AsExpression::<BigInt>::as_expression(1i64));
+        let too_many = prepared.load_with::<_, i32>((one, one));
+
+        for result in vec![wrong_type, too_many] {
+            match result {
+                Err(Error::QueryBuilderError(_)) => {}
+                other => panic!("Expected a query builder error, got {:?}", other),
+            }
+        }
+        assert_eq!(Ok(vec![1]), prepared.load_with(one));
+    }
+
+    #[test]
+    fn queries_cannot_be_prepared_when_prepared_statements_are_disabled() {
+        let connection = connection();
+        connection.set_prepared_statements(false);
+        let query = ::select(AsExpression::<Integer>::as_expression(1));
+
+        match connection.prepare_cached(query) {
+            Err(Error::QueryBuilderError(_)) => {}
+            other => panic!("Expected a query builder error, got {:?}", other),
+        }
+        match connection.prepare(query) {
+            Err(Error::QueryBuilderError(_)) => {}
+            Err(other) => panic!("Expected a query builder error, got {:?}", other),
+            Ok(_) => panic!("Expected a query builder error, got a prepared query"),
+        }
+        assert_eq!(0, connection.statement_cache.len());
+        assert_eq!(0, connection.next_statement_id.get());
+    }
+
+    #[test]
+    fn application_name_is_sent_to_the_server() {
+        let options = ConnectOptions {
+            application_name: Some("diesel_test_app".into()),
+            ..Default::default()
+        };
+        let connection = PgConnection::establish_with_options(&database_url(), options).unwrap();
+        let query = ::select(sql::<VarChar>("current_setting('application_name')"));
+
+        assert_eq!(Ok("diesel_test_app".to_string()), query.get_result(&connection));
+    }
+
+    #[test]
+    fn connecting_to_an_unreachable_server_times_out() {
+        use std::time::{Duration, Instant};
+
+        let options = ConnectOptions {
+            connect_timeout: Some(Duration::from_secs(2)),
+            ..Default::default()
+        };
+        let start = Instant::now();
+        let result = PgConnection::establish_with_options("postgres://10.255.255.1/diesel", options);
+
+        assert!(result.is_err());
+        assert!(start.elapsed() < Duration::from_secs(10));
+    }
+
     fn connection() -> PgConnection {
+        PgConnection::establish(&database_url()).unwrap()
+    }
+
+    fn database_url() -> String {
         dotenv().ok();
-        let database_url = env::var("PG_DATABASE_URL")
+        env::var("PG_DATABASE_URL")
             .or_else(|_| env::var("DATABASE_URL"))
-            .expect("DATABASE_URL must be set in order to run tests");
-        PgConnection::establish(&database_url).unwrap()
+            .expect("DATABASE_URL must be set in order to run tests")
     }
 }
@@ -0,0 +1,49 @@
+use std::ffi::CStr;
+use std::io::Write;
+use std::os::raw as libc;
+use std::panic::{self, AssertUnwindSafe};
+
+use super::raw::pq_sys::{PGresult, PQresultErrorMessage};
+use super::result::{get_result_field, ResultField};
+
+/// A notice or warning sent by the server while a query was run, for example
+/// with `RAISE NOTICE` in a function. Passed to the handler given to
+/// [`PgConnection::set_notice_handler`](struct.PgConnection.html#method.set_notice_handler).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Notice {
+    /// The severity of the notice, such as `NOTICE` or `WARNING`. It is
+    /// translated when the server uses another language for its messages.
+    pub severity: String,
+    /// The message of the notice
+    pub message: String,
+}
+
+impl Notice {
+    fn from_result(result: *const PGresult) -> Self {
+        let field = |field| get_result_field(result, field).unwrap_or("").to_owned();
+        Notice {
+            severity: field(ResultField::Severity),
+            message: field(ResultField::MessagePrimary),
+        }
+    }
+}
+
+pub type NoticeHandler = Box<FnMut(&Notice) + Send>;
+
+/// Calls the handler which `arg` points to. A panic cannot unwind into
+/// libpq, so it is caught here and the notice is dropped.
+pub unsafe extern "C" fn handler_notice_receiver(arg: *mut libc::c_void, result: *const PGresult) {
+    let handler = &mut *(arg as *mut NoticeHandler);
+    let notice = Notice::from_result(result);
+    let _ = panic::catch_unwind(AssertUnwindSafe(|| handler(&notice)));
+}
+
+/// Writes the notice to `stderr`, like the default notice receiver of libpq.
+pub unsafe extern "C" fn default_notice_receiver(_: *mut libc::c_void, result: *const PGresult) {
+    let c_str = CStr::from_ptr(PQresultErrorMessage(result));
+    ::std::io::stderr()
+        .write_all(c_str.to_bytes())
+        .expect("Error writing to `stderr`");
+}
+
+pub unsafe extern "C" fn noop_notice_receiver(_: *mut libc::c_void, _: *const PGresult) {}
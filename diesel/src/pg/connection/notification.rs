@@ -0,0 +1,76 @@
+use std::time::{Duration, Instant};
+
+use result::QueryResult;
+use super::raw::RawConnection;
+
+/// A notification sent with `NOTIFY` to a channel the connection is listening
+/// on. Returned by
+/// [`PgConnection::notifications`](struct.PgConnection.html#method.notifications).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Notification {
+    /// The channel the notification was sent to
+    pub channel: String,
+    /// The payload of the notification. This is empty if no payload was given.
+    pub payload: String,
+    /// The process id of the server process which sent the notification
+    pub pid: i32,
+}
+
+/// An iterator over the notifications received by a connection. Constructed
+/// by [`PgConnection::notifications`](struct.PgConnection.html#method.notifications).
+///
+/// Each call to `next` blocks until a notification arrives. If a timeout was
+/// given, `next` returns `None` once the timeout has passed without a
+/// notification. The iterator can be used again after that.
+#[allow(missing_debug_implementations)]
+pub struct Notifications<'a> {
+    raw_connection: &'a RawConnection,
+    timeout: Option<Duration>,
+}
+
+impl<'a> Notifications<'a> {
+    pub(crate) fn new(raw_connection: &'a RawConnection, timeout: Option<Duration>) -> Self {
+        Notifications {
+            raw_connection: raw_connection,
+            timeout: timeout,
+        }
+    }
+
+    fn next_notification(&self) -> QueryResult<Option<Notification>> {
+        let deadline = self.timeout.map(|t| Instant::now() + t);
+        loop {
+            try!(self.raw_connection.consume_input());
+            if let Some((channel, payload, pid)) = self.raw_connection.notifies() {
+                return Ok(Some(Notification {
+                    channel: channel,
+                    payload: payload,
+                    pid: pid,
+                }));
+            }
+
+            let remaining = match deadline {
+                Some(deadline) => {
+                    let now = Instant::now();
+                    if now >= deadline {
+                        return Ok(None);
+                    }
+                    Some(deadline - now)
+                }
+                None => None,
+            };
+            try!(self.raw_connection.wait_for_input(remaining));
+        }
+    }
+}
+
+impl<'a> Iterator for Notifications<'a> {
+    type Item = QueryResult<Notification>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.next_notification() {
+            Ok(Some(notification)) => Some(Ok(notification)),
+            Ok(None) => None,
+            Err(e) => Some(Err(e)),
+        }
+    }
+}
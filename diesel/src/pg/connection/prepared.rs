@@ -0,0 +1,121 @@
+use std::marker::PhantomData;
+
+use pg::{Pg, PgMetadataLookup, PgQueryBuilder, PgTypeMetadata};
+use query_builder::*;
+use query_builder::bind_collector::RawBytesBindCollector;
+use query_source::Queryable;
+use result::*;
+use super::PgConnection;
+use super::cursor::Cursor;
+use super::stmt::Statement;
+use types::HasSqlType;
+
+/// A statement which has been prepared on the server, and which can be run
+/// any number of times with new values for its binds. Constructed by
+/// [`PgConnection::prepare`](struct.PgConnection.html#method.prepare).
+///
+/// The binds are given to [`execute_with`](#method.execute_with) or
+/// [`load_with`](#method.load_with) directly, as a single bound value or a
+/// tuple of them, in the order in which they appear in the query. They must
+/// have the same SQL types as the binds of the query which was prepared. If
+/// they do not, an error is returned, and nothing is sent to the server.
+///
+/// The statement is deallocated when this is dropped.
+#[allow(missing_debug_implementations)]
+pub struct PreparedQuery<'a, T> {
+    connection: &'a PgConnection,
+    statement: Option<Statement>,
+    bind_types: Vec<PgTypeMetadata>,
+    _marker: PhantomData<T>,
+}
+
+impl<'a, T> PreparedQuery<'a, T> {
+    pub(crate) fn new(connection: &'a PgConnection, source: &T) -> QueryResult<Self>
+    where
+        T: QueryFragment<Pg>,
+    {
+        let mut query_builder = PgQueryBuilder::new();
+        try!(source.to_sql(&mut query_builder));
+        let sql = query_builder.finish();
+        let bind_types = try!(collect_binds(connection, source)).metadata;
+
+        let id = connection.next_statement_id.get();
+        connection.next_statement_id.set(id + 1);
+        let statement = try!(Statement::prepare(
+            &connection.raw_connection,
+            &sql,
+            Some(&format!("__diesel_stmt_{}", id)),
+            &bind_types,
+        ));
+
+        Ok(PreparedQuery {
+            connection: connection,
+            statement: Some(statement),
+            bind_types: bind_types,
+            _marker: PhantomData,
+        })
+    }
+
+    /// Runs the statement with `binds`, and returns the number of affected
+    /// rows.
+    pub fn execute_with<B>(&self, binds: B) -> QueryResult<usize>
+    where
+        B: QueryFragment<Pg>,
+    {
+        let binds = try!(self.collect_binds(&binds));
+        self.statement()
+            .execute(&self.connection.raw_connection, &binds.binds)
+            .map(|r| r.rows_affected())
+    }
+
+    /// Runs the statement with `binds`, and returns the resulting rows.
+    pub fn load_with<B, U>(&self, binds: B) -> QueryResult<Vec<U>>
+    where
+        B: QueryFragment<Pg>,
+        T: Query,
+        Pg: HasSqlType<T::SqlType>,
+        U: Queryable<T::SqlType, Pg>,
+    {
+        let binds = try!(self.collect_binds(&binds));
+        self.statement()
+            .execute(&self.connection.raw_connection, &binds.binds)
+            .and_then(|r| Cursor::new(r).collect())
+    }
+
+    fn collect_binds<B>(&self, binds: &B) -> QueryResult<RawBytesBindCollector<Pg>>
+    where
+        B: QueryFragment<Pg>,
+    {
+        let binds = try!(collect_binds(self.connection, binds));
+        if binds.metadata == self.bind_types {
+            Ok(binds)
+        } else {
+            Err(Error::QueryBuilderError(
+                "The binds do not match the binds of the prepared statement".into(),
+            ))
+        }
+    }
+
+    fn statement(&self) -> &Statement {
+        self.statement
+            .as_ref()
+            .expect("The statement is only taken when it is dropped")
+    }
+}
+
+impl<'a, T> Drop for PreparedQuery<'a, T> {
+    fn drop(&mut self) {
+        if let Some(statement) = self.statement.take() {
+            let _ = statement.deallocate(&self.connection.raw_connection);
+        }
+    }
+}
+
+fn collect_binds<T>(connection: &PgConnection, source: &T) -> QueryResult<RawBytesBindCollector<Pg>>
+where
+    T: QueryFragment<Pg>,
+{
+    let mut bind_collector = RawBytesBindCollector::<Pg>::new();
+    try!(source.collect_binds(&mut bind_collector, PgMetadataLookup::new(connection)));
+    Ok(bind_collector)
+}
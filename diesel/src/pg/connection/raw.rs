@@ -1,10 +1,13 @@
 #![cfg_attr(feature = "clippy", allow(too_many_arguments))]
 
-extern crate pq_sys;
+pub extern crate pq_sys;
+#[cfg(unix)]
+extern crate libc as sys;
 
 use self::pq_sys::*;
 use std::ffi::{CStr, CString};
 use std::os::raw as libc;
+use std::time::Duration;
 use std::{ptr, str};
 
 use result::*;
@@ -16,10 +19,41 @@ pub struct RawConnection {
 
 impl RawConnection {
     pub fn establish(database_url: &str) -> ConnectionResult<Self> {
-        use self::ConnStatusType::*;
-
         let connection_string = try!(CString::new(database_url));
         let connection_ptr = unsafe { PQconnectdb(connection_string.as_ptr()) };
+        RawConnection::from_connection_ptr(connection_ptr)
+    }
+
+    /// Establishes a connection, using `params` to override the parameters
+    /// given in `database_url`.
+    pub fn establish_with_params(
+        database_url: &str,
+        params: &[(&str, String)],
+    ) -> ConnectionResult<Self> {
+        let mut keywords = vec![try!(CString::new("dbname"))];
+        let mut values = vec![try!(CString::new(database_url))];
+        for &(keyword, ref value) in params {
+            keywords.push(try!(CString::new(keyword)));
+            values.push(try!(CString::new(value.as_str())));
+        }
+
+        let mut keyword_ptrs = keywords.iter().map(|k| k.as_ptr()).collect::<Vec<_>>();
+        let mut value_ptrs = values.iter().map(|v| v.as_ptr()).collect::<Vec<_>>();
+        keyword_ptrs.push(ptr::null());
+        value_ptrs.push(ptr::null());
+
+        // Since `dbname` is expanded first, parameters which come after it
+        // take precedence over the ones in the connection string
+        let expand_dbname = 1;
+        let connection_ptr = unsafe {
+            PQconnectdbParams(keyword_ptrs.as_ptr(), value_ptrs.as_ptr(), expand_dbname)
+        };
+        RawConnection::from_connection_ptr(connection_ptr)
+    }
+
+    fn from_connection_ptr(connection_ptr: *mut PGconn) -> ConnectionResult<Self> {
+        use self::ConnStatusType::*;
+
         let connection_status = unsafe { PQstatus(connection_ptr) };
 
         match connection_status {
@@ -33,17 +67,39 @@ impl RawConnection {
         }
     }
 
+    pub fn as_ptr(&self) -> *mut PGconn {
+        self.internal_connection
+    }
+
     pub fn last_error_message(&self) -> String {
         last_error_message(self.internal_connection)
     }
 
-    pub fn set_notice_processor(&self, notice_processor: NoticeProcessor) {
+    /// Whether the connection to the server has been lost. libpq marks the
+    /// connection as bad once it notices that the server closed the socket.
+    pub fn is_broken(&self) -> bool {
+        let status = unsafe { PQstatus(self.internal_connection) };
+        status == ConnStatusType::CONNECTION_BAD
+    }
+
+    /// The error for a command which could not be sent, or whose result could
+    /// not be read. This is `Error::ConnectionClosed` if the connection has
+    /// been lost.
+    fn command_error(&self) -> Error {
+        let message = Box::new(self.last_error_message());
+        if self.is_broken() {
+            Error::ConnectionClosed(message)
+        } else {
+            Error::DatabaseError(DatabaseErrorKind::UnableToSendCommand, message)
+        }
+    }
+
+    /// Sets the function which receives the notices sent by the server.
+    /// `arg` is passed to it, and must stay valid until another receiver is
+    /// set or the connection is dropped.
+    pub fn set_notice_receiver(&self, notice_receiver: NoticeReceiver, arg: *mut libc::c_void) {
         unsafe {
-            PQsetNoticeProcessor(
-                self.internal_connection,
-                Some(notice_processor),
-                ptr::null_mut(),
-            );
+            PQsetNoticeReceiver(self.internal_connection, Some(notice_receiver), arg);
         }
     }
 
@@ -72,6 +128,104 @@ impl RawConnection {
         RawResult::new(ptr, self)
     }
 
+    pub unsafe fn exec_params(
+        &self,
+        query: *const libc::c_char,
+        param_count: libc::c_int,
+        param_types: *const Oid,
+        param_values: *const *const libc::c_char,
+        param_lengths: *const libc::c_int,
+        param_formats: *const libc::c_int,
+        result_format: libc::c_int,
+    ) -> QueryResult<RawResult> {
+        let ptr = PQexecParams(
+            self.internal_connection,
+            query,
+            param_count,
+            param_types,
+            param_values,
+            param_lengths,
+            param_formats,
+            result_format,
+        );
+        RawResult::new(ptr, self)
+    }
+
+    pub unsafe fn send_query_prepared(
+        &self,
+        stmt_name: *const libc::c_char,
+        param_count: libc::c_int,
+        param_values: *const *const libc::c_char,
+        param_lengths: *const libc::c_int,
+        param_formats: *const libc::c_int,
+        result_format: libc::c_int,
+    ) -> QueryResult<()> {
+        let sent = PQsendQueryPrepared(
+            self.internal_connection,
+            stmt_name,
+            param_count,
+            param_values,
+            param_lengths,
+            param_formats,
+            result_format,
+        );
+        if sent == 1 {
+            Ok(())
+        } else {
+            Err(self.command_error())
+        }
+    }
+
+    pub unsafe fn send_query_params(
+        &self,
+        query: *const libc::c_char,
+        param_count: libc::c_int,
+        param_types: *const Oid,
+        param_values: *const *const libc::c_char,
+        param_lengths: *const libc::c_int,
+        param_formats: *const libc::c_int,
+        result_format: libc::c_int,
+    ) -> QueryResult<()> {
+        let sent = PQsendQueryParams(
+            self.internal_connection,
+            query,
+            param_count,
+            param_types,
+            param_values,
+            param_lengths,
+            param_formats,
+            result_format,
+        );
+        if sent == 1 {
+            Ok(())
+        } else {
+            Err(self.command_error())
+        }
+    }
+
+    /// Makes `get_result` return the rows of the command which was just sent
+    /// one at a time, as they are received. Must be called before the first
+    /// call to `get_result`.
+    pub fn set_single_row_mode(&self) -> QueryResult<()> {
+        let result = unsafe { PQsetSingleRowMode(self.internal_connection) };
+        if result == 1 {
+            Ok(())
+        } else {
+            Err(self.command_error())
+        }
+    }
+
+    /// Returns the next result of the command which was sent last, or `None`
+    /// once all results have been returned.
+    pub fn get_result(&self) -> Option<RawResult> {
+        let ptr = unsafe { PQgetResult(self.internal_connection) };
+        if ptr.is_null() {
+            None
+        } else {
+            Some(RawResult(ptr))
+        }
+    }
+
     pub unsafe fn prepare(
         &self,
         stmt_name: *const libc::c_char,
@@ -90,7 +244,71 @@ impl RawConnection {
     }
 }
 
-pub type NoticeProcessor = extern "C" fn(arg: *mut libc::c_void, message: *const libc::c_char);
+impl RawConnection {
+    /// Reads any data which is available on the socket, without blocking.
+    /// Notifications which have been read are returned by `notifies`.
+    pub fn consume_input(&self) -> QueryResult<()> {
+        if unsafe { PQconsumeInput(self.internal_connection) } == 1 {
+            Ok(())
+        } else {
+            Err(self.command_error())
+        }
+    }
+
+    /// Returns the next notification which has already been read from the
+    /// server as `(channel, payload, pid)`.
+    pub fn notifies(&self) -> Option<(String, String, i32)> {
+        unsafe {
+            let notify = PQnotifies(self.internal_connection);
+            if notify.is_null() {
+                return None;
+            }
+            let channel = CStr::from_ptr((*notify).relname).to_string_lossy().into_owned();
+            let payload = CStr::from_ptr((*notify).extra).to_string_lossy().into_owned();
+            let pid = (*notify).be_pid;
+            PQfreemem(notify as *mut libc::c_void);
+            Some((channel, payload, pid))
+        }
+    }
+
+    /// Blocks until the server has sent data, or until `timeout` has passed.
+    #[cfg(unix)]
+    pub fn wait_for_input(&self, timeout: Option<Duration>) -> QueryResult<()> {
+        use std::io;
+
+        let mut poll_fd = sys::pollfd {
+            fd: unsafe { PQsocket(self.internal_connection) },
+            events: sys::POLLIN,
+            revents: 0,
+        };
+        let timeout_ms = timeout.map_or(-1, |t| {
+            let ms = t.as_secs() * 1000 + u64::from(t.subsec_nanos() / 1_000_000);
+            ::std::cmp::min(ms, libc::c_int::max_value() as u64) as libc::c_int
+        });
+        if unsafe { sys::poll(&mut poll_fd, 1, timeout_ms) } == -1 {
+            let error = io::Error::last_os_error();
+            // Interrupted by a signal, the caller will wait again
+            if error.kind() != io::ErrorKind::Interrupted {
+                return Err(Error::DatabaseError(
+                    DatabaseErrorKind::UnableToSendCommand,
+                    Box::new(error.to_string()),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Without `poll`, this sleeps for a short interval, after which the
+    /// caller checks for new data again.
+    #[cfg(not(unix))]
+    pub fn wait_for_input(&self, timeout: Option<Duration>) -> QueryResult<()> {
+        let interval = Duration::from_millis(10);
+        ::std::thread::sleep(timeout.map_or(interval, |t| ::std::cmp::min(t, interval)));
+        Ok(())
+    }
+}
+
+pub type NoticeReceiver = unsafe extern "C" fn(arg: *mut libc::c_void, result: *const PGresult);
 
 impl Drop for RawConnection {
     fn drop(&mut self) {
@@ -120,12 +338,17 @@ unsafe impl Sync for RawResult {}
 impl RawResult {
     fn new(ptr: *mut PGresult, conn: &RawConnection) -> QueryResult<Self> {
         if ptr.is_null() {
-            Err(Error::DatabaseError(
-                DatabaseErrorKind::UnableToSendCommand,
-                Box::new(conn.last_error_message()),
-            ))
+            return Err(conn.command_error());
+        }
+
+        let result = RawResult(ptr);
+        let status = unsafe { PQresultStatus(ptr) };
+        // Errors generated by libpq when the connection is lost have none of
+        // the fields of errors sent by the server, so they are returned here
+        if status == ExecStatusType::PGRES_FATAL_ERROR && conn.is_broken() {
+            Err(conn.command_error())
         } else {
-            Ok(RawResult(ptr))
+            Ok(result)
         }
     }
 
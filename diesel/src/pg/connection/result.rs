@@ -19,7 +19,7 @@ impl PgResult {
 
         let result_status = unsafe { PQresultStatus(internal_result.as_ptr()) };
         match result_status {
-            PGRES_COMMAND_OK | PGRES_TUPLES_OK => Ok(PgResult {
+            PGRES_COMMAND_OK | PGRES_TUPLES_OK | PGRES_SINGLE_TUPLE => Ok(PgResult {
                 internal_result: internal_result,
             }),
             PGRES_EMPTY_QUERY => {
@@ -36,6 +36,10 @@ impl PgResult {
                         Some(error_codes::FOREIGN_KEY_VIOLATION) => {
                             DatabaseErrorKind::ForeignKeyViolation
                         }
+                        Some(error_codes::ADMIN_SHUTDOWN) | Some(error_codes::CRASH_SHUTDOWN) => {
+                            let error_information = Box::new(PgErrorInformation(internal_result));
+                            return Err(Error::ConnectionClosed(error_information));
+                        }
                         _ => DatabaseErrorKind::__Unknown,
                     };
                 let error_information = Box::new(PgErrorInformation(internal_result));
@@ -129,7 +133,8 @@ impl DatabaseErrorInformation for PgErrorInformation {
 /// Their values are defined as C preprocessor macros, and therefore are not exported by libpq-sys.
 /// Their values can be found in `postgres_ext.h`
 #[repr(i32)]
-enum ResultField {
+pub(super) enum ResultField {
+    Severity = 'S' as i32,
     SqlState = 'C' as i32,
     MessagePrimary = 'M' as i32,
     MessageDetail = 'D' as i32,
@@ -139,7 +144,7 @@ enum ResultField {
     ConstraintName = 'n' as i32,
 }
 
-fn get_result_field<'a>(res: *mut PGresult, field: ResultField) -> Option<&'a str> {
+pub(super) fn get_result_field<'a>(res: *const PGresult, field: ResultField) -> Option<&'a str> {
     let ptr = unsafe { PQresultErrorField(res, field as libc::c_int) };
     if ptr.is_null() {
         return None;
@@ -156,4 +161,6 @@ mod error_codes {
     //! They are not exposed programatically through libpq.
     pub const UNIQUE_VIOLATION: &'static str = "23505";
     pub const FOREIGN_KEY_VIOLATION: &'static str = "23503";
+    pub const ADMIN_SHUTDOWN: &'static str = "57P01";
+    pub const CRASH_SHUTDOWN: &'static str = "57P02";
 }
@@ -12,6 +12,11 @@ pub use super::raw::RawConnection;
 
 pub struct Statement {
     name: CString,
+    // The unnamed statement is not prepared in advance. Its SQL is sent along
+    // with the binds each time it is executed, so that it is parsed and run
+    // in a single round trip.
+    unprepared_sql: Option<CString>,
+    param_types: Vec<pq_sys::Oid>,
     param_formats: Vec<libc::c_int>,
 }
 
@@ -22,34 +27,70 @@ impl Statement {
         conn: &RawConnection,
         param_data: &Vec<Option<Vec<u8>>>,
     ) -> QueryResult<PgResult> {
-        let params_pointer = param_data
-            .iter()
-            .map(|data| {
-                data.as_ref()
-                    .map(|d| d.as_ptr() as *const libc::c_char)
-                    .unwrap_or(ptr::null())
-            })
-            .collect::<Vec<_>>();
-        let param_lengths = param_data
-            .iter()
-            .map(|data| {
-                data.as_ref().map(|d| d.len() as libc::c_int).unwrap_or(0)
-            })
-            .collect::<Vec<_>>();
+        let (params_pointer, param_lengths) = raw_params(param_data);
         let internal_res = unsafe {
-            conn.exec_prepared(
-                self.name.as_ptr(),
-                params_pointer.len() as libc::c_int,
-                params_pointer.as_ptr(),
-                param_lengths.as_ptr(),
-                self.param_formats.as_ptr(),
-                1,
-            )
+            match self.unprepared_sql {
+                Some(ref sql) => conn.exec_params(
+                    sql.as_ptr(),
+                    params_pointer.len() as libc::c_int,
+                    param_types_to_ptr(Some(&self.param_types)),
+                    params_pointer.as_ptr(),
+                    param_lengths.as_ptr(),
+                    self.param_formats.as_ptr(),
+                    1,
+                ),
+                None => conn.exec_prepared(
+                    self.name.as_ptr(),
+                    params_pointer.len() as libc::c_int,
+                    params_pointer.as_ptr(),
+                    param_lengths.as_ptr(),
+                    self.param_formats.as_ptr(),
+                    1,
+                ),
+            }
         };
 
         PgResult::new(internal_res?)
     }
 
+    /// Sends the statement without waiting for its results, which are then
+    /// returned one row at a time by `conn.get_result()`.
+    #[cfg_attr(feature = "clippy", allow(ptr_arg))]
+    pub fn send(
+        &self,
+        conn: &RawConnection,
+        param_data: &Vec<Option<Vec<u8>>>,
+    ) -> QueryResult<()> {
+        let (params_pointer, param_lengths) = raw_params(param_data);
+        try!(unsafe {
+            match self.unprepared_sql {
+                Some(ref sql) => conn.send_query_params(
+                    sql.as_ptr(),
+                    params_pointer.len() as libc::c_int,
+                    param_types_to_ptr(Some(&self.param_types)),
+                    params_pointer.as_ptr(),
+                    param_lengths.as_ptr(),
+                    self.param_formats.as_ptr(),
+                    1,
+                ),
+                None => conn.send_query_prepared(
+                    self.name.as_ptr(),
+                    params_pointer.len() as libc::c_int,
+                    params_pointer.as_ptr(),
+                    param_lengths.as_ptr(),
+                    self.param_formats.as_ptr(),
+                    1,
+                ),
+            }
+        });
+        conn.set_single_row_mode().map_err(|e| {
+            while conn.get_result().is_some() {}
+            e
+        })
+    }
+
+    /// Prepares a statement named `name` on the server. When `name` is `None`,
+    /// nothing is sent to the server until the statement is executed.
     #[cfg_attr(feature = "clippy", allow(ptr_arg))]
     pub fn prepare(
         conn: &RawConnection,
@@ -57,9 +98,20 @@ impl Statement {
         name: Option<&str>,
         param_types: &[PgTypeMetadata],
     ) -> QueryResult<Self> {
-        let name = try!(CString::new(name.unwrap_or("")));
         let sql = try!(CString::new(sql));
         let param_types_vec = param_types.iter().map(|x| x.oid).collect();
+        let param_formats = vec![1; param_types.len()];
+        let name = match name {
+            Some(name) => try!(CString::new(name)),
+            None => {
+                return Ok(Statement {
+                    name: CString::default(),
+                    unprepared_sql: Some(sql),
+                    param_types: param_types_vec,
+                    param_formats: param_formats,
+                })
+            }
+        };
 
         let internal_result = unsafe {
             conn.prepare(
@@ -73,9 +125,21 @@ impl Statement {
 
         Ok(Statement {
             name: name,
-            param_formats: vec![1; param_types.len()],
+            unprepared_sql: None,
+            param_types: param_types_vec,
+            param_formats: param_formats,
         })
     }
+
+    /// Releases a named statement on the server. Must not be called on the
+    /// unnamed statement.
+    pub fn deallocate(self, conn: &RawConnection) -> QueryResult<()> {
+        let query = format!("DEALLOCATE \"{}\"", self.name.to_string_lossy());
+        let query = try!(CString::new(query));
+        let internal_result = unsafe { conn.exec(query.as_ptr()) };
+        try!(PgResult::new(internal_result?));
+        Ok(())
+    }
 }
 
 fn param_types_to_ptr(param_types: Option<&Vec<u32>>) -> *const pq_sys::Oid {
@@ -83,3 +147,21 @@ fn param_types_to_ptr(param_types: Option<&Vec<u32>>) -> *const pq_sys::Oid {
         .map(|types| types.as_ptr())
         .unwrap_or(ptr::null())
 }
+
+fn raw_params(param_data: &[Option<Vec<u8>>]) -> (Vec<*const libc::c_char>, Vec<libc::c_int>) {
+    let params_pointer = param_data
+        .iter()
+        .map(|data| {
+            data.as_ref()
+                .map(|d| d.as_ptr() as *const libc::c_char)
+                .unwrap_or(ptr::null())
+        })
+        .collect();
+    let param_lengths = param_data
+        .iter()
+        .map(|data| {
+            data.as_ref().map(|d| d.len() as libc::c_int).unwrap_or(0)
+        })
+        .collect();
+    (params_pointer, param_lengths)
+}
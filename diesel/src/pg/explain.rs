@@ -0,0 +1,136 @@
+#[cfg(feature = "serde_json")]
+extern crate serde_json;
+
+use std::marker::PhantomData;
+
+use pg::{Pg, PgConnection};
+use query_builder::{AsQuery, AstPass, Query, QueryFragment};
+use query_dsl::LoadDsl;
+use result::QueryResult;
+use types::Text;
+#[cfg(feature = "serde_json")]
+use types::Json;
+
+/// Methods to retrieve the query plan PostgreSQL uses for a query. These are
+/// automatically implemented for any query that can be loaded from a
+/// `PgConnection`.
+///
+/// Bind parameters are sent along with the query, so the plan is the one
+/// chosen for the actual values.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate diesel;
+/// # #[macro_use] extern crate diesel_codegen;
+/// # include!("../doctest_setup.rs");
+/// #
+/// # table! {
+/// #     users {
+/// #         id -> Integer,
+/// #         name -> VarChar,
+/// #     }
+/// # }
+/// #
+/// # fn main() {
+/// #     use self::users::dsl::*;
+/// #     let connection = establish_connection();
+/// let plan = users.filter(name.eq("Sean")).explain(&connection).unwrap();
+/// assert!(plan.contains("Filter: ((name)::text = 'Sean'::text)"));
+/// # }
+/// ```
+pub trait ExplainDsl: AsQuery + Sized {
+    /// Runs `EXPLAIN` on the query, returning the plan as text. Each line of
+    /// the returned string is one row of the output.
+    fn explain(self, conn: &PgConnection) -> QueryResult<String>;
+
+    /// Runs `EXPLAIN ANALYZE` on the query, returning the plan as text.
+    ///
+    /// The query is executed to collect the actual run time and row counts.
+    /// If the query modifies data, wrap the call in a transaction which is
+    /// rolled back afterwards.
+    fn explain_analyze(self, conn: &PgConnection) -> QueryResult<String>;
+
+    /// Runs `EXPLAIN (FORMAT JSON)` on the query.
+    #[cfg(feature = "serde_json")]
+    fn explain_json(self, conn: &PgConnection) -> QueryResult<serde_json::Value>;
+
+    /// Runs `EXPLAIN (ANALYZE, FORMAT JSON)` on the query. The query is
+    /// executed, as with [`explain_analyze`](#tymethod.explain_analyze).
+    #[cfg(feature = "serde_json")]
+    fn explain_analyze_json(self, conn: &PgConnection) -> QueryResult<serde_json::Value>;
+}
+
+impl<T> ExplainDsl for T
+where
+    T: AsQuery,
+    T::Query: QueryFragment<Pg>,
+{
+    fn explain(self, conn: &PgConnection) -> QueryResult<String> {
+        Explain::<_, Text>::new(self.as_query(), false)
+            .load::<String>(conn)
+            .map(|rows| rows.join("\n"))
+    }
+
+    fn explain_analyze(self, conn: &PgConnection) -> QueryResult<String> {
+        Explain::<_, Text>::new(self.as_query(), true)
+            .load::<String>(conn)
+            .map(|rows| rows.join("\n"))
+    }
+
+    #[cfg(feature = "serde_json")]
+    fn explain_json(self, conn: &PgConnection) -> QueryResult<serde_json::Value> {
+        Explain::<_, Json>::new(self.as_query(), false).get_result(conn)
+    }
+
+    #[cfg(feature = "serde_json")]
+    fn explain_analyze_json(self, conn: &PgConnection) -> QueryResult<serde_json::Value> {
+        Explain::<_, Json>::new(self.as_query(), true).get_result(conn)
+    }
+}
+
+/// The output format is determined by the SQL type, `Text` or `Json`.
+#[derive(Debug, Clone, Copy)]
+struct Explain<T, ST> {
+    query: T,
+    analyze: bool,
+    _marker: PhantomData<ST>,
+}
+
+impl<T, ST> Explain<T, ST> {
+    fn new(query: T, analyze: bool) -> Self {
+        Explain {
+            query: query,
+            analyze: analyze,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T, ST> Query for Explain<T, ST> {
+    type SqlType = ST;
+}
+
+impl<T: QueryFragment<Pg>> QueryFragment<Pg> for Explain<T, Text> {
+    fn walk_ast(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+        out.push_sql("EXPLAIN ");
+        if self.analyze {
+            out.push_sql("ANALYZE ");
+        }
+        self.query.walk_ast(out.reborrow())
+    }
+}
+
+#[cfg(feature = "serde_json")]
+impl<T: QueryFragment<Pg>> QueryFragment<Pg> for Explain<T, Json> {
+    fn walk_ast(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+        out.push_sql("EXPLAIN (");
+        if self.analyze {
+            out.push_sql("ANALYZE, ");
+        }
+        out.push_sql("FORMAT JSON) ");
+        self.query.walk_ast(out.reborrow())
+    }
+}
+
+impl_query_id!(noop: Explain<T, ST>);
@@ -1,8 +1,10 @@
-use expression::{AsExpression, Expression, NonAggregate};
+use std::fmt::Debug;
+
+use expression::{AppearsOnTable, AsExpression, Expression, NonAggregate, SelectableExpression};
 use pg::Pg;
 use query_builder::*;
 use result::QueryResult;
-use types::Array;
+use types::{Array, Bool, HasSqlType, ToSql};
 
 /// Creates a PostgreSQL `ANY` expression.
 ///
@@ -155,3 +157,63 @@ where
     Expr: NonAggregate,
 {
 }
+
+/// `lhs = ANY($1)`, with all values bound as a single array. Constructed by
+/// [`PgExpressionMethods::eq_any_chunked`](../expression_methods/trait.PgExpressionMethods.html#method.eq_any_chunked).
+#[derive(Debug, Clone)]
+pub struct EqAnyChunked<T, U> {
+    left: T,
+    values: Vec<U>,
+}
+
+impl<T, U> EqAnyChunked<T, U> {
+    pub(crate) fn new(left: T, values: Vec<U>) -> Self {
+        EqAnyChunked {
+            left: left,
+            values: values,
+        }
+    }
+}
+
+impl<T: Expression, U> Expression for EqAnyChunked<T, U> {
+    type SqlType = Bool;
+}
+
+impl<T, U> QueryFragment<Pg> for EqAnyChunked<T, U>
+where
+    Pg: HasSqlType<T::SqlType>,
+    T: Expression + QueryFragment<Pg>,
+    U: ToSql<T::SqlType, Pg> + Debug,
+{
+    fn walk_ast(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+        self.left.walk_ast(out.reborrow())?;
+        out.push_sql(" = ANY(");
+        out.push_bind_param::<Array<T::SqlType>, _>(&self.values)?;
+        out.push_sql(")");
+        Ok(())
+    }
+}
+
+impl_query_id!(noop: EqAnyChunked<T, U>);
+
+impl<T: NonAggregate, U> NonAggregate for EqAnyChunked<T, U>
+where
+    EqAnyChunked<T, U>: Expression,
+{
+}
+
+impl<T, U, QS> SelectableExpression<QS> for EqAnyChunked<T, U>
+where
+    EqAnyChunked<T, U>: AppearsOnTable<QS>,
+    T: SelectableExpression<QS>,
+{
+}
+
+impl_unchecked_distinct_ordering!(EqAnyChunked<T, U>);
+
+impl<T, U, QS> AppearsOnTable<QS> for EqAnyChunked<T, U>
+where
+    EqAnyChunked<T, U>: Expression,
+    T: AppearsOnTable<QS>,
+{
+}
@@ -1,6 +1,7 @@
 use expression::{AsExpression, Expression};
+use super::array_comparison::EqAnyChunked;
 use super::operators::*;
-use types::{Array, Text};
+use types::{Array, Range, Text};
 
 pub trait PgExpressionMethods: Expression + Sized {
     /// Creates a PostgreSQL `IS NOT DISTINCT FROM` expression. This behaves
@@ -68,6 +69,52 @@ pub trait PgExpressionMethods: Expression + Sized {
     {
         IsDistinctFrom::new(self, other.as_expression())
     }
+
+    /// Creates a PostgreSQL `= ANY` expression for a large number of values.
+    ///
+    /// The values are sent as a single array bind parameter, generating
+    /// `lhs = ANY($1)`. This avoids the limit of 65535 bind parameters per
+    /// query, and the query can be placed in the prepared statement cache.
+    /// As the values are never expanded into an `IN` list, they are not split,
+    /// and `chunk_size` does not change the query.
+    ///
+    /// This is only available on PostgreSQL. Other backends would need a bind
+    /// parameter for each value, so the values have to be split and loaded
+    /// with one query per group instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is 0.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate diesel;
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # table! {
+    /// #     users {
+    /// #         id -> Integer,
+    /// #         name -> VarChar,
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     use self::users::dsl::*;
+    /// #     let connection = establish_connection();
+    /// let ids = (1..100_000).collect::<Vec<i32>>();
+    /// let data = users.select(name).filter(id.eq_any_chunked(ids, 1_000));
+    /// assert_eq!(Ok(vec!["Sean".to_string(), "Tess".to_string()]), data.load(&connection));
+    /// # }
+    /// ```
+    fn eq_any_chunked<T, U>(self, values: T, chunk_size: usize) -> EqAnyChunked<Self, U>
+    where
+        T: IntoIterator<Item = U>,
+        U: AsExpression<Self::SqlType>,
+    {
+        assert!(chunk_size > 0, "chunk_size must be greater than 0");
+        EqAnyChunked::new(self, values.into_iter().collect())
+    }
 }
 
 impl<T: Expression> PgExpressionMethods for T {}
@@ -263,6 +310,155 @@ where
 {
 }
 
+pub trait RangeExpressionMethods<ST>: Expression<SqlType = Range<ST>> + Sized {
+    /// Compares two ranges for common points, using the `&&` operator in
+    /// the final SQL
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate diesel;
+    /// # #[macro_use] extern crate diesel_codegen;
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # table! {
+    /// #     reservations {
+    /// #         id -> Integer,
+    /// #         seats -> Range<Integer>,
+    /// #     }
+    /// # }
+    /// #
+    /// # // FIXME: We shouldn't need to define a users table here
+    /// # table! {
+    /// #     users {
+    /// #         id -> Integer,
+    /// #         name -> VarChar,
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     use self::reservations::dsl::*;
+    /// #     use std::collections::Bound;
+    /// #     let conn = establish_connection();
+    /// #     conn.execute("DROP TABLE IF EXISTS reservations").unwrap();
+    /// #     conn.execute("CREATE TABLE reservations (id SERIAL PRIMARY KEY, seats INT4RANGE NOT NULL)").unwrap();
+    /// #     conn.execute("INSERT INTO reservations (seats) VALUES ('[1,5)'), ('[5,10)')").unwrap();
+    /// #
+    /// let query = reservations
+    ///     .select(id)
+    ///     .filter(seats.overlaps_with((Bound::Included(4), Bound::Excluded(6))));
+    /// assert_eq!(Ok(vec![1, 2]), query.load(&conn));
+    ///
+    /// let query = reservations
+    ///     .select(id)
+    ///     .filter(seats.overlaps_with((Bound::Included(10), Bound::Unbounded)));
+    /// assert_eq!(Ok(Vec::new()), query.load::<i32>(&conn));
+    /// # }
+    /// ```
+    fn overlaps_with<T>(self, other: T) -> OverlapsWith<Self, T::Expression>
+    where
+        T: AsExpression<Self::SqlType>,
+    {
+        OverlapsWith::new(self, other.as_expression())
+    }
+
+    /// Compares whether a range contains another range, using the `@>`
+    /// operator.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate diesel;
+    /// # #[macro_use] extern crate diesel_codegen;
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # table! {
+    /// #     reservations {
+    /// #         id -> Integer,
+    /// #         seats -> Range<Integer>,
+    /// #     }
+    /// # }
+    /// #
+    /// # // FIXME: We shouldn't need to define a users table here
+    /// # table! {
+    /// #     users {
+    /// #         id -> Integer,
+    /// #         name -> VarChar,
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     use self::reservations::dsl::*;
+    /// #     use std::collections::Bound;
+    /// #     let conn = establish_connection();
+    /// #     conn.execute("DROP TABLE IF EXISTS reservations").unwrap();
+    /// #     conn.execute("CREATE TABLE reservations (id SERIAL PRIMARY KEY, seats INT4RANGE NOT NULL)").unwrap();
+    /// #     conn.execute("INSERT INTO reservations (seats) VALUES ('[1,5)'), ('[5,10)')").unwrap();
+    /// #
+    /// let query = reservations
+    ///     .select(id)
+    ///     .filter(seats.contains_range((Bound::Included(2), Bound::Included(4))));
+    /// assert_eq!(Ok(vec![1]), query.load(&conn));
+    /// # }
+    /// ```
+    fn contains_range<T>(self, other: T) -> Contains<Self, T::Expression>
+    where
+        T: AsExpression<Self::SqlType>,
+    {
+        Contains::new(self, other.as_expression())
+    }
+
+    /// Compares whether a range contains a single value, using the `@>`
+    /// operator.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate diesel;
+    /// # #[macro_use] extern crate diesel_codegen;
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # table! {
+    /// #     reservations {
+    /// #         id -> Integer,
+    /// #         seats -> Range<Integer>,
+    /// #     }
+    /// # }
+    /// #
+    /// # // FIXME: We shouldn't need to define a users table here
+    /// # table! {
+    /// #     users {
+    /// #         id -> Integer,
+    /// #         name -> VarChar,
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     use self::reservations::dsl::*;
+    /// #     use std::collections::Bound;
+    /// #     let conn = establish_connection();
+    /// #     conn.execute("DROP TABLE IF EXISTS reservations").unwrap();
+    /// #     conn.execute("CREATE TABLE reservations (id SERIAL PRIMARY KEY, seats INT4RANGE NOT NULL)").unwrap();
+    /// #     conn.execute("INSERT INTO reservations (seats) VALUES ('[1,5)'), ('[5,10)')").unwrap();
+    /// #
+    /// let query = reservations.select(id).filter(seats.contains_elem(5));
+    /// assert_eq!(Ok(vec![2]), query.load(&conn));
+    /// # }
+    /// ```
+    fn contains_elem<T>(self, other: T) -> Contains<Self, T::Expression>
+    where
+        T: AsExpression<ST>,
+    {
+        Contains::new(self, other.as_expression())
+    }
+}
+
+impl<T, ST> RangeExpressionMethods<ST> for T
+where
+    T: Expression<SqlType = Range<ST>>,
+{
+}
+
 use expression::operators::{Asc, Desc};
 
 pub trait SortExpressionMethods: Sized {
@@ -367,6 +563,282 @@ pub trait PgTextExpressionMethods: Expression<SqlType = Text> + Sized {
     fn not_ilike<T: AsExpression<Text>>(self, other: T) -> NotILike<Self, T::Expression> {
         NotILike::new(self.as_expression(), other.as_expression())
     }
+
+    /// Returns a SQL `SIMILAR TO` expression. The pattern uses `%` and `_`
+    /// like `LIKE`, along with the alternation and repetition of regular
+    /// expressions, and has to match the whole string.
+    fn similar_to<T: AsExpression<Text>>(self, pattern: T) -> SimilarTo<Self, T::Expression> {
+        SimilarTo::new(self, pattern.as_expression())
+    }
+
+    /// Returns whether this string matches a POSIX regular expression, using
+    /// the `~` operator. The pattern matches anywhere in the string unless it
+    /// is anchored.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate diesel;
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # table! {
+    /// #     users {
+    /// #         id -> Integer,
+    /// #         name -> VarChar,
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     use self::users::dsl::*;
+    /// #     let connection = establish_connection();
+    /// let names = users.select(name).filter(name.regex_match("^T")).load(&connection);
+    /// assert_eq!(Ok(vec!["Tess".to_string()]), names);
+    /// # }
+    /// ```
+    fn regex_match<T: AsExpression<Text>>(self, pattern: T) -> RegexMatch<Self, T::Expression> {
+        RegexMatch::new(self, pattern.as_expression())
+    }
+
+    /// Returns whether this string matches a POSIX regular expression,
+    /// ignoring case, using the `~*` operator.
+    fn regex_imatch<T: AsExpression<Text>>(self, pattern: T) -> RegexIMatch<Self, T::Expression> {
+        RegexIMatch::new(self, pattern.as_expression())
+    }
 }
 
 impl<T: Expression<SqlType = Text>> PgTextExpressionMethods for T {}
+
+use types::Hstore;
+
+pub trait HstoreExpressionMethods: Expression<SqlType = Hstore> + Sized {
+    /// Retrieves the value of a key, using the `->` operator in the final
+    /// SQL. The result is `NULL` when the key doesn't exist, or when its
+    /// value is `NULL`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate diesel;
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # table! {
+    /// #     users {
+    /// #         id -> Integer,
+    /// #         name -> VarChar,
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     use diesel::dsl::sql;
+    /// #     use diesel::types::Hstore;
+    /// #     let connection = establish_connection();
+    /// #     connection.execute("CREATE EXTENSION IF NOT EXISTS hstore").unwrap();
+    /// let attributes = sql::<Hstore>("'color => red, size => NULL'::hstore");
+    /// let color = diesel::select(attributes.get_value("color"))
+    ///     .get_result(&connection);
+    /// assert_eq!(Ok(Some("red".to_string())), color);
+    ///
+    /// let attributes = sql::<Hstore>("'color => red, size => NULL'::hstore");
+    /// let size = diesel::select(attributes.get_value("size"))
+    ///     .get_result::<Option<String>>(&connection);
+    /// assert_eq!(Ok(None), size);
+    /// # }
+    /// ```
+    fn get_value<T>(self, key: T) -> RetrieveHstoreValue<Self, T::Expression>
+    where
+        T: AsExpression<Text>,
+    {
+        RetrieveHstoreValue::new(self, key.as_expression())
+    }
+
+    /// Checks whether a key exists, using the `?` operator in the final
+    /// SQL. This is true for keys with a `NULL` value as well.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate diesel;
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # table! {
+    /// #     users {
+    /// #         id -> Integer,
+    /// #         name -> VarChar,
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     use diesel::dsl::sql;
+    /// #     use diesel::types::Hstore;
+    /// #     let connection = establish_connection();
+    /// #     connection.execute("CREATE EXTENSION IF NOT EXISTS hstore").unwrap();
+    /// let attributes = sql::<Hstore>("'color => red, size => NULL'::hstore");
+    /// let has_size = diesel::select(attributes.has_key("size"))
+    ///     .get_result(&connection);
+    /// assert_eq!(Ok(true), has_size);
+    ///
+    /// let attributes = sql::<Hstore>("'color => red, size => NULL'::hstore");
+    /// let has_weight = diesel::select(attributes.has_key("weight"))
+    ///     .get_result(&connection);
+    /// assert_eq!(Ok(false), has_weight);
+    /// # }
+    /// ```
+    fn has_key<T>(self, key: T) -> HasKey<Self, T::Expression>
+    where
+        T: AsExpression<Text>,
+    {
+        HasKey::new(self, key.as_expression())
+    }
+}
+
+impl<T: Expression<SqlType = Hstore>> HstoreExpressionMethods for T {}
+
+#[cfg(feature = "serde_json")]
+use super::json::*;
+
+#[cfg(feature = "serde_json")]
+pub trait PgJsonExpressionMethods: Expression + Sized {
+    /// Retrieves the value of a key of a JSON object, as JSON. This uses the
+    /// `->` operator in the final SQL.
+    ///
+    /// The result has the same type as this expression, but is nullable,
+    /// since it is `NULL` when the key doesn't exist. This allows keys of
+    /// nested objects to be retrieved by calling this method again.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate diesel;
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # table! {
+    /// #     users {
+    /// #         id -> Integer,
+    /// #         name -> VarChar,
+    /// #     }
+    /// # }
+    /// #
+    /// # #[cfg(feature = "serde_json")]
+    /// # fn main() {
+    /// #     use diesel::dsl::sql;
+    /// #     use diesel::types::Jsonb;
+    /// #     let connection = establish_connection();
+    /// let document = sql::<Jsonb>(r#"'{"address": {"city": "Berlin"}}'::jsonb"#);
+    /// let city = diesel::select(document.retrieve_as_object("address").retrieve_as_text("city"))
+    ///     .get_result(&connection);
+    /// assert_eq!(Ok(Some("Berlin".to_string())), city);
+    /// # }
+    /// # #[cfg(not(feature = "serde_json"))]
+    /// # fn main() {}
+    /// ```
+    fn retrieve_as_object<T>(self, key: T) -> RetrieveAsObject<Self, T::Expression>
+    where
+        T: AsExpression<Text>,
+    {
+        RetrieveAsObject::new(self, key.as_expression())
+    }
+
+    /// Retrieves the value of a key of a JSON object, as text. This uses the
+    /// `->>` operator in the final SQL.
+    ///
+    /// The result is `NULL` when the key doesn't exist, or when its value is
+    /// JSON `null`. Strings are returned without quotes, all other values are
+    /// returned as they are written in JSON.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate diesel;
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # table! {
+    /// #     users {
+    /// #         id -> Integer,
+    /// #         name -> VarChar,
+    /// #     }
+    /// # }
+    /// #
+    /// # #[cfg(feature = "serde_json")]
+    /// # fn main() {
+    /// #     use diesel::dsl::sql;
+    /// #     use diesel::types::Jsonb;
+    /// #     let connection = establish_connection();
+    /// let document = sql::<Jsonb>(r#"'{"status": "active", "logins": 3}'::jsonb"#);
+    /// let status = diesel::select(document.retrieve_as_text("status"))
+    ///     .get_result(&connection);
+    /// assert_eq!(Ok(Some("active".to_string())), status);
+    ///
+    /// let document = sql::<Jsonb>(r#"'{"status": "active", "logins": 3}'::jsonb"#);
+    /// let missing = diesel::select(document.retrieve_as_text("email"))
+    ///     .get_result::<Option<String>>(&connection);
+    /// assert_eq!(Ok(None), missing);
+    /// # }
+    /// # #[cfg(not(feature = "serde_json"))]
+    /// # fn main() {}
+    /// ```
+    fn retrieve_as_text<T>(self, key: T) -> RetrieveAsText<Self, T::Expression>
+    where
+        T: AsExpression<Text>,
+    {
+        RetrieveAsText::new(self, key.as_expression())
+    }
+
+    /// Retrieves the value at a path of keys, as JSON. This uses the `#>`
+    /// operator in the final SQL.
+    ///
+    /// Elements of arrays can be retrieved by giving their index as a key.
+    /// The result is `NULL` when the path doesn't exist.
+    fn retrieve_by_path_as_object<T>(self, path: T) -> RetrieveByPathAsObject<Self, T::Expression>
+    where
+        T: AsExpression<Array<Text>>,
+    {
+        RetrieveByPathAsObject::new(self, path.as_expression())
+    }
+
+    /// Retrieves the value at a path of keys, as text. This uses the `#>>`
+    /// operator in the final SQL.
+    ///
+    /// Elements of arrays can be retrieved by giving their index as a key.
+    /// The result is `NULL` when the path doesn't exist.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate diesel;
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # table! {
+    /// #     users {
+    /// #         id -> Integer,
+    /// #         name -> VarChar,
+    /// #     }
+    /// # }
+    /// #
+    /// # #[cfg(feature = "serde_json")]
+    /// # fn main() {
+    /// #     use diesel::dsl::sql;
+    /// #     use diesel::types::Json;
+    /// #     let connection = establish_connection();
+    /// let document = sql::<Json>(r#"'{"tags": ["new", "cheap"]}'::json"#);
+    /// let second_tag = diesel::select(document.retrieve_by_path_as_text(vec!["tags", "1"]))
+    ///     .get_result(&connection);
+    /// assert_eq!(Ok(Some("cheap".to_string())), second_tag);
+    /// # }
+    /// # #[cfg(not(feature = "serde_json"))]
+    /// # fn main() {}
+    /// ```
+    fn retrieve_by_path_as_text<T>(self, path: T) -> RetrieveByPathAsText<Self, T::Expression>
+    where
+        T: AsExpression<Array<Text>>,
+    {
+        RetrieveByPathAsText::new(self, path.as_expression())
+    }
+}
+
+#[cfg(feature = "serde_json")]
+impl<T> PgJsonExpressionMethods for T
+where
+    T: Expression,
+    T::SqlType: JsonOrNullableJson,
+{
+}
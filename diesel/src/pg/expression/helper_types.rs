@@ -1,8 +1,43 @@
 use dsl::AsExprOf;
-use types::VarChar;
+use types::{Text, VarChar};
+#[cfg(feature = "serde_json")]
+use types::Array;
 
 /// The return type of `lhs.ilike(rhs)`
 pub type ILike<Lhs, Rhs> = super::operators::ILike<Lhs, AsExprOf<Rhs, VarChar>>;
 
 /// The return type of `lhs.not_ilike(rhs)`
 pub type NotILike<Lhs, Rhs> = super::operators::NotILike<Lhs, AsExprOf<Rhs, VarChar>>;
+
+/// The return type of `lhs.similar_to(rhs)`
+pub type SimilarTo<Lhs, Rhs> = super::operators::SimilarTo<Lhs, AsExprOf<Rhs, Text>>;
+
+/// The return type of `lhs.regex_match(rhs)`
+pub type RegexMatch<Lhs, Rhs> = super::operators::RegexMatch<Lhs, AsExprOf<Rhs, Text>>;
+
+/// The return type of `lhs.regex_imatch(rhs)`
+pub type RegexIMatch<Lhs, Rhs> = super::operators::RegexIMatch<Lhs, AsExprOf<Rhs, Text>>;
+
+/// The return type of `lhs.get_value(rhs)`
+pub type GetValue<Lhs, Rhs> = super::operators::RetrieveHstoreValue<Lhs, AsExprOf<Rhs, Text>>;
+
+/// The return type of `lhs.has_key(rhs)`
+pub type HasKey<Lhs, Rhs> = super::operators::HasKey<Lhs, AsExprOf<Rhs, Text>>;
+
+/// The return type of `lhs.retrieve_as_object(rhs)`
+#[cfg(feature = "serde_json")]
+pub type RetrieveAsObject<Lhs, Rhs> = super::json::RetrieveAsObject<Lhs, AsExprOf<Rhs, Text>>;
+
+/// The return type of `lhs.retrieve_as_text(rhs)`
+#[cfg(feature = "serde_json")]
+pub type RetrieveAsText<Lhs, Rhs> = super::json::RetrieveAsText<Lhs, AsExprOf<Rhs, Text>>;
+
+/// The return type of `lhs.retrieve_by_path_as_object(rhs)`
+#[cfg(feature = "serde_json")]
+pub type RetrieveByPathAsObject<Lhs, Rhs> =
+    super::json::RetrieveByPathAsObject<Lhs, AsExprOf<Rhs, Array<Text>>>;
+
+/// The return type of `lhs.retrieve_by_path_as_text(rhs)`
+#[cfg(feature = "serde_json")]
+pub type RetrieveByPathAsText<Lhs, Rhs> =
+    super::json::RetrieveByPathAsText<Lhs, AsExprOf<Rhs, Array<Text>>>;
@@ -0,0 +1,107 @@
+use expression::{Expression, NonAggregate};
+use pg::Pg;
+use query_builder::*;
+use result::QueryResult;
+use types::{Array, IntoNullable, Json, Jsonb, Nullable, Text};
+
+/// Marker trait for types which are valid in JSON operator expressions
+pub trait JsonOrNullableJson: IntoNullable {}
+impl JsonOrNullableJson for Json {}
+impl JsonOrNullableJson for Jsonb {}
+impl JsonOrNullableJson for Nullable<Json> {}
+impl JsonOrNullableJson for Nullable<Jsonb> {}
+
+diesel_infix_operator!(RetrieveAsText, " ->> ", Nullable<Text>, backend: Pg);
+diesel_infix_operator!(RetrieveByPathAsText, " #>> ", Nullable<Text>, backend: Pg);
+
+#[derive(Debug, Copy, Clone)]
+pub struct RetrieveAsObject<T, K> {
+    document: T,
+    key: K,
+}
+
+impl<T, K> RetrieveAsObject<T, K> {
+    pub fn new(document: T, key: K) -> Self {
+        RetrieveAsObject {
+            document: document,
+            key: key,
+        }
+    }
+}
+
+impl<T, K> Expression for RetrieveAsObject<T, K>
+where
+    T: Expression,
+    T::SqlType: JsonOrNullableJson,
+    K: Expression<SqlType = Text>,
+{
+    type SqlType = <T::SqlType as IntoNullable>::Nullable;
+}
+
+impl<T, K> NonAggregate for RetrieveAsObject<T, K>
+where
+    RetrieveAsObject<T, K>: Expression,
+{
+}
+
+impl<T, K> QueryFragment<Pg> for RetrieveAsObject<T, K>
+where
+    T: QueryFragment<Pg>,
+    K: QueryFragment<Pg>,
+{
+    fn walk_ast(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+        self.document.walk_ast(out.reborrow())?;
+        out.push_sql(" -> ");
+        self.key.walk_ast(out.reborrow())?;
+        Ok(())
+    }
+}
+
+impl_query_id!(RetrieveAsObject<T, K>);
+impl_selectable_expression!(RetrieveAsObject<T, K>);
+
+#[derive(Debug, Copy, Clone)]
+pub struct RetrieveByPathAsObject<T, P> {
+    document: T,
+    path: P,
+}
+
+impl<T, P> RetrieveByPathAsObject<T, P> {
+    pub fn new(document: T, path: P) -> Self {
+        RetrieveByPathAsObject {
+            document: document,
+            path: path,
+        }
+    }
+}
+
+impl<T, P> Expression for RetrieveByPathAsObject<T, P>
+where
+    T: Expression,
+    T::SqlType: JsonOrNullableJson,
+    P: Expression<SqlType = Array<Text>>,
+{
+    type SqlType = <T::SqlType as IntoNullable>::Nullable;
+}
+
+impl<T, P> NonAggregate for RetrieveByPathAsObject<T, P>
+where
+    RetrieveByPathAsObject<T, P>: Expression,
+{
+}
+
+impl<T, P> QueryFragment<Pg> for RetrieveByPathAsObject<T, P>
+where
+    T: QueryFragment<Pg>,
+    P: QueryFragment<Pg>,
+{
+    fn walk_ast(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+        self.document.walk_ast(out.reborrow())?;
+        out.push_sql(" #> ");
+        self.path.walk_ast(out.reborrow())?;
+        Ok(())
+    }
+}
+
+impl_query_id!(RetrieveByPathAsObject<T, P>);
+impl_selectable_expression!(RetrieveByPathAsObject<T, P>);
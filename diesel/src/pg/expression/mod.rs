@@ -6,8 +6,12 @@ pub mod extensions;
 pub mod operators;
 #[doc(hidden)]
 pub mod helper_types;
+#[doc(hidden)]
+pub mod percentile;
 
 mod date_and_time;
+#[cfg(feature = "serde_json")]
+mod json;
 
 /// PostgreSQL specific expression DSL methods. This module will be glob
 /// imported by [`diesel::dsl`](../../dsl/index.html) when
@@ -17,4 +21,7 @@ pub mod dsl {
     pub use super::array_comparison::{all, any};
 
     pub use super::extensions::*;
+
+    #[doc(inline)]
+    pub use super::percentile::{percentile_cont, percentile_disc};
 }
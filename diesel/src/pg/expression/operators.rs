@@ -1,4 +1,5 @@
 use pg::Pg;
+use types::{Nullable, Text};
 
 diesel_infix_operator!(IsDistinctFrom, " IS DISTINCT FROM ", backend: Pg);
 diesel_infix_operator!(IsNotDistinctFrom, " IS NOT DISTINCT FROM ", backend: Pg);
@@ -7,5 +8,10 @@ diesel_infix_operator!(Contains, " @> ", backend: Pg);
 diesel_infix_operator!(IsContainedBy, " <@ ", backend: Pg);
 diesel_infix_operator!(ILike, " ILIKE ", backend: Pg);
 diesel_infix_operator!(NotILike, " NOT ILIKE ", backend: Pg);
+diesel_infix_operator!(SimilarTo, " SIMILAR TO ", backend: Pg);
+diesel_infix_operator!(RegexMatch, " ~ ", backend: Pg);
+diesel_infix_operator!(RegexIMatch, " ~* ", backend: Pg);
+diesel_infix_operator!(RetrieveHstoreValue, " -> ", Nullable<Text>, backend: Pg);
+diesel_infix_operator!(HasKey, " ? ", backend: Pg);
 diesel_postfix_operator!(NullsFirst, " NULLS FIRST", (), backend: Pg);
 diesel_postfix_operator!(NullsLast, " NULLS LAST", (), backend: Pg);
@@ -0,0 +1,219 @@
+use expression::{AppearsOnTable, AsExpression, Expression, SelectableExpression};
+use pg::Pg;
+use query_builder::*;
+use query_builder::order_clause::{NoOrderClause, OrderClause};
+use query_source::Never;
+use result::QueryResult;
+use types::{self, Double, IntoNullable, NotNull};
+
+/// Creates a PostgreSQL `percentile_cont` aggregate, which computes the value
+/// at `fraction` of the way through the sorted values of a group, interpolating
+/// between the two nearest values if needed. `percentile_cont(0.5)` is the
+/// median.
+///
+/// This is an ordered-set aggregate, so it can only be used once the values
+/// have been given with `.within_group_order_by(expr)`, which renders
+/// `percentile_cont(fraction) WITHIN GROUP (ORDER BY expr)`. The values are
+/// always sorted in ascending order. `NULL` values are skipped, and the result
+/// is `NULL` if there are no non-null values.
+///
+/// | Values | Result |
+/// |--------|--------|
+/// | `SmallInt`, `Integer`, `BigInt`, `Float`, `Double`, `Numeric` | `Double` |
+/// | `Interval` | `Interval` |
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate diesel;
+/// # include!("../../doctest_setup.rs");
+/// # use diesel::dsl::*;
+/// #
+/// # table! {
+/// #     users {
+/// #         id -> Integer,
+/// #         name -> VarChar,
+/// #     }
+/// # }
+/// #
+/// # fn main() {
+/// #     use self::users::dsl::*;
+/// #     let connection = establish_connection();
+/// let median_id = users
+///     .select(percentile_cont(0.5).within_group_order_by(id))
+///     .get_result(&connection);
+/// assert_eq!(Ok(Some(1.5)), median_id);
+/// # }
+/// ```
+pub fn percentile_cont<F>(fraction: F) -> PercentileCont<F::Expression, NoOrderClause>
+where
+    F: AsExpression<Double>,
+{
+    PercentileCont {
+        fraction: fraction.as_expression(),
+        order: NoOrderClause,
+    }
+}
+
+/// Creates a PostgreSQL `percentile_disc` aggregate, which returns the first
+/// of the sorted values of a group whose position is at least `fraction` of
+/// the way through them. Unlike
+/// [`percentile_cont`](fn.percentile_cont.html), the result is always one of
+/// the values, and has their type.
+///
+/// This is an ordered-set aggregate, so it can only be used once the values
+/// have been given with `.within_group_order_by(expr)`, which renders
+/// `percentile_disc(fraction) WITHIN GROUP (ORDER BY expr)`. The values are
+/// always sorted in ascending order. `NULL` values are skipped, and the result
+/// is `NULL` if there are no non-null values.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate diesel;
+/// # include!("../../doctest_setup.rs");
+/// # use diesel::dsl::*;
+/// #
+/// # table! {
+/// #     users {
+/// #         id -> Integer,
+/// #         name -> VarChar,
+/// #     }
+/// # }
+/// #
+/// # fn main() {
+/// #     use self::users::dsl::*;
+/// #     let connection = establish_connection();
+/// let first_name = users
+///     .select(percentile_disc(0.5).within_group_order_by(name))
+///     .get_result(&connection);
+/// assert_eq!(Ok(Some(String::from("Sean"))), first_name);
+/// # }
+/// ```
+pub fn percentile_disc<F>(fraction: F) -> PercentileDisc<F::Expression, NoOrderClause>
+where
+    F: AsExpression<Double>,
+{
+    PercentileDisc {
+        fraction: fraction.as_expression(),
+        order: NoOrderClause,
+    }
+}
+
+/// The SQL types which [`percentile_cont`](fn.percentile_cont.html) can
+/// interpolate between, and the type of its result.
+pub trait Interpolatable {
+    type Output;
+}
+
+impl<T> Interpolatable for types::Nullable<T>
+where
+    T: Interpolatable + NotNull,
+{
+    type Output = T::Output;
+}
+
+macro_rules! interpolatable_impls {
+    ($($Source:ty => $Output:ty),+,) => {
+        $(
+            impl Interpolatable for $Source {
+                type Output = types::Nullable<$Output>;
+            }
+        )+
+    }
+}
+
+interpolatable_impls! {
+    types::SmallInt => types::Double,
+    types::Integer => types::Double,
+    types::BigInt => types::Double,
+    types::Float => types::Double,
+    types::Double => types::Double,
+    types::Numeric => types::Double,
+    types::Interval => types::Interval,
+}
+
+macro_rules! percentile_function {
+    ($type_name:ident, $sql_name:expr) => {
+        #[derive(Debug, Clone, Copy)]
+        #[doc(hidden)]
+        pub struct $type_name<F, O> {
+            fraction: F,
+            order: O,
+        }
+
+        impl<F> $type_name<F, NoOrderClause> {
+            /// Computes the percentile of the values of `expr`, which are sorted
+            /// with `WITHIN GROUP (ORDER BY expr)`.
+            pub fn within_group_order_by<O: Expression>(
+                self,
+                expr: O,
+            ) -> $type_name<F, OrderClause<O>> {
+                $type_name {
+                    fraction: self.fraction,
+                    order: OrderClause(expr),
+                }
+            }
+        }
+
+        impl<F, O> QueryFragment<Pg> for $type_name<F, OrderClause<O>>
+        where
+            F: QueryFragment<Pg>,
+            O: QueryFragment<Pg>,
+        {
+            fn walk_ast(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+                out.push_sql(concat!($sql_name, "("));
+                self.fraction.walk_ast(out.reborrow())?;
+                out.push_sql(") WITHIN GROUP (ORDER BY ");
+                self.order.0.walk_ast(out.reborrow())?;
+                out.push_sql(")");
+                Ok(())
+            }
+        }
+
+        impl_query_id!($type_name<F, O>);
+
+        impl<F, O, QS> SelectableExpression<QS> for $type_name<F, OrderClause<O>>
+        where
+            F: SelectableExpression<QS>,
+            O: SelectableExpression<QS>,
+            Self: AppearsOnTable<QS>,
+        {
+        }
+
+        impl<F, O, QS> AppearsOnTable<QS> for $type_name<F, OrderClause<O>>
+        where
+            F: AppearsOnTable<QS>,
+            O: AppearsOnTable<QS>,
+            Self: Expression,
+        {
+        }
+
+        impl<F, O, C> SelectsColumn<C> for $type_name<F, O> {
+            type Count = Never;
+        }
+
+        impl<F, O, Selection> ValidOrderTermForDistinct<Selection> for $type_name<F, O> {}
+    }
+}
+
+percentile_function!(PercentileCont, "percentile_cont");
+percentile_function!(PercentileDisc, "percentile_disc");
+
+impl<F, O> Expression for PercentileCont<F, OrderClause<O>>
+where
+    F: Expression<SqlType = Double>,
+    O: Expression,
+    O::SqlType: Interpolatable,
+{
+    type SqlType = <O::SqlType as Interpolatable>::Output;
+}
+
+impl<F, O> Expression for PercentileDisc<F, OrderClause<O>>
+where
+    F: Expression<SqlType = Double>,
+    O: Expression,
+    O::SqlType: IntoNullable,
+{
+    type SqlType = <O::SqlType as IntoNullable>::Nullable;
+}
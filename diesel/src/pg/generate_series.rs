@@ -0,0 +1,295 @@
+//! Support for using `generate_series` as the `FROM` clause of a query.
+use std::marker::PhantomData;
+
+use expression::{AppearsOnTable, AsExpression, Expression, NonAggregate, SelectableExpression};
+use pg::Pg;
+use query_builder::{AsQuery, AstPass, Query, QueryFragment, SelectStatement};
+use query_dsl::{CountDsl, FilterDsl, InternalJoinDsl, OrderDsl, SelectDsl};
+use query_source::{AppearsInFromClause, JoinTo, Never, QuerySource, Table};
+use query_source::joins::{AppendSelection, Join, JoinOn, OnClauseWrapper};
+use result::QueryResult;
+use types::{BigInt, Integer, Interval, Timestamp, Timestamptz};
+
+/// A SQL type which `generate_series` can produce, and the type of the step
+/// between two values.
+pub trait SeriesType {
+    /// The SQL type of the step.
+    type Step;
+}
+
+impl SeriesType for Integer {
+    type Step = Integer;
+}
+
+impl SeriesType for BigInt {
+    type Step = BigInt;
+}
+
+impl SeriesType for Timestamp {
+    type Step = Interval;
+}
+
+impl SeriesType for Timestamptz {
+    type Step = Interval;
+}
+
+/// Generates the values from `start` to `stop`, inclusive, increasing by
+/// `step`, using the PostgreSQL `generate_series` function. The result can be
+/// used as the source of a query, with a single column, `value`.
+///
+/// Series of integers use a step of the same type, and series of timestamps
+/// use an interval as the step.
+///
+/// The series can be joined to a table with an explicit `ON` clause, which is
+/// useful to fill gaps in a report. The bounds cannot reference columns, so
+/// they are usually Rust values, which are sent as bind parameters.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate diesel;
+/// # include!("../doctest_setup.rs");
+/// #
+/// # table! {
+/// #     users {
+/// #         id -> Integer,
+/// #         name -> VarChar,
+/// #     }
+/// # }
+/// #
+/// # fn main() {
+/// #     use diesel::types::Integer;
+/// use diesel::pg::generate_series::generate_series;
+///
+/// #     let connection = establish_connection();
+/// let series = generate_series::<Integer, _, _>(1, 10, 3);
+/// let value = series.value();
+/// let data = series.order(value.desc()).load::<i32>(&connection);
+///
+/// assert_eq!(Ok(vec![10, 7, 4, 1]), data);
+/// # }
+/// ```
+pub fn generate_series<ST, T, S>(
+    start: T,
+    stop: T,
+    step: S,
+) -> GenerateSeries<ST, T::Expression, S::Expression>
+where
+    ST: SeriesType,
+    T: AsExpression<ST>,
+    T::Expression: AppearsOnTable<()>,
+    S: AsExpression<ST::Step>,
+    S::Expression: AppearsOnTable<()>,
+{
+    GenerateSeries {
+        start: start.as_expression(),
+        stop: stop.as_expression(),
+        step: step.as_expression(),
+        _marker: PhantomData,
+    }
+}
+
+/// `generate_series(start, stop, step) AS generate_series(value)`. Constructed
+/// by [`generate_series`](fn.generate_series.html).
+#[derive(Debug)]
+pub struct GenerateSeries<ST, T, S> {
+    start: T,
+    stop: T,
+    step: S,
+    _marker: PhantomData<ST>,
+}
+
+impl<ST, T: Clone, S: Clone> Clone for GenerateSeries<ST, T, S> {
+    fn clone(&self) -> Self {
+        GenerateSeries {
+            start: self.start.clone(),
+            stop: self.stop.clone(),
+            step: self.step.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<ST, T, S> GenerateSeries<ST, T, S> {
+    /// The values of the series.
+    pub fn value(&self) -> Value<ST> {
+        Value(PhantomData)
+    }
+}
+
+impl<ST, T, S> QueryFragment<Pg> for GenerateSeries<ST, T, S>
+where
+    T: QueryFragment<Pg>,
+    S: QueryFragment<Pg>,
+{
+    fn walk_ast(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+        out.push_sql("generate_series(");
+        self.start.walk_ast(out.reborrow())?;
+        out.push_sql(", ");
+        self.stop.walk_ast(out.reborrow())?;
+        out.push_sql(", ");
+        self.step.walk_ast(out.reborrow())?;
+        out.push_sql(") AS generate_series(value)");
+        Ok(())
+    }
+}
+
+impl_query_id!(GenerateSeries<ST, T, S>);
+
+impl<ST, T: Clone, S: Clone> QuerySource for GenerateSeries<ST, T, S> {
+    type FromClause = Self;
+    type DefaultSelection = Value<ST>;
+
+    fn from_clause(&self) -> Self {
+        self.clone()
+    }
+
+    fn default_selection(&self) -> Self::DefaultSelection {
+        self.value()
+    }
+}
+
+impl<ST, T: Clone, S: Clone> AsQuery for GenerateSeries<ST, T, S>
+where
+    SelectStatement<Self>: Query,
+{
+    type SqlType = <SelectStatement<Self> as Query>::SqlType;
+    type Query = SelectStatement<Self>;
+
+    fn as_query(self) -> Self::Query {
+        SelectStatement::simple(self)
+    }
+}
+
+impl<ST, T, S, Selection> SelectDsl<Selection> for GenerateSeries<ST, T, S>
+where
+    Selection: Expression,
+    Self: AsQuery<Query = SelectStatement<Self>>,
+    SelectStatement<Self>: SelectDsl<Selection>,
+{
+    type Output = <SelectStatement<Self> as SelectDsl<Selection>>::Output;
+
+    fn select(self, selection: Selection) -> Self::Output {
+        self.as_query().select(selection)
+    }
+}
+
+impl<ST, T, S> CountDsl for GenerateSeries<ST, T, S>
+where
+    Self: AsQuery<Query = SelectStatement<Self>>,
+    SelectStatement<Self>: CountDsl,
+{
+    type Output = <SelectStatement<Self> as CountDsl>::Output;
+
+    fn count(self) -> Self::Output {
+        self.as_query().count()
+    }
+}
+
+impl<ST, T, S, Predicate> FilterDsl<Predicate> for GenerateSeries<ST, T, S>
+where
+    Self: AsQuery<Query = SelectStatement<Self>>,
+    SelectStatement<Self>: FilterDsl<Predicate, SqlType = Self::SqlType>,
+{
+    type Output = <SelectStatement<Self> as FilterDsl<Predicate>>::Output;
+
+    fn filter(self, predicate: Predicate) -> Self::Output {
+        self.as_query().filter(predicate)
+    }
+}
+
+impl<ST, T, S, Expr> OrderDsl<Expr> for GenerateSeries<ST, T, S>
+where
+    Expr: Expression,
+    Self: AsQuery<Query = SelectStatement<Self>>,
+    SelectStatement<Self>: OrderDsl<Expr, SqlType = Self::SqlType>,
+{
+    type Output = <SelectStatement<Self> as OrderDsl<Expr>>::Output;
+
+    fn order(self, expr: Expr) -> Self::Output {
+        self.as_query().order(expr)
+    }
+}
+
+impl<ST, T, S, Rhs, Kind, On> InternalJoinDsl<Rhs, Kind, On> for GenerateSeries<ST, T, S>
+where
+    Self: AsQuery<Query = SelectStatement<Self>>,
+    SelectStatement<Self>: InternalJoinDsl<Rhs, Kind, On>,
+{
+    type Output = <SelectStatement<Self> as InternalJoinDsl<Rhs, Kind, On>>::Output;
+
+    fn join(self, rhs: Rhs, kind: Kind, on: On) -> Self::Output {
+        self.as_query().join(rhs, kind, on)
+    }
+}
+
+impl<ST, T, S, Rhs, On> JoinTo<OnClauseWrapper<Rhs, On>> for GenerateSeries<ST, T, S> {
+    type FromClause = Rhs;
+    type OnClause = On;
+
+    fn join_target(rhs: OnClauseWrapper<Rhs, On>) -> (Self::FromClause, Self::OnClause) {
+        (rhs.source, rhs.on)
+    }
+}
+
+impl<ST, T, S, Selection> AppendSelection<Selection> for GenerateSeries<ST, T, S> {
+    type Output = (Value<ST>, Selection);
+
+    fn append_selection(&self, selection: Selection) -> Self::Output {
+        (self.value(), selection)
+    }
+}
+
+impl<ST, T, S, Tab: Table> AppearsInFromClause<Tab> for GenerateSeries<ST, T, S> {
+    type Count = Never;
+}
+
+/// The `value` column of [`generate_series`](fn.generate_series.html).
+#[derive(Debug)]
+pub struct Value<ST>(PhantomData<ST>);
+
+impl<ST> Clone for Value<ST> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<ST> Copy for Value<ST> {}
+
+impl<ST> Expression for Value<ST> {
+    type SqlType = ST;
+}
+
+impl<ST> QueryFragment<Pg> for Value<ST> {
+    fn walk_ast(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+        out.push_sql("generate_series.value");
+        Ok(())
+    }
+}
+
+impl_query_id!(Value<ST>);
+
+impl<ST, T, S> SelectableExpression<GenerateSeries<ST, T, S>> for Value<ST> {}
+
+impl_unchecked_distinct_ordering!(Value<ST>);
+
+impl<ST, T, S> AppearsOnTable<GenerateSeries<ST, T, S>> for Value<ST> {}
+
+impl<ST, T, S, Rhs, Kind> SelectableExpression<Join<GenerateSeries<ST, T, S>, Rhs, Kind>>
+    for Value<ST>
+{
+}
+
+impl<ST, T, S, Rhs, Kind> AppearsOnTable<Join<GenerateSeries<ST, T, S>, Rhs, Kind>> for Value<ST> {}
+
+impl<ST, T, S, Rhs, Kind, On>
+    SelectableExpression<JoinOn<Join<GenerateSeries<ST, T, S>, Rhs, Kind>, On>> for Value<ST>
+{
+}
+
+impl<ST, T, S, Rhs, Kind, On> AppearsOnTable<JoinOn<Join<GenerateSeries<ST, T, S>, Rhs, Kind>, On>>
+    for Value<ST>
+{
+}
+
+impl<ST> NonAggregate for Value<ST> {}
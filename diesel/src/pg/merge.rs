@@ -0,0 +1,316 @@
+//! Support for the `MERGE` statement, added in PostgreSQL 15.
+use expression::{AppearsOnTable, Expression, NonAggregate};
+use insertable::{InsertValues, Insertable};
+use pg::Pg;
+use query_builder::{AsChangeset, AstPass, Changeset, QueryFragment};
+use query_source::{AppearsInFromClause, Column, Never, Table};
+use query_source::joins::{Inner, Join};
+use result::Error::QueryBuilderError;
+use result::QueryResult;
+use types::Bool;
+
+/// Creates a `MERGE INTO` statement for the given table. Call
+/// [`using`](struct.IncompleteMerge.html#method.using) and
+/// [`on`](struct.MergeUsing.html#method.on) to specify the rows to merge, and
+/// then add the actions to perform with
+/// [`when_matched_update`](struct.MergeStatement.html#method.when_matched_update)
+/// and
+/// [`when_not_matched_insert`](struct.MergeStatement.html#method.when_not_matched_insert).
+///
+/// The `ON` clause is checked the same way as the `ON` clause of an explicit
+/// join, so the two tables must be joinable, or allowed to appear together
+/// with [`enable_multi_table_joins!`](../../macro.enable_multi_table_joins.html).
+/// Columns of the source table can be used in the actions by wrapping them in
+/// [`source`](fn.source.html).
+///
+/// `MERGE` requires PostgreSQL 15 or later. Older servers will return a syntax
+/// error.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate diesel;
+/// # include!("../doctest_setup.rs");
+/// #
+/// # table! {
+/// #     users {
+/// #         id -> Integer,
+/// #         name -> VarChar,
+/// #     }
+/// # }
+/// #
+/// table! {
+///     new_users {
+///         id -> Integer,
+///         name -> VarChar,
+///     }
+/// }
+///
+/// enable_multi_table_joins!(users, new_users);
+///
+/// # fn main() {
+/// #     use self::users::dsl::*;
+/// use diesel::pg::merge::{merge_into, source};
+///
+/// #     let connection = establish_connection();
+/// #     connection.execute("CREATE TEMPORARY TABLE new_users (id INTEGER PRIMARY KEY, name VARCHAR NOT NULL)").unwrap();
+/// #     connection.execute("INSERT INTO new_users (id, name) VALUES (2, 'Tessa'), (3, 'Jim')").unwrap();
+/// let merged_rows = merge_into(users)
+///     .using(new_users::table)
+///     .on(id.eq(new_users::id))
+///     .when_matched_update(name.eq(source(new_users::name)))
+///     .when_not_matched_insert(&name.eq("Someone new"))
+///     .execute(&connection);
+/// assert_eq!(Ok(2), merged_rows);
+///
+/// let data = users.select(name).order(id).load::<String>(&connection);
+/// let expected = vec!["Sean", "Tessa", "Someone new"];
+/// assert_eq!(Ok(expected.into_iter().map(String::from).collect()), data);
+/// # }
+/// ```
+pub fn merge_into<T: Table>(target: T) -> IncompleteMerge<T> {
+    IncompleteMerge { target: target }
+}
+
+/// The type returned by [`merge_into`](fn.merge_into.html). The only thing
+/// you can do with this type is call `using` on it.
+#[derive(Debug, Clone, Copy)]
+pub struct IncompleteMerge<T> {
+    target: T,
+}
+
+impl<T> IncompleteMerge<T> {
+    /// Specifies the table whose rows are merged into the target table.
+    pub fn using<S: Table>(self, source: S) -> MergeUsing<T, S> {
+        MergeUsing {
+            target: self.target,
+            source: source,
+        }
+    }
+}
+
+/// The type returned by
+/// [`IncompleteMerge::using`](struct.IncompleteMerge.html#method.using). The
+/// only thing you can do with this type is call `on` on it.
+#[derive(Debug, Clone, Copy)]
+pub struct MergeUsing<T, S> {
+    target: T,
+    source: S,
+}
+
+impl<T, S> MergeUsing<T, S> {
+    /// Specifies the condition which matches a row of the source table to a
+    /// row of the target table.
+    pub fn on<On>(self, on: On) -> MergeStatement<T, S, On>
+    where
+        On: Expression<SqlType = Bool> + AppearsOnTable<Join<T, S, Inner>>,
+    {
+        MergeStatement {
+            target: self.target,
+            source: self.source,
+            on: on,
+            when_matched: NoMergeAction,
+            when_not_matched: NoMergeAction,
+        }
+    }
+}
+
+/// A `MERGE` statement. At least one of `when_matched_update` or
+/// `when_not_matched_insert` must be called before the statement can be
+/// executed.
+#[derive(Debug, Clone, Copy)]
+pub struct MergeStatement<T, S, On, Matched = NoMergeAction, NotMatched = NoMergeAction> {
+    target: T,
+    source: S,
+    on: On,
+    when_matched: Matched,
+    when_not_matched: NotMatched,
+}
+
+impl<T, S, On, NotMatched> MergeStatement<T, S, On, NoMergeAction, NotMatched> {
+    /// Adds `WHEN MATCHED THEN UPDATE SET ...`, which updates the target row
+    /// when a source row matches it.
+    pub fn when_matched_update<V>(
+        self,
+        changeset: V,
+    ) -> MergeStatement<T, S, On, WhenMatchedUpdate<V::Changeset>, NotMatched>
+    where
+        T: Table,
+        V: AsChangeset<Target = T>,
+    {
+        MergeStatement {
+            target: self.target,
+            source: self.source,
+            on: self.on,
+            when_matched: WhenMatchedUpdate(changeset.as_changeset()),
+            when_not_matched: self.when_not_matched,
+        }
+    }
+}
+
+impl<T, S, On, Matched> MergeStatement<T, S, On, Matched, NoMergeAction> {
+    /// Adds `WHEN NOT MATCHED THEN INSERT ...`, which inserts a row into the
+    /// target table for every source row which does not match any row of it.
+    ///
+    /// Only a single row can be given. Inserting a slice or a `Vec` will fail
+    /// when the query is run.
+    pub fn when_not_matched_insert<V>(
+        self,
+        records: V,
+    ) -> MergeStatement<T, S, On, Matched, WhenNotMatchedInsert<V>>
+    where
+        T: Table,
+        V: Insertable<T, Pg>,
+    {
+        MergeStatement {
+            target: self.target,
+            source: self.source,
+            on: self.on,
+            when_matched: self.when_matched,
+            when_not_matched: WhenNotMatchedInsert(records),
+        }
+    }
+}
+
+impl<T, S, On, Matched, NotMatched> QueryFragment<Pg> for MergeStatement<T, S, On, Matched, NotMatched>
+where
+    T: Table,
+    T::FromClause: QueryFragment<Pg>,
+    S: Table,
+    S::FromClause: QueryFragment<Pg>,
+    On: QueryFragment<Pg>,
+    Matched: MergeAction<T>,
+    NotMatched: MergeAction<T>,
+{
+    fn walk_ast(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+        if self.when_matched.is_noop() && self.when_not_matched.is_noop() {
+            return Err(QueryBuilderError(
+                "A MERGE statement needs at least one action. This query cannot be built".into(),
+            ));
+        }
+
+        out.unsafe_to_cache_prepared();
+        out.push_sql("MERGE INTO ");
+        self.target.from_clause().walk_ast(out.reborrow())?;
+        out.push_sql(" USING ");
+        self.source.from_clause().walk_ast(out.reborrow())?;
+        out.push_sql(" ON ");
+        self.on.walk_ast(out.reborrow())?;
+        self.when_matched.walk_ast(out.reborrow())?;
+        self.when_not_matched.walk_ast(out.reborrow())?;
+        Ok(())
+    }
+}
+
+impl_query_id!(noop: MergeStatement<T, S, On, Matched, NotMatched>);
+
+#[doc(hidden)]
+pub trait MergeAction<T> {
+    fn is_noop(&self) -> bool;
+
+    fn walk_ast(&self, out: AstPass<Pg>) -> QueryResult<()>;
+}
+
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct NoMergeAction;
+
+impl<T> MergeAction<T> for NoMergeAction {
+    fn is_noop(&self) -> bool {
+        true
+    }
+
+    fn walk_ast(&self, _: AstPass<Pg>) -> QueryResult<()> {
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct WhenMatchedUpdate<V>(V);
+
+impl<T, V: Changeset<Pg>> MergeAction<T> for WhenMatchedUpdate<V> {
+    fn is_noop(&self) -> bool {
+        self.0.is_noop()
+    }
+
+    fn walk_ast(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+        if !self.0.is_noop() {
+            out.push_sql(" WHEN MATCHED THEN UPDATE SET ");
+            self.0.walk_ast(out.reborrow())?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct WhenNotMatchedInsert<V>(V);
+
+impl<T, V> MergeAction<T> for WhenNotMatchedInsert<V>
+where
+    T: Table,
+    V: Insertable<T, Pg> + Copy,
+{
+    fn is_noop(&self) -> bool {
+        false
+    }
+
+    fn walk_ast(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+        let values = self.0.values();
+        out.push_sql(" WHEN NOT MATCHED THEN INSERT");
+        if values.is_noop() {
+            out.push_sql(" DEFAULT VALUES");
+        } else {
+            out.push_sql(" (");
+            if let Some(builder) = out.reborrow().query_builder() {
+                values.column_names(builder)?;
+            }
+            out.push_sql(") VALUES ");
+            values.walk_ast(out.reborrow())?;
+        }
+        Ok(())
+    }
+}
+
+/// References a column of the source table of a `MERGE` statement, so it can
+/// be used in the values of
+/// [`when_matched_update`](struct.MergeStatement.html#method.when_matched_update)
+/// and
+/// [`when_not_matched_insert`](struct.MergeStatement.html#method.when_not_matched_insert).
+///
+/// The result can only be used with a target table which can be merged with
+/// the column's table, which is the same check as the `ON` clause of
+/// [`merge_into`](fn.merge_into.html), and in the values of an insert. It is
+/// only meaningful inside of a `MERGE` statement.
+pub fn source<C: Column>(column: C) -> Source<C> {
+    Source(column)
+}
+
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct Source<C>(C);
+
+impl<C: Expression> Expression for Source<C> {
+    type SqlType = C::SqlType;
+}
+
+impl<C: QueryFragment<Pg>> QueryFragment<Pg> for Source<C> {
+    fn walk_ast(&self, out: AstPass<Pg>) -> QueryResult<()> {
+        self.0.walk_ast(out)
+    }
+}
+
+impl_query_id!(Source<C>);
+
+impl<C, T> AppearsOnTable<T> for Source<C>
+where
+    C: Column,
+    T: Table + AppearsInFromClause<C::Table, Count = Never>,
+{
+}
+
+// Insert values are checked against `()`
+impl<C: Column> AppearsOnTable<()> for Source<C> {}
+
+impl<C: Column> NonAggregate for Source<C> {}
@@ -3,6 +3,34 @@ use std::mem;
 use prelude::*;
 use super::{PgConnection, PgTypeMetadata};
 
+/// Looks up the OIDs of types which are not built into PostgreSQL, such as
+/// enums, or types added by extensions like `citext`. The OIDs of these types
+/// differ between databases, so they cannot be hard coded.
+///
+/// This is passed to `HasSqlType::metadata`. A custom SQL type can use it to
+/// find its OID by name:
+///
+/// ```rust
+/// # extern crate diesel;
+/// use diesel::pg::{Pg, PgMetadataLookup, PgTypeMetadata};
+/// use diesel::types::HasSqlType;
+///
+/// pub struct Citext;
+///
+/// impl HasSqlType<Citext> for Pg {
+///     fn metadata(lookup: &PgMetadataLookup) -> PgTypeMetadata {
+///         lookup.lookup_type("citext")
+///     }
+/// }
+/// # fn main() {}
+/// ```
+///
+/// Implementations of `FromSql` and `ToSql` for the type then handle its
+/// binary representation. The OID of arrays of the type is looked up as well,
+/// so `Array<Citext>` can be used once the type implements `QueryId`. Its
+/// elements are sent and received with the `ToSql` and `FromSql`
+/// implementations of the type. The values of an enum are represented by
+/// the text of their label.
 #[allow(missing_debug_implementations)]
 pub struct PgMetadataLookup {
     conn: PgConnection,
@@ -13,6 +41,8 @@ impl PgMetadataLookup {
         unsafe { mem::transmute(conn) }
     }
 
+    /// Returns the OIDs of the type, and of arrays of the type, with the
+    /// given name. Zero is returned for both OIDs if the type does not exist.
     pub fn lookup_type(&self, type_name: &str) -> PgTypeMetadata {
         use self::pg_type::dsl::*;
 
@@ -4,11 +4,21 @@ mod backend;
 mod metadata_lookup;
 mod query_builder;
 mod connection;
+mod explain;
+pub mod generate_series;
+pub mod merge;
 pub mod types;
+pub mod unnest;
 pub mod upsert;
 
-pub use self::backend::{Pg, PgTypeMetadata};
-pub use self::connection::PgConnection;
+pub use self::backend::{Pg, PgTypeMetadata, PgTypeName};
+/// The libpq bindings used by `PgConnection`. See
+/// [`PgConnection::raw_connection`](struct.PgConnection.html#method.raw_connection).
+pub use self::connection::raw::pq_sys;
+pub use self::connection::{AdvisoryLockGuard, AdvisoryLockKey, CancelToken, ConnectOptions,
+                           CopyFormat, CopyOut, Notice, Notification, Notifications,
+                           PgConnection, PreparedQuery};
+pub use self::explain::ExplainDsl;
 pub use self::metadata_lookup::PgMetadataLookup;
 pub use self::query_builder::PgQueryBuilder;
 
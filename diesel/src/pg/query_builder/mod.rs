@@ -1,5 +1,7 @@
+use std::collections::HashMap;
+
 use super::backend::Pg;
-use query_builder::QueryBuilder;
+use query_builder::{check_identifier, escape_identifier, QueryBuilder, SqlBuffer};
 use result::QueryResult;
 
 mod query_fragment_impls;
@@ -7,35 +9,199 @@ mod query_fragment_impls;
 #[allow(missing_debug_implementations)]
 #[derive(Default)]
 pub struct PgQueryBuilder {
-    sql: String,
+    sql: SqlBuffer,
     bind_idx: u32,
+    inlined_binds: Option<Vec<String>>,
+    named_binds: HashMap<String, u32>,
 }
 
 impl PgQueryBuilder {
     pub fn new() -> Self {
         PgQueryBuilder::default()
     }
+
+    /// Creates a query builder for a command which can't take bind
+    /// parameters, such as `COPY`. The literal of each bind is written in
+    /// place of its placeholder, so `literals` must hold the quoted value of
+    /// each bind of the query, in the order in which they were collected.
+    pub(crate) fn with_inlined_binds(literals: Vec<String>) -> Self {
+        PgQueryBuilder {
+            inlined_binds: Some(literals),
+            ..PgQueryBuilder::default()
+        }
+    }
+
+    fn push_placeholder(&mut self, bind_idx: u32) {
+        let literal = self.inlined_binds
+            .as_ref()
+            .and_then(|literals| literals.get(bind_idx as usize - 1))
+            .cloned();
+        let sql = literal.unwrap_or_else(|| format!("${}", bind_idx));
+        self.push_sql(&sql);
+    }
 }
 
 impl QueryBuilder<Pg> for PgQueryBuilder {
+    const CAN_REPEAT_BIND_PARAMS: bool = true;
+
     fn push_sql(&mut self, sql: &str) {
-        self.sql.push_str(sql);
+        self.sql.push_fragment(sql);
+    }
+
+    fn push_sql_verbatim(&mut self, sql: &str) {
+        self.sql.push_verbatim(sql);
     }
 
     fn push_identifier(&mut self, identifier: &str) -> QueryResult<()> {
+        check_identifier(identifier)?;
         self.push_sql("\"");
-        self.push_sql(&identifier.replace('"', "\"\""));
+        self.push_sql(&escape_identifier(identifier, '"'));
         self.push_sql("\"");
         Ok(())
     }
 
     fn push_bind_param(&mut self) {
         self.bind_idx += 1;
-        let sql = format!("${}", self.bind_idx);
-        self.push_sql(&sql);
+        let bind_idx = self.bind_idx;
+        self.push_placeholder(bind_idx);
+    }
+
+    fn push_bind_param_with_name(&mut self, name: &str) {
+        self.push_bind_param();
+        self.named_binds.insert(name.to_owned(), self.bind_idx);
+    }
+
+    fn push_repeated_bind_param(&mut self, name: &str) {
+        match self.named_binds.get(name).cloned() {
+            Some(bind_idx) => self.push_placeholder(bind_idx),
+            None => self.push_bind_param_with_name(name),
+        }
     }
 
     fn finish(self) -> String {
-        self.sql
+        self.sql.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pg::Pg;
+    use query_builder::{debug_query, AstPass, QueryBuilder, QueryFragment};
+    use result::QueryResult;
+    use types::Integer;
+    use super::PgQueryBuilder;
+
+    #[derive(Default)]
+    struct NamedPlaceholderQueryBuilder {
+        sql: String,
+        bind_idx: u32,
+    }
+
+    impl QueryBuilder<Pg> for NamedPlaceholderQueryBuilder {
+        fn push_sql(&mut self, sql: &str) {
+            self.sql.push_str(sql);
+        }
+
+        fn push_identifier(&mut self, identifier: &str) -> QueryResult<()> {
+            self.push_sql(identifier);
+            Ok(())
+        }
+
+        fn push_bind_param(&mut self) {
+            self.bind_idx += 1;
+            let sql = format!("@p{}", self.bind_idx);
+            self.push_sql(&sql);
+        }
+
+        fn push_bind_param_with_name(&mut self, name: &str) {
+            self.bind_idx += 1;
+            let sql = format!(":{}", name);
+            self.push_sql(&sql);
+        }
+
+        fn finish(self) -> String {
+            self.sql
+        }
+    }
+
+    fn build_with_named_binds<T: QueryBuilder<Pg>>(mut builder: T) -> String {
+        builder.push_sql("SELECT * FROM users WHERE name = ");
+        builder.push_bind_param_with_name("name");
+        builder.push_sql(" AND id > ");
+        builder.push_bind_param();
+        builder.finish()
+    }
+
+    #[test]
+    fn custom_query_builders_can_render_named_placeholders() {
+        let builder = NamedPlaceholderQueryBuilder::default();
+        assert_eq!(
+            "SELECT * FROM users WHERE name = :name AND id > @p2",
+            build_with_named_binds(builder)
+        );
+    }
+
+    #[test]
+    fn pg_query_builder_ignores_the_names_of_bind_params() {
+        let builder = PgQueryBuilder::new();
+        assert_eq!(
+            "SELECT * FROM users WHERE name = $1 AND id > $2",
+            build_with_named_binds(builder)
+        );
+    }
+
+    #[test]
+    fn repeated_bind_params_refer_to_the_first_placeholder_with_their_name() {
+        let mut builder = PgQueryBuilder::new();
+        builder.push_sql("SELECT * FROM users WHERE name = ");
+        builder.push_bind_param_with_name("name");
+        builder.push_sql(" OR (id > ");
+        builder.push_bind_param();
+        builder.push_sql(" AND name <> ");
+        builder.push_repeated_bind_param("name");
+        builder.push_sql(")");
+        assert_eq!(
+            "SELECT * FROM users WHERE name = $1 OR (id > $2 AND name <> $1)",
+            builder.finish()
+        );
+    }
+
+    #[test]
+    fn repeated_bind_params_are_inlined_with_the_literal_of_their_name() {
+        let literals = vec!["'Tess'".to_string(), "0".to_string()];
+        let mut builder = PgQueryBuilder::with_inlined_binds(literals);
+        builder.push_sql("SELECT * FROM users WHERE name = ");
+        builder.push_bind_param_with_name("name");
+        builder.push_sql(" OR (id > ");
+        builder.push_bind_param();
+        builder.push_sql(" AND name <> ");
+        builder.push_repeated_bind_param("name");
+        builder.push_sql(")");
+        assert_eq!(
+            "SELECT * FROM users WHERE name = 'Tess' OR (id > 0 AND name <> 'Tess')",
+            builder.finish()
+        );
+    }
+
+    struct UserById(i32);
+
+    impl QueryFragment<Pg> for UserById {
+        fn walk_ast(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+            out.push_sql("SELECT * FROM users WHERE id = ");
+            out.push_named_bind_param::<Integer, _>("id", &self.0)
+        }
+    }
+
+    #[test]
+    fn named_bind_params_of_a_query_are_written_and_bound_through_ast_pass() {
+        let query = UserById(1);
+        let mut builder = PgQueryBuilder::new();
+        query.to_sql(&mut builder).unwrap();
+
+        assert_eq!("SELECT * FROM users WHERE id = $1", builder.finish());
+        assert_eq!(
+            "SELECT * FROM users WHERE id = $1 -- binds: [1]",
+            debug_query::<Pg, _>(&query).to_string()
+        );
     }
 }
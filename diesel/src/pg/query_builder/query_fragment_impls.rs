@@ -1,11 +1,290 @@
-use pg::Pg;
-use query_builder::{AstPass, QueryFragment};
+use std::fmt::Debug;
+
+use expression::Expression;
+use expression::bound::TypedBound;
+use expression::functions::aggregate_boolean::{BoolAnd, BoolOr};
+use expression::functions::date_and_time::{DatePart, Extract, ExtractFrom};
+use expression::functions::string_agg::StringAgg;
+use expression::icontains::IContains;
+use expression::null::Null;
+use expression::ops::BitXor;
+use expression::order_by_values::OrderByValues;
+use expression::operators::{Asc, Desc};
+use pg::{Pg, PgTypeName};
+use pg::expression::operators::{NullsFirst, NullsLast};
+use query_builder::{AliasReference, AstPass, QueryFragment, ValidDistinctOrdering,
+                    ValidOrderingForDistinct};
+use query_builder::distinct_clause::DistinctClause;
+use query_builder::group_by_clause::HavingClause;
+use query_builder::insert_statement::DefaultValuesClause;
+use query_builder::order_clause::NullsLastOrder;
 use query_builder::for_update_clause::ForUpdateClause;
+use query_builder::truncate_statement::*;
+use query_builder::with_clause::{Materialized, NotMaterialized};
+use query_source::{Column, Table};
 use result::QueryResult;
+use types::{Array, HasSqlType, Text, ToSql, VarChar};
 
-impl QueryFragment<Pg> for ForUpdateClause {
+impl<Of, Modifier> QueryFragment<Pg> for ForUpdateClause<Of, Modifier>
+where
+    Of: QueryFragment<Pg>,
+    Modifier: QueryFragment<Pg>,
+{
     fn walk_ast(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
         out.push_sql(" FOR UPDATE");
+        self.of.walk_ast(out.reborrow())?;
+        self.modifier.walk_ast(out.reborrow())?;
+        Ok(())
+    }
+}
+
+impl<T, U> QueryFragment<Pg> for TypedBound<T, U>
+where
+    Pg: HasSqlType<T>,
+    T: PgTypeName,
+    U: ToSql<T, Pg>,
+{
+    fn walk_ast(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+        self.bound.walk_ast(out.reborrow())?;
+        out.push_sql("::");
+        out.push_sql(T::TYPE_NAME);
+        Ok(())
+    }
+}
+
+impl<T, Identity, Cascade> QueryFragment<Pg> for TruncateStatement<T, Identity, Cascade>
+where
+    T: Table,
+    T::FromClause: QueryFragment<Pg>,
+    Identity: QueryFragment<Pg>,
+    Cascade: QueryFragment<Pg>,
+{
+    fn walk_ast(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+        out.push_sql("TRUNCATE TABLE ");
+        self.table.from_clause().walk_ast(out.reborrow())?;
+        self.restart_identity.walk_ast(out.reborrow())?;
+        self.cascade.walk_ast(out.reborrow())?;
+        Ok(())
+    }
+}
+
+impl QueryFragment<Pg> for RestartIdentityClause {
+    fn walk_ast(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+        out.push_sql(" RESTART IDENTITY");
+        Ok(())
+    }
+}
+
+impl QueryFragment<Pg> for CascadeClause {
+    fn walk_ast(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+        out.push_sql(" CASCADE");
+        Ok(())
+    }
+}
+
+impl<ST: PgTypeName> QueryFragment<Pg> for Null<ST> {
+    fn walk_ast(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+        out.push_sql("NULL::");
+        out.push_sql(ST::TYPE_NAME);
+        Ok(())
+    }
+}
+
+impl<'a, T, O> QueryFragment<Pg> for StringAgg<'a, T, O>
+where
+    T: QueryFragment<Pg>,
+    O: QueryFragment<Pg>,
+{
+    fn walk_ast(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+        out.push_sql("string_agg(");
+        self.expr.walk_ast(out.reborrow())?;
+        out.push_sql(", ");
+        out.push_bind_param::<Text, _>(&self.delimiter)?;
+        self.order.walk_ast(out.reborrow())?;
+        out.push_sql(")");
+        Ok(())
+    }
+}
+
+/// PostgreSQL does not allow aliases from the select clause in `HAVING`, so
+/// aliased expressions are written again in full.
+impl<GroupBy, Predicate> QueryFragment<Pg> for HavingClause<GroupBy, Predicate>
+where
+    GroupBy: QueryFragment<Pg>,
+    Predicate: QueryFragment<Pg>,
+{
+    fn walk_ast(&self, out: AstPass<Pg>) -> QueryResult<()> {
+        self.walk_with_aliases(out, AliasReference::Expression)
+    }
+}
+
+impl QueryFragment<Pg> for Materialized {
+    fn walk_ast(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+        out.push_sql("MATERIALIZED ");
+        Ok(())
+    }
+}
+
+impl QueryFragment<Pg> for NotMaterialized {
+    fn walk_ast(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+        out.push_sql("NOT MATERIALIZED ");
+        Ok(())
+    }
+}
+
+impl<T> QueryFragment<Pg> for BoolAnd<T>
+where
+    T: QueryFragment<Pg>,
+{
+    fn walk_ast(&self, out: AstPass<Pg>) -> QueryResult<()> {
+        self.walk_with_function(out, "BOOL_AND")
+    }
+}
+
+impl<T> QueryFragment<Pg> for BoolOr<T>
+where
+    T: QueryFragment<Pg>,
+{
+    fn walk_ast(&self, out: AstPass<Pg>) -> QueryResult<()> {
+        self.walk_with_function(out, "BOOL_OR")
+    }
+}
+
+impl<T> QueryFragment<Pg> for IContains<T>
+where
+    T: QueryFragment<Pg>,
+{
+    fn walk_ast(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+        self.expr.walk_ast(out.reborrow())?;
+        out.push_sql(" ILIKE ");
+        out.push_bind_param::<VarChar, _>(&self.pattern)?;
+        out.push_sql(" ESCAPE ");
+        out.push_bind_param::<VarChar, _>(&"\\")
+    }
+}
+
+impl QueryFragment<Pg> for DefaultValuesClause {
+    fn walk_ast(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+        out.push_sql(" DEFAULT VALUES");
         Ok(())
     }
 }
+
+/// The values are bound as a single array, so the query is the same regardless
+/// of the number of values.
+impl<T, U> QueryFragment<Pg> for OrderByValues<T, U>
+where
+    Pg: HasSqlType<T::SqlType>,
+    T: Expression + QueryFragment<Pg>,
+    U: ToSql<T::SqlType, Pg> + Debug,
+{
+    fn walk_ast(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+        out.push_sql("array_position(");
+        out.push_bind_param::<Array<T::SqlType>, _>(&self.values)?;
+        out.push_sql(", ");
+        self.column.walk_ast(out.reborrow())?;
+        out.push_sql(")");
+        Ok(())
+    }
+}
+
+/// `EXTRACT` returns `numeric` since PostgreSQL 14, so the equivalent
+/// `date_part` is used, which returns `double precision` on every version.
+impl<T> QueryFragment<Pg> for Extract<T>
+where
+    T: Expression + QueryFragment<Pg>,
+    T::SqlType: ExtractFrom,
+{
+    fn walk_ast(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+        self.check_part()?;
+        let part = match self.part {
+            DatePart::Year => "year",
+            DatePart::Month => "month",
+            DatePart::Day => "day",
+            DatePart::Hour => "hour",
+            DatePart::Minute => "minute",
+            DatePart::Second => "second",
+            DatePart::DayOfWeek => "dow",
+            DatePart::DayOfYear => "doy",
+            DatePart::Epoch => "epoch",
+        };
+        out.push_sql(&format!("date_part('{}', ", part));
+        self.expr.walk_ast(out.reborrow())?;
+        out.push_sql(")");
+        Ok(())
+    }
+}
+
+/// PostgreSQL uses `#` for exclusive or, as `^` is exponentiation.
+impl<Lhs, Rhs> QueryFragment<Pg> for BitXor<Lhs, Rhs>
+where
+    Lhs: QueryFragment<Pg>,
+    Rhs: QueryFragment<Pg>,
+{
+    fn walk_ast(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+        out.push_sql("(");
+        self.lhs.walk_ast(out.reborrow())?;
+        out.push_sql(" # ");
+        self.rhs.walk_ast(out.reborrow())?;
+        out.push_sql(")");
+        Ok(())
+    }
+}
+
+impl<T> NullsLastOrder<Pg> for T
+where
+    T: Column + QueryFragment<Pg>,
+{
+    fn walk_nulls_last(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+        self.walk_ast(out.reborrow())?;
+        out.push_sql(" NULLS LAST");
+        Ok(())
+    }
+}
+
+impl<T> NullsLastOrder<Pg> for Asc<T>
+where
+    Asc<T>: QueryFragment<Pg>,
+{
+    fn walk_nulls_last(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+        self.walk_ast(out.reborrow())?;
+        out.push_sql(" NULLS LAST");
+        Ok(())
+    }
+}
+
+impl<T> NullsLastOrder<Pg> for Desc<T>
+where
+    Desc<T>: QueryFragment<Pg>,
+{
+    fn walk_nulls_last(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+        self.walk_ast(out.reborrow())?;
+        out.push_sql(" NULLS LAST");
+        Ok(())
+    }
+}
+
+/// Terms with an explicit `NULLS FIRST` or `NULLS LAST` are written unchanged.
+impl<T> NullsLastOrder<Pg> for NullsFirst<T>
+where
+    NullsFirst<T>: QueryFragment<Pg>,
+{
+    fn walk_nulls_last(&self, out: AstPass<Pg>) -> QueryResult<()> {
+        self.walk_ast(out)
+    }
+}
+
+impl<T> NullsLastOrder<Pg> for NullsLast<T>
+where
+    NullsLast<T>: QueryFragment<Pg>,
+{
+    fn walk_nulls_last(&self, out: AstPass<Pg>) -> QueryResult<()> {
+        self.walk_ast(out)
+    }
+}
+
+impl<O, Selection> ValidDistinctOrdering<Pg, O, Selection> for DistinctClause
+where
+    O: ValidOrderingForDistinct<Selection>,
+{
+}
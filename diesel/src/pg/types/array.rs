@@ -75,9 +75,11 @@ where
     }
 }
 
+// The elements are read with `FromSql`, so custom types such as enums do not
+// need to implement `Queryable` to be loaded in an array.
 impl<T, ST> Queryable<Array<ST>, Pg> for Vec<T>
 where
-    T: FromSql<ST, Pg> + Queryable<ST, Pg>,
+    T: FromSql<ST, Pg>,
     Pg: HasSqlType<ST>,
 {
     type Row = Self;
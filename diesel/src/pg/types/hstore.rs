@@ -0,0 +1,121 @@
+//! Support for `hstore` values under PostgreSQL.
+
+use byteorder::{NetworkEndian, ReadBytesExt, WriteBytesExt};
+use std::collections::HashMap;
+use std::error::Error;
+use std::io::Write;
+use std::str;
+
+use pg::{Pg, PgMetadataLookup, PgTypeMetadata};
+use types::{self, FromSql, HasSqlType, Hstore, IsNull, ToSql, ToSqlOutput};
+
+primitive_impls!(Hstore -> HashMap<String, Option<String>>);
+
+// `hstore` is added by an extension, so its OID differs between databases.
+impl HasSqlType<Hstore> for Pg {
+    fn metadata(lookup: &PgMetadataLookup) -> PgTypeMetadata {
+        lookup.lookup_type("hstore")
+    }
+}
+
+/// Reads a string prefixed with its size. The size is -1 for `NULL`.
+fn read_string(bytes: &mut &[u8]) -> Result<Option<String>, Box<Error + Send + Sync>> {
+    let num_bytes = bytes.read_i32::<NetworkEndian>()?;
+    if num_bytes < 0 {
+        return Ok(None);
+    }
+
+    let num_bytes = num_bytes as usize;
+    if num_bytes > bytes.len() {
+        return Err("Received a hstore entry which is longer than the hstore".into());
+    }
+    let (string_bytes, rest) = bytes.split_at(num_bytes);
+    *bytes = rest;
+    Ok(Some(str::from_utf8(string_bytes)?.to_owned()))
+}
+
+impl FromSql<types::Hstore, Pg> for HashMap<String, Option<String>> {
+    fn from_sql(bytes: Option<&[u8]>) -> Result<Self, Box<Error + Send + Sync>> {
+        let mut bytes = not_none!(bytes);
+        let num_entries = bytes.read_i32::<NetworkEndian>()?;
+        if num_entries < 0 {
+            return Err("Received a hstore with a negative number of entries".into());
+        }
+        // Each entry takes at least 8 bytes for the sizes of its key and value,
+        // so a corrupt count can't make us allocate more than the hstore needs
+        let max_entries = bytes.len() / 8;
+        let mut result = HashMap::with_capacity(max_entries.min(num_entries as usize));
+        for _ in 0..num_entries {
+            let key = read_string(&mut bytes)?.ok_or("Received a hstore key which is NULL")?;
+            let value = read_string(&mut bytes)?;
+            result.insert(key, value);
+        }
+        Ok(result)
+    }
+}
+
+impl ToSql<types::Hstore, Pg> for HashMap<String, Option<String>> {
+    fn to_sql<W: Write>(
+        &self,
+        out: &mut ToSqlOutput<W, Pg>,
+    ) -> Result<IsNull, Box<Error + Send + Sync>> {
+        out.write_i32::<NetworkEndian>(self.len() as i32)?;
+        for (key, value) in self {
+            out.write_i32::<NetworkEndian>(key.len() as i32)?;
+            out.write_all(key.as_bytes())?;
+            match *value {
+                Some(ref value) => {
+                    out.write_i32::<NetworkEndian>(value.len() as i32)?;
+                    out.write_all(value.as_bytes())?;
+                }
+                None => out.write_i32::<NetworkEndian>(-1)?,
+            }
+        }
+        Ok(IsNull::No)
+    }
+}
+
+#[test]
+fn hstore_to_sql_writes_null_values_with_a_negative_size() {
+    let mut bytes = ToSqlOutput::test();
+    let mut hstore = HashMap::new();
+    hstore.insert("a".to_string(), None);
+    ToSql::<types::Hstore, Pg>::to_sql(&hstore, &mut bytes).unwrap();
+    assert_eq!(bytes, b"\0\0\0\x01\0\0\0\x01a\xff\xff\xff\xff");
+}
+
+#[test]
+fn hstore_from_sql() {
+    let input = b"\0\0\0\x02\0\0\0\x01a\0\0\0\x011\0\0\0\x01b\xff\xff\xff\xff";
+    let hstore: HashMap<String, Option<String>> =
+        FromSql::<types::Hstore, Pg>::from_sql(Some(input)).unwrap();
+
+    let mut expected = HashMap::new();
+    expected.insert("a".to_string(), Some("1".to_string()));
+    expected.insert("b".to_string(), None);
+    assert_eq!(expected, hstore);
+}
+
+#[test]
+fn truncated_hstore_from_sql() {
+    let input = b"\0\0\0\x01\0\0\0\x05a";
+    let hstore: Result<HashMap<String, Option<String>>, _> =
+        FromSql::<types::Hstore, Pg>::from_sql(Some(input));
+    assert!(hstore.is_err());
+}
+
+#[test]
+fn hstore_with_a_negative_number_of_entries_from_sql() {
+    let input = b"\xff\xff\xff\xff";
+    let hstore: Result<HashMap<String, Option<String>>, _> =
+        FromSql::<types::Hstore, Pg>::from_sql(Some(input));
+    assert!(hstore.is_err());
+}
+
+#[test]
+fn hstore_with_more_entries_than_bytes_from_sql() {
+    let input = b"\x7f\xff\xff\xff\0\0\0\x01a\0\0\0\x011";
+    let hstore: Result<HashMap<String, Option<String>>, _> =
+        FromSql::<types::Hstore, Pg>::from_sql(Some(input));
+    assert!(hstore.is_err());
+}
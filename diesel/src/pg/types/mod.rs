@@ -1,5 +1,7 @@
 mod array;
+mod hstore;
 mod ranges;
+mod record;
 pub mod date_and_time;
 pub mod floats;
 #[cfg(feature = "network-address")]
@@ -12,6 +14,7 @@ mod uuid;
 #[cfg(feature = "serde_json")]
 mod json;
 pub mod money;
+mod type_names;
 
 /// PostgreSQL specific SQL types
 ///
@@ -98,6 +101,65 @@ pub mod sql_types {
     #[doc(hidden)]
     pub type Tstzrange = Range<::types::Timestamptz>;
 
+    /// The `record` SQL type, which is the type of row constructors such as
+    /// `ROW('Main Street', 12)`, and of composite types created with
+    /// `CREATE TYPE ... AS`. `ST` is a tuple of the SQL types of the fields,
+    /// in the order they were declared. A column of a composite type can be
+    /// declared as `Record<(Text, Integer)>` in `table!`.
+    ///
+    /// Records are loaded into tuples, or into any type which implements
+    /// `Queryable` for the SQL types of the fields, such as a struct deriving
+    /// `Queryable`. The SQL types of the fields must match the types declared
+    /// in the database exactly, as PostgreSQL checks them when sending and
+    /// receiving records.
+    ///
+    /// When a tuple is sent as a bind parameter, PostgreSQL infers the
+    /// composite type from where the value is used, such as the column it is
+    /// inserted into.
+    ///
+    /// ### [`ToSql`](/diesel/types/trait.ToSql.html) impls
+    ///
+    /// - Tuples of up to 8 elements, where each element implements `ToSql`
+    ///   for the SQL type of the field
+    ///
+    /// ### [`FromSql`](/diesel/types/trait.FromSql.html) impls
+    ///
+    /// - Tuples of up to 8 elements, where each element implements `FromSql`
+    ///   for the SQL type of the field
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate diesel_codegen;
+    /// # #[macro_use] extern crate diesel;
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # table! {
+    /// #     users {
+    /// #         id -> Integer,
+    /// #         name -> VarChar,
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     let connection = establish_connection();
+    /// use diesel::dsl::sql;
+    /// use diesel::types::{Integer, Record, Text};
+    ///
+    /// #[derive(Debug, PartialEq, Queryable)]
+    /// struct Address {
+    ///     street: String,
+    ///     number: i32,
+    /// }
+    ///
+    /// let row = sql::<Record<(Text, Integer)>>("SELECT ROW('Main Street', 12)");
+    /// let expected = Address { street: "Main Street".into(), number: 12 };
+    /// assert_eq!(Ok(expected), row.get_result(&connection));
+    /// # }
+    /// ```
+    #[derive(Debug, Clone, Copy, Default)]
+    pub struct Record<ST>(ST);
+
     /// Alias for `SmallInt`
     pub type SmallSerial = ::types::SmallInt;
 
@@ -320,6 +382,24 @@ pub mod sql_types {
     #[derive(Debug, Clone, Copy, Default)]
     pub struct Money;
 
+    /// The [`hstore`](https://www.postgresql.org/docs/9.6/static/hstore.html)
+    /// SQL type, which stores a set of keys and values. The `hstore`
+    /// extension has to be installed in the database to use this type.
+    ///
+    /// Keys and values are strings, and values can be `NULL`.
+    ///
+    /// ### [`ToSql`](/diesel/types/trait.ToSql.html) impls
+    ///
+    /// - [`HashMap<String, Option<String>>`][HashMap]
+    ///
+    /// ### [`FromSql`](/diesel/types/trait.FromSql.html) impls
+    ///
+    /// - [`HashMap<String, Option<String>>`][HashMap]
+    ///
+    /// [HashMap]: https://doc.rust-lang.org/std/collections/struct.HashMap.html
+    #[derive(Debug, Clone, Copy, Default)]
+    pub struct Hstore;
+
     #[cfg(feature = "network-address")]
     /// The [`MACADDR`](https://www.postgresql.org/docs/9.6/static/datatype-net-types.html) SQL type. This type can only be used with `feature = "network-address"`
     ///
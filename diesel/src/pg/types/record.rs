@@ -0,0 +1,191 @@
+use byteorder::{NetworkEndian, ReadBytesExt, WriteBytesExt};
+use std::error::Error;
+use std::io::Write;
+
+use expression::{AsExpression, NonAggregate};
+use expression::bound::Bound;
+use pg::{Pg, PgMetadataLookup, PgTypeMetadata};
+use query_source::Queryable;
+use types::*;
+
+impl<ST> HasSqlType<Record<ST>> for Pg {
+    // Binds are sent without a type, so that PostgreSQL uses the composite
+    // type expected where the record is used. Records sent as the anonymous
+    // `record` type cannot be received.
+    fn metadata(_: &PgMetadataLookup) -> PgTypeMetadata {
+        PgTypeMetadata {
+            oid: 0,
+            array_oid: 0,
+        }
+    }
+}
+
+impl_query_id!(Record<ST>);
+impl<ST> NotNull for Record<ST> {}
+impl<ST> SingleValue for Record<ST> {}
+impl<ST> NonAggregate for Record<ST> {}
+
+/// Reads the next field of a record, which is its OID, followed by its size
+/// and its value. The size is -1 for `NULL`.
+fn read_field<'a>(bytes: &mut &'a [u8]) -> Result<Option<&'a [u8]>, Box<Error + Send + Sync>> {
+    let _oid = bytes.read_u32::<NetworkEndian>()?;
+    let num_bytes = bytes.read_i32::<NetworkEndian>()?;
+    if num_bytes < 0 {
+        return Ok(None);
+    }
+
+    let num_bytes = num_bytes as usize;
+    if num_bytes > bytes.len() {
+        return Err("Received a record field which is longer than the record".into());
+    }
+    let (field_bytes, rest) = bytes.split_at(num_bytes);
+    *bytes = rest;
+    Ok(Some(field_bytes))
+}
+
+macro_rules! record_impls {
+    ($(
+        $Tuple:tt {
+            $(($idx:tt) -> $T:ident, $ST:ident,)+
+        }
+    )+) => {
+        $(
+            impl<$($T,)+ $($ST,)+> FromSql<Record<($($ST,)+)>, Pg> for ($($T,)+)
+            where
+                $($T: FromSql<$ST, Pg>,)+
+                $(Pg: HasSqlType<$ST>,)+
+            {
+                fn from_sql(bytes: Option<&[u8]>) -> Result<Self, Box<Error + Send + Sync>> {
+                    let mut bytes = not_none!(bytes);
+                    let num_fields = bytes.read_i32::<NetworkEndian>()?;
+                    if num_fields != $Tuple {
+                        return Err(format!(
+                            "Expected a record with {} fields, but it has {}",
+                            $Tuple,
+                            num_fields
+                        ).into());
+                    }
+
+                    Ok(($($T::from_sql(read_field(&mut bytes)?)?,)+))
+                }
+            }
+
+            impl<$($T,)+ $($ST,)+> FromSqlRow<Record<($($ST,)+)>, Pg> for ($($T,)+)
+            where
+                ($($T,)+): FromSql<Record<($($ST,)+)>, Pg>,
+            {
+                fn build_from_row<R: ::row::Row<Pg>>(row: &mut R) -> Result<Self, Box<Error + Send + Sync>> {
+                    FromSql::<Record<($($ST,)+)>, Pg>::from_sql(row.take())
+                }
+            }
+
+            impl<$($T,)+ $($ST,)+> Queryable<Record<($($ST,)+)>, Pg> for ($($T,)+)
+            where
+                ($($T,)+): FromSqlRow<Record<($($ST,)+)>, Pg>,
+            {
+                type Row = Self;
+
+                fn build(row: Self) -> Self {
+                    row
+                }
+            }
+
+            impl<$($T,)+ $($ST,)+> AsExpression<Record<($($ST,)+)>> for ($($T,)+) {
+                type Expression = Bound<Record<($($ST,)+)>, Self>;
+
+                fn as_expression(self) -> Self::Expression {
+                    Bound::new(self)
+                }
+            }
+
+            impl<'a, $($T,)+ $($ST,)+> AsExpression<Record<($($ST,)+)>> for &'a ($($T,)+) {
+                type Expression = Bound<Record<($($ST,)+)>, Self>;
+
+                fn as_expression(self) -> Self::Expression {
+                    Bound::new(self)
+                }
+            }
+
+            impl<$($T,)+ $($ST,)+> ToSql<Record<($($ST,)+)>, Pg> for ($($T,)+)
+            where
+                $($T: ToSql<$ST, Pg>,)+
+                $(Pg: HasSqlType<$ST>,)+
+            {
+                fn to_sql<W: Write>(
+                    &self,
+                    out: &mut ToSqlOutput<W, Pg>,
+                ) -> Result<IsNull, Box<Error + Send + Sync>> {
+                    out.write_i32::<NetworkEndian>($Tuple)?;
+                    $(
+                        let oid = <Pg as HasSqlType<$ST>>::metadata(out.metadata_lookup()).oid;
+                        out.write_u32::<NetworkEndian>(oid)?;
+                        let mut buffer = out.with_buffer(Vec::new());
+                        match self.$idx.to_sql(&mut buffer)? {
+                            IsNull::Yes => out.write_i32::<NetworkEndian>(-1)?,
+                            IsNull::No => {
+                                out.write_i32::<NetworkEndian>(buffer.len() as i32)?;
+                                out.write_all(&buffer)?;
+                            }
+                        }
+                    )+
+                    Ok(IsNull::No)
+                }
+            }
+        )+
+    }
+}
+
+record_impls! {
+    1 {
+        (0) -> A, SA,
+    }
+    2 {
+        (0) -> A, SA,
+        (1) -> B, SB,
+    }
+    3 {
+        (0) -> A, SA,
+        (1) -> B, SB,
+        (2) -> C, SC,
+    }
+    4 {
+        (0) -> A, SA,
+        (1) -> B, SB,
+        (2) -> C, SC,
+        (3) -> D, SD,
+    }
+    5 {
+        (0) -> A, SA,
+        (1) -> B, SB,
+        (2) -> C, SC,
+        (3) -> D, SD,
+        (4) -> E, SE,
+    }
+    6 {
+        (0) -> A, SA,
+        (1) -> B, SB,
+        (2) -> C, SC,
+        (3) -> D, SD,
+        (4) -> E, SE,
+        (5) -> F, SF,
+    }
+    7 {
+        (0) -> A, SA,
+        (1) -> B, SB,
+        (2) -> C, SC,
+        (3) -> D, SD,
+        (4) -> E, SE,
+        (5) -> F, SF,
+        (6) -> G, SG,
+    }
+    8 {
+        (0) -> A, SA,
+        (1) -> B, SB,
+        (2) -> C, SC,
+        (3) -> D, SD,
+        (4) -> E, SE,
+        (5) -> F, SF,
+        (6) -> G, SG,
+        (7) -> H, SH,
+    }
+}
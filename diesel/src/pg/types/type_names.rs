@@ -0,0 +1,60 @@
+use pg::PgTypeName;
+use types::*;
+
+macro_rules! pg_type_names {
+    ($($ty:ty => $name:expr,)+) => {
+        $(
+            impl PgTypeName for $ty {
+                const TYPE_NAME: &'static str = $name;
+            }
+        )+
+    }
+}
+
+pg_type_names! {
+    Bool => "boolean",
+    SmallInt => "smallint",
+    Integer => "integer",
+    BigInt => "bigint",
+    Float => "real",
+    Double => "double precision",
+    Numeric => "numeric",
+    Text => "text",
+    Binary => "bytea",
+    Date => "date",
+    Time => "time",
+    Timestamp => "timestamp",
+    Timestamptz => "timestamptz",
+    Interval => "interval",
+    Oid => "oid",
+    Money => "money",
+    Hstore => "hstore",
+    Int4range => "int4range",
+    Int8range => "int8range",
+    Numrange => "numrange",
+    Daterange => "daterange",
+    Tsrange => "tsrange",
+    Tstzrange => "tstzrange",
+}
+
+#[cfg(feature = "uuid")]
+pg_type_names! {
+    Uuid => "uuid",
+}
+
+#[cfg(feature = "serde_json")]
+pg_type_names! {
+    Json => "json",
+    Jsonb => "jsonb",
+}
+
+#[cfg(feature = "network-address")]
+pg_type_names! {
+    MacAddr => "macaddr",
+    Inet => "inet",
+    Cidr => "cidr",
+}
+
+impl<T: NotNull + PgTypeName> PgTypeName for Nullable<T> {
+    const TYPE_NAME: &'static str = T::TYPE_NAME;
+}
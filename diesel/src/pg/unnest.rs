@@ -0,0 +1,297 @@
+//! Support for using `unnest` as the `FROM` clause of a query.
+use std::marker::PhantomData;
+
+use expression::{AppearsOnTable, AsExpression, Expression, NonAggregate, SelectableExpression};
+use pg::Pg;
+use query_builder::{AsQuery, AstPass, Query, QueryFragment, SelectStatement};
+use query_dsl::{CountDsl, FilterDsl, OrderDsl, SelectDsl};
+use query_source::QuerySource;
+use result::QueryResult;
+use types::{Array, BigInt};
+
+/// Expands an array to a set of rows, using the PostgreSQL `unnest` function.
+/// The result can be used as the source of a query, with a single column,
+/// `value`.
+///
+/// The array cannot reference columns, so it is usually a Rust value, which is
+/// sent as a bind parameter.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate diesel;
+/// # include!("../doctest_setup.rs");
+/// #
+/// # table! {
+/// #     users {
+/// #         id -> Integer,
+/// #         name -> VarChar,
+/// #     }
+/// # }
+/// #
+/// # fn main() {
+/// #     use diesel::types::Integer;
+/// use diesel::pg::unnest::unnest;
+///
+/// #     let connection = establish_connection();
+/// let source = unnest::<Integer, _>(vec![3, 1, 2]).with_ordinality();
+/// let value = source.value();
+/// let position = source.ordinality();
+/// let data = source
+///     .select((value, position))
+///     .order(value)
+///     .load::<(i32, i64)>(&connection);
+///
+/// assert_eq!(Ok(vec![(1, 2), (2, 3), (3, 1)]), data);
+/// # }
+/// ```
+pub fn unnest<ST, T>(array: T) -> Unnest<ST, T::Expression>
+where
+    T: AsExpression<Array<ST>>,
+    T::Expression: AppearsOnTable<()>,
+{
+    Unnest {
+        array: array.as_expression(),
+        _marker: PhantomData,
+    }
+}
+
+/// `unnest(array) AS unnest(value)`. Constructed by [`unnest`](fn.unnest.html).
+#[derive(Debug)]
+pub struct Unnest<ST, T> {
+    array: T,
+    _marker: PhantomData<ST>,
+}
+
+impl<ST, T: Clone> Clone for Unnest<ST, T> {
+    fn clone(&self) -> Self {
+        Unnest {
+            array: self.array.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<ST, T> Unnest<ST, T> {
+    /// Adds `WITH ORDINALITY`, which numbers the elements starting from 1 in
+    /// a second column, `ordinality`.
+    pub fn with_ordinality(self) -> UnnestWithOrdinality<ST, T> {
+        UnnestWithOrdinality {
+            array: self.array,
+            _marker: PhantomData,
+        }
+    }
+
+    /// The elements of the array.
+    pub fn value(&self) -> Value<ST> {
+        Value(PhantomData)
+    }
+}
+
+/// `unnest(array) WITH ORDINALITY AS unnest(value, ordinality)`. Constructed by
+/// [`Unnest::with_ordinality`](struct.Unnest.html#method.with_ordinality).
+#[derive(Debug)]
+pub struct UnnestWithOrdinality<ST, T> {
+    array: T,
+    _marker: PhantomData<ST>,
+}
+
+impl<ST, T: Clone> Clone for UnnestWithOrdinality<ST, T> {
+    fn clone(&self) -> Self {
+        UnnestWithOrdinality {
+            array: self.array.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<ST, T> UnnestWithOrdinality<ST, T> {
+    /// The elements of the array.
+    pub fn value(&self) -> Value<ST> {
+        Value(PhantomData)
+    }
+
+    /// The position of the element in the array, starting from 1.
+    pub fn ordinality(&self) -> Ordinality {
+        Ordinality
+    }
+}
+
+impl<ST, T: QueryFragment<Pg>> QueryFragment<Pg> for Unnest<ST, T> {
+    fn walk_ast(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+        out.push_sql("unnest(");
+        self.array.walk_ast(out.reborrow())?;
+        out.push_sql(") AS unnest(value)");
+        Ok(())
+    }
+}
+
+impl<ST, T: QueryFragment<Pg>> QueryFragment<Pg> for UnnestWithOrdinality<ST, T> {
+    fn walk_ast(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+        out.push_sql("unnest(");
+        self.array.walk_ast(out.reborrow())?;
+        out.push_sql(") WITH ORDINALITY AS unnest(value, ordinality)");
+        Ok(())
+    }
+}
+
+impl_query_id!(Unnest<ST, T>);
+impl_query_id!(UnnestWithOrdinality<ST, T>);
+
+impl<ST, T: Clone> QuerySource for Unnest<ST, T> {
+    type FromClause = Self;
+    type DefaultSelection = Value<ST>;
+
+    fn from_clause(&self) -> Self {
+        self.clone()
+    }
+
+    fn default_selection(&self) -> Self::DefaultSelection {
+        self.value()
+    }
+}
+
+impl<ST, T: Clone> QuerySource for UnnestWithOrdinality<ST, T> {
+    type FromClause = Self;
+    type DefaultSelection = (Value<ST>, Ordinality);
+
+    fn from_clause(&self) -> Self {
+        self.clone()
+    }
+
+    fn default_selection(&self) -> Self::DefaultSelection {
+        (self.value(), self.ordinality())
+    }
+}
+
+macro_rules! unnest_query_dsl {
+    ($source:ident) => {
+        impl<ST, T: Clone> AsQuery for $source<ST, T>
+        where
+            SelectStatement<Self>: Query,
+        {
+            type SqlType = <SelectStatement<Self> as Query>::SqlType;
+            type Query = SelectStatement<Self>;
+
+            fn as_query(self) -> Self::Query {
+                SelectStatement::simple(self)
+            }
+        }
+
+        impl<ST, T, Selection> SelectDsl<Selection> for $source<ST, T>
+        where
+            Selection: Expression,
+            Self: AsQuery<Query = SelectStatement<Self>>,
+            SelectStatement<Self>: SelectDsl<Selection>,
+        {
+            type Output = <SelectStatement<Self> as SelectDsl<Selection>>::Output;
+
+            fn select(self, selection: Selection) -> Self::Output {
+                self.as_query().select(selection)
+            }
+        }
+
+        impl<ST, T> CountDsl for $source<ST, T>
+        where
+            Self: AsQuery<Query = SelectStatement<Self>>,
+            SelectStatement<Self>: CountDsl,
+        {
+            type Output = <SelectStatement<Self> as CountDsl>::Output;
+
+            fn count(self) -> Self::Output {
+                self.as_query().count()
+            }
+        }
+
+        impl<ST, T, Predicate> FilterDsl<Predicate> for $source<ST, T>
+        where
+            Self: AsQuery<Query = SelectStatement<Self>>,
+            SelectStatement<Self>: FilterDsl<Predicate, SqlType = Self::SqlType>,
+        {
+            type Output = <SelectStatement<Self> as FilterDsl<Predicate>>::Output;
+
+            fn filter(self, predicate: Predicate) -> Self::Output {
+                self.as_query().filter(predicate)
+            }
+        }
+
+        impl<ST, T, Expr> OrderDsl<Expr> for $source<ST, T>
+        where
+            Expr: Expression,
+            Self: AsQuery<Query = SelectStatement<Self>>,
+            SelectStatement<Self>: OrderDsl<Expr, SqlType = Self::SqlType>,
+        {
+            type Output = <SelectStatement<Self> as OrderDsl<Expr>>::Output;
+
+            fn order(self, expr: Expr) -> Self::Output {
+                self.as_query().order(expr)
+            }
+        }
+    }
+}
+
+unnest_query_dsl!(Unnest);
+unnest_query_dsl!(UnnestWithOrdinality);
+
+/// The `value` column of [`unnest`](fn.unnest.html).
+#[derive(Debug)]
+pub struct Value<ST>(PhantomData<ST>);
+
+impl<ST> Clone for Value<ST> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<ST> Copy for Value<ST> {}
+
+impl<ST> Expression for Value<ST> {
+    type SqlType = ST;
+}
+
+impl<ST> QueryFragment<Pg> for Value<ST> {
+    fn walk_ast(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+        out.push_sql("unnest.value");
+        Ok(())
+    }
+}
+
+impl_query_id!(Value<ST>);
+
+impl<ST, T> SelectableExpression<Unnest<ST, T>> for Value<ST> {}
+
+impl_unchecked_distinct_ordering!(Value<ST>);
+
+impl<ST, T> AppearsOnTable<Unnest<ST, T>> for Value<ST> {}
+
+impl<ST, T> SelectableExpression<UnnestWithOrdinality<ST, T>> for Value<ST> {}
+
+impl<ST, T> AppearsOnTable<UnnestWithOrdinality<ST, T>> for Value<ST> {}
+
+impl<ST> NonAggregate for Value<ST> {}
+
+/// The `ordinality` column of
+/// [`unnest(...).with_ordinality()`](struct.Unnest.html#method.with_ordinality).
+#[derive(Debug, Clone, Copy)]
+pub struct Ordinality;
+
+impl Expression for Ordinality {
+    type SqlType = BigInt;
+}
+
+impl QueryFragment<Pg> for Ordinality {
+    fn walk_ast(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+        out.push_sql("unnest.ordinality");
+        Ok(())
+    }
+}
+
+impl_query_id!(Ordinality);
+
+impl<ST, T> SelectableExpression<UnnestWithOrdinality<ST, T>> for Ordinality {}
+
+impl_unchecked_distinct_ordering!(Ordinality);
+
+impl<ST, T> AppearsOnTable<UnnestWithOrdinality<ST, T>> for Ordinality {}
+
+impl NonAggregate for Ordinality {}
@@ -0,0 +1,87 @@
+use connection::Connection;
+use dsl::{Filter, Limit};
+use expression::operators::Eq;
+use pg::PgConnection;
+use query_builder::insert_statement::InsertStatement;
+use query_dsl::{FilterDsl, FirstDsl, LoadDsl, LoadQuery};
+use query_source::{Column, Table};
+use result::{OptionalExtension, QueryResult};
+use super::on_conflict_actions::DoNothing;
+use super::on_conflict_clause::OnConflict;
+use super::on_conflict_target::ConflictTarget;
+
+impl<T, U, Op> InsertStatement<T, U, Op> {
+    /// Inserts a record unless it conflicts with an existing one, and returns
+    /// the inserted or the existing row.
+    ///
+    /// The record is inserted with `ON CONFLICT (column) DO NOTHING RETURNING
+    /// *`, where `column` is the column compared by `key`. If nothing was
+    /// returned, the row matching `key` is selected instead. Both statements
+    /// are run in a transaction.
+    ///
+    /// A row inserted by another connection at the same time makes the insert
+    /// wait until the other transaction ends, and is seen by the select once
+    /// that transaction has been committed. This relies on each statement
+    /// seeing the rows committed before it started, which is the case for the
+    /// default isolation level of PostgreSQL, but not when the transaction is
+    /// `REPEATABLE READ` or `SERIALIZABLE`.
+    ///
+    /// `key` should match the value of the column in the record. If it
+    /// doesn't, the select can return `NotFound` even though the insert
+    /// conflicted.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate diesel;
+    /// # #[macro_use] extern crate diesel_codegen;
+    /// # include!("on_conflict_docs_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     use self::users::dsl::*;
+    /// #     let conn = establish_connection();
+    /// #     conn.execute("TRUNCATE TABLE users").unwrap();
+    /// conn.execute("CREATE UNIQUE INDEX users_name ON users (name)").unwrap();
+    ///
+    /// let sean = User { id: 1, name: "Sean" };
+    /// let created = diesel::insert(&sean).into(users).find_or_create(name.eq("Sean"), &conn);
+    /// assert_eq!(Ok((1, "Sean".to_string())), created);
+    ///
+    /// let another_sean = User { id: 2, name: "Sean" };
+    /// let found = diesel::insert(&another_sean)
+    ///     .into(users)
+    ///     .find_or_create(name.eq("Sean"), &conn);
+    /// assert_eq!(Ok((1, "Sean".to_string())), found);
+    /// # }
+    /// ```
+    pub fn find_or_create<Col, Value, Record>(
+        self,
+        key: Eq<Col, Value>,
+        conn: &PgConnection,
+    ) -> QueryResult<Record>
+    where
+        T: Table + Copy + FilterDsl<Eq<Col, Value>>,
+        Col: Column<Table = T> + Copy,
+        for<'a> InsertStatement<T, &'a OnConflict<U, ConflictTarget<Col>, DoNothing>, Op>:
+            LoadQuery<PgConnection, Record>,
+        Filter<T, Eq<Col, Value>>: FirstDsl<PgConnection>,
+        Limit<Filter<T, Eq<Col, Value>>>: LoadQuery<PgConnection, Record>,
+    {
+        let InsertStatement {
+            operator,
+            target,
+            records,
+            ..
+        } = self;
+        let records = OnConflict::new(records, ConflictTarget(*key.left()), DoNothing);
+        conn.transaction(|| {
+            let inserted = InsertStatement::no_returning_clause(target, &records, operator)
+                .get_result(conn)
+                .optional()?;
+            match inserted {
+                Some(record) => Ok(record),
+                None => target.filter(key).first(conn),
+            }
+        })
+    }
+}
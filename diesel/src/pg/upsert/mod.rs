@@ -1,8 +1,13 @@
+mod find_or_create;
 mod on_conflict_actions;
 mod on_conflict_clause;
 mod on_conflict_extension;
 mod on_conflict_target;
+mod returning_previous;
+mod stats;
 
 pub use self::on_conflict_actions::{do_nothing, do_update, excluded};
 pub use self::on_conflict_extension::OnConflictExtension;
 pub use self::on_conflict_target::on_constraint;
+pub use self::returning_previous::UpsertReturningPrevious;
+pub use self::stats::UpsertStats;
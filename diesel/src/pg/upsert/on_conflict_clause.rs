@@ -17,6 +17,21 @@ impl<T> OnConflictDoNothing<T> {
     }
 }
 
+#[derive(Debug, Clone, Copy)]
+pub struct OnConflictDoUpdate<T, Changes> {
+    records: T,
+    changes: Changes,
+}
+
+impl<T, Changes> OnConflictDoUpdate<T, Changes> {
+    pub fn new(records: T, changes: Changes) -> Self {
+        OnConflictDoUpdate {
+            records: records,
+            changes: changes,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct OnConflict<Records, Target, Action> {
     records: Records,
@@ -42,6 +57,14 @@ impl<'a, T, Tab, Op> IntoInsertStatement<Tab, Op> for &'a OnConflictDoNothing<T>
     }
 }
 
+impl<'a, T, Changes, Tab, Op> IntoInsertStatement<Tab, Op> for &'a OnConflictDoUpdate<T, Changes> {
+    type InsertStatement = InsertStatement<Tab, Self, Op>;
+
+    fn into_insert_statement(self, target: Tab, operator: Op) -> Self::InsertStatement {
+        InsertStatement::no_returning_clause(target, self, operator)
+    }
+}
+
 impl<'a, Recods, Target, Action, Tab, Op> IntoInsertStatement<Tab, Op>
     for &'a OnConflict<Recods, Target, Action> {
     type InsertStatement = InsertStatement<Tab, Self, Op>;
@@ -68,6 +91,30 @@ where
     }
 }
 
+impl<'a, T, Changes, Tab> Insertable<Tab, Pg> for &'a OnConflictDoUpdate<T, Changes>
+where
+    Tab: Table,
+    T: Insertable<Tab, Pg> + Copy,
+    T: UndecoratedInsertRecord<Tab>,
+    Changes: AsChangeset + Copy,
+    DoUpdate<Changes>: IntoConflictAction<Tab>,
+    PrimaryKeyTarget<Tab>: OnConflictTarget<Tab>,
+{
+    type Values = OnConflictValues<
+        T::Values,
+        PrimaryKeyTarget<Tab>,
+        <DoUpdate<Changes> as IntoConflictAction<Tab>>::Action,
+    >;
+
+    fn values(self) -> Self::Values {
+        OnConflictValues {
+            values: self.records.values(),
+            target: PrimaryKeyTarget::new(),
+            action: do_update().set(self.changes).into_conflict_action(),
+        }
+    }
+}
+
 impl<'a, Records, Target, Action, Tab> Insertable<Tab, Pg>
     for &'a OnConflict<Records, Target, Action>
 where
@@ -1,3 +1,4 @@
+use query_builder::AsChangeset;
 pub use super::on_conflict_clause::*;
 pub use super::on_conflict_target::*;
 
@@ -5,6 +6,8 @@ pub use super::on_conflict_target::*;
 pub trait OnConflictExtension {
     /// Adds `ON CONFLICT DO NOTHING` to the insert statement, without
     /// specifying any columns or constraints to restrict the conflict to.
+    /// Conflicts on the primary key are ignored as well, so there is no need
+    /// to name it.
     ///
     /// # Examples
     ///
@@ -79,6 +82,48 @@ pub trait OnConflictExtension {
         OnConflictDoNothing::new(self)
     }
 
+    /// Adds `ON CONFLICT (primary_key) DO UPDATE SET ...` to the insert
+    /// statement. The target of the conflict is the primary key of the table
+    /// the records are inserted into, as declared with `table!`. This is a
+    /// shortcut for `.on_conflict(primary_key_columns, do_update().set(changes))`.
+    ///
+    /// Use [`on_conflict`](#method.on_conflict) to update the row when a
+    /// different unique constraint conflicts.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate diesel;
+    /// # #[macro_use] extern crate diesel_codegen;
+    /// # include!("on_conflict_docs_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     use self::users::dsl::*;
+    /// use self::diesel::pg::upsert::*;
+    ///
+    /// #     let conn = establish_connection();
+    /// #     conn.execute("TRUNCATE TABLE users").unwrap();
+    /// let user = User { id: 1, name: "Pascal" };
+    /// let user2 = User { id: 1, name: "Sean" };
+    ///
+    /// assert_eq!(Ok(1), diesel::insert(&user).into(users).execute(&conn));
+    ///
+    /// let insert_count = diesel::insert(&user2.on_conflict_do_update(&user2))
+    ///     .into(users)
+    ///     .execute(&conn);
+    /// assert_eq!(Ok(1), insert_count);
+    ///
+    /// let users_in_db = users.load(&conn);
+    /// assert_eq!(Ok(vec![(1, "Sean".to_string())]), users_in_db);
+    /// # }
+    /// ```
+    fn on_conflict_do_update<Changes>(&self, changes: Changes) -> OnConflictDoUpdate<&Self, Changes>
+    where
+        Changes: AsChangeset,
+    {
+        OnConflictDoUpdate::new(self, changes)
+    }
+
     /// Adds an `ON CONFLICT` to the insert statement, performing the action
     /// specified by `Action` if a conflict occurs for `Target`.
     ///
@@ -1,7 +1,9 @@
+use std::marker::PhantomData;
+
 use expression::SqlLiteral;
 use pg::Pg;
 use query_builder::*;
-use query_source::Column;
+use query_source::{Column, Table};
 use result::QueryResult;
 
 /// Used to specify the constraint name for an upsert statement in the form `ON
@@ -139,3 +141,69 @@ on_conflict_tuples!(U, V, W);
 on_conflict_tuples!(U, V, W, X);
 on_conflict_tuples!(U, V, W, X, Y);
 on_conflict_tuples!(U, V, W, X, Y, Z);
+
+#[doc(hidden)]
+#[derive(Debug, Clone, Copy)]
+pub struct PrimaryKeyTarget<Table>(PhantomData<Table>);
+
+impl<Table> PrimaryKeyTarget<Table> {
+    pub fn new() -> Self {
+        PrimaryKeyTarget(PhantomData)
+    }
+}
+
+impl<Tab> QueryFragment<Pg> for PrimaryKeyTarget<Tab>
+where
+    Tab: Table,
+    Tab::PrimaryKey: ConflictTargetColumns,
+{
+    fn walk_ast(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+        out.push_sql(" (");
+        Tab::PrimaryKey::walk_column_names(out.reborrow())?;
+        out.push_sql(")");
+        Ok(())
+    }
+}
+
+impl<Tab> OnConflictTarget<Tab> for PrimaryKeyTarget<Tab>
+where
+    Tab: Table,
+    Tab::PrimaryKey: ConflictTargetColumns,
+{
+}
+
+#[doc(hidden)]
+pub trait ConflictTargetColumns {
+    fn walk_column_names(out: AstPass<Pg>) -> QueryResult<()>;
+}
+
+impl<T: Column> ConflictTargetColumns for T {
+    fn walk_column_names(mut out: AstPass<Pg>) -> QueryResult<()> {
+        out.push_identifier(T::NAME)
+    }
+}
+
+macro_rules! conflict_target_columns_tuples {
+    ($($col:ident),+) => {
+        impl<T, $($col),+> ConflictTargetColumns for (T, $($col),+) where
+            T: Column,
+            $($col: Column<Table=T::Table>,)+
+        {
+            fn walk_column_names(mut out: AstPass<Pg>) -> QueryResult<()> {
+                try!(out.push_identifier(T::NAME));
+                $(
+                    out.push_sql(", ");
+                    try!(out.push_identifier($col::NAME));
+                )+
+                Ok(())
+            }
+        }
+    }
+}
+
+conflict_target_columns_tuples!(U);
+conflict_target_columns_tuples!(U, V);
+conflict_target_columns_tuples!(U, V, W);
+conflict_target_columns_tuples!(U, V, W, X);
+conflict_target_columns_tuples!(U, V, W, X, Y);
+conflict_target_columns_tuples!(U, V, W, X, Y, Z);
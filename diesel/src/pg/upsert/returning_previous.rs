@@ -0,0 +1,203 @@
+use expression::{AppearsOnTable, Expression, NonAggregate};
+use pg::Pg;
+use query_builder::*;
+use query_builder::insert_statement::InsertStatement;
+use query_builder::nodes::Identifier;
+use query_builder::order_clause::{NoOrderClause, OrderClause};
+use query_dsl::OrderDsl;
+use query_source::{QuerySource, Table};
+use result::QueryResult;
+use super::on_conflict_target::ConflictTargetColumns;
+use types::{Bool, NotNull, Nullable};
+
+impl<T, U, Op> InsertStatement<T, U, Op> {
+    /// Runs the insert statement and returns each row as it was before the
+    /// statement, along with the row as it is after the statement. This is
+    /// intended to be used with an `ON CONFLICT DO UPDATE` clause, to record
+    /// the values which were overwritten.
+    ///
+    /// PostgreSQL cannot return the previous values of an updated row, so the
+    /// rows matching `existing` are selected in a common table expression
+    /// next to the insert statement:
+    ///
+    /// ```sql
+    /// WITH "old" AS (SELECT ... FROM "users" WHERE existing),
+    /// "upserted" AS (INSERT INTO "users" ... RETURNING ...)
+    /// SELECT "old".*, "upserted".* FROM "upserted" LEFT JOIN "old" USING ("id")
+    /// ```
+    ///
+    /// `existing` should match the rows which the insert statement can
+    /// conflict with. The rows are matched by the primary key of the table.
+    /// The previous row is `None` for rows which were inserted rather than
+    /// updated.
+    ///
+    /// The rows are returned in no particular order. Calling `.order` on the
+    /// result sorts them by columns of the rows as they are after the
+    /// statement, which are selected as the table itself:
+    ///
+    /// ```sql
+    /// ... SELECT "old".*, "users".* FROM "upserted" AS "users"
+    /// LEFT JOIN "old" USING ("id") ORDER BY "users"."id"
+    /// ```
+    ///
+    /// Both parts of the statement see the same snapshot of the table, so
+    /// `"old"` never sees the changes made by the insert. It cannot use
+    /// `FOR UPDATE`, as PostgreSQL skips rows which were already changed by
+    /// the same statement. If other connections may update the rows at the
+    /// same time, lock them first with
+    /// [`.for_update()`](../../query_dsl/trait.ForUpdateDsl.html) in the same
+    /// transaction, so that the previous values cannot change before the
+    /// insert is run.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate diesel;
+    /// # #[macro_use] extern crate diesel_codegen;
+    /// # include!("on_conflict_docs_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     use self::users::dsl::*;
+    /// use self::diesel::pg::upsert::*;
+    ///
+    /// #     let conn = establish_connection();
+    /// #     conn.execute("TRUNCATE TABLE users").unwrap();
+    /// diesel::insert(&User { id: 1, name: "Pascal" }).into(users).execute(&conn).unwrap();
+    ///
+    /// let new_users = vec![User { id: 1, name: "Sean" }, User { id: 2, name: "Tess" }];
+    /// let upsert = new_users.on_conflict(id, do_update().set(name.eq(excluded(name))));
+    /// let changes = diesel::insert(&upsert)
+    ///     .into(users)
+    ///     .upsert_returning_previous(id.eq_any(vec![1, 2]))
+    ///     .order(id)
+    ///     .load(&conn);
+    /// let expected_changes = vec![
+    ///     (Some((1, "Pascal".to_string())), (1, "Sean".to_string())),
+    ///     (None, (2, "Tess".to_string())),
+    /// ];
+    /// assert_eq!(Ok(expected_changes), changes);
+    /// # }
+    /// ```
+    pub fn upsert_returning_previous<P>(
+        self,
+        existing: P,
+    ) -> UpsertReturningPrevious<T, U, Op, P, NoOrderClause>
+    where
+        T: Table,
+        P: AppearsOnTable<T, SqlType = Bool> + NonAggregate,
+    {
+        UpsertReturningPrevious {
+            insert: self,
+            existing: existing,
+            order: NoOrderClause,
+        }
+    }
+}
+
+/// An insert statement which returns the previous values of the rows it
+/// changes. Constructed by
+/// [`InsertStatement::upsert_returning_previous`](../../query_builder/insert_statement/struct.InsertStatement.html#method.upsert_returning_previous).
+#[derive(Debug, Clone, Copy)]
+#[must_use = "Queries are only executed when calling `load`, `get_result` or similar."]
+pub struct UpsertReturningPrevious<T, U, Op, P, O> {
+    insert: InsertStatement<T, U, Op>,
+    existing: P,
+    order: O,
+}
+
+impl<T, U, Op, P, O> UpsertReturningPrevious<T, U, Op, P, O>
+where
+    T: Table,
+    T::FromClause: QueryFragment<Pg>,
+    T::AllColumns: QueryFragment<Pg>,
+    T::PrimaryKey: ConflictTargetColumns,
+    InsertStatement<T, U, Op>: QueryFragment<Pg>,
+    P: QueryFragment<Pg>,
+{
+    fn walk_upsert(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+        out.push_sql("WITH \"old\" AS (SELECT ");
+        T::all_columns().walk_ast(out.reborrow())?;
+        out.push_sql(" FROM ");
+        self.insert.target.from_clause().walk_ast(out.reborrow())?;
+        out.push_sql(" WHERE ");
+        self.existing.walk_ast(out.reborrow())?;
+        out.push_sql("), \"upserted\" AS (");
+        self.insert.walk_ast(out.reborrow())?;
+        out.push_sql(" RETURNING ");
+        T::all_columns().walk_ast(out.reborrow())?;
+        out.push_sql(") ");
+        Ok(())
+    }
+}
+
+impl<T, U, Op, P, O> Query for UpsertReturningPrevious<T, U, Op, P, O>
+where
+    T: Table,
+    <T::AllColumns as Expression>::SqlType: NotNull,
+{
+    type SqlType = (
+        Nullable<<T::AllColumns as Expression>::SqlType>,
+        <T::AllColumns as Expression>::SqlType,
+    );
+}
+
+impl<T, U, Op, P> QueryFragment<Pg> for UpsertReturningPrevious<T, U, Op, P, NoOrderClause>
+where
+    T: Table,
+    T::FromClause: QueryFragment<Pg>,
+    T::AllColumns: QueryFragment<Pg>,
+    T::PrimaryKey: ConflictTargetColumns,
+    InsertStatement<T, U, Op>: QueryFragment<Pg>,
+    P: QueryFragment<Pg>,
+{
+    fn walk_ast(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+        self.walk_upsert(out.reborrow())?;
+        out.push_sql("SELECT \"old\".*, \"upserted\".* FROM \"upserted\"");
+        out.push_sql(" LEFT JOIN \"old\" USING (");
+        T::PrimaryKey::walk_column_names(out.reborrow())?;
+        out.push_sql(")");
+        Ok(())
+    }
+}
+
+impl<T, U, Op, P, Expr> QueryFragment<Pg> for UpsertReturningPrevious<T, U, Op, P, OrderClause<Expr>>
+where
+    T: Table + QuerySource<FromClause = Identifier<'static>>,
+    T::AllColumns: QueryFragment<Pg>,
+    T::PrimaryKey: ConflictTargetColumns,
+    InsertStatement<T, U, Op>: QueryFragment<Pg>,
+    P: QueryFragment<Pg>,
+    Expr: QueryFragment<Pg>,
+{
+    fn walk_ast(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+        self.walk_upsert(out.reborrow())?;
+        out.push_sql("SELECT \"old\".*, ");
+        self.insert.target.from_clause().walk_ast(out.reborrow())?;
+        out.push_sql(".* FROM \"upserted\" AS ");
+        self.insert.target.from_clause().walk_ast(out.reborrow())?;
+        out.push_sql(" LEFT JOIN \"old\" USING (");
+        T::PrimaryKey::walk_column_names(out.reborrow())?;
+        out.push_sql(")");
+        self.order.walk_ast(out.reborrow())?;
+        Ok(())
+    }
+}
+
+impl_query_id!(noop: UpsertReturningPrevious<T, U, Op, P, O>);
+
+impl<T, U, Op, P, Expr> OrderDsl<Expr> for UpsertReturningPrevious<T, U, Op, P, NoOrderClause>
+where
+    T: Table + QuerySource<FromClause = Identifier<'static>>,
+    <T::AllColumns as Expression>::SqlType: NotNull,
+    Expr: AppearsOnTable<T>,
+{
+    type Output = UpsertReturningPrevious<T, U, Op, P, OrderClause<Expr>>;
+
+    fn order(self, expr: Expr) -> Self::Output {
+        UpsertReturningPrevious {
+            insert: self.insert,
+            existing: self.existing,
+            order: OrderClause(expr),
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use dsl::sql;
+use expression::SqlLiteral;
+use pg::PgConnection;
+use query_builder::Query;
+use query_builder::insert_statement::InsertStatement;
+use query_builder::returning_clause::ReturningClause;
+use query_dsl::{LoadDsl, LoadQuery};
+use result::QueryResult;
+use types::Bool;
+
+/// The number of rows inserted and updated by an upsert. Returned by
+/// [`InsertStatement::execute_with_upsert_stats`](../../query_builder/insert_statement/struct.InsertStatement.html#method.execute_with_upsert_stats).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct UpsertStats {
+    /// The number of rows which were inserted
+    pub inserted: usize,
+    /// The number of rows which conflicted with an existing row, and updated
+    /// it instead
+    pub updated: usize,
+}
+
+impl<T, U, Op> InsertStatement<T, U, Op> {
+    /// Runs an insert statement with an `ON CONFLICT DO UPDATE` clause, and
+    /// returns how many rows were inserted and how many were updated.
+    ///
+    /// Each row returns whether it was inserted, which is the case when its
+    /// `xmax` system column is 0. Rows skipped by `ON CONFLICT DO NOTHING`
+    /// are not returned, so they are not counted.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate diesel;
+    /// # #[macro_use] extern crate diesel_codegen;
+    /// # include!("on_conflict_docs_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     use self::users::dsl::*;
+    /// use self::diesel::pg::upsert::*;
+    ///
+    /// #     let conn = establish_connection();
+    /// #     conn.execute("TRUNCATE TABLE users").unwrap();
+    /// diesel::insert(&User { id: 1, name: "Pascal" }).into(users).execute(&conn).unwrap();
+    ///
+    /// let new_users = vec![User { id: 1, name: "Sean" }, User { id: 2, name: "Tess" }];
+    /// let upsert = new_users.on_conflict(id, do_update().set(name.eq(excluded(name))));
+    /// let stats = diesel::insert(&upsert)
+    ///     .into(users)
+    ///     .execute_with_upsert_stats(&conn);
+    /// assert_eq!(Ok(UpsertStats { inserted: 1, updated: 1 }), stats);
+    /// # }
+    /// ```
+    pub fn execute_with_upsert_stats(self, conn: &PgConnection) -> QueryResult<UpsertStats>
+    where
+        InsertStatement<T, U, Op, ReturningClause<SqlLiteral<Bool>>>:
+            Query + LoadQuery<PgConnection, bool>,
+    {
+        let inserted_flags = self.returning(sql::<Bool>("xmax = 0"))
+            .load::<bool>(conn)?;
+        let inserted = inserted_flags.iter().filter(|&&inserted| inserted).count();
+        Ok(UpsertStats {
+            inserted: inserted,
+            updated: inserted_flags.len() - inserted,
+        })
+    }
+}
@@ -28,6 +28,18 @@ where
     DB::MetadataLookup: 'a,
 {
     internals: AstPassInternals<'a, DB>,
+    alias_reference: Option<AliasReference>,
+}
+
+/// How an aliased expression is written when it is referenced outside of the
+/// select clause.
+#[doc(hidden)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AliasReference {
+    /// Only the alias is written
+    Name,
+    /// The aliased expression is written again in full
+    Expression,
 }
 
 impl<'a, DB> AstPass<'a, DB>
@@ -39,6 +51,7 @@ where
     pub fn to_sql(query_builder: &'a mut DB::QueryBuilder) -> Self {
         AstPass {
             internals: AstPassInternals::ToSql(query_builder),
+            alias_reference: None,
         }
     }
 
@@ -52,6 +65,7 @@ where
                 collector,
                 metadata_lookup,
             },
+            alias_reference: None,
         }
     }
 
@@ -59,6 +73,15 @@ where
     pub fn is_safe_to_cache_prepared(result: &'a mut bool) -> Self {
         AstPass {
             internals: AstPassInternals::IsSafeToCachePrepared(result),
+            alias_reference: None,
+        }
+    }
+
+    #[doc(hidden)]
+    pub fn is_noop(result: &'a mut bool) -> Self {
+        AstPass {
+            internals: AstPassInternals::IsNoop(result),
+            alias_reference: None,
         }
     }
 
@@ -66,6 +89,7 @@ where
     pub fn debug_binds(formatter: &'a mut fmt::DebugList<'a, 'a>) -> Self {
         AstPass {
             internals: AstPassInternals::DebugBinds(formatter),
+            alias_reference: None,
         }
     }
 
@@ -90,13 +114,41 @@ where
                 metadata_lookup: &*metadata_lookup,
             },
             IsSafeToCachePrepared(ref mut result) => IsSafeToCachePrepared(&mut **result),
+            IsNoop(ref mut result) => IsNoop(&mut **result),
             DebugBinds(ref mut f) => {
                 // Safe because the lifetime is always being shortened.
                 let f_with_shorter_lifetime = unsafe { mem::transmute(&mut **f) };
                 DebugBinds(f_with_shorter_lifetime)
             }
         };
-        AstPass { internals }
+        AstPass {
+            internals,
+            alias_reference: self.alias_reference,
+        }
+    }
+
+    /// Like `reborrow`, but aliased expressions walked with the returned
+    /// `AstPass` are written as references to their alias, rather than as
+    /// `expr AS alias`.
+    #[doc(hidden)]
+    pub fn referencing_aliases(&mut self, reference: AliasReference) -> AstPass<DB> {
+        let mut out = self.reborrow();
+        out.alias_reference = Some(reference);
+        out
+    }
+
+    /// Aliased expressions walked with the returned `AstPass` are written as
+    /// `expr AS alias` again. Used for subqueries, which have their own select
+    /// clause.
+    #[doc(hidden)]
+    pub fn without_alias_references(mut self) -> Self {
+        self.alias_reference = None;
+        self
+    }
+
+    #[doc(hidden)]
+    pub fn alias_reference(&self) -> Option<AliasReference> {
+        self.alias_reference
     }
 
     /// Mark the current query being constructed as unsafe to store in the
@@ -145,8 +197,24 @@ where
     /// }
     /// ```
     pub fn push_sql(&mut self, sql: &str) {
-        if let AstPassInternals::ToSql(ref mut builder) = self.internals {
-            builder.push_sql(sql);
+        match self.internals {
+            AstPassInternals::ToSql(ref mut builder) => builder.push_sql(sql),
+            AstPassInternals::IsNoop(ref mut result) => **result = false,
+            _ => {}
+        }
+    }
+
+    /// Push the given SQL string on the end of the query being constructed,
+    /// without collapsing its whitespace.
+    ///
+    /// This should be used for SQL which was written by the user, such as the
+    /// SQL of a [`sql`](../dsl/fn.sql.html) literal. Diesel's own fragments
+    /// should use [`push_sql`](#method.push_sql).
+    pub fn push_sql_verbatim(&mut self, sql: &str) {
+        match self.internals {
+            AstPassInternals::ToSql(ref mut builder) => builder.push_sql_verbatim(sql),
+            AstPassInternals::IsNoop(ref mut result) => **result = false,
+            _ => {}
         }
     }
 
@@ -155,8 +223,10 @@ where
     /// The identifier will be quoted using the rules specific to the backend
     /// the query is being constructed for.
     pub fn push_identifier(&mut self, identifier: &str) -> QueryResult<()> {
-        if let AstPassInternals::ToSql(ref mut builder) = self.internals {
-            builder.push_identifier(identifier)?;
+        match self.internals {
+            AstPassInternals::ToSql(ref mut builder) => builder.push_identifier(identifier)?,
+            AstPassInternals::IsNoop(ref mut result) => **result = false,
+            _ => {}
         }
         Ok(())
     }
@@ -181,11 +251,56 @@ where
             DebugBinds(ref mut f) => {
                 f.entry(bind);
             }
+            IsNoop(ref mut result) => **result = false,
             _ => {} // noop
         }
         Ok(())
     }
 
+    /// Push a value onto the given query to be sent separate from the SQL,
+    /// giving the placeholder a name.
+    ///
+    /// The built in backends write the same placeholder as
+    /// [`push_bind_param`](#method.push_bind_param). PostgreSQL remembers the
+    /// name, so that
+    /// [`push_repeated_named_bind_param`](#method.push_repeated_named_bind_param)
+    /// can refer to the placeholder again. A custom
+    /// [`QueryBuilder`](trait.QueryBuilder.html) can use it to write a named
+    /// placeholder, such as `:name`, instead.
+    pub fn push_named_bind_param<T, U>(&mut self, name: &str, bind: &U) -> QueryResult<()>
+    where
+        DB: HasSqlType<T>,
+        U: ToSql<T, DB>,
+    {
+        if let AstPassInternals::ToSql(ref mut out) = self.internals {
+            out.push_bind_param_with_name(name);
+            Ok(())
+        } else {
+            self.push_bind_param(bind)
+        }
+    }
+
+    /// Push the value bound to a named parameter which was already pushed
+    /// with [`push_named_bind_param`](#method.push_named_bind_param).
+    ///
+    /// When the query builder of the backend can refer to an earlier bind
+    /// parameter, such as `$1` on PostgreSQL, its placeholder is written again
+    /// and the value is only sent once. Otherwise this is the same as
+    /// `push_named_bind_param`.
+    pub fn push_repeated_named_bind_param<T, U>(&mut self, name: &str, bind: &U) -> QueryResult<()>
+    where
+        DB: HasSqlType<T>,
+        U: ToSql<T, DB>,
+    {
+        if !<DB::QueryBuilder as QueryBuilder<DB>>::CAN_REPEAT_BIND_PARAMS {
+            return self.push_named_bind_param(name, bind);
+        }
+        if let AstPassInternals::ToSql(ref mut out) = self.internals {
+            out.push_repeated_bind_param(name);
+        }
+        Ok(())
+    }
+
     /// FIXME: This method is a temporary shim, and should be removed when
     /// we are able to merge `InsertValues` into `QueryFragment`
     #[doc(hidden)]
@@ -230,5 +345,6 @@ where
         metadata_lookup: &'a DB::MetadataLookup,
     },
     IsSafeToCachePrepared(&'a mut bool),
+    IsNoop(&'a mut bool),
     DebugBinds(&'a mut fmt::DebugList<'a, 'a>),
 }
@@ -0,0 +1,156 @@
+use std::marker::PhantomData;
+
+use backend::Backend;
+use query_builder::*;
+use result::QueryResult;
+use types::HasSqlType;
+
+#[allow(missing_debug_implementations)]
+/// Assembles a query from pieces chosen at runtime.
+///
+/// Pieces are added in the order they appear in the query, either as SQL
+/// with [`push_sql`](#method.push_sql), or as any `QueryFragment` with
+/// [`push`](#method.push). Bind parameters of the fragments are sent in the
+/// order the fragments were pushed, and placeholders are numbered to match.
+/// The collected pieces can be run with `execute`, or loaded once the SQL type
+/// of the result is given with [`into_query`](#method.into_query).
+///
+/// This is the most general escape hatch for dynamic queries, and it comes at
+/// the cost of all of Diesel's checks. Diesel cannot verify that the pieces
+/// form valid SQL, that the tables of the columns are part of the query, or
+/// that the query returns the type passed to `into_query`. Mistakes surface as
+/// errors from the database or as deserialization errors. Prefer
+/// [`into_boxed`](../query_dsl/trait.BoxedDsl.html) whenever the SQL type of
+/// the query is known at compile time.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate diesel;
+/// # include!("../doctest_setup.rs");
+/// #
+/// # table! {
+/// #     users {
+/// #         id -> Integer,
+/// #         name -> VarChar,
+/// #     }
+/// # }
+/// #
+/// # fn main() {
+/// #     let connection = establish_connection();
+/// use diesel::query_builder::QueryBuilderCollector;
+/// use diesel::types::Text;
+///
+/// let name_filter = Some("Tess");
+///
+/// let mut query = QueryBuilderCollector::<DB>::new();
+/// query.push_sql("SELECT ");
+/// query.push(users::name);
+/// query.push_sql(" FROM users");
+/// if let Some(name) = name_filter {
+///     query.push_sql(" WHERE ");
+///     query.push(users::name.eq(name));
+/// }
+/// let names = query.into_query::<Text>().load::<String>(&connection);
+/// assert_eq!(Ok(vec!["Tess".to_string()]), names);
+/// # }
+/// ```
+pub struct QueryBuilderCollector<'a, DB> {
+    fragments: Vec<Box<QueryFragment<DB> + 'a>>,
+}
+
+impl<'a, DB: Backend> QueryBuilderCollector<'a, DB> {
+    /// Creates a collector with no pieces.
+    pub fn new() -> Self {
+        QueryBuilderCollector {
+            fragments: Vec::new(),
+        }
+    }
+
+    /// Appends literal SQL to the query. The SQL is used exactly as given, so
+    /// it must never contain input from users.
+    pub fn push_sql(&mut self, sql: &str) -> &mut Self {
+        self.push(RawSql(sql.into()))
+    }
+
+    /// Appends a fragment, such as a column, an expression, or a boxed
+    /// `QueryFragment`, to the query.
+    pub fn push<T>(&mut self, fragment: T) -> &mut Self
+    where
+        T: QueryFragment<DB> + 'a,
+    {
+        self.fragments.push(Box::new(fragment));
+        self
+    }
+
+    /// Gives the query the SQL type `ST`, so that it can be loaded. Diesel
+    /// cannot check that the query actually returns this type.
+    pub fn into_query<ST>(self) -> CollectedQuery<'a, DB, ST> {
+        CollectedQuery {
+            collector: self,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, DB: Backend> Default for QueryBuilderCollector<'a, DB> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, DB: Backend> QueryFragment<DB> for QueryBuilderCollector<'a, DB> {
+    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+        for fragment in &self.fragments {
+            fragment.walk_ast(out.reborrow())?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a, DB> QueryId for QueryBuilderCollector<'a, DB> {
+    type QueryId = ();
+
+    const HAS_STATIC_QUERY_ID: bool = false;
+}
+
+#[allow(missing_debug_implementations)]
+/// A query assembled with a
+/// [`QueryBuilderCollector`](struct.QueryBuilderCollector.html). Constructed
+/// by [`into_query`](struct.QueryBuilderCollector.html#method.into_query).
+pub struct CollectedQuery<'a, DB, ST> {
+    collector: QueryBuilderCollector<'a, DB>,
+    _marker: PhantomData<ST>,
+}
+
+impl<'a, DB, ST> Query for CollectedQuery<'a, DB, ST>
+where
+    DB: Backend + HasSqlType<ST>,
+{
+    type SqlType = ST;
+}
+
+impl<'a, DB, ST> QueryFragment<DB> for CollectedQuery<'a, DB, ST>
+where
+    DB: Backend,
+{
+    fn walk_ast(&self, out: AstPass<DB>) -> QueryResult<()> {
+        self.collector.walk_ast(out)
+    }
+}
+
+impl<'a, DB, ST> QueryId for CollectedQuery<'a, DB, ST> {
+    type QueryId = ();
+
+    const HAS_STATIC_QUERY_ID: bool = false;
+}
+
+struct RawSql(String);
+
+impl<DB: Backend> QueryFragment<DB> for RawSql {
+    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+        out.unsafe_to_cache_prepared();
+        out.push_sql_verbatim(&self.0);
+        Ok(())
+    }
+}
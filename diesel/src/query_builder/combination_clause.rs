@@ -0,0 +1,328 @@
+use backend::Backend;
+use expression::{AppearsOnTable, AsExpression};
+use expression::operators::{Asc, Desc};
+use query_builder::*;
+use query_builder::distinct_clause::AtLeastOnce;
+use query_builder::limit_clause::{LimitClause, NoLimitClause};
+use query_builder::nodes::Identifier;
+use query_builder::offset_clause::{NoOffsetClause, OffsetClause};
+use query_builder::order_clause::{NoOrderClause, OrderClause};
+use query_builder::select_clause::SelectClauseExpression;
+use query_dsl::{LimitDsl, OffsetDsl, OrderDsl};
+use query_source::{Column, QuerySource};
+use result::QueryResult;
+use types::BigInt;
+
+/// Two queries combined with a set operation, such as `UNION`. Constructed by
+/// the methods of [`CombineDsl`](../query_dsl/trait.CombineDsl.html).
+///
+/// `.order`, `.limit` and `.offset` apply to the rows of the combination,
+/// rather than to the right hand query. To order the rows, the combination is
+/// selected from as a subquery, which is named after the table of the left
+/// hand query. `.order` can therefore only be given the columns selected by the
+/// left hand query (see
+/// [`ValidOrderTermForCombination`](trait.ValidOrderTermForCombination.html)),
+/// and that table must not be in a schema.
+#[derive(Debug, Clone, Copy)]
+#[must_use = "Queries are only executed when calling `load`, `get_result` or similar."]
+pub struct CombinationClause<
+    Combinator,
+    Rule,
+    Source,
+    Rhs,
+    Order = NoOrderClause,
+    Limit = NoLimitClause,
+    Offset = NoOffsetClause,
+> {
+    combinator: Combinator,
+    duplicate_rule: Rule,
+    source: Source,
+    rhs: Rhs,
+    order: Order,
+    limit: Limit,
+    offset: Offset,
+}
+
+impl<Combinator, Rule, Source, Rhs> CombinationClause<Combinator, Rule, Source, Rhs> {
+    #[doc(hidden)]
+    pub fn new(combinator: Combinator, duplicate_rule: Rule, source: Source, rhs: Rhs) -> Self {
+        CombinationClause {
+            combinator: combinator,
+            duplicate_rule: duplicate_rule,
+            source: source,
+            rhs: rhs,
+            order: NoOrderClause,
+            limit: NoLimitClause,
+            offset: NoOffsetClause,
+        }
+    }
+}
+
+impl<Combinator, Rule, Source, Rhs, O, L, Of>
+    CombinationClause<Combinator, Rule, Source, Rhs, O, L, Of> {
+    fn walk_combination<DB>(&self, mut out: AstPass<DB>) -> QueryResult<()>
+    where
+        DB: Backend,
+        Combinator: QueryFragment<DB>,
+        Rule: QueryFragment<DB>,
+        Source: QueryFragment<DB>,
+        Rhs: QueryFragment<DB>,
+    {
+        self.source.walk_ast(out.reborrow())?;
+        self.combinator.walk_ast(out.reborrow())?;
+        self.duplicate_rule.walk_ast(out.reborrow())?;
+        out.push_sql(" ");
+        self.rhs.walk_ast(out.reborrow())?;
+        Ok(())
+    }
+}
+
+impl<Combinator, Rule, Source, Rhs, O, L, Of> Query
+    for CombinationClause<Combinator, Rule, Source, Rhs, O, L, Of>
+where
+    Source: Query,
+{
+    type SqlType = Source::SqlType;
+}
+
+impl<Combinator, Rule, Source, Rhs, L, Of, DB> QueryFragment<DB>
+    for CombinationClause<Combinator, Rule, Source, Rhs, NoOrderClause, L, Of>
+where
+    DB: Backend,
+    Combinator: QueryFragment<DB>,
+    Rule: QueryFragment<DB>,
+    Source: QueryFragment<DB>,
+    Rhs: QueryFragment<DB>,
+    L: QueryFragment<DB>,
+    Of: QueryFragment<DB>,
+{
+    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+        self.walk_combination(out.reborrow())?;
+        self.limit.walk_ast(out.reborrow())?;
+        self.offset.walk_ast(out.reborrow())?;
+        Ok(())
+    }
+}
+
+impl<Combinator, Rule, F, S, D, W, G, Rhs, Expr, L, Of, DB> QueryFragment<DB>
+    for CombinationClause<
+        Combinator,
+        Rule,
+        SelectStatement<F, S, D, W, NoOrderClause, NoLimitClause, NoOffsetClause, G>,
+        Rhs,
+        OrderClause<Expr>,
+        L,
+        Of,
+    >
+where
+    DB: Backend,
+    Combinator: QueryFragment<DB>,
+    Rule: QueryFragment<DB>,
+    F: QuerySource<FromClause = Identifier<'static>>,
+    SelectStatement<F, S, D, W, NoOrderClause, NoLimitClause, NoOffsetClause, G>: QueryFragment<DB>,
+    Rhs: QueryFragment<DB>,
+    Expr: QueryFragment<DB>,
+    L: QueryFragment<DB>,
+    Of: QueryFragment<DB>,
+{
+    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+        out.push_sql("SELECT * FROM (");
+        self.walk_combination(out.reborrow())?;
+        out.push_sql(") AS ");
+        self.source.from.from_clause().walk_ast(out.reborrow())?;
+        self.order.walk_ast(out.reborrow())?;
+        self.limit.walk_ast(out.reborrow())?;
+        self.offset.walk_ast(out.reborrow())?;
+        Ok(())
+    }
+}
+
+impl_query_id!(CombinationClause<Combinator, Rule, Source, Rhs, Order, Limit, Offset>);
+
+impl<ST, Combinator, Rule, F, S, D, W, G, Rhs, O, L, Of, Expr> OrderDsl<Expr>
+    for CombinationClause<
+        Combinator,
+        Rule,
+        SelectStatement<F, S, D, W, NoOrderClause, NoLimitClause, NoOffsetClause, G>,
+        Rhs,
+        O,
+        L,
+        Of,
+    >
+where
+    Expr: AppearsOnTable<F> + ValidOrderTermForCombination<S::Selection>,
+    F: QuerySource<FromClause = Identifier<'static>>,
+    S: SelectClauseExpression<F>,
+    Self: AsQuery<SqlType = ST>,
+    CombinationClause<
+        Combinator,
+        Rule,
+        SelectStatement<F, S, D, W, NoOrderClause, NoLimitClause, NoOffsetClause, G>,
+        Rhs,
+        OrderClause<Expr>,
+        L,
+        Of,
+    >: AsQuery<SqlType = ST>,
+{
+    type Output = CombinationClause<
+        Combinator,
+        Rule,
+        SelectStatement<F, S, D, W, NoOrderClause, NoLimitClause, NoOffsetClause, G>,
+        Rhs,
+        OrderClause<Expr>,
+        L,
+        Of,
+    >;
+
+    fn order(self, expr: Expr) -> Self::Output {
+        CombinationClause {
+            combinator: self.combinator,
+            duplicate_rule: self.duplicate_rule,
+            source: self.source,
+            rhs: self.rhs,
+            order: OrderClause(expr),
+            limit: self.limit,
+            offset: self.offset,
+        }
+    }
+}
+
+/// Implemented by the terms of an `ORDER BY` clause which can be used on a
+/// combination whose left hand query selects `Selection`.
+///
+/// The rows of a combination only have the columns selected by its left hand
+/// query, so this is implemented for those columns, for `.asc()` and
+/// `.desc()` of them, and for tuples of valid terms.
+pub trait ValidOrderTermForCombination<Selection> {}
+
+impl<T, Selection> ValidOrderTermForCombination<Selection> for T
+where
+    T: Column,
+    Selection: SelectsColumn<T>,
+    Selection::Count: AtLeastOnce,
+{
+}
+
+impl<T, Selection> ValidOrderTermForCombination<Selection> for Asc<T>
+where
+    T: ValidOrderTermForCombination<Selection>,
+{
+}
+
+impl<T, Selection> ValidOrderTermForCombination<Selection> for Desc<T>
+where
+    T: ValidOrderTermForCombination<Selection>,
+{
+}
+
+type BigIntExpression = <i64 as AsExpression<BigInt>>::Expression;
+
+impl<ST, Combinator, Rule, Source, Rhs, O, L, Of> LimitDsl
+    for CombinationClause<Combinator, Rule, Source, Rhs, O, L, Of>
+where
+    Self: AsQuery<SqlType = ST>,
+    CombinationClause<Combinator, Rule, Source, Rhs, O, LimitClause<BigIntExpression>, Of>:
+        AsQuery<SqlType = ST>,
+{
+    type Output =
+        CombinationClause<Combinator, Rule, Source, Rhs, O, LimitClause<BigIntExpression>, Of>;
+
+    fn limit(self, limit: i64) -> Self::Output {
+        CombinationClause {
+            combinator: self.combinator,
+            duplicate_rule: self.duplicate_rule,
+            source: self.source,
+            rhs: self.rhs,
+            order: self.order,
+            limit: LimitClause(AsExpression::<BigInt>::as_expression(limit)),
+            offset: self.offset,
+        }
+    }
+}
+
+impl<ST, Combinator, Rule, Source, Rhs, O, L, Of> OffsetDsl
+    for CombinationClause<Combinator, Rule, Source, Rhs, O, L, Of>
+where
+    Self: AsQuery<SqlType = ST>,
+    CombinationClause<Combinator, Rule, Source, Rhs, O, L, OffsetClause<BigIntExpression>>:
+        AsQuery<SqlType = ST>,
+{
+    type Output =
+        CombinationClause<Combinator, Rule, Source, Rhs, O, L, OffsetClause<BigIntExpression>>;
+
+    fn offset(self, offset: i64) -> Self::Output {
+        CombinationClause {
+            combinator: self.combinator,
+            duplicate_rule: self.duplicate_rule,
+            source: self.source,
+            rhs: self.rhs,
+            order: self.order,
+            limit: self.limit,
+            offset: OffsetClause(AsExpression::<BigInt>::as_expression(offset)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct Union;
+
+impl<DB: Backend> QueryFragment<DB> for Union {
+    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+        out.push_sql(" UNION");
+        Ok(())
+    }
+}
+
+impl_query_id!(Union);
+
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct Intersect;
+
+impl<DB: Backend> QueryFragment<DB> for Intersect {
+    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+        out.push_sql(" INTERSECT");
+        Ok(())
+    }
+}
+
+impl_query_id!(Intersect);
+
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct Except;
+
+impl<DB: Backend> QueryFragment<DB> for Except {
+    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+        out.push_sql(" EXCEPT");
+        Ok(())
+    }
+}
+
+impl_query_id!(Except);
+
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct Distinct;
+
+impl<DB: Backend> QueryFragment<DB> for Distinct {
+    fn walk_ast(&self, _: AstPass<DB>) -> QueryResult<()> {
+        Ok(())
+    }
+}
+
+impl_query_id!(Distinct);
+
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct All;
+
+impl<DB: Backend> QueryFragment<DB> for All {
+    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+        out.push_sql(" ALL");
+        Ok(())
+    }
+}
+
+impl_query_id!(All);
@@ -1,3 +1,6 @@
+#[cfg(feature = "postgres")]
+mod using;
+
 use backend::Backend;
 use expression::SelectableExpression;
 use query_builder::*;
@@ -5,6 +8,9 @@ use query_builder::returning_clause::*;
 use query_source::Table;
 use result::QueryResult;
 
+#[cfg(feature = "postgres")]
+pub use self::using::DeleteUsingStatement;
+
 #[derive(Debug)]
 pub struct DeleteStatement<T, U, Ret = NoReturningClause> {
     table: T,
@@ -73,7 +79,7 @@ impl<T, U> DeleteStatement<T, U, NoReturningClause> {
     ///
     /// ```rust
     /// # #[macro_use] extern crate diesel;
-    /// # include!("../doctest_setup.rs");
+    /// # include!("../../doctest_setup.rs");
     /// #
     /// # table! {
     /// #     users {
@@ -94,6 +100,39 @@ impl<T, U> DeleteStatement<T, U, NoReturningClause> {
     /// # #[cfg(not(feature = "postgres"))]
     /// # fn main() {}
     /// ```
+    ///
+    /// ### Deleting several records and returning their primary keys:
+    ///
+    /// This is useful to find out which rows were deleted, for example to
+    /// invalidate cached copies of them. The type which is loaded must match
+    /// the SQL type of the primary key, such as `i32` for an `Integer` column.
+    /// The rows are returned in no particular order.
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate diesel;
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # table! {
+    /// #     users {
+    /// #         id -> Integer,
+    /// #         name -> VarChar,
+    /// #     }
+    /// # }
+    /// #
+    /// # #[cfg(feature = "postgres")]
+    /// # fn main() {
+    /// #     use self::users::dsl::*;
+    /// #     let connection = establish_connection();
+    /// let mut deleted_ids = diesel::delete(users.filter(id.lt(3)))
+    ///     .returning(id)
+    ///     .get_results::<i32>(&connection)
+    ///     .unwrap();
+    /// deleted_ids.sort();
+    /// assert_eq!(vec![1, 2], deleted_ids);
+    /// # }
+    /// # #[cfg(not(feature = "postgres"))]
+    /// # fn main() {}
+    /// ```
     pub fn returning<E>(self, returns: E) -> DeleteStatement<T, U, ReturningClause<E>>
     where
         E: SelectableExpression<T>,
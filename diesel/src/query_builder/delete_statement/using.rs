@@ -0,0 +1,118 @@
+use expression::{AppearsOnTable, NonAggregate};
+use pg::Pg;
+use query_builder::*;
+use query_builder::returning_clause::NoReturningClause;
+use query_builder::where_clause::WhereAnd;
+use query_source::Table;
+use query_source::joins::{Inner, Join};
+use result::QueryResult;
+use super::DeleteStatement;
+use types::Bool;
+
+impl<T, U> DeleteStatement<T, U, NoReturningClause> {
+    /// Adds a `USING` clause, so that the rows to delete can be chosen by
+    /// comparing them with the rows of another table. Conditions referencing
+    /// both tables are given with
+    /// [`filter`](struct.DeleteUsingStatement.html#method.filter).
+    ///
+    /// The conditions are checked the same way as the `ON` clause of an
+    /// explicit join, so the two tables must be joinable, or allowed to
+    /// appear together with
+    /// [`enable_multi_table_joins!`](../macro.enable_multi_table_joins.html).
+    /// This is only supported on PostgreSQL.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate diesel;
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// table! {
+    ///     users {
+    ///         id -> Integer,
+    ///         name -> VarChar,
+    ///     }
+    /// }
+    ///
+    /// table! {
+    ///     posts {
+    ///         id -> Integer,
+    ///         user_id -> Integer,
+    ///         title -> VarChar,
+    ///     }
+    /// }
+    ///
+    /// enable_multi_table_joins!(users, posts);
+    ///
+    /// # fn main() {
+    /// #     let connection = establish_connection();
+    /// // DELETE FROM posts USING users
+    /// // WHERE posts.user_id = users.id AND users.name = 'Sean'
+    /// let deleted_rows = diesel::delete(posts::table)
+    ///     .using(users::table)
+    ///     .filter(posts::user_id.eq(users::id))
+    ///     .filter(users::name.eq("Sean"))
+    ///     .execute(&connection);
+    /// assert_eq!(Ok(2), deleted_rows);
+    ///
+    /// let titles = posts::table.select(posts::title).load::<String>(&connection);
+    /// assert_eq!(Ok(vec!["My first post too".to_string()]), titles);
+    /// # }
+    /// ```
+    pub fn using<S: Table>(self, source: S) -> DeleteUsingStatement<T, S, U> {
+        DeleteUsingStatement {
+            table: self.table,
+            source: source,
+            where_clause: self.where_clause,
+        }
+    }
+}
+
+/// A `DELETE` statement with a `USING` clause. Constructed by calling `using`
+/// on the statement returned by [`delete`](../fn.delete.html).
+#[derive(Debug)]
+pub struct DeleteUsingStatement<T, S, U> {
+    table: T,
+    source: S,
+    where_clause: U,
+}
+
+impl<T, S, U> DeleteUsingStatement<T, S, U> {
+    /// Adds a condition to the `WHERE` clause of the statement, which can
+    /// reference the columns of both tables. It is combined with the
+    /// existing conditions using `AND`.
+    pub fn filter<Predicate>(
+        self,
+        predicate: Predicate,
+    ) -> DeleteUsingStatement<T, S, U::Output>
+    where
+        U: WhereAnd<Predicate>,
+        Predicate: AppearsOnTable<Join<T, S, Inner>, SqlType = Bool> + NonAggregate,
+    {
+        DeleteUsingStatement {
+            table: self.table,
+            source: self.source,
+            where_clause: self.where_clause.and(predicate),
+        }
+    }
+}
+
+impl<T, S, U> QueryFragment<Pg> for DeleteUsingStatement<T, S, U>
+where
+    T: Table,
+    T::FromClause: QueryFragment<Pg>,
+    S: Table,
+    S::FromClause: QueryFragment<Pg>,
+    U: QueryFragment<Pg>,
+{
+    fn walk_ast(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+        out.push_sql("DELETE FROM ");
+        self.table.from_clause().walk_ast(out.reborrow())?;
+        out.push_sql(" USING ");
+        self.source.from_clause().walk_ast(out.reborrow())?;
+        self.where_clause.walk_ast(out.reborrow())?;
+        Ok(())
+    }
+}
+
+impl_query_id!(DeleteUsingStatement<T, S, U>);
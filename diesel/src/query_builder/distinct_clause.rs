@@ -1,5 +1,9 @@
 use backend::Backend;
+use expression::nullable::Nullable;
+use expression::operators::{Asc, Desc};
 use query_builder::*;
+use query_builder::order_clause::{NoOrderClause, NullsLastEverywhere, OrderClause};
+use query_source::{AppearsInFromClause, Column, Never, Once, Succ};
 use result::QueryResult;
 
 #[derive(Debug, Clone, Copy)]
@@ -23,3 +27,130 @@ impl<DB: Backend> QueryFragment<DB> for DistinctClause {
 }
 
 impl_query_id!(DistinctClause);
+
+/// Implemented by the distinct clauses which can be used with the order
+/// clause `O` and the select list `Selection` on the backend `DB`.
+///
+/// PostgreSQL and MySQL require the columns in the `ORDER BY` clause of a
+/// `SELECT DISTINCT` query to appear in its select list, so on those backends
+/// a `DISTINCT` clause requires `O` to implement
+/// [`ValidOrderingForDistinct`](trait.ValidOrderingForDistinct.html). SQLite
+/// accepts these queries, and queries without a `DISTINCT` clause can be
+/// ordered by anything.
+///
+/// This is checked when a query is run rather than when it is built, as the
+/// backend is only known then. Boxed queries are not checked.
+pub trait ValidDistinctOrdering<DB: Backend, O, Selection> {}
+
+impl<DB: Backend, O, Selection> ValidDistinctOrdering<DB, O, Selection> for NoDistinctClause {}
+
+/// Implemented by the `ORDER BY` clauses which can be used in a `SELECT
+/// DISTINCT` query with the select list `Selection` on PostgreSQL and MySQL.
+///
+/// Each ordered column must be selected. Other expressions are not checked.
+pub trait ValidOrderingForDistinct<Selection> {}
+
+impl<Selection> ValidOrderingForDistinct<Selection> for NoOrderClause {}
+
+impl<Expr, Selection> ValidOrderingForDistinct<Selection> for OrderClause<Expr>
+where
+    Expr: ValidOrderTermForDistinct<Selection>,
+{
+}
+
+/// Implemented by the terms of an `ORDER BY` clause which can be used in a
+/// `SELECT DISTINCT` query with the select list `Selection`.
+///
+/// This is implemented for columns which appear in `Selection`, for `.asc()`
+/// and `.desc()` of those, and for tuples of valid terms. Every other
+/// expression implements it unconditionally.
+pub trait ValidOrderTermForDistinct<Selection> {}
+
+impl<T, Selection> ValidOrderTermForDistinct<Selection> for T
+where
+    T: Column,
+    Selection: SelectsColumn<T>,
+    Selection::Count: AtLeastOnce,
+{
+}
+
+impl<T, Selection> ValidOrderTermForDistinct<Selection> for Asc<T>
+where
+    T: ValidOrderTermForDistinct<Selection>,
+{
+}
+
+impl<T, Selection> ValidOrderTermForDistinct<Selection> for Desc<T>
+where
+    T: ValidOrderTermForDistinct<Selection>,
+{
+}
+
+impl<T, Selection> ValidOrderTermForDistinct<Selection> for Nullable<T>
+where
+    T: ValidOrderTermForDistinct<Selection>,
+{
+}
+
+impl<T, Selection> ValidOrderTermForDistinct<Selection> for NullsLastEverywhere<T>
+where
+    T: ValidOrderTermForDistinct<Selection>,
+{
+}
+
+/// Counts how many times the column `C` appears in a select list. Only the
+/// column itself is counted, so expressions which use the column, such as
+/// `lower(name)`, count as `Never`.
+pub trait SelectsColumn<C> {
+    type Count;
+}
+
+impl<T, C> SelectsColumn<C> for T
+where
+    T: Column,
+    C: Column,
+    C::Table: AppearsInFromClause<T::Table>,
+    <C::Table as AppearsInFromClause<T::Table>>::Count: SameColumnCount<T, C>,
+{
+    type Count = <<C::Table as AppearsInFromClause<T::Table>>::Count as SameColumnCount<
+        T,
+        C,
+    >>::Count;
+}
+
+impl<T, C> SelectsColumn<C> for Nullable<T>
+where
+    T: SelectsColumn<C>,
+{
+    type Count = T::Count;
+}
+
+/// Implemented by `table!` for every pair of columns of the same table. The
+/// `Count` is `Once` when both are the same column, and `Never` otherwise.
+#[doc(hidden)]
+pub trait IsSameColumn<C> {
+    type Count;
+}
+
+/// Compares two columns, given whether they belong to the same table. Columns
+/// of different tables are never the same column.
+#[doc(hidden)]
+pub trait SameColumnCount<Left, Right> {
+    type Count;
+}
+
+impl<Left, Right> SameColumnCount<Left, Right> for Never {
+    type Count = Never;
+}
+
+impl<Left, Right> SameColumnCount<Left, Right> for Once
+where
+    Left: IsSameColumn<Right>,
+{
+    type Count = Left::Count;
+}
+
+#[doc(hidden)]
+pub trait AtLeastOnce {}
+
+impl<T> AtLeastOnce for Succ<T> {}
@@ -1,5 +1,7 @@
 use backend::Backend;
 use query_builder::{AstPass, QueryFragment};
+use query_builder::nodes::Identifier;
+use query_source::{AppearsInFromClause, Once, QuerySource, Table};
 use result::QueryResult;
 
 #[derive(Debug, Clone, Copy)]
@@ -14,6 +16,149 @@ impl<DB: Backend> QueryFragment<DB> for NoForUpdateClause {
 impl_query_id!(NoForUpdateClause);
 
 #[derive(Debug, Clone, Copy)]
-pub struct ForUpdateClause;
+pub struct ForUpdateClause<Of = NoOfClause, Modifier = NoLockModifier> {
+    pub(crate) of: Of,
+    // Only PostgreSQL supports lock modifiers
+    #[cfg_attr(not(feature = "postgres"), allow(dead_code))]
+    pub(crate) modifier: Modifier,
+}
+
+impl ForUpdateClause {
+    pub fn new() -> Self {
+        ForUpdateClause {
+            of: NoOfClause,
+            modifier: NoLockModifier,
+        }
+    }
+}
+
+impl<Tables> ForUpdateClause<OfClause<Tables>> {
+    pub fn of(tables: Tables) -> Self {
+        ForUpdateClause {
+            of: OfClause(tables),
+            modifier: NoLockModifier,
+        }
+    }
+}
+
+impl<Of> ForUpdateClause<Of> {
+    pub fn skip_locked(self) -> ForUpdateClause<Of, SkipLocked> {
+        ForUpdateClause {
+            of: self.of,
+            modifier: SkipLocked,
+        }
+    }
+}
+
+impl_query_id!(ForUpdateClause<Of, Modifier>);
+
+#[derive(Debug, Clone, Copy)]
+pub struct NoOfClause;
+
+impl<DB: Backend> QueryFragment<DB> for NoOfClause {
+    fn walk_ast(&self, _: AstPass<DB>) -> QueryResult<()> {
+        Ok(())
+    }
+}
+
+impl_query_id!(NoOfClause);
+
+/// The tables named in `FOR UPDATE OF`.
+#[derive(Debug, Clone, Copy)]
+pub struct OfClause<Tables>(Tables);
+
+impl<Tables, DB> QueryFragment<DB> for OfClause<Tables>
+where
+    DB: Backend,
+    Tables: LockedTables,
+{
+    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+        out.push_sql(" OF ");
+        self.0.walk_table_names(out.reborrow())
+    }
+}
+
+impl_query_id!(OfClause<Tables>);
+
+#[derive(Debug, Clone, Copy)]
+pub struct NoLockModifier;
+
+impl<DB: Backend> QueryFragment<DB> for NoLockModifier {
+    fn walk_ast(&self, _: AstPass<DB>) -> QueryResult<()> {
+        Ok(())
+    }
+}
+
+impl_query_id!(NoLockModifier);
+
+#[derive(Debug, Clone, Copy)]
+pub struct SkipLocked;
+
+impl<DB: Backend> QueryFragment<DB> for SkipLocked {
+    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+        out.push_sql(" SKIP LOCKED");
+        Ok(())
+    }
+}
+
+impl_query_id!(SkipLocked);
+
+/// A table, or a tuple of tables, which can be named in `FOR UPDATE OF`.
+/// PostgreSQL requires the tables to be named the way they appear in the
+/// from clause, so tables with a schema cannot be used.
+pub trait LockedTables {
+    #[doc(hidden)]
+    fn walk_table_names<DB: Backend>(&self, out: AstPass<DB>) -> QueryResult<()>;
+}
+
+/// Implemented when every table of `Tables` appears exactly once in the from
+/// clause `QS`.
+pub trait LockedTablesAppearIn<QS>: LockedTables {}
+
+impl<T> LockedTables for T
+where
+    T: Table + QuerySource<FromClause = Identifier<'static>>,
+{
+    fn walk_table_names<DB: Backend>(&self, out: AstPass<DB>) -> QueryResult<()> {
+        self.from_clause().walk_ast(out)
+    }
+}
+
+impl<T, QS> LockedTablesAppearIn<QS> for T
+where
+    T: LockedTables + Table,
+    QS: AppearsInFromClause<T, Count = Once>,
+{
+}
+
+macro_rules! locked_tables_tuples {
+    ($first:ident, $($table:ident),+) => {
+        impl<$first, $($table),+> LockedTables for ($first, $($table),+)
+        where
+            $first: LockedTables,
+            $($table: LockedTables,)+
+        {
+            #[allow(non_snake_case)]
+            fn walk_table_names<DB: Backend>(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+                let (ref $first, $(ref $table),+) = *self;
+                $first.walk_table_names(out.reborrow())?;
+                $(
+                    out.push_sql(", ");
+                    $table.walk_table_names(out.reborrow())?;
+                )+
+                Ok(())
+            }
+        }
+
+        impl<QS, $first, $($table),+> LockedTablesAppearIn<QS> for ($first, $($table),+)
+        where
+            $first: LockedTablesAppearIn<QS>,
+            $($table: LockedTablesAppearIn<QS>,)+
+        {
+        }
+    }
+}
 
-impl_query_id!(ForUpdateClause);
+locked_tables_tuples!(A, B);
+locked_tables_tuples!(A, B, C);
+locked_tables_tuples!(A, B, C, D);
@@ -1,8 +1,13 @@
+use associations::{Cascade, HasTable};
+use connection::Connection;
 use dsl::Select;
 use expression::Expression;
-use query_dsl::SelectDsl;
+use query_dsl::{ExecuteDsl, SelectDsl};
+use query_source::Table;
+use result::QueryResult;
 use super::delete_statement::DeleteStatement;
 use super::insert_statement::{DefaultValues, Insert};
+use super::truncate_statement::TruncateStatement;
 use super::{IncompleteInsertStatement, IncompleteUpdateStatement, IntoUpdateTarget,
             SelectStatement};
 
@@ -144,6 +149,60 @@ pub fn delete<T: IntoUpdateTarget>(source: T) -> DeleteStatement<T::Table, T::Wh
     DeleteStatement::new(target.table, target.where_clause)
 }
 
+/// Creates a `TRUNCATE TABLE` statement, which removes all rows from the given
+/// table. Because this function has a very generic name, it is not exported by
+/// default.
+///
+/// See [`TruncateStatement`](query_builder/struct.TruncateStatement.html)
+/// for details on how this is handled by each backend.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate diesel;
+/// # include!("../doctest_setup.rs");
+/// #
+/// # table! {
+/// #     users {
+/// #         id -> Integer,
+/// #         name -> VarChar,
+/// #     }
+/// # }
+/// #
+/// # fn main() {
+/// #     use self::users::dsl::*;
+/// #     let connection = establish_connection();
+/// diesel::truncate(users).restart_identity().execute(&connection).unwrap();
+/// assert_eq!(Ok(0), users.count().get_result(&connection));
+///
+/// diesel::insert(&name.eq("Jim")).into(users).execute(&connection).unwrap();
+/// assert_eq!(Ok(1), users.select(id).first(&connection));
+/// # }
+/// ```
+pub fn truncate<T: Table>(table: T) -> TruncateStatement<T> {
+    TruncateStatement::new(table)
+}
+
+/// Deletes a record after deleting the rows which depend on it, as declared
+/// by its [`Cascade`](associations/trait.Cascade.html) implementation.
+///
+/// Everything is deleted within a single transaction, so either all of the
+/// rows are deleted or none of them are. Returns the number of rows deleted by
+/// the final statement, which deletes the record itself.
+pub fn delete_cascade<'a, T, Conn>(entity: &'a T, conn: &Conn) -> QueryResult<usize>
+where
+    Conn: Connection,
+    T: Cascade<Conn>,
+    &'a T: IntoUpdateTarget,
+    DeleteStatement<<&'a T as HasTable>::Table, <&'a T as IntoUpdateTarget>::WhereClause>:
+        ExecuteDsl<Conn>,
+{
+    conn.transaction(|| {
+        try!(entity.delete_dependents(conn));
+        delete(entity).execute(conn)
+    })
+}
+
 /// Creates an insert statement. Will add the given data to a table. This
 /// function is not exported by default. As with other commands, the resulting
 /// query can return the inserted rows if you choose.
@@ -1 +1,48 @@
 simple_clause!(NoGroupByClause, GroupByClause, " GROUP BY ");
+
+use result::Error::QueryBuilderError;
+use super::AliasReference;
+
+/// A `GROUP BY` clause followed by `HAVING predicate`.
+///
+/// PostgreSQL does not allow aliases from the select clause to be used in
+/// `HAVING`, so each backend decides how aliased expressions in the predicate
+/// are written.
+#[derive(Debug, Clone, Copy)]
+pub struct HavingClause<GroupBy, Predicate> {
+    group_by: GroupBy,
+    predicate: Predicate,
+}
+
+impl<GroupBy, Predicate> HavingClause<GroupBy, Predicate> {
+    pub fn new(group_by: GroupBy, predicate: Predicate) -> Self {
+        HavingClause {
+            group_by: group_by,
+            predicate: predicate,
+        }
+    }
+
+    pub fn walk_with_aliases<DB>(
+        &self,
+        mut out: AstPass<DB>,
+        aliases: AliasReference,
+    ) -> QueryResult<()>
+    where
+        DB: Backend,
+        GroupBy: QueryFragment<DB>,
+        Predicate: QueryFragment<DB>,
+    {
+        // Only boxed queries can get here without a `GROUP BY` clause
+        if self.group_by.is_noop()? {
+            return Err(QueryBuilderError(
+                "HAVING can only be used on a query with a GROUP BY clause".into(),
+            ));
+        }
+        self.group_by.walk_ast(out.reborrow())?;
+        out.push_sql(" HAVING ");
+        self.predicate.walk_ast(out.referencing_aliases(aliases))?;
+        Ok(())
+    }
+}
+
+impl_query_id!(HavingClause<GroupBy, Predicate>);
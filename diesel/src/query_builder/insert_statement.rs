@@ -4,7 +4,7 @@ use expression::{Expression, NonAggregate, SelectableExpression};
 use expression::operators::Eq;
 use insertable::{InsertValues, Insertable};
 use query_builder::*;
-use query_dsl::{ExecuteDsl, LoadDsl, LoadQuery};
+use query_dsl::{ExecuteDsl, LoadDsl, LoadIterQuery, LoadQuery};
 use query_source::{Column, Table};
 use result::QueryResult;
 use super::returning_clause::*;
@@ -70,9 +70,9 @@ where
 
 #[derive(Debug, Copy, Clone)]
 pub struct InsertStatement<T, U, Op = Insert, Ret = NoReturningClause> {
-    operator: Op,
-    target: T,
-    records: U,
+    pub(crate) operator: Op,
+    pub(crate) target: T,
+    pub(crate) records: U,
     returning: Ret,
 }
 
@@ -101,6 +101,7 @@ where
     U: Insertable<T, DB> + Copy,
     Op: QueryFragment<DB>,
     Ret: QueryFragment<DB>,
+    DefaultValuesClause: QueryFragment<DB>,
 {
     fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
         let values = self.records.values();
@@ -109,7 +110,7 @@ where
         out.push_sql(" INTO ");
         self.target.from_clause().walk_ast(out.reborrow())?;
         if self.records.values().is_noop() {
-            out.push_sql(" DEFAULT VALUES");
+            DefaultValuesClause.walk_ast(out.reborrow())?;
         } else {
             out.push_sql(" (");
             if let Some(builder) = out.reborrow().query_builder() {
@@ -125,6 +126,13 @@ where
 
 impl_query_id!(noop: InsertStatement<T, U, Op, Ret>);
 
+/// The end of an insert statement for a row which only has default values.
+/// This is `DEFAULT VALUES` on PostgreSQL and SQLite, and `() VALUES ()` on
+/// MySQL, which does not support `DEFAULT VALUES`.
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct DefaultValuesClause;
+
 impl<T, U, Op> AsQuery for InsertStatement<T, U, Op, NoReturningClause>
 where
     T: Table,
@@ -306,6 +314,24 @@ where
     }
 }
 
+impl<'a, 'b, T, U, V, Op, Ret, Conn> LoadIterQuery<'b, Conn, V>
+    for BatchInsertStatement<T, &'a [U], Op, Ret>
+where
+    InsertStatement<T, &'a [U], Op, Ret>: LoadIterQuery<'b, Conn, V>,
+    V: 'b,
+{
+    fn internal_load_iter(
+        self,
+        conn: &'b Conn,
+    ) -> QueryResult<Box<Iterator<Item = QueryResult<V>> + 'b>> {
+        if self.records.is_empty() {
+            Ok(Box::new(::std::iter::empty()))
+        } else {
+            self.into_insert_statement().internal_load_iter(conn)
+        }
+    }
+}
+
 impl<'a, T, U, Op, Ret, Conn> LoadDsl<Conn> for BatchInsertStatement<T, &'a [U], Op, Ret> {}
 
 #[derive(Debug, Copy, Clone)]
@@ -11,40 +11,63 @@ mod clause_macro;
 
 mod ast_pass;
 pub mod bind_collector;
+mod collector;
+#[doc(hidden)]
+pub mod combination_clause;
 mod debug_query;
 mod delete_statement;
 #[doc(hidden)]
 pub mod functions;
 #[doc(hidden)]
 pub mod nodes;
-mod distinct_clause;
+pub(crate) mod distinct_clause;
 pub(crate) mod for_update_clause;
-mod group_by_clause;
+pub(crate) mod group_by_clause;
 mod limit_clause;
 mod offset_clause;
-mod order_clause;
-mod returning_clause;
+pub(crate) mod order_clause;
+pub(crate) mod returning_clause;
 mod select_clause;
 mod select_statement;
+pub(crate) mod truncate_statement;
 pub mod where_clause;
+pub(crate) mod with_clause;
 pub mod insert_statement;
 pub mod update_statement;
 
 pub use self::ast_pass::AstPass;
+#[doc(hidden)]
+pub use self::ast_pass::AliasReference;
 pub use self::bind_collector::BindCollector;
+pub use self::collector::{CollectedQuery, QueryBuilderCollector};
+pub use self::combination_clause::{CombinationClause, ValidOrderTermForCombination};
 pub use self::debug_query::DebugQuery;
+pub use self::distinct_clause::{SelectsColumn, ValidDistinctOrdering, ValidOrderTermForDistinct,
+                                ValidOrderingForDistinct};
+#[doc(hidden)]
+pub use self::distinct_clause::IsSameColumn;
 pub use self::query_id::QueryId;
 #[doc(hidden)]
-pub use self::select_statement::{BoxedSelectStatement, SelectStatement};
+pub use self::select_statement::{BoxedSelectStatement, NoRowsWithoutMatches, SelectStatement,
+                                 ShortCircuitEmptyIn};
 #[doc(inline)]
 pub use self::update_statement::{AsChangeset, Changeset, IncompleteUpdateStatement,
                                  IntoUpdateTarget, UpdateStatement, UpdateTarget};
 #[doc(inline)]
 pub use self::insert_statement::IncompleteInsertStatement;
+#[doc(inline)]
+pub use self::truncate_statement::TruncateStatement;
+#[cfg(feature = "postgres")]
+#[doc(inline)]
+pub use self::delete_statement::DeleteUsingStatement;
+pub use self::with_clause::{with, WithClause, WithQuery};
 
+use std::borrow::Cow;
+use std::cmp;
 use std::error::Error;
 
 use backend::Backend;
+use result::Error::QueryBuilderError;
 use result::QueryResult;
 
 #[doc(hidden)]
@@ -58,20 +81,138 @@ pub type BuildQueryResult = Result<(), Box<Error + Send + Sync>>;
 /// [`QueryFragment`](trait.QueryFragment.html) manually.
 pub trait QueryBuilder<DB: Backend> {
     /// Add `sql` to the end of the query being constructed.
+    ///
+    /// Clauses are written with a leading space, such as `" WHERE "`. When
+    /// the query already ends with whitespace, the leading spaces of `sql` are
+    /// skipped, so that the query never contains two spaces between clauses.
     fn push_sql(&mut self, sql: &str);
 
+    /// Add `sql` to the end of the query being constructed, exactly as given.
+    /// This is used for SQL written by the user, such as a `sql` literal,
+    /// whose whitespace must not be changed.
+    ///
+    /// Builders whose `push_sql` changes whitespace must override this method.
+    /// By default, `sql` is passed to [`push_sql`](#tymethod.push_sql).
+    fn push_sql_verbatim(&mut self, sql: &str) {
+        self.push_sql(sql);
+    }
+
+    /// Add `sql` to the end of the query being constructed, followed by a
+    /// line break. This is useful when building SQL which is meant to be read,
+    /// such as a migration or a log message.
+    fn push_sql_line(&mut self, sql: &str) {
+        self.push_sql(sql);
+        self.push_sql("\n");
+    }
+
     /// Quote `identifier`, and add it to the end of the query being
     /// constructed.
+    ///
+    /// Returns an error if `identifier` contains a NUL byte or any other
+    /// control character, rather than producing invalid SQL.
     fn push_identifier(&mut self, identifier: &str) -> QueryResult<()>;
 
     /// Add a placeholder for a bind parameter to the end of the query being
     /// constructed.
     fn push_bind_param(&mut self);
 
-    /// Returns the constructed SQL query.
+    /// Add a placeholder for a bind parameter called `name` to the end of the
+    /// query being constructed.
+    ///
+    /// Builders for drivers which expect named placeholders, such as `:name`,
+    /// can override this method. By default the name is ignored, and the
+    /// placeholder written by [`push_bind_param`](#tymethod.push_bind_param)
+    /// is used.
+    fn push_bind_param_with_name(&mut self, name: &str) {
+        let _ = name;
+        self.push_bind_param();
+    }
+
+    /// Whether a placeholder can refer to a bind parameter which was already
+    /// added, with [`push_repeated_bind_param`](#method.push_repeated_bind_param).
+    /// When this is `false`, a bind parameter whose name is used more than
+    /// once gets a new placeholder, and its value is sent again, for each use.
+    const CAN_REPEAT_BIND_PARAMS: bool = false;
+
+    /// Add a placeholder which refers to the bind parameter called `name`,
+    /// which was already added with
+    /// [`push_bind_param_with_name`](#method.push_bind_param_with_name).
+    /// This is only called when `CAN_REPEAT_BIND_PARAMS` is `true`.
+    fn push_repeated_bind_param(&mut self, name: &str) {
+        self.push_bind_param_with_name(name);
+    }
+
+    /// Returns the constructed SQL query, without trailing whitespace unless
+    /// it was added with [`push_sql_verbatim`](#method.push_sql_verbatim).
     fn finish(self) -> String;
 }
 
+/// The SQL of a query being constructed. Whitespace is only collapsed between
+/// the fragments written by Diesel. SQL written by the user, such as a `sql`
+/// literal, is kept exactly as given.
+#[derive(Debug, Default)]
+pub(crate) struct SqlBuffer {
+    sql: String,
+    verbatim_end: usize,
+}
+
+impl SqlBuffer {
+    /// Appends `fragment`, skipping its leading spaces when the SQL already
+    /// ends with whitespace written by Diesel.
+    pub(crate) fn push_fragment(&mut self, fragment: &str) {
+        if self.sql.len() > self.verbatim_end && self.sql.ends_with(char::is_whitespace) {
+            self.sql.push_str(fragment.trim_left_matches(' '));
+        } else {
+            self.sql.push_str(fragment);
+        }
+    }
+
+    /// Appends `sql` without changing it.
+    pub(crate) fn push_verbatim(&mut self, sql: &str) {
+        self.sql.push_str(sql);
+        self.verbatim_end = self.sql.len();
+    }
+
+    /// Returns the SQL, without the trailing whitespace written by Diesel.
+    pub(crate) fn finish(mut self) -> String {
+        let len = self.sql.trim_right().len();
+        self.sql.truncate(cmp::max(len, self.verbatim_end));
+        self.sql
+    }
+}
+
+/// Identifiers come from table and column definitions, so a control character
+/// in one is a bug in the application (or a value which should have been a
+/// bind parameter). Quoting would not make them valid, as PostgreSQL rejects
+/// NUL bytes in a query entirely.
+pub(crate) fn check_identifier(identifier: &str) -> QueryResult<()> {
+    if identifier.chars().any(char::is_control) {
+        Err(QueryBuilderError(
+            format!("Identifier {:?} contains a control character", identifier).into(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Doubles each `quote` in `identifier`, so that it can be written between two
+/// quotes. Identifiers rarely contain quotes, so `identifier` is only copied
+/// when one has to be escaped.
+pub(crate) fn escape_identifier(identifier: &str, quote: char) -> Cow<str> {
+    if !identifier.contains(quote) {
+        return Cow::Borrowed(identifier);
+    }
+
+    let mut escaped = String::with_capacity(identifier.len() + 2);
+    for c in identifier.chars() {
+        if c == quote {
+            escaped.push(quote);
+        }
+        escaped.push(c);
+    }
+    Cow::Owned(escaped)
+}
+
 /// A complete SQL query with a return type. This can be a select statement, or
 /// a command such as `update` or `insert` with a `RETURNING` clause. Unlike
 /// [`Expression`](../expression/trait.Expression.html), types implementing this
@@ -134,6 +275,29 @@ pub trait QueryFragment<DB: Backend> {
         self.walk_ast(AstPass::is_safe_to_cache_prepared(&mut result))?;
         Ok(result)
     }
+
+    /// Does this fragment write nothing to the query?
+    ///
+    /// This is `true` for empty clauses, such as the `GROUP BY` clause of a
+    /// query which has not been grouped.
+    #[doc(hidden)]
+    fn is_noop(&self) -> QueryResult<bool> {
+        let mut result = true;
+        self.walk_ast(AstPass::is_noop(&mut result))?;
+        Ok(result)
+    }
+
+    /// Is this fragment known to match no rows, without running it?
+    ///
+    /// This is `true` for `IN` with no values, and for `AND` expressions
+    /// where either side is known to match no rows. Queries which opt in with
+    /// [`.short_circuit_empty_in()`](../query_dsl/trait.ShortCircuitEmptyInDsl.html)
+    /// return no rows without being sent to the database when their where
+    /// clause is known to match no rows.
+    #[doc(hidden)]
+    fn is_known_empty(&self) -> bool {
+        false
+    }
 }
 
 impl<T: ?Sized, DB> QueryFragment<DB> for Box<T>
@@ -144,6 +308,10 @@ where
     fn walk_ast(&self, pass: AstPass<DB>) -> QueryResult<()> {
         QueryFragment::walk_ast(&**self, pass)
     }
+
+    fn is_known_empty(&self) -> bool {
+        QueryFragment::is_known_empty(&**self)
+    }
 }
 
 impl<'a, T: ?Sized, DB> QueryFragment<DB> for &'a T
@@ -154,6 +322,10 @@ where
     fn walk_ast(&self, pass: AstPass<DB>) -> QueryResult<()> {
         QueryFragment::walk_ast(&**self, pass)
     }
+
+    fn is_known_empty(&self) -> bool {
+        QueryFragment::is_known_empty(&**self)
+    }
 }
 
 impl<DB: Backend> QueryFragment<DB> for () {
@@ -275,3 +447,59 @@ pub fn deprecated_debug_sql<T>(_query: &T) -> String {
         "At least one backend must be enabled to generated debug SQL",
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use super::{escape_identifier, SqlBuffer};
+
+    #[test]
+    fn identifiers_without_quotes_are_not_copied() {
+        let identifier = "users";
+        match escape_identifier(identifier, '"') {
+            Cow::Borrowed(escaped) => assert_eq!(identifier.as_ptr(), escaped.as_ptr()),
+            Cow::Owned(_) => panic!("Identifier without quotes was copied"),
+        }
+        assert_eq!("users", escape_identifier("users", '`'));
+    }
+
+    #[test]
+    fn quotes_in_identifiers_are_doubled() {
+        assert_eq!("my \"\"table\"\"", escape_identifier("my \"table\"", '"'));
+        assert_eq!("my ``table``", escape_identifier("my `table`", '`'));
+        assert_eq!("\"", escape_identifier("\"", '`'));
+    }
+
+    #[test]
+    fn spaces_are_not_repeated_between_fragments() {
+        let mut sql = SqlBuffer::default();
+        sql.push_fragment("SELECT 1 ");
+        sql.push_fragment(" WHERE ");
+        sql.push_fragment("  x\n");
+        sql.push_fragment(" AND 'a  b'");
+        assert_eq!("SELECT 1 WHERE x\nAND 'a  b'", sql.finish());
+    }
+
+    #[test]
+    fn sql_pushed_verbatim_is_not_changed() {
+        let mut sql = SqlBuffer::default();
+        sql.push_fragment("SELECT 1 WHERE ");
+        sql.push_verbatim("  x ");
+        sql.push_fragment(" AND ");
+        sql.push_verbatim(" y\n");
+        assert_eq!("SELECT 1 WHERE   x  AND  y\n", sql.finish());
+    }
+
+    #[test]
+    fn trailing_whitespace_is_removed_when_finishing() {
+        let mut sql = SqlBuffer::default();
+        sql.push_fragment("SELECT 1 \n");
+        assert_eq!("SELECT 1", sql.finish());
+
+        let mut sql = SqlBuffer::default();
+        sql.push_verbatim("SELECT 1 ");
+        sql.push_fragment("\n");
+        assert_eq!("SELECT 1 ", sql.finish());
+    }
+}
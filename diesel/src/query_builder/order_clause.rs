@@ -1 +1,55 @@
 simple_clause!(NoOrderClause, OrderClause, " ORDER BY ");
+
+/// An order clause where `NULLS LAST` applies to every term. Constructed by
+/// [`.nulls_last_everywhere()`](../query_dsl/trait.NullsLastEverywhereDsl.html).
+#[derive(Debug, Clone, Copy)]
+pub struct NullsLastEverywhere<Expr>(pub Expr);
+
+impl<Expr, DB> QueryFragment<DB> for NullsLastEverywhere<Expr>
+where
+    DB: Backend,
+    Expr: NullsLastOrder<DB>,
+{
+    fn walk_ast(&self, out: AstPass<DB>) -> QueryResult<()> {
+        self.0.walk_nulls_last(out)
+    }
+}
+
+impl_query_id!(NullsLastEverywhere<Expr>);
+
+/// An order expression which can be written with nulls sorted last. This is
+/// implemented for columns, for `.asc()` and `.desc()`, and for tuples of
+/// those. Terms which already use `.nulls_first()` or `.nulls_last()` are
+/// written unchanged.
+pub trait NullsLastOrder<DB: Backend> {
+    #[doc(hidden)]
+    fn walk_nulls_last(&self, out: AstPass<DB>) -> QueryResult<()>;
+}
+
+macro_rules! nulls_last_order_tuples {
+    ($first:ident, $($term:ident),+) => {
+        impl<DB, $first, $($term),+> NullsLastOrder<DB> for ($first, $($term),+)
+        where
+            DB: Backend,
+            $first: NullsLastOrder<DB>,
+            $($term: NullsLastOrder<DB>,)+
+        {
+            #[allow(non_snake_case)]
+            fn walk_nulls_last(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+                let (ref $first, $(ref $term),+) = *self;
+                $first.walk_nulls_last(out.reborrow())?;
+                $(
+                    out.push_sql(", ");
+                    $term.walk_nulls_last(out.reborrow())?;
+                )+
+                Ok(())
+            }
+        }
+    }
+}
+
+nulls_last_order_tuples!(A, B);
+nulls_last_order_tuples!(A, B, C);
+nulls_last_order_tuples!(A, B, C, D);
+nulls_last_order_tuples!(A, B, C, D, E);
+nulls_last_order_tuples!(A, B, C, D, E, F);
@@ -2,17 +2,29 @@ use backend::Backend;
 use expression::{Expression, SelectableExpression};
 use query_builder::*;
 use query_source::QuerySource;
+use types::BigInt;
 
 #[derive(Debug, Clone, Copy)]
 pub struct DefaultSelectClause;
 #[derive(Debug, Clone, Copy)]
 pub struct SelectClause<T>(pub T);
 
+/// A select clause followed by a subquery which counts the rows of the whole
+/// query, without its `LIMIT` and `OFFSET` clauses.
+#[derive(Debug, Clone, Copy)]
+pub struct WithTotalSelectClause<S, Total> {
+    pub select: S,
+    pub total: Total,
+}
+
 impl_query_id!(DefaultSelectClause);
 impl_query_id!(SelectClause<T>);
+impl_query_id!(WithTotalSelectClause<S, Total>);
 
 pub trait SelectClauseExpression<QS> {
     type SelectClauseSqlType;
+    /// The expression which is selected
+    type Selection;
 }
 
 impl<T, QS> SelectClauseExpression<QS> for SelectClause<T>
@@ -20,6 +32,7 @@ where
     T: SelectableExpression<QS>,
 {
     type SelectClauseSqlType = T::SqlType;
+    type Selection = T;
 }
 
 impl<QS> SelectClauseExpression<QS> for DefaultSelectClause
@@ -27,6 +40,16 @@ where
     QS: QuerySource,
 {
     type SelectClauseSqlType = <QS::DefaultSelection as Expression>::SqlType;
+    type Selection = QS::DefaultSelection;
+}
+
+impl<S, Total, QS> SelectClauseExpression<QS> for WithTotalSelectClause<S, Total>
+where
+    S: SelectClauseExpression<QS>,
+    Total: Query<SqlType = BigInt>,
+{
+    type SelectClauseSqlType = (S::SelectClauseSqlType, BigInt);
+    type Selection = (S::Selection, Total);
 }
 
 pub trait SelectClauseQueryFragment<QS, DB: Backend> {
@@ -53,3 +76,18 @@ where
         source.default_selection().walk_ast(pass)
     }
 }
+
+impl<S, Total, QS, DB> SelectClauseQueryFragment<QS, DB> for WithTotalSelectClause<S, Total>
+where
+    DB: Backend,
+    S: SelectClauseQueryFragment<QS, DB>,
+    Total: QueryFragment<DB>,
+{
+    fn walk_ast(&self, source: &QS, mut pass: AstPass<DB>) -> QueryResult<()> {
+        self.select.walk_ast(source, pass.reborrow())?;
+        pass.push_sql(", (");
+        self.total.walk_ast(pass.reborrow())?;
+        pass.push_sql(")");
+        Ok(())
+    }
+}
@@ -2,13 +2,14 @@ use std::marker::PhantomData;
 
 use backend::Backend;
 use expression::*;
+use expression::count::{count_star, CountStar};
 use query_builder::*;
-use query_builder::group_by_clause::GroupByClause;
-use query_builder::limit_clause::LimitClause;
-use query_builder::offset_clause::OffsetClause;
-use query_builder::order_clause::OrderClause;
+use query_builder::group_by_clause::{GroupByClause, HavingClause};
+use query_builder::limit_clause::{LimitClause, NoLimitClause};
+use query_builder::offset_clause::{NoOffsetClause, OffsetClause};
+use query_builder::order_clause::{NoOrderClause, OrderClause};
 use query_dsl::*;
-use query_source::QuerySource;
+use query_source::{QuerySource, Table};
 use query_source::joins::*;
 use result::QueryResult;
 use types::{BigInt, Bool, HasSqlType};
@@ -66,7 +67,8 @@ where
     QS: QuerySource,
     QS::FromClause: QueryFragment<DB>,
 {
-    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+    fn walk_ast(&self, out: AstPass<DB>) -> QueryResult<()> {
+        let mut out = out.without_alias_references();
         out.push_sql("SELECT ");
         self.distinct.walk_ast(out.reborrow())?;
         self.select.walk_ast(out.reborrow())?;
@@ -90,7 +92,8 @@ impl<'a, ST, DB> QueryFragment<DB> for BoxedSelectStatement<'a, ST, (), DB>
 where
     DB: Backend,
 {
-    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+    fn walk_ast(&self, out: AstPass<DB>) -> QueryResult<()> {
+        let mut out = out.without_alias_references();
         out.push_sql("SELECT ");
         self.distinct.walk_ast(out.reborrow())?;
         self.select.walk_ast(out.reborrow())?;
@@ -156,6 +159,27 @@ where
     }
 }
 
+impl<'a, ST, QS, DB> CountDsl for BoxedSelectStatement<'a, ST, QS, DB>
+where
+    DB: Backend + HasSqlType<BigInt>,
+    CountStar: SelectableExpression<QS> + QueryFragment<DB>,
+{
+    type Output = BoxedSelectStatement<'a, BigInt, QS, DB>;
+
+    fn count(self) -> Self::Output {
+        BoxedSelectStatement::new(
+            Box::new(count_star()),
+            self.from,
+            self.distinct,
+            self.where_clause,
+            Box::new(NoOrderClause),
+            Box::new(NoLimitClause),
+            Box::new(NoOffsetClause),
+            self.group_by,
+        )
+    }
+}
+
 impl<'a, ST, QS, DB, Predicate> FilterDsl<Predicate> for BoxedSelectStatement<'a, ST, QS, DB>
 where
     DB: Backend + HasSqlType<ST> + 'a,
@@ -202,6 +226,10 @@ where
     }
 }
 
+/// A boxed query doesn't know whether it is distinct or which columns it
+/// selects, so unlike other queries, a boxed `SELECT DISTINCT` query can be
+/// ordered by columns which it doesn't select. PostgreSQL and MySQL will
+/// return an error when such a query is run.
 impl<'a, ST, QS, DB, Order> OrderDsl<Order> for BoxedSelectStatement<'a, ST, QS, DB>
 where
     DB: Backend,
@@ -216,6 +244,20 @@ where
     }
 }
 
+impl<'a, ST, QS, DB, Expr> StableOrderDsl<Expr> for BoxedSelectStatement<'a, ST, QS, DB>
+where
+    QS: Table,
+    Expr: Expression + NonAggregate,
+    Self: OrderDsl<(Expr, QS::PrimaryKey)>,
+{
+    type Output = <Self as OrderDsl<(Expr, QS::PrimaryKey)>>::Output;
+
+    fn order_stable_by(self, expr: Expr) -> Self::Output {
+        let primary_key = self.from.primary_key();
+        self.order((expr, primary_key))
+    }
+}
+
 impl<'a, ST, QS, DB, Expr> GroupByDsl<Expr> for BoxedSelectStatement<'a, ST, QS, DB>
 where
     DB: Backend,
@@ -230,6 +272,21 @@ where
     }
 }
 
+impl<'a, ST, QS, DB, Predicate> HavingDsl<Predicate> for BoxedSelectStatement<'a, ST, QS, DB>
+where
+    DB: Backend,
+    Predicate: AppearsOnTable<QS, SqlType = Bool>,
+    HavingClause<Box<QueryFragment<DB> + 'a>, Predicate>: QueryFragment<DB> + 'a,
+    Self: Query,
+{
+    type Output = Self;
+
+    fn having(mut self, predicate: Predicate) -> Self::Output {
+        self.group_by = Box::new(HavingClause::new(self.group_by, predicate));
+        self
+    }
+}
+
 // FIXME: Should we disable joining when `.group_by` has been called? Are there
 // any other query methods where a join no longer has the same semantics as
 // joining on just the table?
@@ -1,6 +1,7 @@
 use associations::HasTable;
 use backend::Backend;
 use expression::*;
+use expression::count::{count_star, CountStar};
 use query_builder::distinct_clause::*;
 use query_builder::for_update_clause::*;
 use query_builder::group_by_clause::*;
@@ -15,8 +16,8 @@ use query_dsl::*;
 use query_dsl::boxed_dsl::InternalBoxedDsl;
 use query_source::QuerySource;
 use query_source::joins::{Join, JoinOn, JoinTo};
-use super::BoxedSelectStatement;
-use types::{self, Bool};
+use super::{BoxedSelectStatement, NoRowsWithoutMatches, ShortCircuitEmptyIn};
+use types::{self, BigInt, Bool};
 
 impl<F, S, D, W, O, L, Of, G, FU, Rhs, Kind, On> InternalJoinDsl<Rhs, Kind, On>
     for SelectStatement<F, S, D, W, O, L, Of, G, FU>
@@ -63,6 +64,47 @@ where
     }
 }
 
+impl<F, S, D, W, O, L, Of, G, FU> CountDsl for SelectStatement<F, S, D, W, O, L, Of, G, FU>
+where
+    SelectStatement<
+        F,
+        SelectClause<CountStar>,
+        D,
+        W,
+        NoOrderClause,
+        NoLimitClause,
+        NoOffsetClause,
+        G,
+        FU,
+    >: Query<SqlType = BigInt>,
+{
+    type Output = SelectStatement<
+        F,
+        SelectClause<CountStar>,
+        D,
+        W,
+        NoOrderClause,
+        NoLimitClause,
+        NoOffsetClause,
+        G,
+        FU,
+    >;
+
+    fn count(self) -> Self::Output {
+        SelectStatement::new(
+            SelectClause(count_star()),
+            self.from,
+            self.distinct,
+            self.where_clause,
+            NoOrderClause,
+            NoLimitClause,
+            NoOffsetClause,
+            self.group_by,
+            self.for_update,
+        )
+    }
+}
+
 impl<ST, F, S, D, W, O, L, Of, G> DistinctDsl for SelectStatement<F, S, D, W, O, L, Of, G>
 where
     SelectStatement<F, S, D, W, O, L, Of, G>: AsQuery<SqlType = ST>,
@@ -153,9 +195,104 @@ where
     }
 }
 
+impl<F, S, D, W, L, Of, G, FU, Expr> NullsLastEverywhereDsl
+    for SelectStatement<F, S, D, W, OrderClause<Expr>, L, Of, G, FU>
+{
+    type Output = SelectStatement<F, S, D, W, OrderClause<NullsLastEverywhere<Expr>>, L, Of, G, FU>;
+
+    fn nulls_last_everywhere(self) -> Self::Output {
+        let order = OrderClause(NullsLastEverywhere(self.order.0));
+        SelectStatement::new(
+            self.select,
+            self.from,
+            self.distinct,
+            self.where_clause,
+            order,
+            self.limit,
+            self.offset,
+            self.group_by,
+            self.for_update,
+        )
+    }
+}
+
+impl<F, S, D, W, O, L, Of, G, FU> ShortCircuitEmptyInDsl
+    for SelectStatement<F, S, D, W, O, L, Of, G, FU>
+where
+    G: NoRowsWithoutMatches<F, S>,
+{
+    type Output = ShortCircuitEmptyIn<Self>;
+
+    fn short_circuit_empty_in(self) -> Self::Output {
+        ShortCircuitEmptyIn::new(self)
+    }
+}
+
 #[doc(hidden)]
 pub type Limit = <i64 as AsExpression<types::BigInt>>::Expression;
 
+impl<F, S, D, W, O, L, Of, G, FU, Expr> StableOrderDsl<Expr>
+    for SelectStatement<F, S, D, W, O, L, Of, G, FU>
+where
+    F: Table,
+    Expr: Expression + NonAggregate,
+    Self: OrderDsl<(Expr, F::PrimaryKey)>,
+{
+    type Output = <Self as OrderDsl<(Expr, F::PrimaryKey)>>::Output;
+
+    fn order_stable_by(self, expr: Expr) -> Self::Output {
+        let primary_key = self.from.primary_key();
+        self.order((expr, primary_key))
+    }
+}
+
+impl<F, S, W, O, L, Of> WithTotalDsl
+    for SelectStatement<F, S, NoDistinctClause, W, O, L, Of, NoGroupByClause, NoForUpdateClause>
+where
+    Self: Clone + CountDsl,
+    SelectStatement<
+        F,
+        WithTotalSelectClause<S, <Self as CountDsl>::Output>,
+        NoDistinctClause,
+        W,
+        O,
+        L,
+        Of,
+        NoGroupByClause,
+        NoForUpdateClause,
+    >: Query,
+{
+    type Output = SelectStatement<
+        F,
+        WithTotalSelectClause<S, <Self as CountDsl>::Output>,
+        NoDistinctClause,
+        W,
+        O,
+        L,
+        Of,
+        NoGroupByClause,
+        NoForUpdateClause,
+    >;
+
+    fn with_total(self) -> Self::Output {
+        let total = self.clone().count();
+        SelectStatement::new(
+            WithTotalSelectClause {
+                select: self.select,
+                total: total,
+            },
+            self.from,
+            self.distinct,
+            self.where_clause,
+            self.order,
+            self.limit,
+            self.offset,
+            self.group_by,
+            self.for_update,
+        )
+    }
+}
+
 impl<ST, F, S, D, W, O, L, Of, G, FU> LimitDsl for SelectStatement<F, S, D, W, O, L, Of, G, FU>
 where
     Self: AsQuery<SqlType = ST>,
@@ -228,6 +365,32 @@ where
     }
 }
 
+impl<ST, F, S, D, W, O, L, Of, G, FU, Predicate> HavingDsl<Predicate>
+    for SelectStatement<F, S, D, W, O, L, Of, GroupByClause<G>, FU>
+where
+    Self: AsQuery<SqlType = ST>,
+    SelectStatement<F, S, D, W, O, L, Of, HavingClause<GroupByClause<G>, Predicate>, FU>:
+        Query<SqlType = ST>,
+    Predicate: AppearsOnTable<F, SqlType = Bool>,
+{
+    type Output =
+        SelectStatement<F, S, D, W, O, L, Of, HavingClause<GroupByClause<G>, Predicate>, FU>;
+
+    fn having(self, predicate: Predicate) -> Self::Output {
+        SelectStatement::new(
+            self.select,
+            self.from,
+            self.distinct,
+            self.where_clause,
+            self.order,
+            self.limit,
+            self.offset,
+            HavingClause::new(self.group_by, predicate),
+            self.for_update,
+        )
+    }
+}
+
 impl<F, S, W, O, L, Of> ForUpdateDsl for SelectStatement<F, S, NoDistinctClause, W, O, L, Of> {
     type Output = SelectStatement<
         F,
@@ -251,11 +414,80 @@ impl<F, S, W, O, L, Of> ForUpdateDsl for SelectStatement<F, S, NoDistinctClause,
             self.limit,
             self.offset,
             self.group_by,
-            ForUpdateClause,
+            ForUpdateClause::new(),
         )
     }
 }
 
+impl<F, S, W, O, L, Of, Tables> ForUpdateOfDsl<Tables>
+    for SelectStatement<F, S, NoDistinctClause, W, O, L, Of>
+where
+    Tables: LockedTablesAppearIn<F>,
+{
+    type Output = SelectStatement<
+        F,
+        S,
+        NoDistinctClause,
+        W,
+        O,
+        L,
+        Of,
+        NoGroupByClause,
+        ForUpdateClause<OfClause<Tables>>,
+    >;
+
+    fn for_update_of(self, tables: Tables) -> Self::Output {
+        SelectStatement::new(
+            self.select,
+            self.from,
+            self.distinct,
+            self.where_clause,
+            self.order,
+            self.limit,
+            self.offset,
+            self.group_by,
+            ForUpdateClause::of(tables),
+        )
+    }
+}
+
+impl<F, S, W, O, L, Of, Tables> SkipLockedDsl
+    for SelectStatement<F, S, NoDistinctClause, W, O, L, Of, NoGroupByClause, ForUpdateClause<Tables>>
+{
+    type Output = SelectStatement<
+        F,
+        S,
+        NoDistinctClause,
+        W,
+        O,
+        L,
+        Of,
+        NoGroupByClause,
+        ForUpdateClause<Tables, SkipLocked>,
+    >;
+
+    fn skip_locked(self) -> Self::Output {
+        SelectStatement::new(
+            self.select,
+            self.from,
+            self.distinct,
+            self.where_clause,
+            self.order,
+            self.limit,
+            self.offset,
+            self.group_by,
+            self.for_update.skip_locked(),
+        )
+    }
+}
+
+impl<F, S, D, W, G> CombineDsl
+    for SelectStatement<F, S, D, W, NoOrderClause, NoLimitClause, NoOffsetClause, G>
+where
+    Self: AsQuery,
+{
+}
+
 impl<'a, F, S, D, W, O, L, Of, G, DB> InternalBoxedDsl<'a, DB>
     for SelectStatement<F, SelectClause<S>, D, W, O, L, Of, G>
 where
@@ -11,15 +11,17 @@
 //! FU: For Update Clause
 mod dsl_impls;
 mod boxed;
+mod short_circuit;
 
 pub use self::boxed::BoxedSelectStatement;
+pub use self::short_circuit::{NoRowsWithoutMatches, ShortCircuitEmptyIn};
 
 use backend::Backend;
 use expression::*;
 use query_source::*;
 use query_source::joins::AppendSelection;
 use result::QueryResult;
-use super::distinct_clause::NoDistinctClause;
+use super::distinct_clause::{NoDistinctClause, ValidDistinctOrdering};
 use super::for_update_clause::NoForUpdateClause;
 use super::group_by_clause::NoGroupByClause;
 use super::limit_clause::NoLimitClause;
@@ -44,7 +46,7 @@ pub struct SelectStatement<
     ForUpdate = NoForUpdateClause,
 > {
     select: Select,
-    from: From,
+    pub(crate) from: From,
     distinct: Distinct,
     where_clause: Where,
     order: Order,
@@ -124,10 +126,10 @@ impl<F, S, D, W, O, L, Of, G, FU, DB> QueryFragment<DB>
     for SelectStatement<F, S, D, W, O, L, Of, G, FU>
 where
     DB: Backend,
-    S: SelectClauseQueryFragment<F, DB>,
+    S: SelectClauseQueryFragment<F, DB> + SelectClauseExpression<F>,
     F: QuerySource,
     F::FromClause: QueryFragment<DB>,
-    D: QueryFragment<DB>,
+    D: QueryFragment<DB> + ValidDistinctOrdering<DB, O, S::Selection>,
     W: QueryFragment<DB>,
     O: QueryFragment<DB>,
     L: QueryFragment<DB>,
@@ -135,7 +137,8 @@ where
     G: QueryFragment<DB>,
     FU: QueryFragment<DB>,
 {
-    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+    fn walk_ast(&self, out: AstPass<DB>) -> QueryResult<()> {
+        let mut out = out.without_alias_references();
         out.push_sql("SELECT ");
         self.distinct.walk_ast(out.reborrow())?;
         self.select.walk_ast(&self.from, out.reborrow())?;
@@ -155,8 +158,8 @@ impl<S, D, W, O, L, Of, G, FU, DB> QueryFragment<DB>
     for SelectStatement<(), S, D, W, O, L, Of, G, FU>
 where
     DB: Backend,
-    S: SelectClauseQueryFragment<(), DB>,
-    D: QueryFragment<DB>,
+    S: SelectClauseQueryFragment<(), DB> + SelectClauseExpression<()>,
+    D: QueryFragment<DB> + ValidDistinctOrdering<DB, O, S::Selection>,
     W: QueryFragment<DB>,
     O: QueryFragment<DB>,
     L: QueryFragment<DB>,
@@ -164,7 +167,8 @@ where
     G: QueryFragment<DB>,
     FU: QueryFragment<DB>,
 {
-    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+    fn walk_ast(&self, out: AstPass<DB>) -> QueryResult<()> {
+        let mut out = out.without_alias_references();
         out.push_sql("SELECT ");
         self.distinct.walk_ast(out.reborrow())?;
         self.select.walk_ast(&(), out.reborrow())?;
@@ -187,6 +191,11 @@ where
 {
 }
 
+impl_unchecked_distinct_ordering!(
+    ty_params = (F, S, D, W, O, L, Of, G, FU),
+    struct_ty = SelectStatement<F, S, D, W, O, L, Of, G, FU>,
+);
+
 impl<S, F, D, W, O, L, Of, G, FU, QS> AppearsOnTable<QS>
     for SelectStatement<S, F, D, W, O, L, Of, FU, G>
 where
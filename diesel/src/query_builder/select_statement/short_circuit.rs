@@ -0,0 +1,68 @@
+use backend::Backend;
+use expression::NonAggregate;
+use query_builder::group_by_clause::{GroupByClause, HavingClause, NoGroupByClause};
+use query_builder::select_clause::{DefaultSelectClause, SelectClause};
+use query_builder::{AstPass, Query, QueryFragment};
+use query_source::QuerySource;
+use result::QueryResult;
+use super::SelectStatement;
+
+/// A select statement which returns no rows without being sent to the
+/// database when its where clause is known to match no rows. Constructed by
+/// [`.short_circuit_empty_in()`](../query_dsl/trait.ShortCircuitEmptyInDsl.html).
+#[derive(Debug, Clone, Copy)]
+#[must_use = "Queries are only executed when calling `load`, `get_result` or similar."]
+pub struct ShortCircuitEmptyIn<T> {
+    query: T,
+}
+
+impl<T> ShortCircuitEmptyIn<T> {
+    pub(crate) fn new(query: T) -> Self {
+        ShortCircuitEmptyIn { query: query }
+    }
+}
+
+impl<T: Query> Query for ShortCircuitEmptyIn<T> {
+    type SqlType = T::SqlType;
+}
+
+impl<F, S, D, W, O, L, Of, G, FU, DB> QueryFragment<DB>
+    for ShortCircuitEmptyIn<SelectStatement<F, S, D, W, O, L, Of, G, FU>>
+where
+    DB: Backend,
+    SelectStatement<F, S, D, W, O, L, Of, G, FU>: QueryFragment<DB>,
+    W: QueryFragment<DB>,
+{
+    fn walk_ast(&self, out: AstPass<DB>) -> QueryResult<()> {
+        self.query.walk_ast(out)
+    }
+
+    fn is_known_empty(&self) -> bool {
+        self.query.where_clause.is_known_empty()
+    }
+}
+
+impl_query_id!(ShortCircuitEmptyIn<T>);
+
+/// Implemented for the group by clause of queries which return no rows when
+/// their where clause matches no rows. Queries without a group by clause
+/// return a row for aggregates such as `COUNT(*)`, so their select clause
+/// must not be an aggregate.
+pub trait NoRowsWithoutMatches<QS, Selection> {}
+
+impl<QS, T> NoRowsWithoutMatches<QS, SelectClause<T>> for NoGroupByClause
+where
+    T: NonAggregate,
+{
+}
+
+impl<QS> NoRowsWithoutMatches<QS, DefaultSelectClause> for NoGroupByClause
+where
+    QS: QuerySource,
+    QS::DefaultSelection: NonAggregate,
+{
+}
+
+impl<QS, S, Expr> NoRowsWithoutMatches<QS, S> for GroupByClause<Expr> {}
+
+impl<QS, S, GroupBy, Predicate> NoRowsWithoutMatches<QS, S> for HavingClause<GroupBy, Predicate> {}
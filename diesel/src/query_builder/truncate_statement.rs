@@ -0,0 +1,148 @@
+use backend::Backend;
+use query_builder::{AstPass, QueryFragment};
+use result::QueryResult;
+
+#[derive(Debug, Clone, Copy)]
+/// Represents `TRUNCATE TABLE`. Constructed by
+/// [`truncate`](../fn.truncate.html).
+///
+/// PostgreSQL supports `.restart_identity()` and `.cascade()`. MySQL always
+/// resets the auto increment counter of the table, and does not support
+/// `.cascade()`. SQLite does not have `TRUNCATE`, so the table is cleared with
+/// `DELETE FROM` instead. When `.restart_identity()` is called on SQLite, the
+/// entry for the table is also deleted from `sqlite_sequence`.
+pub struct TruncateStatement<T, Identity = NoRestartIdentityClause, Cascade = NoCascadeClause> {
+    pub(crate) table: T,
+    pub(crate) restart_identity: Identity,
+    pub(crate) cascade: Cascade,
+}
+
+impl<T> TruncateStatement<T> {
+    pub(crate) fn new(table: T) -> Self {
+        TruncateStatement {
+            table: table,
+            restart_identity: NoRestartIdentityClause,
+            cascade: NoCascadeClause,
+        }
+    }
+}
+
+impl<T, Cascade> TruncateStatement<T, NoRestartIdentityClause, Cascade> {
+    /// Resets sequences owned by the columns of the table
+    /// (`RESTART IDENTITY`).
+    pub fn restart_identity(self) -> TruncateStatement<T, RestartIdentityClause, Cascade> {
+        TruncateStatement {
+            table: self.table,
+            restart_identity: RestartIdentityClause,
+            cascade: self.cascade,
+        }
+    }
+}
+
+impl<T, Identity> TruncateStatement<T, Identity, NoCascadeClause> {
+    /// Also truncates all tables that have foreign key references to the
+    /// table (`CASCADE`). Only supported on PostgreSQL.
+    pub fn cascade(self) -> TruncateStatement<T, Identity, CascadeClause> {
+        TruncateStatement {
+            table: self.table,
+            restart_identity: self.restart_identity,
+            cascade: CascadeClause,
+        }
+    }
+}
+
+impl_query_id!(TruncateStatement<T, Identity, Cascade>);
+
+#[derive(Debug, Clone, Copy)]
+pub struct NoRestartIdentityClause;
+
+impl<DB: Backend> QueryFragment<DB> for NoRestartIdentityClause {
+    fn walk_ast(&self, _: AstPass<DB>) -> QueryResult<()> {
+        Ok(())
+    }
+}
+
+impl_query_id!(NoRestartIdentityClause);
+
+#[derive(Debug, Clone, Copy)]
+pub struct RestartIdentityClause;
+
+impl_query_id!(RestartIdentityClause);
+
+#[derive(Debug, Clone, Copy)]
+pub struct NoCascadeClause;
+
+impl<DB: Backend> QueryFragment<DB> for NoCascadeClause {
+    fn walk_ast(&self, _: AstPass<DB>) -> QueryResult<()> {
+        Ok(())
+    }
+}
+
+impl_query_id!(NoCascadeClause);
+
+#[derive(Debug, Clone, Copy)]
+pub struct CascadeClause;
+
+impl_query_id!(CascadeClause);
+
+#[cfg(feature = "sqlite")]
+mod sqlite {
+    use connection::Connection;
+    use expression::dsl::sql;
+    use query_builder::{AstPass, IntoUpdateTarget, QueryFragment};
+    use query_builder::delete_statement::DeleteStatement;
+    use query_builder::functions::delete;
+    use query_builder::nodes::Identifier;
+    use query_dsl::{ExecuteDsl, LoadDsl};
+    use query_source::QuerySource;
+    use result::QueryResult;
+    use sqlite::{Sqlite, SqliteConnection};
+    use super::*;
+    use types::{Bool, Text};
+
+    impl<T> ExecuteDsl<SqliteConnection> for TruncateStatement<T>
+    where
+        T: IntoUpdateTarget,
+        DeleteStatement<T::Table, T::WhereClause>: ExecuteDsl<SqliteConnection>,
+    {
+        fn execute(self, conn: &SqliteConnection) -> QueryResult<usize> {
+            delete(self.table).execute(conn)
+        }
+    }
+
+    impl<T> ExecuteDsl<SqliteConnection> for TruncateStatement<T, RestartIdentityClause>
+    where
+        T: QuerySource<FromClause = Identifier<'static>>,
+        TruncateStatement<T>: ExecuteDsl<SqliteConnection>,
+    {
+        fn execute(self, conn: &SqliteConnection) -> QueryResult<usize> {
+            let table_name = self.table.from_clause().0;
+            conn.transaction(|| {
+                let deleted_rows = TruncateStatement::new(self.table).execute(conn)?;
+                // `sqlite_sequence` is only created once a table using
+                // `AUTOINCREMENT` exists
+                let has_sequences = sql::<Bool>(
+                    "SELECT EXISTS (SELECT 1 FROM sqlite_master \
+                     WHERE type = 'table' AND name = 'sqlite_sequence')",
+                ).get_result(conn)?;
+                if has_sequences {
+                    ResetSqliteSequence(table_name).execute(conn)?;
+                }
+                Ok(deleted_rows)
+            })
+        }
+    }
+
+    #[derive(Debug, Clone, Copy)]
+    struct ResetSqliteSequence(&'static str);
+
+    impl QueryFragment<Sqlite> for ResetSqliteSequence {
+        fn walk_ast(&self, mut out: AstPass<Sqlite>) -> QueryResult<()> {
+            out.push_sql("DELETE FROM sqlite_sequence WHERE name = ");
+            out.push_bind_param::<Text, _>(&self.0)?;
+            Ok(())
+        }
+    }
+
+    impl_query_id!(ResetSqliteSequence);
+}
@@ -18,6 +18,11 @@ use result::QueryResult;
 /// `None`. If you would like to assign `NULL` to the field instead, you can
 /// annotate your struct with `#[changeset_options(treat_none_as_null =
 /// "true")]`.
+///
+/// A field annotated with `#[diesel(serialize_as = "SomeType")]` is cloned
+/// and converted to `SomeType` before it is assigned, using
+/// `SomeType: From<FieldType>`. For an `Option` field, the value inside of it
+/// is converted.
 pub trait AsChangeset {
     /// The table which `Self::Changeset` will be updating
     type Target: QuerySource;
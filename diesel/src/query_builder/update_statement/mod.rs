@@ -5,12 +5,15 @@ pub use self::changeset::{AsChangeset, Changeset};
 pub use self::target::{IntoUpdateTarget, UpdateTarget};
 
 use backend::Backend;
-use expression::{Expression, NonAggregate, SelectableExpression};
+use expression::{AppearsOnTable, AsExpression, Expression, NonAggregate, SelectableExpression};
 use query_builder::*;
+use query_builder::limit_clause::{LimitClause, NoLimitClause};
+use query_builder::order_clause::{NoOrderClause, OrderClause};
 use query_builder::returning_clause::*;
 use query_source::Table;
 use result::Error::QueryBuilderError;
 use result::QueryResult;
+use types::BigInt;
 
 /// The type returned by [`update`](/diesel/fn.update.html). The only thing you can do
 /// with this type is call `set` on it.
@@ -36,19 +39,31 @@ impl<T, U> IncompleteUpdateStatement<T, U> {
             where_clause: self.0.where_clause,
             values: values.as_changeset(),
             returning: NoReturningClause,
+            order: NoOrderClause,
+            limit: NoLimitClause,
         }
     }
 }
 
 #[derive(Debug, Copy, Clone)]
-pub struct UpdateStatement<T, U, V, Ret = NoReturningClause> {
+pub struct UpdateStatement<
+    T,
+    U,
+    V,
+    Ret = NoReturningClause,
+    Order = NoOrderClause,
+    Limit = NoLimitClause,
+> {
     table: T,
     where_clause: U,
     values: V,
     returning: Ret,
+    order: Order,
+    limit: Limit,
 }
 
-impl<T, U, V, Ret, DB> QueryFragment<DB> for UpdateStatement<T, U, V, Ret>
+impl<T, U, V, Ret, Order, Limit, DB> QueryFragment<DB>
+    for UpdateStatement<T, U, V, Ret, Order, Limit>
 where
     DB: Backend,
     T: Table,
@@ -56,6 +71,8 @@ where
     U: QueryFragment<DB>,
     V: changeset::Changeset<DB>,
     Ret: QueryFragment<DB>,
+    Order: QueryFragment<DB>,
+    Limit: QueryFragment<DB>,
 {
     fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
         if self.values.is_noop() {
@@ -70,27 +87,30 @@ where
         out.push_sql(" SET ");
         self.values.walk_ast(out.reborrow())?;
         self.where_clause.walk_ast(out.reborrow())?;
+        self.order.walk_ast(out.reborrow())?;
+        self.limit.walk_ast(out.reborrow())?;
         self.returning.walk_ast(out.reborrow())?;
         Ok(())
     }
 }
 
-impl_query_id!(noop: UpdateStatement<T, U, V, Ret>);
+impl_query_id!(noop: UpdateStatement<T, U, V, Ret, Order, Limit>);
 
-impl<T, U, V> AsQuery for UpdateStatement<T, U, V, NoReturningClause>
+impl<T, U, V, Order, Limit> AsQuery for UpdateStatement<T, U, V, NoReturningClause, Order, Limit>
 where
     T: Table,
-    UpdateStatement<T, U, V, ReturningClause<T::AllColumns>>: Query,
+    UpdateStatement<T, U, V, ReturningClause<T::AllColumns>, Order, Limit>: Query,
 {
     type SqlType = <Self::Query as Query>::SqlType;
-    type Query = UpdateStatement<T, U, V, ReturningClause<T::AllColumns>>;
+    type Query = UpdateStatement<T, U, V, ReturningClause<T::AllColumns>, Order, Limit>;
 
     fn as_query(self) -> Self::Query {
         self.returning(T::all_columns())
     }
 }
 
-impl<T, U, V, Ret> Query for UpdateStatement<T, U, V, ReturningClause<Ret>>
+impl<T, U, V, Ret, Order, Limit> Query
+    for UpdateStatement<T, U, V, ReturningClause<Ret>, Order, Limit>
 where
     T: Table,
     Ret: Expression + SelectableExpression<T> + NonAggregate,
@@ -98,7 +118,7 @@ where
     type SqlType = Ret::SqlType;
 }
 
-impl<T, U, V> UpdateStatement<T, U, V, NoReturningClause> {
+impl<T, U, V, Order, Limit> UpdateStatement<T, U, V, NoReturningClause, Order, Limit> {
     /// Specify what expression is returned after execution of the `update`.
     /// # Examples
     ///
@@ -128,16 +148,114 @@ impl<T, U, V> UpdateStatement<T, U, V, NoReturningClause> {
     /// # #[cfg(not(feature = "postgres"))]
     /// # fn main() {}
     /// ```
-    pub fn returning<E>(self, returns: E) -> UpdateStatement<T, U, V, ReturningClause<E>>
+    pub fn returning<E>(
+        self,
+        returns: E,
+    ) -> UpdateStatement<T, U, V, ReturningClause<E>, Order, Limit>
     where
         T: Table,
-        UpdateStatement<T, U, V, ReturningClause<E>>: Query,
+        UpdateStatement<T, U, V, ReturningClause<E>, Order, Limit>: Query,
     {
         UpdateStatement {
             table: self.table,
             where_clause: self.where_clause,
             values: self.values,
             returning: ReturningClause(returns),
+            order: self.order,
+            limit: self.limit,
         }
     }
 }
+
+impl<T, U, V, Ret, Limit> UpdateStatement<T, U, V, Ret, NoOrderClause, Limit> {
+    /// Sets the order in which the rows are updated. This is usually
+    /// combined with [`.limit`](#method.limit), to update a limited number of
+    /// rows at a time.
+    ///
+    /// Only MySQL supports ordering the rows of an update, so this can't be
+    /// executed on other backends.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate diesel;
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # table! {
+    /// #     users {
+    /// #         id -> Integer,
+    /// #         name -> VarChar,
+    /// #     }
+    /// # }
+    /// #
+    /// # #[cfg(feature = "mysql")]
+    /// # fn main() {
+    /// #     use self::users::dsl::*;
+    /// #     let connection = establish_connection();
+    /// // UPDATE `users` SET `name` = ? ORDER BY `users`.`id` DESC LIMIT ?
+    /// let updated_rows = diesel::update(users)
+    ///     .set(name.eq("Jim"))
+    ///     .order_by(id.desc())
+    ///     .limit(1)
+    ///     .execute(&connection);
+    /// assert_eq!(Ok(1), updated_rows);
+    ///
+    /// let names = users.select(name).order(id).load::<String>(&connection);
+    /// assert_eq!(Ok(vec!["Sean".to_string(), "Jim".to_string()]), names);
+    /// # }
+    /// # #[cfg(not(feature = "mysql"))]
+    /// # fn main() {}
+    /// ```
+    pub fn order_by<E>(self, expr: E) -> UpdateStatement<T, U, V, Ret, UpdateOrderClause<E>, Limit>
+    where
+        E: AppearsOnTable<T>,
+    {
+        UpdateStatement {
+            table: self.table,
+            where_clause: self.where_clause,
+            values: self.values,
+            returning: self.returning,
+            order: UpdateOrderClause(OrderClause(expr)),
+            limit: self.limit,
+        }
+    }
+}
+
+type BigIntExpression = <i64 as AsExpression<BigInt>>::Expression;
+
+impl<T, U, V, Ret, Order> UpdateStatement<T, U, V, Ret, Order, NoLimitClause> {
+    /// Limits the number of rows which are updated. See
+    /// [`.order_by`](#method.order_by) for an example.
+    ///
+    /// Only MySQL supports limiting the rows of an update, so this can't be
+    /// executed on other backends.
+    pub fn limit(
+        self,
+        limit: i64,
+    ) -> UpdateStatement<T, U, V, Ret, Order, UpdateLimitClause<BigIntExpression>> {
+        UpdateStatement {
+            table: self.table,
+            where_clause: self.where_clause,
+            values: self.values,
+            returning: self.returning,
+            order: self.order,
+            limit: UpdateLimitClause(LimitClause(AsExpression::<BigInt>::as_expression(limit))),
+        }
+    }
+}
+
+/// The `ORDER BY` clause of an update. Constructed by
+/// [`UpdateStatement::order_by`](struct.UpdateStatement.html#method.order_by).
+/// Only MySQL supports this clause.
+#[derive(Debug, Clone, Copy)]
+pub struct UpdateOrderClause<Expr>(pub(crate) OrderClause<Expr>);
+
+impl_query_id!(UpdateOrderClause<Expr>);
+
+/// The `LIMIT` clause of an update. Constructed by
+/// [`UpdateStatement::limit`](struct.UpdateStatement.html#method.limit).
+/// Only MySQL supports this clause.
+#[derive(Debug, Clone, Copy)]
+pub struct UpdateLimitClause<Expr>(pub(crate) LimitClause<Expr>);
+
+impl_query_id!(UpdateLimitClause<Expr>);
@@ -53,6 +53,10 @@ where
         self.0.walk_ast(out.reborrow())?;
         Ok(())
     }
+
+    fn is_known_empty(&self) -> bool {
+        self.0.is_known_empty()
+    }
 }
 
 impl_query_id!(WhereClause<T>);
@@ -0,0 +1,186 @@
+use backend::Backend;
+use query_builder::*;
+use query_builder::nodes::Identifier;
+use query_source::{QuerySource, Table};
+use result::QueryResult;
+
+/// Defines a common table expression, `WITH alias AS (query)`.
+///
+/// `alias` must be a table declared with [`table!`](../macro.table.html)
+/// without a schema, as with [`lateral`](../query_source/fn.lateral.html).
+/// The query which uses the common table expression is passed to
+/// [`.query`](struct.WithClause.html#method.query), and can select from the
+/// alias like any other table. Diesel cannot check that `query` returns
+/// columns with the names and types of the columns of `alias`.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate diesel;
+/// # include!("../doctest_setup.rs");
+/// #
+/// # table! {
+/// #     users {
+/// #         id -> Integer,
+/// #         name -> VarChar,
+/// #     }
+/// # }
+/// #
+/// # table! {
+/// #     posts {
+/// #         id -> Integer,
+/// #         user_id -> Integer,
+/// #         title -> VarChar,
+/// #     }
+/// # }
+/// #
+/// table! {
+///     seans_posts {
+///         id -> Integer,
+///         title -> VarChar,
+///     }
+/// }
+///
+/// # fn main() {
+/// #     let connection = establish_connection();
+/// use diesel::query_builder::with;
+///
+/// let titles = with(
+///     seans_posts::table,
+///     posts::table
+///         .select((posts::id, posts::title))
+///         .filter(posts::user_id.eq(1)),
+/// ).query(seans_posts::table.select(seans_posts::title).order(seans_posts::id))
+///     .load::<String>(&connection);
+/// let expected = vec!["My first post".to_string(), "About Rust".to_string()];
+/// assert_eq!(Ok(expected), titles);
+/// # }
+/// ```
+pub fn with<T, Q>(alias: T, query: Q) -> WithClause<T, Q::Query>
+where
+    T: Table,
+    Q: AsQuery,
+{
+    WithClause {
+        alias: alias,
+        query: query.as_query(),
+        hint: NoMaterializedHint,
+    }
+}
+
+/// A common table expression. Constructed by [`with`](fn.with.html).
+#[derive(Debug, Clone, Copy)]
+pub struct WithClause<T, Q, Hint = NoMaterializedHint> {
+    alias: T,
+    query: Q,
+    hint: Hint,
+}
+
+impl<T, Q, Hint> WithClause<T, Q, Hint> {
+    /// Adds `MATERIALIZED` after `AS`, which forces PostgreSQL to evaluate the
+    /// common table expression once, rather than inlining it into the query.
+    /// This hint is only available on PostgreSQL 12 and later.
+    pub fn materialized(self) -> WithClause<T, Q, Materialized> {
+        WithClause {
+            alias: self.alias,
+            query: self.query,
+            hint: Materialized,
+        }
+    }
+
+    /// Adds `NOT MATERIALIZED` after `AS`, which allows PostgreSQL to inline
+    /// the common table expression into the query, even if it is referenced
+    /// more than once. This hint is only available on PostgreSQL 12 and later.
+    pub fn not_materialized(self) -> WithClause<T, Q, NotMaterialized> {
+        WithClause {
+            alias: self.alias,
+            query: self.query,
+            hint: NotMaterialized,
+        }
+    }
+
+    /// Places the common table expression in front of the given query.
+    pub fn query<Main>(self, query: Main) -> WithQuery<Self, Main::Query>
+    where
+        Main: AsQuery,
+    {
+        WithQuery {
+            with: self,
+            query: query.as_query(),
+        }
+    }
+}
+
+impl<T, Q, Hint, DB> QueryFragment<DB> for WithClause<T, Q, Hint>
+where
+    DB: Backend,
+    T: QuerySource<FromClause = Identifier<'static>>,
+    Q: QueryFragment<DB>,
+    Hint: QueryFragment<DB>,
+{
+    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+        out.push_sql("WITH ");
+        self.alias.from_clause().walk_ast(out.reborrow())?;
+        out.push_sql(" AS ");
+        self.hint.walk_ast(out.reborrow())?;
+        out.push_sql("(");
+        self.query.walk_ast(out.reborrow())?;
+        out.push_sql(")");
+        Ok(())
+    }
+}
+
+impl_query_id!(WithClause<T, Q, Hint>);
+
+/// A query preceded by a common table expression. Constructed by
+/// [`WithClause::query`](struct.WithClause.html#method.query).
+#[derive(Debug, Clone, Copy)]
+#[must_use = "Queries are only executed when calling `load`, `get_result` or similar."]
+pub struct WithQuery<W, Main> {
+    with: W,
+    query: Main,
+}
+
+impl<W, Main: Query> Query for WithQuery<W, Main> {
+    type SqlType = Main::SqlType;
+}
+
+impl<W, Main, DB> QueryFragment<DB> for WithQuery<W, Main>
+where
+    DB: Backend,
+    W: QueryFragment<DB>,
+    Main: QueryFragment<DB>,
+{
+    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+        self.with.walk_ast(out.reborrow())?;
+        out.push_sql(" ");
+        self.query.walk_ast(out.reborrow())?;
+        Ok(())
+    }
+}
+
+impl_query_id!(WithQuery<W, Main>);
+
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct NoMaterializedHint;
+
+impl<DB: Backend> QueryFragment<DB> for NoMaterializedHint {
+    fn walk_ast(&self, _: AstPass<DB>) -> QueryResult<()> {
+        Ok(())
+    }
+}
+
+impl_query_id!(NoMaterializedHint);
+
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct Materialized;
+
+impl_query_id!(Materialized);
+
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct NotMaterialized;
+
+impl_query_id!(NotMaterialized);
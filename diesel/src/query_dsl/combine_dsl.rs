@@ -0,0 +1,77 @@
+use query_builder::{AsQuery, CombinationClause};
+use query_builder::combination_clause::{All, Distinct, Except, Intersect, Union};
+use query_source::Table;
+
+/// Combines the rows of two queries with a set operation. Both queries must
+/// select the same SQL type.
+///
+/// This is automatically implemented for tables, and for select statements
+/// which have no order, limit or offset clause. Order and limit the
+/// combination instead, as described on
+/// [`CombinationClause`](../query_builder/struct.CombinationClause.html).
+/// MySQL only supports `INTERSECT` and `EXCEPT` as of version 8.0.31.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate diesel;
+/// # include!("../doctest_setup.rs");
+/// #
+/// # table! {
+/// #     users {
+/// #         id -> Integer,
+/// #         name -> VarChar,
+/// #     }
+/// # }
+/// #
+/// # fn main() {
+/// #     let connection = establish_connection();
+/// use self::users::dsl::*;
+///
+/// let names = users.select(name).filter(id.eq(1))
+///     .union_all(users.select(name))
+///     .order(name)
+///     .load::<String>(&connection);
+/// let expected = vec!["Sean".to_string(), "Sean".to_string(), "Tess".to_string()];
+/// assert_eq!(Ok(expected), names);
+/// # }
+/// ```
+pub trait CombineDsl: AsQuery + Sized {
+    /// Returns the rows of both queries, with duplicates removed.
+    fn union<Rhs>(self, rhs: Rhs) -> CombinationClause<Union, Distinct, Self::Query, Rhs::Query>
+    where
+        Rhs: CombineDsl + AsQuery<SqlType = Self::SqlType>,
+    {
+        CombinationClause::new(Union, Distinct, self.as_query(), rhs.as_query())
+    }
+
+    /// Returns the rows of both queries, including duplicates.
+    fn union_all<Rhs>(self, rhs: Rhs) -> CombinationClause<Union, All, Self::Query, Rhs::Query>
+    where
+        Rhs: CombineDsl + AsQuery<SqlType = Self::SqlType>,
+    {
+        CombinationClause::new(Union, All, self.as_query(), rhs.as_query())
+    }
+
+    /// Returns the rows which both queries return, with duplicates removed.
+    fn intersect<Rhs>(
+        self,
+        rhs: Rhs,
+    ) -> CombinationClause<Intersect, Distinct, Self::Query, Rhs::Query>
+    where
+        Rhs: CombineDsl + AsQuery<SqlType = Self::SqlType>,
+    {
+        CombinationClause::new(Intersect, Distinct, self.as_query(), rhs.as_query())
+    }
+
+    /// Returns the rows of this query which `rhs` does not return, with
+    /// duplicates removed.
+    fn except<Rhs>(self, rhs: Rhs) -> CombinationClause<Except, Distinct, Self::Query, Rhs::Query>
+    where
+        Rhs: CombineDsl + AsQuery<SqlType = Self::SqlType>,
+    {
+        CombinationClause::new(Except, Distinct, self.as_query(), rhs.as_query())
+    }
+}
+
+impl<T: Table> CombineDsl for T {}
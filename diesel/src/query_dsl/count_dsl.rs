@@ -1,8 +1,16 @@
-use expression::count::*;
-use super::SelectDsl;
+use query_builder::{AsQuery, Query};
+use query_source::Table;
+use types::BigInt;
 
-/// Adds a simple `count` function to queries. Automatically implemented for all
-/// types which implement `SelectDsl`.
+/// Adds a simple `count` function to queries. Automatically implemented for
+/// tables and select statements.
+///
+/// The select clause of the query is replaced with `COUNT(*)`, and any
+/// `ORDER BY` clause is removed, as ordering has no effect on the count and
+/// PostgreSQL rejects ordering by a column which is not in the select clause.
+/// The `LIMIT` and `OFFSET` clauses are removed as well, so the count is the
+/// total number of rows, which is what is needed to paginate a query. This
+/// works the same way for boxed queries.
 ///
 /// # Example
 ///
@@ -22,13 +30,35 @@ use super::SelectDsl;
 /// #     let connection = establish_connection();
 /// let count = users.count().get_result(&connection);
 /// assert_eq!(Ok(2), count);
+///
+/// let count = users.filter(name.ne("Sean")).order(name).count().get_result(&connection);
+/// assert_eq!(Ok(1), count);
+///
+/// let count = users.order(name).limit(1).offset(1).count().get_result(&connection);
+/// assert_eq!(Ok(2), count);
 /// # }
 /// ```
-pub trait CountDsl: SelectDsl<CountStar> + Sized {
-    /// Get the count of a query. This is equivalent to `.select(count_star())`
-    fn count(self) -> <Self as SelectDsl<CountStar>>::Output {
-        self.select(count_star())
-    }
+pub trait CountDsl {
+    /// The query returned by `count`. See [`dsl::Count`] for convenient
+    /// access to this type.
+    ///
+    /// [`dsl::Count`]: ../dsl/type.Count.html
+    type Output: Query<SqlType = BigInt>;
+
+    /// Get the count of a query. This is equivalent to
+    /// `.select(count_star())`, with the `ORDER BY`, `LIMIT` and `OFFSET`
+    /// clauses removed.
+    fn count(self) -> Self::Output;
 }
 
-impl<T: SelectDsl<CountStar>> CountDsl for T {}
+impl<T> CountDsl for T
+where
+    T: Table + AsQuery,
+    T::Query: CountDsl,
+{
+    type Output = <T::Query as CountDsl>::Output;
+
+    fn count(self) -> Self::Output {
+        self.as_query().count()
+    }
+}
@@ -3,6 +3,13 @@ use query_source::Table;
 
 /// Adds the `DISTINCT` keyword to a query.
 ///
+/// On PostgreSQL and MySQL, every column in the `ORDER BY` clause of a
+/// distinct query must also be selected, as the query is rejected otherwise.
+/// Running a distinct query ordered by a column which is not selected fails
+/// to compile on those backends, unless the query is boxed. See
+/// [`ValidDistinctOrdering`](../query_builder/trait.ValidDistinctOrdering.html)
+/// for the details.
+///
 /// # Example
 ///
 /// ```rust
@@ -0,0 +1,100 @@
+use dsl::Filter;
+use expression::{AsExpression, Expression};
+use expression::grouped::Grouped;
+use expression::operators::{And, Or};
+use expression_methods::BoolExpressionMethods;
+use super::FilterDsl;
+use types::Bool;
+
+/// Adds a group of conditions built by a closure to the `WHERE` clause of a
+/// query. Automatically implemented for all types which implement
+/// `FilterDsl`.
+///
+/// The closure is given an empty group, which conditions can be added to with
+/// `.filter` and `.or_filter`. The resulting predicate is combined with the
+/// rest of the `WHERE` clause as a single condition. Any `OR` inside of the
+/// group is wrapped in parenthesis, so that `AND` conditions outside of the
+/// group can't change its meaning.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate diesel;
+/// # include!("../doctest_setup.rs");
+/// #
+/// # table! {
+/// #     users {
+/// #         id -> Integer,
+/// #         name -> VarChar,
+/// #     }
+/// # }
+/// #
+/// # fn main() {
+/// #     use self::users::dsl::*;
+/// #     let connection = establish_connection();
+/// // SELECT "users"."name" FROM "users"
+/// //     WHERE "users"."id" < $1 AND ("users"."name" = $2 OR "users"."name" = $3)
+/// let names = users.select(name)
+///     .filter(id.lt(10))
+///     .filter_group(|q| q.filter(name.eq("Sean")).or_filter(name.eq("Tess")))
+///     .order(id)
+///     .load::<String>(&connection);
+/// assert_eq!(Ok(vec!["Sean".to_string(), "Tess".to_string()]), names);
+/// # }
+/// ```
+pub trait FilterGroupDsl: Sized {
+    /// See the trait level documentation
+    fn filter_group<F, Predicate>(self, f: F) -> Filter<Self, Predicate>
+    where
+        F: FnOnce(PredicateGroupBuilder) -> PredicateGroup<Predicate>,
+        Self: FilterDsl<Predicate>,
+    {
+        self.filter(f(PredicateGroupBuilder).0)
+    }
+}
+
+impl<T> FilterGroupDsl for T {}
+
+#[derive(Debug, Clone, Copy)]
+/// The empty group of conditions passed to the closure given to
+/// `filter_group`.
+pub struct PredicateGroupBuilder;
+
+impl PredicateGroupBuilder {
+    /// Starts the group with the given condition.
+    pub fn filter<T>(self, predicate: T) -> PredicateGroup<T::Expression>
+    where
+        T: AsExpression<Bool>,
+    {
+        PredicateGroup(predicate.as_expression())
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+/// A group of conditions being built inside of `filter_group`.
+pub struct PredicateGroup<Predicate>(Predicate);
+
+impl<Predicate> PredicateGroup<Predicate>
+where
+    Predicate: Expression<SqlType = Bool>,
+{
+    /// Adds a condition to the group with `AND`.
+    pub fn filter<T>(self, predicate: T) -> PredicateGroup<And<Predicate, T::Expression>>
+    where
+        T: AsExpression<Bool>,
+    {
+        PredicateGroup(self.0.and(predicate))
+    }
+
+    /// Adds a condition to the group with `OR`. The resulting expression is
+    /// wrapped in parenthesis.
+    pub fn or_filter<T>(
+        self,
+        predicate: T,
+    ) -> PredicateGroup<Grouped<Or<Predicate, T::Expression>>>
+    where
+        T: AsExpression<Bool>,
+    {
+        PredicateGroup(self.0.or(predicate))
+    }
+}
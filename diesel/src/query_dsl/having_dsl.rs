@@ -0,0 +1,60 @@
+/// Adds a `HAVING` clause to a query which has been grouped with `.group_by`.
+/// Unlike `.filter`, the predicate may contain aggregate functions.
+///
+/// An expression which was given a name with
+/// [`.aliased`](../expression_methods/trait.ExpressionMethods.html#method.aliased)
+/// can be used in the predicate. MySQL and SQLite refer to it by its alias.
+/// PostgreSQL does not allow aliases in `HAVING`, so the whole expression is
+/// written again instead.
+///
+/// Boxed queries do not know whether they have been grouped, so calling
+/// `.having` on a boxed query without `.group_by` will cause an error when the
+/// query is run.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate diesel;
+/// # include!("../doctest_setup.rs");
+/// #
+/// # table! {
+/// #     users {
+/// #         id -> Integer,
+/// #         name -> VarChar,
+/// #     }
+/// # }
+/// #
+/// # table! {
+/// #     posts {
+/// #         id -> Integer,
+/// #         user_id -> Integer,
+/// #         title -> VarChar,
+/// #     }
+/// # }
+/// #
+/// # fn main() {
+/// #     use self::posts::dsl::*;
+/// #     let connection = establish_connection();
+/// use diesel::expression::dsl::count;
+///
+/// let post_count = count(id).aliased("post_count");
+/// // SELECT COUNT("posts"."id") AS "post_count" FROM "posts"
+/// //     GROUP BY "posts"."user_id" HAVING COUNT("posts"."id") > $1
+/// let counts_over_one = posts
+///     .group_by(user_id)
+///     .select(post_count)
+///     .having(post_count.gt(1))
+///     .load::<i64>(&connection);
+/// assert_eq!(Ok(vec![2]), counts_over_one);
+/// # }
+/// ```
+pub trait HavingDsl<Predicate> {
+    /// The query returned by `having`. See [`dsl::Having`] for convenient
+    /// access to this type.
+    ///
+    /// [`dsl::Having`]: ../dsl/type.Having.html
+    type Output;
+
+    /// See the trait level documentation
+    fn having(self, predicate: Predicate) -> Self::Output;
+}
@@ -80,6 +80,10 @@ where
 ///     INNER JOIN comments ON comments.user_id = users.id
 /// ```
 ///
+/// To load only some of the joined tables, select their `all_columns`. For
+/// example, `.select((users::all_columns, posts::all_columns))` on the first
+/// query above would deserialize into `(User, Post)`.
+///
 /// [associations]: ../associations/index.html
 /// [`enable_multi_table_joins!`]: ../macro.enable_multi_table_joins.html
 pub trait JoinDsl: Sized {
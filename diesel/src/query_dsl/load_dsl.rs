@@ -1,3 +1,6 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
 use backend::Backend;
 use connection::Connection;
 use dsl::Limit;
@@ -20,7 +23,39 @@ where
     U: Queryable<T::SqlType, Conn::Backend>,
 {
     fn internal_load(self, conn: &Conn) -> QueryResult<Vec<U>> {
-        conn.query_by_index(self)
+        let query = self.as_query();
+        if query.is_known_empty() {
+            return Ok(Vec::new());
+        }
+        conn.query_by_index(query)
+    }
+}
+
+pub trait LoadIterQuery<'a, Conn, U>: LoadDsl<Conn> {
+    fn internal_load_iter(
+        self,
+        conn: &'a Conn,
+    ) -> QueryResult<Box<Iterator<Item = QueryResult<U>> + 'a>>;
+}
+
+impl<'a, Conn, T, U> LoadIterQuery<'a, Conn, U> for T
+where
+    Conn: Connection,
+    Conn::Backend: HasSqlType<T::SqlType>,
+    T: AsQuery,
+    T::Query: QueryFragment<Conn::Backend> + QueryId,
+    T::SqlType: 'a,
+    U: Queryable<T::SqlType, Conn::Backend> + 'a,
+{
+    fn internal_load_iter(
+        self,
+        conn: &'a Conn,
+    ) -> QueryResult<Box<Iterator<Item = QueryResult<U>> + 'a>> {
+        let query = self.as_query();
+        if query.is_known_empty() {
+            return Ok(Box::new(::std::iter::empty()));
+        }
+        conn.query_by_index_iter(query)
     }
 }
 
@@ -35,6 +70,52 @@ pub trait LoadDsl<Conn>: Sized {
         self.internal_load(conn)
     }
 
+    /// Executes the given query, returning a `HashMap` built from the returned
+    /// rows. The query must select a tuple of two elements, where the first
+    /// element is used as the key, and the second as the value. If more than
+    /// one row has the same key, the last one returned is kept.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate diesel_codegen;
+    /// # #[macro_use] extern crate diesel;
+    /// # include!("../doctest_setup.rs");
+    /// # use std::collections::HashMap;
+    /// #
+    /// # table! {
+    /// #     users {
+    /// #         id -> Integer,
+    /// #         name -> VarChar,
+    /// #     }
+    /// # }
+    /// #
+    /// #[derive(Queryable, PartialEq, Debug)]
+    /// struct User {
+    ///     id: i32,
+    ///     name: String,
+    /// }
+    ///
+    /// # fn main() {
+    /// #     let connection = establish_connection();
+    /// let users_by_id = users::table
+    ///     .select((users::id, users::all_columns))
+    ///     .load_hash_map::<i32, User>(&connection)
+    ///     .unwrap();
+    /// let sean = User { id: 1, name: "Sean".into() };
+    ///
+    /// assert_eq!(2, users_by_id.len());
+    /// assert_eq!(Some(&sean), users_by_id.get(&1));
+    /// # }
+    /// ```
+    fn load_hash_map<K, V>(self, conn: &Conn) -> QueryResult<HashMap<K, V>>
+    where
+        Self: LoadQuery<Conn, (K, V)>,
+        K: Hash + Eq,
+    {
+        self.load(conn).map(|rows| rows.into_iter().collect())
+    }
+
     /// Runs the command, and returns the affected row. `Err(NotFound)` will be
     /// returned if the query affected 0 rows. You can call `.optional()` on the
     /// result of this if the command was optional to get back a
@@ -53,6 +134,56 @@ pub trait LoadDsl<Conn>: Sized {
     {
         self.load(conn)
     }
+
+    /// Runs the command, returning an iterator over the affected rows. This
+    /// is most useful for commands with a `RETURNING` clause which affect many
+    /// rows, since no `Vec` of all rows is built.
+    ///
+    /// On PostgreSQL each row is received from the server and deserialized
+    /// only once the iterator reaches it, so an error in a later row of a
+    /// `SELECT` is returned by the iterator rather than by this method (the
+    /// rows of a `RETURNING` clause are all computed before the first one is
+    /// sent). Dropping the iterator early still waits for the command to
+    /// finish, discarding the remaining rows. Other backends load all rows
+    /// before returning. The iterator borrows the connection, so it must be
+    /// dropped before the connection is used again.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate diesel_codegen;
+    /// # #[macro_use] extern crate diesel;
+    /// # include!("../doctest_setup.rs");
+    /// #
+    /// # table! {
+    /// #     users {
+    /// #         id -> Integer,
+    /// #         name -> VarChar,
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     let connection = establish_connection();
+    /// let mut names = users::table
+    ///     .select(users::name)
+    ///     .order(users::id)
+    ///     .get_results_iter::<String>(&connection)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(Some(Ok("Sean".to_string())), names.next());
+    /// assert_eq!(Some(Ok("Tess".to_string())), names.next());
+    /// assert_eq!(None, names.next());
+    /// # }
+    /// ```
+    fn get_results_iter<'a, U>(
+        self,
+        conn: &'a Conn,
+    ) -> QueryResult<Box<Iterator<Item = QueryResult<U>> + 'a>>
+    where
+        Self: LoadIterQuery<'a, Conn, U>,
+    {
+        self.internal_load_iter(conn)
+    }
 }
 
 impl<Conn, T> LoadDsl<Conn> for T
@@ -0,0 +1,108 @@
+use dsl::{Count, Limit, Offset, WithTotal};
+use query_builder::AsQuery;
+use query_source::Table;
+use result::Error::QueryBuilderError;
+use result::QueryResult;
+use super::{CountDsl, LimitDsl, LoadDsl, LoadQuery, OffsetDsl};
+
+/// Appends the total number of rows of a query to its select clause, as a
+/// `COUNT(*)` subquery. This is used by
+/// [`load_page_with_total`](trait.LoadPageDsl.html#method.load_page_with_total).
+#[doc(hidden)]
+pub trait WithTotalDsl {
+    type Output;
+
+    fn with_total(self) -> Self::Output;
+}
+
+impl<T> WithTotalDsl for T
+where
+    T: Table + AsQuery,
+    T::Query: WithTotalDsl,
+{
+    type Output = <T::Query as WithTotalDsl>::Output;
+
+    fn with_total(self) -> Self::Output {
+        self.as_query().with_total()
+    }
+}
+
+pub trait LoadPageDsl<Conn>: WithTotalDsl + CountDsl + LoadDsl<Conn> + Clone {
+    /// Loads a single page of the query, along with the total number of rows
+    /// of the query. Pages are numbered from 1.
+    ///
+    /// The total is selected with a subquery, so both are loaded in a single
+    /// round trip, without relying on window functions. When the page is
+    /// past the last row, there is no row to hold the total, and it is
+    /// loaded with a second query instead.
+    ///
+    /// The query should be ordered, so that each row appears on exactly one
+    /// page. Queries with a `DISTINCT`, `GROUP BY` or `FOR UPDATE` clause
+    /// cannot be paginated this way. An error is returned if `page` or
+    /// `per_page` is less than 1.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[macro_use] extern crate diesel;
+    /// # include!("../doctest_setup.rs");
+    /// #
+    /// # table! {
+    /// #     users {
+    /// #         id -> Integer,
+    /// #         name -> VarChar,
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     use self::users::dsl::*;
+    /// #     let connection = establish_connection();
+    /// let second_page = users
+    ///     .select(name)
+    ///     .order(name)
+    ///     .load_page_with_total::<String>(&connection, 2, 1);
+    /// assert_eq!(Ok((vec!["Tess".to_string()], 2)), second_page);
+    ///
+    /// let third_page = users
+    ///     .select(name)
+    ///     .order(name)
+    ///     .load_page_with_total::<String>(&connection, 3, 1);
+    /// assert_eq!(Ok((Vec::new(), 2)), third_page);
+    /// # }
+    /// ```
+    fn load_page_with_total<U>(
+        self,
+        conn: &Conn,
+        page: i64,
+        per_page: i64,
+    ) -> QueryResult<(Vec<U>, i64)>
+    where
+        WithTotal<Self>: LimitDsl,
+        Limit<WithTotal<Self>>: OffsetDsl,
+        Offset<Limit<WithTotal<Self>>>: LoadQuery<Conn, (U, i64)>,
+        Count<Self>: LoadQuery<Conn, i64>,
+    {
+        if page < 1 || per_page < 1 {
+            return Err(QueryBuilderError(
+                "The page and the number of rows per page must be at least 1".into(),
+            ));
+        }
+        let rows = self.clone()
+            .with_total()
+            .limit(per_page)
+            .offset((page - 1) * per_page)
+            .load::<(U, i64)>(conn)?;
+        let total = match rows.first() {
+            Some(&(_, total)) => total,
+            None if page == 1 => 0,
+            None => self.count().get_result(conn)?,
+        };
+        Ok((rows.into_iter().map(|(row, _)| row).collect(), total))
+    }
+}
+
+impl<Conn, T> LoadPageDsl<Conn> for T
+where
+    T: WithTotalDsl + CountDsl + LoadDsl<Conn> + Clone,
+{
+}
@@ -37,3 +37,66 @@ where
         self.as_query().for_update()
     }
 }
+
+/// Adds `FOR UPDATE OF tables` to the end of the select statement, which only
+/// locks the rows of the given tables. This is useful when a query joins
+/// other tables whose rows do not need to be locked. `tables` can be a table,
+/// or a tuple of tables, which must appear in the from clause of the query.
+/// This method is only available for PostgreSQL.
+///
+/// Tables with a schema cannot be used, as PostgreSQL requires the tables to
+/// be named without it. The same restrictions as
+/// [`.for_update`](trait.ForUpdateDsl.html) apply.
+///
+/// # Example
+///
+/// ```ignore
+/// // Executes `SELECT ... FROM users INNER JOIN posts ON ... FOR UPDATE OF users`
+/// users.inner_join(posts).for_update_of(users).load(&connection)
+/// ```
+pub trait ForUpdateOfDsl<Tables> {
+    /// The query returned by `for_update_of`. See [`dsl::ForUpdateOf`] for
+    /// convenient access to this type.
+    ///
+    /// [`dsl::ForUpdateOf`]: ../dsl/type.ForUpdateOf.html
+    type Output;
+
+    /// See the trait level documentation
+    fn for_update_of(self, tables: Tables) -> Self::Output;
+}
+
+impl<T, Tables> ForUpdateOfDsl<Tables> for T
+where
+    T: Table + AsQuery,
+    T::Query: ForUpdateOfDsl<Tables>,
+{
+    type Output = <T::Query as ForUpdateOfDsl<Tables>>::Output;
+
+    fn for_update_of(self, tables: Tables) -> Self::Output {
+        self.as_query().for_update_of(tables)
+    }
+}
+
+/// Adds `SKIP LOCKED` after `FOR UPDATE`, so that rows which are locked by
+/// another transaction are skipped rather than waited for. This is commonly
+/// used to build job queues, where each worker takes the first row nobody
+/// else is working on. This method is only available for PostgreSQL, and
+/// can only be called after [`.for_update`](trait.ForUpdateDsl.html) or
+/// [`.for_update_of`](trait.ForUpdateOfDsl.html).
+///
+/// # Example
+///
+/// ```ignore
+/// // Executes `SELECT * FROM jobs LIMIT 1 FOR UPDATE SKIP LOCKED`
+/// jobs.limit(1).for_update().skip_locked().first(&connection)
+/// ```
+pub trait SkipLockedDsl {
+    /// The query returned by `skip_locked`. See [`dsl::SkipLocked`] for
+    /// convenient access to this type.
+    ///
+    /// [`dsl::SkipLocked`]: ../dsl/type.SkipLocked.html
+    type Output;
+
+    /// See the trait level documentation
+    fn skip_locked(self) -> Self::Output;
+}
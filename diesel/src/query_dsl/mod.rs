@@ -1,35 +1,53 @@
 mod belonging_to_dsl;
 #[doc(hidden)]
 pub mod boxed_dsl;
+mod combine_dsl;
 mod count_dsl;
 mod distinct_dsl;
 mod group_by_dsl;
+mod having_dsl;
 mod join_dsl;
 #[doc(hidden)]
 pub mod limit_dsl;
 #[doc(hidden)]
 pub mod load_dsl;
+mod load_page_dsl;
 mod locking_dsl;
 #[doc(hidden)]
 pub mod select_dsl;
 #[doc(hidden)]
 pub mod filter_dsl;
+#[doc(hidden)]
+pub mod filter_group_dsl;
 mod save_changes_dsl;
 mod offset_dsl;
+mod order_by_values_dsl;
 mod order_dsl;
+mod short_circuit_dsl;
+mod stable_order_dsl;
 
 pub use self::belonging_to_dsl::BelongingToDsl;
 pub use self::boxed_dsl::BoxedDsl;
+pub use self::combine_dsl::CombineDsl;
 pub use self::count_dsl::CountDsl;
 pub use self::distinct_dsl::DistinctDsl;
 pub use self::filter_dsl::{FilterDsl, FindDsl};
+pub use self::filter_group_dsl::FilterGroupDsl;
 #[doc(hidden)]
 pub use self::group_by_dsl::GroupByDsl;
+pub use self::having_dsl::HavingDsl;
 pub use self::join_dsl::{InternalJoinDsl, JoinDsl, JoinOnDsl, JoinWithImplicitOnClause};
 pub use self::limit_dsl::LimitDsl;
-pub use self::load_dsl::{ExecuteDsl, FirstDsl, LoadDsl, LoadQuery};
-pub use self::locking_dsl::ForUpdateDsl;
+pub use self::load_dsl::{ExecuteDsl, FirstDsl, LoadDsl, LoadIterQuery, LoadQuery};
+pub use self::load_page_dsl::{LoadPageDsl, WithTotalDsl};
+pub use self::locking_dsl::{ForUpdateDsl, ForUpdateOfDsl, SkipLockedDsl};
 pub use self::offset_dsl::OffsetDsl;
-pub use self::order_dsl::OrderDsl;
+pub use self::order_by_values_dsl::OrderByValuesDsl;
+pub use self::order_dsl::{NullsLastEverywhereDsl, OrderDsl};
 pub use self::save_changes_dsl::SaveChangesDsl;
 pub use self::select_dsl::SelectDsl;
+pub use self::short_circuit_dsl::ShortCircuitEmptyInDsl;
+pub use self::stable_order_dsl::StableOrderDsl;
+
+#[cfg(feature = "postgres")]
+pub use pg::ExplainDsl;
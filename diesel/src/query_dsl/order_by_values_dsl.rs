@@ -0,0 +1,77 @@
+use expression::Expression;
+use expression::order_by_values::OrderByValues;
+use query_builder::AsQuery;
+use query_dsl::OrderDsl;
+
+/// Sets the order clause of a query like [`.order`](trait.OrderDsl.html), so
+/// that the rows are returned in the order in which the values of `column`
+/// appear in `values`.
+///
+/// This is useful to keep the order of a list of ids, such as after loading
+/// them with [`.eq_any`](../expression_methods/trait.ExpressionMethods.html#method.eq_any).
+/// Rows whose value is not in the list are returned after all other rows on
+/// PostgreSQL and SQLite, and before them on MySQL.
+///
+/// On PostgreSQL this is written as `ORDER BY array_position($1, column)`,
+/// with the values bound as a single array. On MySQL it is written as
+/// `ORDER BY FIELD(column, ?, ...)`, and on SQLite as a `CASE` expression.
+/// On these two backends, queries using this method will not be placed in the
+/// prepared statement cache.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate diesel;
+/// # include!("../doctest_setup.rs");
+/// #
+/// # table! {
+/// #     users {
+/// #         id -> Integer,
+/// #         name -> VarChar,
+/// #     }
+/// # }
+/// #
+/// # fn main() {
+/// use self::users::dsl::*;
+///
+/// let connection = establish_connection();
+/// # connection.execute("DELETE FROM users").unwrap();
+/// connection.execute("INSERT INTO users (id, name) VALUES (1, 'Sean'), (2, 'Tess'), (3, 'Jim')")
+///     .unwrap();
+/// let ids = vec![3, 1, 2];
+/// let names = users
+///     .select(name)
+///     .filter(id.eq_any(&ids))
+///     .order_by_values(id, &ids)
+///     .load::<String>(&connection);
+/// let expected = vec!["Jim".to_string(), "Sean".to_string(), "Tess".to_string()];
+/// assert_eq!(Ok(expected), names);
+/// # }
+/// ```
+pub trait OrderByValuesDsl<Col, U>: AsQuery {
+    /// The query returned by `order_by_values`. See [`dsl::OrderByValues`]
+    /// for convenient access to this type.
+    ///
+    /// [`dsl::OrderByValues`]: ../dsl/type.OrderByValues.html
+    type Output: AsQuery<SqlType = Self::SqlType>;
+
+    /// See the trait level documentation
+    fn order_by_values<V>(self, column: Col, values: V) -> Self::Output
+    where
+        V: IntoIterator<Item = U>;
+}
+
+impl<T, Col, U> OrderByValuesDsl<Col, U> for T
+where
+    Col: Expression,
+    T: OrderDsl<OrderByValues<Col, U>>,
+{
+    type Output = T::Output;
+
+    fn order_by_values<V>(self, column: Col, values: V) -> Self::Output
+    where
+        V: IntoIterator<Item = U>,
+    {
+        self.order(OrderByValues::new(column, values.into_iter().collect()))
+    }
+}
@@ -59,3 +59,58 @@ where
         self.as_query().order(expr)
     }
 }
+
+/// Sorts nulls after all other values for every term of the order clause of
+/// a query, whether the term is ascending or descending. This must be called
+/// after [`.order`](trait.OrderDsl.html), as a new order clause replaces it.
+///
+/// On PostgreSQL, `NULLS LAST` is added to every term. Terms which already
+/// use [`.nulls_first()`] or [`.nulls_last()`] are left unchanged, so that an
+/// explicit ordering wins. On MySQL and SQLite, which cannot use these
+/// methods, every term is preceded by `expr IS NULL`.
+///
+/// Each term must be a column, or an expression with `.asc()` or `.desc()`.
+///
+/// Boxed queries do not know the terms of their order clause, so this cannot
+/// be called after [`.into_boxed()`](trait.BoxedDsl.html). Call it before
+/// boxing the query instead.
+///
+/// [`.nulls_first()`]: ../pg/expression/expression_methods/trait.SortExpressionMethods.html#method.nulls_first
+/// [`.nulls_last()`]: ../pg/expression/expression_methods/trait.SortExpressionMethods.html#method.nulls_last
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate diesel;
+/// # include!("../doctest_setup.rs");
+/// #
+/// # table! {
+/// #     users {
+/// #         id -> Integer,
+/// #         name -> VarChar,
+/// #     }
+/// # }
+/// #
+/// # fn main() {
+/// use self::animals::dsl::*;
+///
+/// let connection = establish_connection();
+/// // Nulls normally come first in descending order on PostgreSQL
+/// let species_by_name = animals
+///     .select(species)
+///     .order((name.desc(), legs))
+///     .nulls_last_everywhere()
+///     .load::<String>(&connection);
+/// assert_eq!(Ok(vec!["dog".to_string(), "spider".to_string()]), species_by_name);
+/// # }
+/// ```
+pub trait NullsLastEverywhereDsl {
+    /// The query returned by `nulls_last_everywhere`. See
+    /// [`dsl::NullsLastEverywhere`] for convenient access to this type.
+    ///
+    /// [`dsl::NullsLastEverywhere`]: ../dsl/type.NullsLastEverywhere.html
+    type Output;
+
+    /// See the trait level documentation
+    fn nulls_last_everywhere(self) -> Self::Output;
+}
@@ -6,6 +6,11 @@ use query_source::Table;
 /// will be overridden. The expression passed to `select` must actually be valid
 /// for the query (only contains columns from the target table, doesn't mix
 /// aggregate + non-aggregate expressions, etc).
+///
+/// Diesel never generates `SELECT *`. Every column is listed explicitly, so
+/// the values of each row are deserialized in the order of the expressions
+/// given to `select`, regardless of the order the columns were defined in
+/// the database.
 pub trait SelectDsl<Selection: Expression> {
     type Output: Query<SqlType = <Selection as Expression>::SqlType>;
 
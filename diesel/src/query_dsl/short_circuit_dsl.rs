@@ -0,0 +1,53 @@
+/// Returns no rows without sending the query to the database, when its where
+/// clause is known to match no rows. This is the case when the where clause
+/// contains [`.eq_any`] with no values, and every other filter is combined
+/// with it using `AND`, such as when calling `.filter` more than once. This
+/// must be called after the other methods of the query.
+///
+/// This is useful for endpoints which filter by a list of values, where the
+/// list is often empty. Queries without this method send `1=0` to the
+/// database for an empty list.
+///
+/// Queries without a `GROUP BY` clause can only use this method when they do
+/// not select an aggregate, such as [`count_star`], which returns a row even
+/// when no rows match. Boxed queries cannot use this method.
+///
+/// [`.eq_any`]: ../expression_methods/trait.ExpressionMethods.html#method.eq_any
+/// [`count_star`]: ../dsl/fn.count_star.html
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate diesel;
+/// # include!("../doctest_setup.rs");
+/// #
+/// # table! {
+/// #     users {
+/// #         id -> Integer,
+/// #         name -> VarChar,
+/// #     }
+/// # }
+/// #
+/// # fn main() {
+/// use self::users::dsl::*;
+///
+/// let connection = establish_connection();
+/// let ids = Vec::<i32>::new();
+/// let names = users
+///     .select(name)
+///     .filter(id.eq_any(&ids))
+///     .short_circuit_empty_in()
+///     .load::<String>(&connection);
+/// assert_eq!(Ok(Vec::new()), names);
+/// # }
+/// ```
+pub trait ShortCircuitEmptyInDsl {
+    /// The query returned by `short_circuit_empty_in`. See
+    /// [`dsl::ShortCircuitEmptyIn`] for convenient access to this type.
+    ///
+    /// [`dsl::ShortCircuitEmptyIn`]: ../dsl/type.ShortCircuitEmptyIn.html
+    type Output;
+
+    /// See the trait level documentation
+    fn short_circuit_empty_in(self) -> Self::Output;
+}
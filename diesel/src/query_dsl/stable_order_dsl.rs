@@ -0,0 +1,64 @@
+use expression::Expression;
+use query_builder::AsQuery;
+use query_source::Table;
+
+/// Sets the order clause of a query like [`.order`](trait.OrderDsl.html), and
+/// appends the primary key of the table as a tiebreaker.
+///
+/// Rows which are equal in the given ordering may otherwise be returned in any
+/// order, which can change between two queries. When paginating with
+/// `.limit` and `.offset`, this makes rows appear on more than one page, or on
+/// none at all. Ordering by the primary key as well makes the order stable.
+/// The primary key is always sorted in ascending order.
+///
+/// This is automatically implemented for queries which select from a single
+/// table.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate diesel;
+/// # include!("../doctest_setup.rs");
+/// #
+/// # table! {
+/// #     users {
+/// #         id -> Integer,
+/// #         name -> VarChar,
+/// #     }
+/// # }
+/// #
+/// # fn main() {
+/// use self::users::dsl::*;
+///
+/// let connection = establish_connection();
+/// # connection.execute("DELETE FROM users").unwrap();
+/// connection.execute("INSERT INTO users (id, name) VALUES (1, 'Sean'), (2, 'Tess'), (3, 'Sean')")
+///     .unwrap();
+/// // SELECT "users"."id" FROM "users" ORDER BY "users"."name" DESC, "users"."id"
+/// let ids = users.select(id).order_stable_by(name.desc()).load(&connection);
+/// assert_eq!(Ok(vec![2, 1, 3]), ids);
+/// # }
+/// ```
+pub trait StableOrderDsl<Expr: Expression>: AsQuery {
+    /// The query returned by `order_stable_by`. See [`dsl::OrderStableBy`]
+    /// for convenient access to this type.
+    ///
+    /// [`dsl::OrderStableBy`]: ../dsl/type.OrderStableBy.html
+    type Output: AsQuery<SqlType = Self::SqlType>;
+
+    /// See the trait level documentation
+    fn order_stable_by(self, expr: Expr) -> Self::Output;
+}
+
+impl<T, Expr, ST> StableOrderDsl<Expr> for T
+where
+    Expr: Expression,
+    T: Table + AsQuery<SqlType = ST>,
+    T::Query: StableOrderDsl<Expr, SqlType = ST>,
+{
+    type Output = <T::Query as StableOrderDsl<Expr>>::Output;
+
+    fn order_stable_by(self, expr: Expr) -> Self::Output {
+        self.as_query().order_stable_by(expr)
+    }
+}
@@ -0,0 +1,124 @@
+use expression::SelectableExpression;
+use query_builder::*;
+use query_builder::nodes::{Identifier, InfixNode};
+use super::{AppearsInFromClause, Once, QuerySource, Table};
+
+/// Queries a table whose name is only known at runtime, such as one shard of
+/// a table which is split by month. The columns are the ones of `table`,
+/// which must have been declared with [`table!`](../macro.table.html).
+///
+/// Diesel cannot check that a table with the given name exists, or that its
+/// columns match the declaration. The column types are asserted by the
+/// `table!` declaration, the same way they are for a table with a fixed name.
+///
+/// The name is quoted with `push_identifier`, and the table is given the name
+/// of `table` as an alias (`FROM "events_2017_01" AS "events"`), so the columns
+/// can be used as usual. `table` must not be declared with a schema.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate diesel;
+/// # include!("../doctest_setup.rs");
+/// #
+/// # table! {
+/// #     users {
+/// #         id -> Integer,
+/// #         name -> VarChar,
+/// #     }
+/// # }
+/// #
+/// table! {
+///     events {
+///         id -> Integer,
+///         name -> VarChar,
+///     }
+/// }
+///
+/// # fn main() {
+/// #     let connection = establish_connection();
+/// #     connection.execute("CREATE TABLE events_2017_01 (id INTEGER PRIMARY KEY, name VARCHAR NOT NULL)").unwrap();
+/// #     connection.execute("INSERT INTO events_2017_01 (id, name) VALUES (1, 'launch')").unwrap();
+/// use diesel::dynamic_table;
+///
+/// let table_name = format!("events_{}_{:02}", 2017, 1);
+/// let names = dynamic_table(events::table, &table_name)
+///     .select(events::name)
+///     .filter(events::id.eq(1))
+///     .load::<String>(&connection);
+/// assert_eq!(Ok(vec!["launch".to_string()]), names);
+/// # }
+/// ```
+pub fn dynamic_table<T: Table>(table: T, name: &str) -> DynamicTable<T> {
+    DynamicTable {
+        table: table,
+        name: name,
+    }
+}
+
+/// A table with a name given at runtime. Constructed by
+/// [`dynamic_table`](fn.dynamic_table.html).
+#[derive(Debug, Clone, Copy)]
+pub struct DynamicTable<'a, T> {
+    table: T,
+    name: &'a str,
+}
+
+impl<'a, T> QuerySource for DynamicTable<'a, T>
+where
+    T: Table + QuerySource<FromClause = Identifier<'static>>,
+    T::AllColumns: SelectableExpression<Self>,
+{
+    type FromClause = InfixNode<'static, Identifier<'a>, Identifier<'static>>;
+    type DefaultSelection = T::AllColumns;
+
+    fn from_clause(&self) -> Self::FromClause {
+        InfixNode::new(Identifier(self.name), self.table.from_clause(), " AS ")
+    }
+
+    fn default_selection(&self) -> Self::DefaultSelection {
+        T::all_columns()
+    }
+}
+
+impl<'a, T> AsQuery for DynamicTable<'a, T>
+where
+    SelectStatement<Self>: Query,
+{
+    type SqlType = <SelectStatement<Self> as Query>::SqlType;
+    type Query = SelectStatement<Self>;
+
+    fn as_query(self) -> Self::Query {
+        SelectStatement::simple(self)
+    }
+}
+
+impl<'a, T> Table for DynamicTable<'a, T>
+where
+    T: Table,
+    Self: QuerySource + AsQuery,
+    T::PrimaryKey: SelectableExpression<Self>,
+    T::AllColumns: SelectableExpression<Self>,
+{
+    type PrimaryKey = T::PrimaryKey;
+    type AllColumns = T::AllColumns;
+
+    fn primary_key(&self) -> Self::PrimaryKey {
+        self.table.primary_key()
+    }
+
+    fn all_columns() -> Self::AllColumns {
+        T::all_columns()
+    }
+}
+
+impl<'a, T: Table> AppearsInFromClause<T> for DynamicTable<'a, T> {
+    type Count = Once;
+}
+
+// The name is part of the SQL, so the type alone does not identify the query
+impl<'a, T> QueryId for DynamicTable<'a, T> {
+    type QueryId = ();
+
+    const HAS_STATIC_QUERY_ID: bool = false;
+}
@@ -292,8 +292,8 @@ impl<T> Plus<T> for Never {
 #[doc(hidden)]
 #[derive(Debug, Clone, Copy)]
 pub struct OnClauseWrapper<Source, On> {
-    source: Source,
-    on: On,
+    pub(crate) source: Source,
+    pub(crate) on: On,
 }
 
 impl<Source, On> OnClauseWrapper<Source, On> {
@@ -0,0 +1,213 @@
+use backend::Backend;
+use expression::{AppearsOnTable, Expression, NonAggregate, SelectableExpression};
+use query_builder::*;
+use query_builder::nodes::Identifier;
+use result::QueryResult;
+use super::{AppearsInFromClause, Column, JoinTo, Never, QuerySource, Table};
+use types::Bool;
+
+/// Creates a `LATERAL` subquery which can be joined to a table with
+/// `inner_join` or `left_join`. The subquery can reference columns of the
+/// tables it is joined to by wrapping them in [`outer`](fn.outer.html).
+///
+/// The columns of the subquery are described by `alias`, which must be a table
+/// declared with [`table!`](../macro.table.html) without a schema. The
+/// subquery is given the name of `alias` (`LATERAL (SELECT ...) AS
+/// latest_posts`), so the columns of `alias` can be selected from the joined
+/// query like any other column. Diesel cannot check that the subquery returns
+/// columns with those names and types. Select columns with the same names as
+/// the columns of `alias`, in the same order.
+///
+/// Joining a lateral subquery without calling `.on` uses `ON TRUE`, so
+/// `users.inner_join(lateral(...))` is equivalent to `CROSS JOIN LATERAL`, and
+/// `users.left_join(lateral(...))` keeps the rows for which the subquery
+/// returns no rows. Since the tables to the left of the subquery need to know
+/// that the alias is a different table, the two need to be passed to
+/// [`enable_multi_table_joins!`](../macro.enable_multi_table_joins.html).
+///
+/// `LATERAL` is supported by PostgreSQL and MySQL 8.0.14 or later. SQLite will
+/// return a syntax error.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate diesel;
+/// # include!("../doctest_setup.rs");
+/// #
+/// # table! {
+/// #     users {
+/// #         id -> Integer,
+/// #         name -> VarChar,
+/// #     }
+/// # }
+/// #
+/// # table! {
+/// #     posts {
+/// #         id -> Integer,
+/// #         user_id -> Integer,
+/// #         title -> VarChar,
+/// #     }
+/// # }
+/// #
+/// table! {
+///     latest_posts (id) {
+///         id -> Integer,
+///         title -> VarChar,
+///     }
+/// }
+///
+/// enable_multi_table_joins!(users, latest_posts);
+/// joinable!(posts -> users (user_id));
+///
+/// # #[cfg(feature = "postgres")]
+/// # fn main() {
+/// #     let connection = establish_connection();
+/// use diesel::query_source::{lateral, outer};
+///
+/// let latest_post = posts::table
+///     .select((posts::id, posts::title))
+///     .filter(posts::user_id.eq(outer(users::id)))
+///     .order(posts::id.desc())
+///     .limit(1);
+/// let data = users::table
+///     .inner_join(lateral(latest_post, latest_posts::table))
+///     .select((users::name, latest_posts::title))
+///     .order(users::id)
+///     .load::<(String, String)>(&connection);
+/// let expected = vec![
+///     ("Sean".to_string(), "About Rust".to_string()),
+///     ("Tess".to_string(), "My first post too".to_string()),
+/// ];
+/// assert_eq!(Ok(expected), data);
+/// # }
+/// # #[cfg(not(feature = "postgres"))]
+/// # fn main() {}
+/// ```
+pub fn lateral<Q, T>(query: Q, alias: T) -> Lateral<Q::Query, T>
+where
+    Q: AsQuery,
+    T: Table,
+{
+    Lateral {
+        query: query.as_query(),
+        alias: alias,
+    }
+}
+
+/// A `LATERAL` subquery. Constructed by [`lateral`](fn.lateral.html).
+#[derive(Debug, Clone, Copy)]
+pub struct Lateral<Q, T> {
+    query: Q,
+    alias: T,
+}
+
+impl<Q, T> QuerySource for Lateral<Q, T>
+where
+    T: Table + QuerySource<FromClause = Identifier<'static>>,
+    T::AllColumns: SelectableExpression<Self>,
+    Self: Clone,
+{
+    type FromClause = Self;
+    type DefaultSelection = T::AllColumns;
+
+    fn from_clause(&self) -> Self::FromClause {
+        self.clone()
+    }
+
+    fn default_selection(&self) -> Self::DefaultSelection {
+        T::all_columns()
+    }
+}
+
+impl<Q, T, DB> QueryFragment<DB> for Lateral<Q, T>
+where
+    DB: Backend,
+    Q: QueryFragment<DB>,
+    T: QuerySource<FromClause = Identifier<'static>>,
+{
+    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+        out.push_sql("LATERAL (");
+        self.query.walk_ast(out.reborrow())?;
+        out.push_sql(") AS ");
+        self.alias.from_clause().walk_ast(out.reborrow())
+    }
+}
+
+impl_query_id!(Lateral<Q, T>);
+
+impl<Q, T, U> AppearsInFromClause<U> for Lateral<Q, T>
+where
+    T: AppearsInFromClause<U>,
+{
+    type Count = T::Count;
+}
+
+impl<Lhs, Q, T> JoinTo<Lateral<Q, T>> for Lhs
+where
+    Lhs: Table,
+{
+    type FromClause = Lateral<Q, T>;
+    type OnClause = OnTrue;
+
+    fn join_target(rhs: Lateral<Q, T>) -> (Self::FromClause, Self::OnClause) {
+        (rhs, OnTrue)
+    }
+}
+
+/// The `ON` clause used when a lateral subquery is joined without calling
+/// `.on`.
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct OnTrue;
+
+impl Expression for OnTrue {
+    type SqlType = Bool;
+}
+
+impl<DB: Backend> QueryFragment<DB> for OnTrue {
+    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+        out.push_sql("TRUE");
+        Ok(())
+    }
+}
+
+impl_query_id!(OnTrue);
+
+impl<QS> AppearsOnTable<QS> for OnTrue {}
+
+/// References a column of a table which is joined to the left of a `LATERAL`
+/// subquery, so it can be used inside of the subquery.
+///
+/// The column can only be used in a query whose tables are allowed to appear
+/// in the same query as the column's table, and which does not include that
+/// table itself. Diesel does not check that the table is actually part of the
+/// outer query. The result is only meaningful inside of a subquery passed to
+/// [`lateral`](fn.lateral.html).
+pub fn outer<C: Column>(column: C) -> Outer<C> {
+    Outer(column)
+}
+
+#[derive(Debug, Clone, Copy)]
+#[doc(hidden)]
+pub struct Outer<C>(C);
+
+impl<C: Expression> Expression for Outer<C> {
+    type SqlType = C::SqlType;
+}
+
+impl<C: QueryFragment<DB>, DB: Backend> QueryFragment<DB> for Outer<C> {
+    fn walk_ast(&self, out: AstPass<DB>) -> QueryResult<()> {
+        self.0.walk_ast(out)
+    }
+}
+
+impl_query_id!(Outer<C>);
+
+impl<C, QS> AppearsOnTable<QS> for Outer<C>
+where
+    C: Column,
+    QS: AppearsInFromClause<C::Table, Count = Never>,
+{
+}
+
+impl<C: Column> NonAggregate for Outer<C> {}
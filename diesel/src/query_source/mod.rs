@@ -1,19 +1,27 @@
 //! Types in this module are mostly internal and automatically generated. You
 //! shouldn't need to interact with these types during normal usage, other than
 //! the methods on [`Table`](/diesel/query_source/trait.Table.html)
+mod dynamic_table;
 #[doc(hidden)]
 pub mod joins;
+mod lateral;
 
 use backend::Backend;
 use expression::{Expression, NonAggregate, SelectableExpression};
 use query_builder::*;
 use types::{FromSqlRow, HasSqlType};
 
+pub use self::dynamic_table::{dynamic_table, DynamicTable};
 pub use self::joins::JoinTo;
+pub use self::lateral::{lateral, outer, Lateral};
 
 /// Trait indicating that a record can be queried from the database. This trait
 /// can be derived automatically using `diesel_codegen`. This trait can only be derived for
 /// structs, not enums.
+///
+/// When deriving this trait, a field annotated with
+/// `#[diesel(deserialize_as = "SomeType")]` is loaded as `SomeType`, and then
+/// converted to the type of the field, using `FieldType: From<SomeType>`.
 pub trait Queryable<ST, DB>
 where
     DB: Backend + HasSqlType<ST>,
@@ -18,6 +18,10 @@ pub enum Error {
     QueryBuilderError(Box<StdError + Send + Sync>),
     DeserializationError(Box<StdError + Send + Sync>),
     SerializationError(Box<StdError + Send + Sync>),
+    /// The connection to the database was lost while running a query, for
+    /// example because the server was shut down or the network failed. The
+    /// connection cannot be used anymore, and should be discarded.
+    ConnectionClosed(Box<DatabaseErrorInformation + Send + Sync>),
     /// You can return this variant inside of a transaction when you want to
     /// roll it back, but have no actual error to return. Diesel will never
     /// return this variant unless you gave it to us, and it can be safely
@@ -39,12 +43,57 @@ pub enum DatabaseErrorKind {
     #[doc(hidden)] __Unknown, // Match against _ instead, more variants may be added in the future
 }
 
+/// Information about an error that was returned by the database.
+///
+/// Which of these fields are available depends on the backend and the kind of
+/// error. Only PostgreSQL reports the table, column, and constraint involved.
+/// These can be used to tell which constraint was violated without matching
+/// on the message.
+///
+/// # Example
+///
+/// ```rust
+/// # #[macro_use] extern crate diesel;
+/// # include!("doctest_setup.rs");
+/// # use diesel::result::{DatabaseErrorKind, Error};
+/// #
+/// # table! {
+/// #     users {
+/// #         id -> Integer,
+/// #         name -> VarChar,
+/// #     }
+/// # }
+/// #
+/// # #[cfg(feature = "postgres")]
+/// # fn main() {
+/// #     use self::users::dsl::*;
+/// #     let connection = establish_connection();
+/// #     connection.execute("ALTER TABLE users ADD CONSTRAINT users_name_key UNIQUE (name)").unwrap();
+/// let result = diesel::insert(&name.eq("Sean")).into(users).execute(&connection);
+/// let message = match result {
+///     Err(Error::DatabaseError(DatabaseErrorKind::UniqueViolation, ref info))
+///         if info.constraint_name() == Some("users_name_key") => "That name is taken",
+///     _ => "Something went wrong",
+/// };
+/// assert_eq!("That name is taken", message);
+/// # }
+/// # #[cfg(not(feature = "postgres"))]
+/// # fn main() {}
+/// ```
 pub trait DatabaseErrorInformation {
+    /// The primary human-readable error message.
     fn message(&self) -> &str;
+    /// An optional secondary error message with more details.
     fn details(&self) -> Option<&str>;
+    /// An optional suggestion of what to do about the problem.
     fn hint(&self) -> Option<&str>;
+    /// The name of the table the error was associated with, if any.
     fn table_name(&self) -> Option<&str>;
+    /// The name of the column the error was associated with, if any. This is
+    /// reported for `NOT NULL` violations, but not for unique or foreign key
+    /// violations.
     fn column_name(&self) -> Option<&str>;
+    /// The name of the constraint which was violated, if any.
     fn constraint_name(&self) -> Option<&str>;
 }
 
@@ -159,6 +208,7 @@ impl Display for Error {
             Error::QueryBuilderError(ref e) => e.fmt(f),
             Error::DeserializationError(ref e) => e.fmt(f),
             Error::SerializationError(ref e) => e.fmt(f),
+            Error::ConnectionClosed(ref e) => write!(f, "{}", e.message()),
             Error::RollbackTransaction => write!(f, "{}", self.description()),
             Error::__Nonexhaustive => unreachable!(),
         }
@@ -174,6 +224,7 @@ impl StdError for Error {
             Error::QueryBuilderError(ref e) => e.description(),
             Error::DeserializationError(ref e) => e.description(),
             Error::SerializationError(ref e) => e.description(),
+            Error::ConnectionClosed(ref e) => e.message(),
             Error::RollbackTransaction => "The current transaction was aborted",
             Error::__Nonexhaustive => unreachable!(),
         }
@@ -211,6 +262,9 @@ impl PartialEq for Error {
             (&Error::DatabaseError(_, ref a), &Error::DatabaseError(_, ref b)) => {
                 a.message() == b.message()
             }
+            (&Error::ConnectionClosed(ref a), &Error::ConnectionClosed(ref b)) => {
+                a.message() == b.message()
+            }
             (&Error::NotFound, &Error::NotFound) => true,
             _ => false,
         }
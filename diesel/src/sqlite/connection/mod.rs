@@ -74,6 +74,19 @@ impl Connection for SqliteConnection {
         iter.collect()
     }
 
+    fn execute_query<T>(&self, query: &T) -> QueryResult<usize>
+    where
+        T: Query + QueryFragment<Self::Backend> + QueryId,
+    {
+        let mut statement = try!(self.prepare_query(query));
+        let mut statement_use = StatementUse::new(&mut statement);
+        let mut row_count = 0;
+        while statement_use.step().is_some() {
+            row_count += 1;
+        }
+        Ok(row_count)
+    }
+
     #[doc(hidden)]
     fn execute_returning_count<T>(&self, source: &T) -> QueryResult<usize>
     where
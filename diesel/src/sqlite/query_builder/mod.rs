@@ -1,15 +1,16 @@
 use super::backend::Sqlite;
-use query_builder::QueryBuilder;
+use query_builder::{check_identifier, escape_identifier, QueryBuilder, SqlBuffer};
 use result::QueryResult;
 
 pub mod functions;
 #[doc(hidden)]
 pub mod nodes;
+mod query_fragment_impls;
 
 #[allow(missing_debug_implementations)]
 #[derive(Default)]
 pub struct SqliteQueryBuilder {
-    sql: String,
+    sql: SqlBuffer,
 }
 
 impl SqliteQueryBuilder {
@@ -20,12 +21,17 @@ impl SqliteQueryBuilder {
 
 impl QueryBuilder<Sqlite> for SqliteQueryBuilder {
     fn push_sql(&mut self, sql: &str) {
-        self.sql.push_str(sql);
+        self.sql.push_fragment(sql);
+    }
+
+    fn push_sql_verbatim(&mut self, sql: &str) {
+        self.sql.push_verbatim(sql);
     }
 
     fn push_identifier(&mut self, identifier: &str) -> QueryResult<()> {
+        check_identifier(identifier)?;
         self.push_sql("`");
-        self.push_sql(&identifier.replace("`", "``"));
+        self.push_sql(&escape_identifier(identifier, '`'));
         self.push_sql("`");
         Ok(())
     }
@@ -35,6 +41,6 @@ impl QueryBuilder<Sqlite> for SqliteQueryBuilder {
     }
 
     fn finish(self) -> String {
-        self.sql
+        self.sql.finish()
     }
 }
@@ -0,0 +1,222 @@
+use expression::Expression;
+use expression::bound::TypedBound;
+use expression::functions::aggregate_boolean::{BoolAnd, BoolOr};
+use expression::functions::date_and_time::{DatePart, Extract, ExtractFrom};
+use expression::functions::string_agg::StringAgg;
+use expression::icontains::IContains;
+use expression::null::Null;
+use expression::ops::BitXor;
+use expression::order_by_values::OrderByValues;
+use expression::operators::{Asc, Desc};
+use query_builder::{AliasReference, AstPass, QueryFragment, ValidDistinctOrdering};
+use query_builder::distinct_clause::DistinctClause;
+use query_builder::group_by_clause::HavingClause;
+use query_builder::insert_statement::DefaultValuesClause;
+use query_builder::order_clause::{NoOrderClause, NullsLastOrder};
+use query_source::Column;
+use result::QueryResult;
+use sqlite::Sqlite;
+use types::{HasSqlType, Text, ToSql, VarChar};
+
+impl<T, U> QueryFragment<Sqlite> for TypedBound<T, U>
+where
+    Sqlite: HasSqlType<T>,
+    U: ToSql<T, Sqlite>,
+{
+    fn walk_ast(&self, out: AstPass<Sqlite>) -> QueryResult<()> {
+        self.bound.walk_ast(out)
+    }
+}
+
+impl<ST> QueryFragment<Sqlite> for Null<ST> {
+    fn walk_ast(&self, mut out: AstPass<Sqlite>) -> QueryResult<()> {
+        out.push_sql("NULL");
+        Ok(())
+    }
+}
+
+/// SQLite does not support ordering the values of `group_concat`, so only the
+/// unordered form is implemented.
+impl<'a, T> QueryFragment<Sqlite> for StringAgg<'a, T, NoOrderClause>
+where
+    T: QueryFragment<Sqlite>,
+{
+    fn walk_ast(&self, mut out: AstPass<Sqlite>) -> QueryResult<()> {
+        out.push_sql("group_concat(");
+        self.expr.walk_ast(out.reborrow())?;
+        out.push_sql(", ");
+        out.push_bind_param::<Text, _>(&self.delimiter)?;
+        self.order.walk_ast(out.reborrow())?;
+        out.push_sql(")");
+        Ok(())
+    }
+}
+
+impl<GroupBy, Predicate> QueryFragment<Sqlite> for HavingClause<GroupBy, Predicate>
+where
+    GroupBy: QueryFragment<Sqlite>,
+    Predicate: QueryFragment<Sqlite>,
+{
+    fn walk_ast(&self, out: AstPass<Sqlite>) -> QueryResult<()> {
+        self.walk_with_aliases(out, AliasReference::Name)
+    }
+}
+
+/// SQLite has no boolean aggregates, but stores booleans as integers, so the
+/// smallest and largest values give the same result.
+impl<T> QueryFragment<Sqlite> for BoolAnd<T>
+where
+    T: QueryFragment<Sqlite>,
+{
+    fn walk_ast(&self, out: AstPass<Sqlite>) -> QueryResult<()> {
+        self.walk_with_function(out, "MIN")
+    }
+}
+
+impl<T> QueryFragment<Sqlite> for BoolOr<T>
+where
+    T: QueryFragment<Sqlite>,
+{
+    fn walk_ast(&self, out: AstPass<Sqlite>) -> QueryResult<()> {
+        self.walk_with_function(out, "MAX")
+    }
+}
+
+/// SQLite has no `ILIKE`, so both sides are compared in lower case.
+impl<T> QueryFragment<Sqlite> for IContains<T>
+where
+    T: QueryFragment<Sqlite>,
+{
+    fn walk_ast(&self, mut out: AstPass<Sqlite>) -> QueryResult<()> {
+        out.push_sql("lower(");
+        self.expr.walk_ast(out.reborrow())?;
+        out.push_sql(") LIKE lower(");
+        out.push_bind_param::<VarChar, _>(&self.pattern)?;
+        out.push_sql(") ESCAPE ");
+        out.push_bind_param::<VarChar, _>(&"\\")
+    }
+}
+
+impl QueryFragment<Sqlite> for DefaultValuesClause {
+    fn walk_ast(&self, mut out: AstPass<Sqlite>) -> QueryResult<()> {
+        out.push_sql(" DEFAULT VALUES");
+        Ok(())
+    }
+}
+
+/// SQLite has neither `array_position` nor `FIELD`, so each value is mapped to
+/// its position with a `CASE` expression. Rows with values which are not in
+/// the list come last.
+impl<T, U> QueryFragment<Sqlite> for OrderByValues<T, U>
+where
+    Sqlite: HasSqlType<T::SqlType>,
+    T: Expression + QueryFragment<Sqlite>,
+    U: ToSql<T::SqlType, Sqlite>,
+{
+    fn walk_ast(&self, mut out: AstPass<Sqlite>) -> QueryResult<()> {
+        out.unsafe_to_cache_prepared();
+        if self.values.is_empty() {
+            out.push_sql("NULL");
+            return Ok(());
+        }
+
+        out.push_sql("CASE ");
+        self.column.walk_ast(out.reborrow())?;
+        for (position, value) in self.values.iter().enumerate() {
+            out.push_sql(" WHEN ");
+            out.push_bind_param::<T::SqlType, _>(value)?;
+            out.push_sql(&format!(" THEN {}", position + 1));
+        }
+        out.push_sql(&format!(" ELSE {} END", self.values.len() + 1));
+        Ok(())
+    }
+}
+
+/// SQLite has no `EXTRACT`, so the part is formatted with `strftime`, and
+/// converted to a number.
+impl<T> QueryFragment<Sqlite> for Extract<T>
+where
+    T: Expression + QueryFragment<Sqlite>,
+    T::SqlType: ExtractFrom,
+{
+    fn walk_ast(&self, mut out: AstPass<Sqlite>) -> QueryResult<()> {
+        self.check_part()?;
+        let format = match self.part {
+            DatePart::Year => "%Y",
+            DatePart::Month => "%m",
+            DatePart::Day => "%d",
+            DatePart::Hour => "%H",
+            DatePart::Minute => "%M",
+            DatePart::Second => "%f",
+            DatePart::DayOfWeek => "%w",
+            DatePart::DayOfYear => "%j",
+            DatePart::Epoch => "%s",
+        };
+        out.push_sql(&format!("CAST(strftime('{}', ", format));
+        self.expr.walk_ast(out.reborrow())?;
+        out.push_sql(") AS REAL)");
+        Ok(())
+    }
+}
+
+/// SQLite has no operator for exclusive or, so it is computed as the bits
+/// which are set in either value, without those which are set in both.
+impl<Lhs, Rhs> QueryFragment<Sqlite> for BitXor<Lhs, Rhs>
+where
+    Lhs: QueryFragment<Sqlite>,
+    Rhs: QueryFragment<Sqlite>,
+{
+    fn walk_ast(&self, mut out: AstPass<Sqlite>) -> QueryResult<()> {
+        out.push_sql("((");
+        self.lhs.walk_ast(out.reborrow())?;
+        out.push_sql(" | ");
+        self.rhs.walk_ast(out.reborrow())?;
+        out.push_sql(") & ~(");
+        self.lhs.walk_ast(out.reborrow())?;
+        out.push_sql(" & ");
+        self.rhs.walk_ast(out.reborrow())?;
+        out.push_sql("))");
+        Ok(())
+    }
+}
+
+/// `NULLS LAST` requires SQLite 3.30, so nulls are sorted last by ordering
+/// by `expr IS NULL` first.
+impl<T> NullsLastOrder<Sqlite> for T
+where
+    T: Column + QueryFragment<Sqlite>,
+{
+    fn walk_nulls_last(&self, mut out: AstPass<Sqlite>) -> QueryResult<()> {
+        self.walk_ast(out.reborrow())?;
+        out.push_sql(" IS NULL, ");
+        self.walk_ast(out.reborrow())?;
+        Ok(())
+    }
+}
+
+impl<T> NullsLastOrder<Sqlite> for Asc<T>
+where
+    T: QueryFragment<Sqlite>,
+{
+    fn walk_nulls_last(&self, mut out: AstPass<Sqlite>) -> QueryResult<()> {
+        self.expr().walk_ast(out.reborrow())?;
+        out.push_sql(" IS NULL, ");
+        self.walk_ast(out.reborrow())?;
+        Ok(())
+    }
+}
+
+impl<T> NullsLastOrder<Sqlite> for Desc<T>
+where
+    T: QueryFragment<Sqlite>,
+{
+    fn walk_nulls_last(&self, mut out: AstPass<Sqlite>) -> QueryResult<()> {
+        self.expr().walk_ast(out.reborrow())?;
+        out.push_sql(" IS NULL, ");
+        self.walk_ast(out.reborrow())?;
+        Ok(())
+    }
+}
+
+/// SQLite allows a `SELECT DISTINCT` query to be ordered by any column.
+impl<O, Selection> ValidDistinctOrdering<Sqlite, O, Selection> for DistinctClause {}
@@ -3,14 +3,40 @@ use std::error::Error;
 use associations::BelongsTo;
 use backend::Backend;
 use expression::{AppearsOnTable, Expression, NonAggregate, SelectableExpression};
+use expression::operators::{Asc, Desc};
+use expression_methods::{ExpressionMethods, TupleExpressionMethods};
 use insertable::InsertValues;
 use query_builder::*;
 use query_source::{QuerySource, Queryable, Table};
+use query_source::joins::Plus;
 use result::QueryResult;
 use row::Row;
 use types::{FromSqlRow, HasSqlType, NotNull};
 use util::TupleAppend;
 
+// A column is selected by a tuple as many times as it is selected by each of
+// its elements, so the count is the sum of the count of the first element and
+// the count of the remaining tuple.
+macro_rules! tuple_selects_column {
+    ($T:ident) => {
+        impl<$T, Col> SelectsColumn<Col> for ($T,) where
+            $T: SelectsColumn<Col>,
+        {
+            type Count = $T::Count;
+        }
+    };
+
+    ($T:ident, $($Rest:ident),+) => {
+        impl<$T, $($Rest,)+ Col> SelectsColumn<Col> for ($T, $($Rest,)+) where
+            $T: SelectsColumn<Col>,
+            ($($Rest,)+): SelectsColumn<Col>,
+            $T::Count: Plus<<($($Rest,)+) as SelectsColumn<Col>>::Count>,
+        {
+            type Count = <$T::Count as Plus<<($($Rest,)+) as SelectsColumn<Col>>::Count>>::Output;
+        }
+    };
+}
+
 macro_rules! tuple_impls {
     ($(
         $Tuple:tt {
@@ -85,6 +111,21 @@ macro_rules! tuple_impls {
             impl<$($T: Expression + NonAggregate),+> NonAggregate for ($($T,)+) {
             }
 
+            impl<$($T),+> TupleExpressionMethods for ($($T,)+) where
+                $($T: ExpressionMethods + NonAggregate,)+
+            {
+                type Desc = ($(Desc<$T>,)+);
+                type Asc = ($(Asc<$T>,)+);
+
+                fn desc(self) -> Self::Desc {
+                    ($(self.$idx.desc(),)+)
+                }
+
+                fn asc(self) -> Self::Asc {
+                    ($(self.$idx.asc(),)+)
+                }
+            }
+
             #[allow(unused_assignments)]
             impl<$($T,)+ Tab, DB> InsertValues<Tab, DB> for ($($T,)+)
             where
@@ -141,6 +182,18 @@ macro_rules! tuple_impls {
             {
             }
 
+            tuple_selects_column!($($T),+);
+
+            impl<$($T,)+ Selection> ValidOrderTermForDistinct<Selection> for ($($T,)+) where
+                $($T: ValidOrderTermForDistinct<Selection>,)+
+            {
+            }
+
+            impl<$($T,)+ Selection> ValidOrderTermForCombination<Selection> for ($($T,)+) where
+                $($T: ValidOrderTermForCombination<Selection>,)+
+            {
+            }
+
             impl<Target, $($T,)+> AsChangeset for ($($T,)+) where
                 $($T: AsChangeset<Target=Target>,)+
                 Target: QuerySource,
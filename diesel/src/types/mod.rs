@@ -219,6 +219,12 @@ pub type Longtext = Text;
 ///
 /// On MySQL, it is also aliased by `Tinyblob`, `Blob`, `Mediumblob`, `Longblob`, `Bit` and `Varbinary`.
 ///
+/// Values are sent and received in the binary format of the backend, and are
+/// copied once in each direction: into the buffer of bind parameters when a
+/// query is sent, and out of the result when it is loaded. They are not
+/// streamed, since none of the client libraries Diesel uses can stream a
+/// single value, so the whole value is held in memory while it is used.
+///
 /// ### [`ToSql`](/diesel/types/trait.ToSql.html) impls
 ///
 /// - [`Vec<u8>`][Vec]
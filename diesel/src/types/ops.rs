@@ -166,3 +166,11 @@ impl Sub for super::Nullable<super::Timestamp> {
     type Rhs = super::Nullable<super::Interval>;
     type Output = super::Nullable<super::Timestamp>;
 }
+
+/// Marker trait for integer types, which allow bitwise operators.
+pub trait Bitwise {}
+
+impl Bitwise for super::SmallInt {}
+impl Bitwise for super::Integer {}
+impl Bitwise for super::BigInt {}
+impl<T: Bitwise + super::NotNull> Bitwise for super::Nullable<T> {}
@@ -13,4 +13,11 @@ impl SqlOrd for types::Date {}
 impl SqlOrd for types::Interval {}
 impl SqlOrd for types::Time {}
 impl SqlOrd for types::Timestamp {}
+impl SqlOrd for types::Numeric {}
+#[cfg(feature = "postgres")]
+impl SqlOrd for types::Timestamptz {}
+#[cfg(feature = "postgres")]
+impl SqlOrd for types::Money {}
+#[cfg(feature = "mysql")]
+impl SqlOrd for types::Datetime {}
 impl<T: SqlOrd + NotNull> SqlOrd for types::Nullable<T> {}
@@ -10,6 +10,9 @@ pub struct Attr {
     pub column_name: Option<syn::Ident>,
     pub field_name: Option<syn::Ident>,
     pub ty: syn::Ty,
+    pub skip_insertion: bool,
+    pub serialize_as: Option<syn::Ty>,
+    pub deserialize_as: Option<syn::Ty>,
     field_position: usize,
 }
 
@@ -20,11 +23,15 @@ impl Attr {
             .cloned()
             .or_else(|| field_name.clone());
         let ty = field.ty.clone();
+        let options = diesel_options(&field.attrs);
 
         Attr {
             column_name: column_name,
             field_name: field_name,
             ty: ty,
+            skip_insertion: options.skip_insertion,
+            serialize_as: options.serialize_as,
+            deserialize_as: options.deserialize_as,
             field_position: index,
         }
     }
@@ -47,6 +54,62 @@ impl Attr {
     }
 }
 
+/// The options given in `#[diesel(...)]` on a field.
+#[derive(Default)]
+struct DieselOptions {
+    skip_insertion: bool,
+    serialize_as: Option<syn::Ty>,
+    deserialize_as: Option<syn::Ty>,
+}
+
+fn diesel_options(attrs: &[syn::Attribute]) -> DieselOptions {
+    fn usage_err() -> ! {
+        panic!(
+            "`diesel` must be in the form `#[diesel(skip_insertion)]`, \
+             `#[diesel(serialize_as = \"Type\")]` or `#[diesel(deserialize_as = \"Type\")]`"
+        )
+    }
+
+    let mut options = DieselOptions::default();
+    let items = match attr_with_name(attrs, "diesel") {
+        Some(attr) => match attr.value {
+            syn::MetaItem::List(_, ref items) => items,
+            _ => usage_err(),
+        },
+        None => return options,
+    };
+    for item in items {
+        let item = match *item {
+            syn::NestedMetaItem::MetaItem(ref item) => item,
+            _ => usage_err(),
+        };
+        match *item {
+            syn::MetaItem::Word(ref name) if name == "skip_insertion" => {
+                options.skip_insertion = true;
+            }
+            syn::MetaItem::NameValue(ref name, _) if name == "serialize_as" => {
+                options.serialize_as = Some(ty_value_of_meta_item(item, "serialize_as"));
+            }
+            syn::MetaItem::NameValue(ref name, _) if name == "deserialize_as" => {
+                options.deserialize_as = Some(ty_value_of_meta_item(item, "deserialize_as"));
+            }
+            _ => panic!(
+                "Unknown option `{}` in `#[diesel(...)]`. The supported options are \
+                 `skip_insertion`, `serialize_as` and `deserialize_as`",
+                item.name()
+            ),
+        }
+    }
+    options
+}
+
+fn ty_value_of_meta_item(item: &syn::MetaItem, name: &str) -> syn::Ty {
+    let value = str_value_of_meta_item(item, name);
+    syn::parse_type(value).unwrap_or_else(|e| {
+        panic!("`{}` must be given a type, but `{}` is not one: {}", name, value, e)
+    })
+}
+
 impl quote::ToTokens for Attr {
     fn to_tokens(&self, tokens: &mut quote::Tokens) {
         tokens.append("{");
@@ -71,6 +134,11 @@ impl quote::ToTokens for Attr {
             .unwrap_or(&self.ty)
             .to_tokens(tokens);
         tokens.append(", ");
+        if let Some(ref ty) = self.serialize_as {
+            tokens.append("serialize_as: ");
+            ty.to_tokens(tokens);
+            tokens.append(", ");
+        }
         tokens.append("}");
     }
 }
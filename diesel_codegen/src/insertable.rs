@@ -17,11 +17,24 @@ pub fn derive_insertable(item: syn::DeriveInput) -> quote::Tokens {
         panic!("`#[derive(Insertable)]` does not support generic types");
     }
 
+    if model.is_tuple_struct() && model.attrs.as_slice().iter().any(|a| a.skip_insertion) {
+        panic!(
+            "Failed to derive `Insertable` for `{}`: `#[diesel(skip_insertion)]` \
+             can only be used on structs with named fields",
+            model.name
+        );
+    }
+
     let struct_name = &model.name;
     let struct_ty = &model.ty;
     let table_name = &model.table_name();
     let lifetimes = model.generics.lifetimes;
-    let fields = model.attrs.as_slice();
+    let fields = model
+        .attrs
+        .as_slice()
+        .iter()
+        .filter(|a| !a.skip_insertion)
+        .collect::<Vec<_>>();
 
     if fields.is_empty() {
         panic!(
@@ -14,7 +14,11 @@ pub fn derive_queryable(item: syn::DeriveInput) -> Tokens {
         .build();
     let struct_ty = &model.ty;
 
-    let row_ty = model.attrs.as_slice().iter().map(|a| &a.ty);
+    let row_ty = model
+        .attrs
+        .as_slice()
+        .iter()
+        .map(|a| a.deserialize_as.as_ref().unwrap_or(&a.ty));
     let row_ty = quote!((#(#row_ty,)*));
 
     let build_expr = build_expr_for_model(&model);
@@ -43,12 +47,18 @@ pub fn derive_queryable(item: syn::DeriveInput) -> Tokens {
 
 fn build_expr_for_model(model: &Model) -> Tokens {
     let struct_name = &model.name;
-    let field_names = model.attrs.as_slice().iter().map(Attr::name_for_pattern);
-
-    let field_assignments = field_names.map(|field_name| if model.is_tuple_struct() {
-        quote!(#field_name)
-    } else {
-        quote!(#field_name: #field_name)
+    let field_assignments = model.attrs.as_slice().iter().map(|attr| {
+        let field_name = attr.name_for_pattern();
+        let value = if attr.deserialize_as.is_some() {
+            quote!(::std::convert::Into::into(#field_name))
+        } else {
+            quote!(#field_name)
+        };
+        if model.is_tuple_struct() {
+            value
+        } else {
+            quote!(#field_name: #value)
+        }
     });
 
     if model.is_tuple_struct() {
@@ -36,3 +36,26 @@ fn tuple_struct_without_column_name_annotations() {
     let data = select(sql::<(Integer, Integer)>("1, 2")).get_result(&conn);
     assert_eq!(Ok(MyStruct(1, 2)), data);
 }
+
+#[test]
+fn fields_can_be_converted_with_deserialize_as() {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct Doubled(i32);
+
+    impl From<i32> for Doubled {
+        fn from(value: i32) -> Self {
+            Doubled(value * 2)
+        }
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Queryable)]
+    struct MyStruct {
+        foo: i32,
+        #[diesel(deserialize_as = "i32")]
+        bar: Doubled,
+    }
+
+    let conn = connection();
+    let data = select(sql::<(Integer, Integer)>("1, 2")).get_result(&conn);
+    assert_eq!(Ok(MyStruct { foo: 1, bar: Doubled(4) }), data);
+}
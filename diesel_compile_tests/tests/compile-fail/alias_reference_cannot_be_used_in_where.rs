@@ -0,0 +1,23 @@
+#[macro_use]
+extern crate diesel;
+
+use diesel::*;
+use diesel::dsl::count_star;
+
+table! {
+    users {
+        id -> Integer,
+    }
+}
+
+fn main() {
+    use self::users::dsl::*;
+
+    let user_count = count_star().aliased("user_count");
+    let source = users.filter(user_count.alias().gt(3));
+    //~^ ERROR NonAggregate
+    let source = users.filter(user_count.alias().eq_any(vec![3]));
+    //~^ ERROR NonAggregate
+    let source = users.filter(user_count.alias().ne_any(vec![3]));
+    //~^ ERROR NonAggregate
+}
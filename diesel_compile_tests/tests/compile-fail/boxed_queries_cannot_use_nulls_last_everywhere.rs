@@ -0,0 +1,25 @@
+#[macro_use]
+extern crate diesel;
+
+use diesel::*;
+use diesel::pg::Pg;
+
+table! {
+    users {
+        id -> Integer,
+        name -> Nullable<VarChar>,
+    }
+}
+
+fn main() {
+    users::table
+        .order(users::name.desc())
+        .into_boxed::<Pg>()
+        .nulls_last_everywhere();
+    //~^ ERROR no method named `nulls_last_everywhere` found
+
+    users::table
+        .order(users::name.desc())
+        .nulls_last_everywhere()
+        .into_boxed::<Pg>();
+}
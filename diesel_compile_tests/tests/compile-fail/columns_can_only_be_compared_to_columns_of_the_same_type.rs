@@ -0,0 +1,23 @@
+#[macro_use]
+extern crate diesel;
+
+use diesel::*;
+
+table! {
+    posts {
+        id -> Integer,
+        title -> VarChar,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+    }
+}
+
+fn main() {
+    use self::posts::dsl::*;
+
+    let pred = updated_at.gt(created_at);
+    let pred = updated_at.gt(id);
+    //~^ ERROR type mismatch
+    let pred = created_at.le(title);
+    //~^ ERROR type mismatch
+}
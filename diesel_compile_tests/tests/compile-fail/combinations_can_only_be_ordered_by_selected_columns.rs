@@ -0,0 +1,24 @@
+#[macro_use]
+extern crate diesel;
+
+use diesel::*;
+
+table! {
+    users {
+        id -> Integer,
+        name -> VarChar,
+    }
+}
+
+fn main() {
+    use self::users::dsl::*;
+
+    let names = users.select(name).union(users.select(name)).order(name.desc());
+    let names = users.select((id, name)).union(users.select((id, name))).order((name, id));
+    let names = users.select(name).union(users.select(name)).order(id);
+    //~^ ERROR E0277
+    let names = users.select(name).union(users.select(name)).order((name, id.desc()));
+    //~^ ERROR E0277
+    let names = users.select(name).union(users.select(name)).order(id + 1);
+    //~^ ERROR E0277
+}
@@ -0,0 +1,31 @@
+#[macro_use]
+extern crate diesel;
+
+use diesel::*;
+
+table! {
+    users {
+        id -> Integer,
+        name -> VarChar,
+    }
+}
+
+table! {
+    posts {
+        id -> Integer,
+        title -> VarChar,
+    }
+}
+
+fn main() {
+    let connection = PgConnection::establish("").unwrap();
+
+    let names = users::table.select(users::name).union(posts::table.select(posts::title))
+        .load::<String>(&connection);
+    let names = users::table.select(users::name).union(posts::table.select(posts::id));
+    //~^ ERROR E0271
+    let names = users::table.select(users::name).union_all(posts::table.select(posts::title))
+        .order(posts::title);
+    //~^ ERROR E0277
+    //~| ERROR E0271
+}
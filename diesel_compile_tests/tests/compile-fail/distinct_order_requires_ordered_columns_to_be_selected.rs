@@ -0,0 +1,30 @@
+#[macro_use]
+extern crate diesel;
+
+use diesel::*;
+use diesel::pg::PgConnection;
+
+table! {
+    users {
+        id -> Integer,
+        name -> VarChar,
+    }
+}
+
+fn main() {
+    use self::users::dsl::*;
+    let conn = PgConnection::establish("").unwrap();
+
+    users.select(name).distinct().order(id).load::<String>(&conn);
+    //~^ ERROR E0599
+    users.select(name).distinct().order((name, id.desc())).load::<String>(&conn);
+    //~^ ERROR E0599
+    users.select(name).order(id).distinct().load::<String>(&conn);
+    //~^ ERROR E0599
+    users.distinct().order(id).select(name).load::<String>(&conn);
+    //~^ ERROR E0599
+
+    users.select((id, name)).distinct().order((name, id.desc())).load::<(i32, String)>(&conn);
+    users.select(name).order(id).load::<String>(&conn);
+    users.select(name).distinct().order(id).into_boxed().load::<String>(&conn);
+}
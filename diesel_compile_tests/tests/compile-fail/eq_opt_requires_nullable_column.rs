@@ -0,0 +1,20 @@
+#[macro_use]
+extern crate diesel;
+
+use diesel::*;
+
+table! {
+    users {
+        id -> Integer,
+        name -> VarChar,
+        hair_color -> Nullable<VarChar>,
+    }
+}
+
+fn main() {
+    use self::users::dsl::*;
+
+    let _ = users.filter(hair_color.eq_opt(Some("black")));
+    let _ = users.filter(name.eq_opt(Some("Sean")));
+    //~^ ERROR E0271
+}
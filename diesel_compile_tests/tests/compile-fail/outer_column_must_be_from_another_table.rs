@@ -0,0 +1,43 @@
+#[macro_use] extern crate diesel;
+
+use diesel::*;
+use diesel::query_source::outer;
+
+table! {
+    users {
+        id -> Integer,
+        name -> VarChar,
+    }
+}
+
+table! {
+    posts {
+        id -> Integer,
+        user_id -> Integer,
+    }
+}
+
+table! {
+    comments {
+        id -> Integer,
+        post_id -> Integer,
+    }
+}
+
+joinable!(posts -> users (user_id));
+
+fn main() {
+    use self::posts::dsl::*;
+    let connection = PgConnection::establish("postgres://localhost").unwrap();
+
+    // Valid outer column as sanity check
+    posts.filter(user_id.eq(outer(users::id))).load::<(i32, i32)>(&connection);
+
+    // Column of a table which cannot appear in the same query
+    posts.filter(id.eq(outer(comments::post_id))).load::<(i32, i32)>(&connection);
+    //~^ ERROR E0277
+
+    // Column of the subquery's own table
+    posts.filter(user_id.eq(outer(id))).load::<(i32, i32)>(&connection);
+    //~^ ERROR E0271
+}
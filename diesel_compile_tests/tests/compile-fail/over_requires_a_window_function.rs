@@ -0,0 +1,33 @@
+#[macro_use]
+extern crate diesel;
+
+use diesel::*;
+use diesel::dsl::*;
+
+table! {
+    users {
+        id -> Integer,
+        name -> VarChar,
+    }
+}
+
+fn main() {
+    use self::users::dsl::*;
+
+    let connection = PgConnection::establish("").unwrap();
+
+    let sums = users.select(sum(id).over(window())).load::<Option<i64>>(&connection);
+    let row_numbers = users.select(row_number().over(window())).load::<i64>(&connection);
+
+    let ids = users.select(id.over(window())).load::<i32>(&connection);
+    //~^ ERROR the trait bound `users::columns::id: diesel::expression::window::WindowFunction` is not satisfied
+    //~| ERROR WindowFunctionAppearsOnTable
+    //~| ERROR no method named `load`
+
+    let row_numbers = users.select(row_number()).load::<i64>(&connection);
+    //~^ ERROR the trait bound `diesel::expression::window::RowNumber: diesel::SelectableExpression<users::table>` is not satisfied
+    //~| ERROR no method named `load`
+
+    let ranks = users.order(rank()).load::<(i32, String)>(&connection);
+    //~^ ERROR the trait bound `diesel::expression::window::Rank: diesel::AppearsOnTable<users::table>` is not satisfied
+}
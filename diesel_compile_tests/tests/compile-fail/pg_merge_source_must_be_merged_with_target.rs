@@ -0,0 +1,57 @@
+#[macro_use] extern crate diesel;
+
+use diesel::*;
+use diesel::pg::merge::{merge_into, source};
+
+table! {
+    users {
+        id -> Integer,
+        name -> VarChar,
+    }
+}
+
+table! {
+    new_users {
+        id -> Integer,
+        name -> VarChar,
+    }
+}
+
+table! {
+    posts {
+        id -> Integer,
+        title -> VarChar,
+    }
+}
+
+enable_multi_table_joins!(users, new_users);
+
+fn main() {
+    use self::users::dsl::*;
+    let connection = PgConnection::establish("postgres://localhost").unwrap();
+
+    // Valid merge as sanity check
+    merge_into(users)
+        .using(new_users::table)
+        .on(id.eq(new_users::id))
+        .when_matched_update(name.eq(source(new_users::name)))
+        .execute(&connection);
+
+    // Source column from a table which cannot be merged with the target
+    merge_into(users)
+        .using(new_users::table)
+        .on(id.eq(new_users::id))
+        .when_matched_update(name.eq(source(posts::title)));
+        //~^ ERROR E0277
+
+    // Source column of the target table
+    merge_into(users)
+        .using(new_users::table)
+        .on(id.eq(new_users::id))
+        .when_matched_update(name.eq(source(name)));
+        //~^ ERROR E0271
+
+    // Source is only valid in merge
+    // FIXME: This should not compile
+    update(users).set(name.eq(source(new_users::name))).execute(&connection);
+}
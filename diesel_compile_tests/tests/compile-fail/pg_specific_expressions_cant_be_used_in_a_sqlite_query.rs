@@ -30,6 +30,9 @@ fn main() {
     users.select(id).filter(name.is_not_distinct_from("Sean"))
         .load::<i32>(&connection);
     //~^ ERROR type mismatch resolving `<diesel::SqliteConnection as diesel::Connection>::Backend == diesel::pg::Pg`
+    users.select(id).filter(id.eq_any_chunked(vec![1, 2], 1))
+        .load::<i32>(&connection);
+    //~^ ERROR type mismatch resolving `<diesel::SqliteConnection as diesel::Connection>::Backend == diesel::pg::Pg`
     users.select(id).filter(now.eq(now.at_time_zone("UTC")))
         .load::<i32>(&connection);
     //~^ ERROR type mismatch resolving `<diesel::SqliteConnection as diesel::Connection>::Backend == diesel::pg::Pg`
@@ -0,0 +1,43 @@
+#[macro_use]
+extern crate diesel;
+
+use diesel::*;
+use diesel::pg::PgConnection;
+use diesel::sqlite::SqliteConnection;
+
+table! {
+    users {
+        id -> Integer,
+    }
+}
+
+table! {
+    posts {
+        id -> Integer,
+        user_id -> Integer,
+    }
+}
+
+fn main() {
+    users::table.for_update_of(posts::table);
+    //~^ ERROR: E0277
+    users::table.for_update_of((users::table, posts::table));
+    //~^ ERROR: E0277
+
+    let conn = PgConnection::establish("").unwrap();
+    users::table
+        .for_update_of(users::table)
+        .skip_locked()
+        .select(users::id)
+        .load::<i32>(&conn)
+        .unwrap();
+
+    let conn = SqliteConnection::establish("").unwrap();
+    users::table
+        .for_update()
+        .skip_locked()
+        .select(users::id)
+        .load::<i32>(&conn)
+        //~^ ERROR: E0277
+        .unwrap();
+}
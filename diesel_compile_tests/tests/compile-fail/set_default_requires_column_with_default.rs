@@ -0,0 +1,25 @@
+#[macro_use]
+extern crate diesel;
+
+use diesel::*;
+use diesel::dsl::set_default;
+use diesel::expression::HasDefault;
+
+table! {
+    users {
+        id -> Integer,
+        name -> VarChar,
+        hair_color -> Nullable<VarChar>,
+    }
+}
+
+impl HasDefault for users::hair_color {}
+
+fn main() {
+    use self::users::dsl::*;
+
+    let _ = update(users).set(set_default(hair_color));
+    let _ = update(users).set(set_default(name));
+    //~^ ERROR HasDefault
+    //~| ERROR HasDefault
+}
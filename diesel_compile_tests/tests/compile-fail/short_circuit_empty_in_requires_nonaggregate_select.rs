@@ -0,0 +1,39 @@
+#[macro_use]
+extern crate diesel;
+
+use diesel::*;
+use diesel::dsl::count_star;
+use diesel::pg::PgConnection;
+
+table! {
+    users {
+        id -> Integer,
+        name -> VarChar,
+    }
+}
+
+fn main() {
+    let conn = PgConnection::establish("").unwrap();
+    let ids = Vec::<i32>::new();
+
+    users::table
+        .select(users::name)
+        .filter(users::id.eq_any(&ids))
+        .short_circuit_empty_in()
+        .load::<String>(&conn)
+        .unwrap();
+
+    users::table
+        .group_by(users::name)
+        .select(count_star())
+        .filter(users::id.eq_any(&ids))
+        .short_circuit_empty_in()
+        .load::<i64>(&conn)
+        .unwrap();
+
+    users::table
+        .select(count_star())
+        .filter(users::id.eq_any(&ids))
+        .short_circuit_empty_in();
+        //~^ ERROR: E0599
+}
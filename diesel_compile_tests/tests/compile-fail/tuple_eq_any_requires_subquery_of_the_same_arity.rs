@@ -0,0 +1,31 @@
+#[macro_use]
+extern crate diesel;
+
+use diesel::*;
+
+table! {
+    users {
+        id -> Integer,
+        name -> VarChar,
+    }
+}
+
+table! {
+    posts {
+        id -> Integer,
+        user_id -> Integer,
+    }
+}
+
+fn main() {
+    let user_ids = posts::table.select(posts::user_id);
+    users::table.filter((users::id, users::id).eq_any(user_ids));
+    //~^ ERROR E0277
+
+    let ids = posts::table.select((posts::user_id, posts::id));
+    users::table.filter((users::id, users::name).eq_any(ids));
+    //~^ ERROR E0277
+
+    let ids = posts::table.select((posts::user_id, posts::id));
+    users::table.filter((users::id, users::id).eq_any(ids));
+}
@@ -0,0 +1,33 @@
+#[macro_use]
+extern crate diesel;
+
+use diesel::*;
+use diesel::pg::PgConnection;
+use diesel::sqlite::SqliteConnection;
+
+table! {
+    users {
+        id -> Integer,
+        name -> VarChar,
+    }
+}
+
+fn main() {
+    use self::users::dsl::*;
+
+    let conn = PgConnection::establish("").unwrap();
+    update(users)
+        .set(name.eq("Jim"))
+        .order_by(id)
+        .execute(&conn)
+        //~^ ERROR: E0599
+        .unwrap();
+
+    let conn = SqliteConnection::establish("").unwrap();
+    update(users)
+        .set(name.eq("Jim"))
+        .limit(1)
+        .execute(&conn)
+        //~^ ERROR: E0599
+        .unwrap();
+}
@@ -0,0 +1,38 @@
+#[macro_use]
+extern crate diesel;
+
+use diesel::*;
+use diesel::dsl::*;
+
+table! {
+    users {
+        id -> Integer,
+        name -> VarChar,
+    }
+}
+
+fn main() {
+    use self::users::dsl::*;
+
+    let connection = PgConnection::establish("").unwrap();
+
+    let row_numbers = users
+        .select(row_number().over(window().order_by(id)))
+        .load::<i64>(&connection);
+    let row_numbers = users
+        .select(row_number().over(window().order_by(id)))
+        .load::<Option<i64>>(&connection);
+    //~^ ERROR the trait bound `std::option::Option<i64>: diesel::Queryable<diesel::types::BigInt, _>` is not satisfied
+    let ranks = users
+        .select(rank().over(window().order_by(name)))
+        .load::<i32>(&connection);
+    //~^ ERROR the trait bound `i32: diesel::Queryable<diesel::types::BigInt, _>` is not satisfied
+
+    let sums = users
+        .select(sum(id).over(window()))
+        .load::<Option<i64>>(&connection);
+    let sums = users
+        .select(sum(id).over(window()))
+        .load::<i64>(&connection);
+    //~^ ERROR the trait bound `i64: diesel::Queryable<diesel::types::Nullable<diesel::types::BigInt>, _>` is not satisfied
+}
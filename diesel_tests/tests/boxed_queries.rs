@@ -144,3 +144,89 @@ fn queries_with_borrowed_data_can_be_boxed() {
     let expected_data = vec![find_user_by_name("Tess", &connection)];
     assert_eq!(Ok(expected_data), data);
 }
+
+#[test]
+fn queries_can_be_assembled_from_runtime_chosen_pieces() {
+    use diesel::query_builder::QueryBuilderCollector;
+    use diesel::types::Text;
+
+    let connection = connection_with_sean_and_tess_in_users_table();
+    insert(&NewUser::new("Jim", Some("black")))
+        .into(users::table)
+        .execute(&connection)
+        .unwrap();
+
+    let requested_filters = vec!["hair_color", "name"];
+    let mut query = QueryBuilderCollector::<TestBackend>::new();
+    query
+        .push_sql("SELECT ")
+        .push(users::name)
+        .push_sql(" FROM users WHERE ");
+    for (i, filter) in requested_filters.into_iter().enumerate() {
+        if i != 0 {
+            query.push_sql(" AND ");
+        }
+        match filter {
+            "name" => query.push(users::name.ne("Tess")),
+            "hair_color" => {
+                query.push(users::hair_color.eq("black").or(users::hair_color.is_null()))
+            }
+            _ => unreachable!(),
+        };
+    }
+    query.push_sql(" ORDER BY ").push(users::name);
+
+    let mut expected_sql = "SELECT `users`.`name` FROM users \
+                            WHERE (`users`.`hair_color` = ? OR `users`.`hair_color` IS NULL) \
+                            AND `users`.`name` != ? \
+                            ORDER BY `users`.`name` \
+                            -- binds: [\"black\", \"Tess\"]"
+        .to_string();
+    if cfg!(feature = "postgres") {
+        expected_sql = expected_sql
+            .replace('`', "\"")
+            .replace("!= ?", "!= $2")
+            .replace("= ?", "= $1");
+    }
+    assert_eq!(expected_sql, debug_query::<TestBackend, _>(&query).to_string());
+
+    let names = query.into_query::<Text>().load::<String>(&connection);
+    assert_eq!(Ok(vec!["Jim".to_string(), "Sean".to_string()]), names);
+}
+
+#[test]
+fn boxed_queries_can_be_counted_for_pagination() {
+    let connection = connection_with_sean_and_tess_in_users_table();
+    insert(&NewUser::new("Jim", Some("black")))
+        .into(users::table)
+        .execute(&connection)
+        .unwrap();
+
+    let filtered_by_hair_color = |hair_color: Option<&'static str>| {
+        let mut query = users::table.into_boxed();
+        if let Some(hair_color) = hair_color {
+            query = query.filter(users::hair_color.eq(hair_color));
+        }
+        query.order(users::name).limit(1).offset(1)
+    };
+
+    let page = filtered_by_hair_color(None)
+        .select(users::name)
+        .load::<String>(&connection);
+    assert_eq!(Ok(vec!["Sean".to_string()]), page);
+    let total = filtered_by_hair_color(None).count().get_result(&connection);
+    assert_eq!(Ok(3), total);
+    let black_haired = filtered_by_hair_color(Some("black"))
+        .count()
+        .get_result(&connection);
+    assert_eq!(Ok(1), black_haired);
+
+    let mut expected_sql = "SELECT COUNT(*) FROM `users` WHERE `users`.`hair_color` = ? \
+                            -- binds: [\"black\"]"
+        .to_string();
+    if cfg!(feature = "postgres") {
+        expected_sql = expected_sql.replace('`', "\"").replace('?', "$1");
+    }
+    let count_query = filtered_by_hair_color(Some("black")).count();
+    assert_eq!(expected_sql, debug_query(&count_query).to_string());
+}
@@ -0,0 +1,136 @@
+use schema::*;
+use schema::TestBackend;
+use diesel::*;
+
+#[test]
+fn union_returns_the_rows_of_both_filtered_queries() {
+    use schema::users::dsl::*;
+
+    let connection = connection_with_sean_and_tess_in_users_table();
+    insert(&NewUser::new("Jim", None))
+        .into(users)
+        .execute(&connection)
+        .unwrap();
+
+    let names = users
+        .select(name)
+        .filter(name.eq("Sean"))
+        .union(users.select(name).filter(name.eq("Jim")))
+        .order(name)
+        .load::<String>(&connection);
+
+    assert_eq!(Ok(vec!["Jim".to_string(), "Sean".to_string()]), names);
+}
+
+#[test]
+fn union_removes_duplicates_and_union_all_keeps_them() {
+    use schema::users::dsl::*;
+
+    let connection = connection_with_sean_and_tess_in_users_table();
+    let seans_name = users.select(name).filter(id.eq(1));
+
+    let distinct_names = seans_name
+        .union(users.select(name))
+        .order(name)
+        .load::<String>(&connection);
+    let all_names = seans_name
+        .union_all(users.select(name))
+        .order(name)
+        .load::<String>(&connection);
+
+    let sean = "Sean".to_string();
+    let tess = "Tess".to_string();
+    assert_eq!(Ok(vec![sean.clone(), tess.clone()]), distinct_names);
+    assert_eq!(Ok(vec![sean.clone(), sean, tess]), all_names);
+}
+
+#[test]
+fn limit_and_offset_apply_to_the_whole_combination() {
+    use schema::users::dsl::*;
+
+    let connection = connection_with_sean_and_tess_in_users_table();
+
+    let names = users
+        .select(name)
+        .filter(id.eq(1))
+        .union_all(users.select(name).filter(id.ne(3)))
+        .order(name.desc())
+        .limit(2)
+        .offset(1)
+        .load::<String>(&connection);
+
+    assert_eq!(Ok(vec!["Sean".to_string(), "Sean".to_string()]), names);
+}
+
+#[test]
+fn binds_of_a_combination_are_in_the_order_of_the_query() {
+    use schema::users::dsl::*;
+
+    let connection = connection_with_sean_and_tess_in_users_table();
+    let query = users
+        .select(name)
+        .filter(name.eq("Sean"))
+        .union_all(users.select(name).filter(id.ne(3)))
+        .order(name)
+        .limit(2);
+    let mut expected_sql = "SELECT * FROM (\
+                            SELECT `users`.`name` FROM `users` WHERE `users`.`name` = ? \
+                            UNION ALL \
+                            SELECT `users`.`name` FROM `users` WHERE `users`.`id` != ?\
+                            ) AS `users` ORDER BY `users`.`name` LIMIT ? \
+                            -- binds: [\"Sean\", 3, 2]"
+        .to_string();
+    if cfg!(feature = "postgres") {
+        expected_sql = expected_sql
+            .replace('`', "\"")
+            .replacen('?', "$1", 1)
+            .replacen('?', "$2", 1)
+            .replacen('?', "$3", 1);
+    }
+
+    assert_eq!(expected_sql, debug_query::<TestBackend, _>(&query).to_string());
+    assert_eq!(
+        Ok(vec!["Sean".to_string(), "Sean".to_string()]),
+        query.load::<String>(&connection)
+    );
+}
+
+#[test]
+fn queries_on_different_tables_can_be_combined() {
+    let connection = connection_with_sean_and_tess_in_users_table();
+    let sean = find_user_by_name("Sean", &connection);
+    insert(&sean.new_post("Hello", None))
+        .into(posts::table)
+        .execute(&connection)
+        .unwrap();
+
+    let strings = users::table
+        .select(users::name)
+        .filter(users::id.eq(sean.id))
+        .union(posts::table.select(posts::title))
+        .order(users::name)
+        .load::<String>(&connection);
+
+    assert_eq!(Ok(vec!["Hello".to_string(), "Sean".to_string()]), strings);
+}
+
+#[test]
+#[cfg(not(feature = "mysql"))] // INTERSECT and EXCEPT require MySQL 8.0.31
+fn intersect_and_except_compare_the_rows_of_both_queries() {
+    use schema::users::dsl::*;
+
+    let connection = connection_with_sean_and_tess_in_users_table();
+    let tess_name = users.select(name).filter(id.eq(2));
+
+    let both = users
+        .select(name)
+        .intersect(tess_name)
+        .load::<String>(&connection);
+    let only_left = users
+        .select(name)
+        .except(tess_name)
+        .load::<String>(&connection);
+
+    assert_eq!(Ok(vec!["Tess".to_string()]), both);
+    assert_eq!(Ok(vec!["Sean".to_string()]), only_left);
+}
@@ -58,3 +58,414 @@ fn managing_updated_at_for_table() {
     // clean up because we aren't in a transaction
     connection.execute("DROP TABLE auto_time;").unwrap();
 }
+
+#[test]
+#[cfg(feature = "postgres")]
+fn notifications_are_received_from_other_connections() {
+    use std::time::Duration;
+
+    // notifications are only delivered when the transaction commits
+    let listener = connection_without_transaction();
+    let sender = connection_without_transaction();
+    listener.listen("diesel_test_channel").unwrap();
+
+    sender.notify("diesel_test_channel", "hello").unwrap();
+    sender.notify("diesel_test_channel", "").unwrap();
+
+    let timeout = Some(Duration::from_secs(5));
+    let first = listener.notifications(timeout).next().unwrap().unwrap();
+    assert_eq!("diesel_test_channel", first.channel);
+    assert_eq!("hello", first.payload);
+    let second = listener.notifications(timeout).next().unwrap().unwrap();
+    assert_eq!("", second.payload);
+    assert_eq!(first.pid, second.pid);
+    assert!(
+        listener
+            .notifications(Some(Duration::from_millis(50)))
+            .next()
+            .is_none()
+    );
+
+    listener.unlisten("diesel_test_channel").unwrap();
+    sender.notify("diesel_test_channel", "ignored").unwrap();
+    assert!(
+        listener
+            .notifications(Some(Duration::from_millis(50)))
+            .next()
+            .is_none()
+    );
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn unqualified_tables_are_found_in_the_search_path() {
+    use diesel::types::{Integer, Text};
+    use schema::connection;
+
+    let connection = connection();
+    connection
+        .execute("CREATE SCHEMA \"tenant \"\"1\"\"\"")
+        .unwrap();
+    connection
+        .execute("CREATE TABLE \"tenant \"\"1\"\"\".tenant_only (id INTEGER)")
+        .unwrap();
+    connection
+        .execute("INSERT INTO \"tenant \"\"1\"\"\".tenant_only VALUES (42)")
+        .unwrap();
+    let search_path = || {
+        select(sql::<Text>("current_setting('search_path')")).get_result::<String>(&connection)
+    };
+    let original_search_path = search_path().unwrap();
+
+    let ids = connection.with_search_path(&["tenant \"1\""], || {
+        assert_eq!(Ok("\"tenant \"\"1\"\"\"".to_string()), search_path());
+        sql::<Integer>("SELECT id FROM tenant_only").load::<i32>(&connection)
+    });
+    assert_eq!(Ok(vec![42]), ids);
+    assert_eq!(Ok(original_search_path), search_path());
+
+    connection.set_search_path(&[]).unwrap();
+    assert_eq!(Ok("\"\"".to_string()), search_path());
+    connection.set_search_path(&["tenant \"1\"", "public"]).unwrap();
+    let ids = sql::<Integer>("SELECT id FROM tenant_only").load::<i32>(&connection);
+    assert_eq!(Ok(vec![42]), ids);
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn libpq_functions_can_be_called_with_the_raw_connection() {
+    use diesel::pg::pq_sys::PQbackendPID;
+    use diesel::types::Integer;
+    use schema::connection;
+
+    let connection = connection();
+    let backend_pid = unsafe { PQbackendPID(connection.raw_connection()) };
+    let expected_pid = select(sql::<Integer>("pg_backend_pid()")).get_result(&connection);
+
+    assert_eq!(Ok(backend_pid), expected_pid);
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn running_queries_can_be_cancelled_from_another_thread() {
+    use std::thread;
+    use std::time::{Duration, Instant};
+
+    let connection = connection_without_transaction();
+    let cancel_token = connection.cancel_token().unwrap();
+    let canceller = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(200));
+        cancel_token.cancel()
+    });
+
+    let start = Instant::now();
+    let result = connection.execute("SELECT pg_sleep(10)");
+    let elapsed = start.elapsed();
+
+    assert_eq!(Ok(()), canceller.join().unwrap());
+    match result {
+        Err(result::Error::DatabaseError(_, info)) => {
+            assert_eq!("canceling statement due to user request", info.message())
+        }
+        other => panic!("Expected the query to be cancelled, got {:?}", other),
+    }
+    assert!(elapsed < Duration::from_secs(5));
+    assert_eq!(Ok(1), connection.execute("SELECT 1"));
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn copy_out_exports_every_row_of_the_query() {
+    use diesel::pg::CopyFormat;
+    use schema::{connection, users, NewUser};
+    use std::io::{BufRead, BufReader};
+
+    let connection = connection();
+    let new_users = (0..100)
+        .map(|i| NewUser::new(&format!("User {}", i), Some("Brown")))
+        .collect::<Vec<_>>();
+    insert(&new_users)
+        .into(users::table)
+        .execute(&connection)
+        .unwrap();
+
+    let query = users::table.filter(users::hair_color.eq("Brown"));
+    let expected_count = query.count().get_result::<i64>(&connection);
+    let exported_rows = BufReader::new(connection.copy_out(query, CopyFormat::Csv).unwrap())
+        .lines()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(Ok(exported_rows.len() as i64), expected_count);
+    assert!(exported_rows[0].ends_with(",User 0,Brown"));
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn copy_out_inlines_only_the_binds_of_the_query() {
+    use diesel::pg::CopyFormat;
+    use diesel::types::Text;
+    use schema::{connection_with_sean_and_tess_in_users_table, users};
+    use std::io::Read;
+
+    let connection = connection_with_sean_and_tess_in_users_table();
+    let query = users::table
+        .select((sql::<Text>("'$1'"), users::name))
+        .filter(users::name.eq("Tess"));
+
+    let mut output = String::new();
+    connection
+        .copy_out(query, CopyFormat::Text)
+        .unwrap()
+        .read_to_string(&mut output)
+        .unwrap();
+    assert_eq!("$1\tTess\n", output);
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn dropping_copy_out_cancels_the_command() {
+    use diesel::pg::CopyFormat;
+    use diesel::types::Text;
+    use std::io::Read;
+    use std::time::{Duration, Instant};
+
+    // Each row takes 10ms, and is large enough to be sent on its own
+    let connection = connection_without_transaction();
+    let query = select(sql::<Text>(
+        "repeat('x', 10000) FROM generate_series(1, 1000) AS n \
+         WHERE pg_sleep(0.01)::text = ''",
+    ));
+
+    let start = Instant::now();
+    let mut first_byte = [0];
+    let mut copy_out = connection.copy_out(query, CopyFormat::Text).unwrap();
+    copy_out.read_exact(&mut first_byte).unwrap();
+    drop(copy_out);
+
+    assert!(start.elapsed() < Duration::from_secs(5));
+    assert_eq!(Ok(1), connection.execute("SELECT 1"));
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn copy_out_returns_errors_which_occur_while_rows_are_sent() {
+    use diesel::pg::CopyFormat;
+    use diesel::types::Integer;
+    use std::io::Read;
+
+    let connection = connection_without_transaction();
+    let query = select(sql::<Integer>("1 / (n - 3) FROM generate_series(1, 5) AS n"));
+
+    let mut output = String::new();
+    let result = connection
+        .copy_out(query, CopyFormat::Text)
+        .unwrap()
+        .read_to_string(&mut output);
+
+    assert!(result.is_err());
+    assert_eq!("0\n-1\n", output);
+    assert_eq!(Ok(1), connection.execute("SELECT 1"));
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn queries_return_connection_closed_when_the_backend_is_terminated() {
+    use diesel::types::{Bool, Integer};
+
+    let connection = connection_without_transaction();
+    let backend_pid = select(sql::<Integer>("pg_backend_pid()"))
+        .get_result::<i32>(&connection)
+        .unwrap();
+    assert!(!connection.is_broken());
+
+    let other_connection = connection_without_transaction();
+    let terminated = select(sql::<Bool>(&format!("pg_terminate_backend({})", backend_pid)))
+        .get_result(&other_connection);
+    assert_eq!(Ok(true), terminated);
+    // The backend exits asynchronously, so wait for it to be gone
+    let running = format!("EXISTS (SELECT 1 FROM pg_stat_activity WHERE pid = {})", backend_pid);
+    while select(sql::<Bool>(&running)).get_result(&other_connection) == Ok(true) {
+        ::std::thread::sleep(::std::time::Duration::from_millis(10));
+    }
+
+    match connection.execute("SELECT 1") {
+        Err(result::Error::ConnectionClosed(_)) => {}
+        other => panic!("Expected the connection to be closed, got {:?}", other),
+    }
+    assert!(connection.is_broken());
+    match connection.execute("SELECT 1") {
+        Err(result::Error::ConnectionClosed(_)) => {}
+        other => panic!("Expected the connection to be closed, got {:?}", other),
+    }
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn estimated_row_count_is_close_to_the_exact_count_after_analyze() {
+    use schema::{connection, users, NewUser};
+
+    let connection = connection();
+    let new_users = (0..1000)
+        .map(|i| NewUser::new(&format!("User {}", i), None))
+        .collect::<Vec<_>>();
+    insert(&new_users)
+        .into(users::table)
+        .execute(&connection)
+        .unwrap();
+    connection.execute("ANALYZE users").unwrap();
+
+    let exact_count = users::table.count().get_result::<i64>(&connection).unwrap();
+    let estimate = connection.estimated_row_count(users::table).unwrap();
+    assert_eq!(1000, exact_count);
+    assert!((estimate - exact_count).abs() <= exact_count / 10, "estimate was {}", estimate);
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn execute_query_runs_a_select_for_its_side_effects() {
+    use schema::connection;
+    use diesel::types::Bool;
+
+    let connection = connection();
+    let other_connection = connection_without_transaction();
+    let lock = select(sql::<Bool>("pg_try_advisory_xact_lock(4242)"));
+
+    assert_eq!(Ok(1), connection.execute_query(&lock));
+    assert_eq!(Ok(false), lock.get_result(&other_connection));
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn advisory_locks_are_exclusive_until_released() {
+    let connection = connection_without_transaction();
+    let other_connection = connection_without_transaction();
+
+    connection.advisory_lock(1729).unwrap();
+    assert_eq!(Ok(false), other_connection.try_advisory_lock(1729));
+    assert_eq!(Ok(true), connection.advisory_unlock(1729));
+    assert_eq!(Ok(true), other_connection.try_advisory_lock(1729));
+    assert_eq!(Ok(true), other_connection.advisory_unlock(1729));
+    assert_eq!(Ok(false), other_connection.advisory_unlock(1729));
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn advisory_lock_guards_release_the_lock_when_dropped() {
+    let connection = connection_without_transaction();
+    let other_connection = connection_without_transaction();
+
+    {
+        let guard = connection.advisory_lock_guard((17, 29)).unwrap();
+        assert_eq!(diesel::pg::AdvisoryLockKey::Pair(17, 29), guard.key());
+        assert!(other_connection.try_advisory_lock_guard((17, 29)).unwrap().is_none());
+        // The single key form does not conflict with the pair form
+        assert_eq!(Ok(true), other_connection.try_advisory_lock(17));
+        assert_eq!(Ok(true), other_connection.advisory_unlock(17));
+    }
+
+    let guard = other_connection.try_advisory_lock_guard((17, 29)).unwrap();
+    assert!(guard.is_some());
+    assert_eq!(Ok(true), guard.unwrap().unlock());
+    assert_eq!(Ok(true), connection.try_advisory_lock((17, 29)));
+    assert_eq!(Ok(true), connection.advisory_unlock((17, 29)));
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn notices_raised_by_functions_are_passed_to_the_notice_handler() {
+    use schema::connection;
+    use diesel::pg::Notice;
+    use diesel::types::Integer;
+    use std::sync::{Arc, Mutex};
+
+    let connection = connection();
+    connection
+        .execute(
+            "CREATE FUNCTION pg_temp.noisy_answer() RETURNS integer AS $$
+             BEGIN
+                 RAISE NOTICE 'computing the answer';
+                 RAISE WARNING 'the answer is %', 42;
+                 RETURN 42;
+             END
+             $$ LANGUAGE plpgsql",
+        )
+        .unwrap();
+    let notices = Arc::new(Mutex::new(Vec::new()));
+    let received = notices.clone();
+    connection.set_notice_handler(move |notice| received.lock().unwrap().push(notice.clone()));
+
+    let answer = select(sql::<Integer>("pg_temp.noisy_answer()")).get_result(&connection);
+
+    assert_eq!(Ok(42), answer);
+    let expected_notices = vec![
+        Notice {
+            severity: "NOTICE".into(),
+            message: "computing the answer".into(),
+        },
+        Notice {
+            severity: "WARNING".into(),
+            message: "the answer is 42".into(),
+        },
+    ];
+    assert_eq!(expected_notices, *notices.lock().unwrap());
+
+    // Notices silenced by Diesel, such as those of migrations, are not passed
+    // to the handler
+    connection.silence_notices(|| {
+        select(sql::<Integer>("pg_temp.noisy_answer()"))
+            .execute(&connection)
+            .unwrap();
+    });
+    assert_eq!(2, notices.lock().unwrap().len());
+    select(sql::<Integer>("pg_temp.noisy_answer()"))
+        .execute(&connection)
+        .unwrap();
+    assert_eq!(4, notices.lock().unwrap().len());
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn a_panicking_notice_handler_does_not_fail_the_query() {
+    use schema::connection;
+
+    let connection = connection();
+    connection.set_notice_handler(|notice| panic!("{}", notice.message));
+
+    let result = connection.execute("DROP TABLE IF EXISTS not_a_table");
+
+    assert_eq!(Ok(0), result);
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn queries_succeed_with_prepared_statements_disabled() {
+    use schema::{connection, users, NewUser};
+    use diesel::types::BigInt;
+
+    let connection = connection();
+    connection.set_prepared_statements(false);
+    let prepared_statements = || {
+        select(sql::<BigInt>("COUNT(*) FROM pg_prepared_statements")).get_result::<i64>(&connection)
+    };
+
+    insert(&NewUser::new("Sean", None))
+        .into(users::table)
+        .execute(&connection)
+        .unwrap();
+    update(users::table.filter(users::name.eq("Sean")))
+        .set(users::hair_color.eq("black"))
+        .execute(&connection)
+        .unwrap();
+    let hair_color = users::table
+        .select(users::hair_color)
+        .filter(users::name.eq("Sean"))
+        .first(&connection);
+
+    assert_eq!(Ok(Some("black".to_string())), hair_color);
+    assert_eq!(Ok(0), prepared_statements());
+
+    connection.set_prepared_statements(true);
+    let names = users::table.select(users::name).load::<String>(&connection);
+    assert_eq!(Ok(vec!["Sean".to_string()]), names);
+    assert_ne!(Ok(0), prepared_statements());
+}
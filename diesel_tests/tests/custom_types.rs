@@ -109,3 +109,136 @@ fn custom_types_round_trip() {
         .unwrap();
     assert_eq!(data, inserted);
 }
+
+table! {
+    use diesel::types::*;
+    use super::MyItemType;
+    custom_type_arrays {
+        id -> Integer,
+        custom_items -> Array<MyItemType>,
+    }
+}
+
+pub struct MyItemType;
+
+#[derive(Debug, PartialEq)]
+pub enum MyItem {
+    Foo,
+    Bar,
+}
+
+mod impls_for_arrays {
+    use diesel::expression::AsExpression;
+    use diesel::expression::bound::Bound;
+    use diesel::pg::Pg;
+    use diesel::query_builder::QueryId;
+    use diesel::row::Row;
+    use diesel::types::*;
+    use std::error::Error;
+    use std::io::Write;
+
+    use super::{MyItem, MyItemType};
+
+    impl HasSqlType<MyItemType> for Pg {
+        fn metadata(lookup: &Self::MetadataLookup) -> Self::TypeMetadata {
+            lookup.lookup_type("my_item_type")
+        }
+    }
+
+    impl NotNull for MyItemType {}
+
+    impl QueryId for MyItemType {
+        type QueryId = Self;
+
+        const HAS_STATIC_QUERY_ID: bool = true;
+    }
+
+    impl<'a> AsExpression<MyItemType> for &'a MyItem {
+        type Expression = Bound<MyItemType, &'a MyItem>;
+
+        fn as_expression(self) -> Self::Expression {
+            Bound::new(self)
+        }
+    }
+
+    impl ToSql<MyItemType, Pg> for MyItem {
+        fn to_sql<W: Write>(
+            &self,
+            out: &mut ToSqlOutput<W, Pg>,
+        ) -> Result<IsNull, Box<Error + Send + Sync>> {
+            match *self {
+                MyItem::Foo => out.write_all(b"foo")?,
+                MyItem::Bar => out.write_all(b"bar")?,
+            }
+            Ok(IsNull::No)
+        }
+    }
+
+    impl FromSql<MyItemType, Pg> for MyItem {
+        fn from_sql(bytes: Option<&[u8]>) -> Result<Self, Box<Error + Send + Sync>> {
+            match bytes {
+                Some(b"foo") => Ok(MyItem::Foo),
+                Some(b"bar") => Ok(MyItem::Bar),
+                Some(_) => Err("Unrecognized enum variant".into()),
+                None => Err("Unexpected null for non-null column".into()),
+            }
+        }
+    }
+
+    impl FromSqlRow<MyItemType, Pg> for MyItem {
+        fn build_from_row<T: Row<Pg>>(row: &mut T) -> Result<Self, Box<Error + Send + Sync>> {
+            FromSql::<MyItemType, Pg>::from_sql(row.take())
+        }
+    }
+}
+
+#[derive(Insertable, Queryable, Debug, PartialEq)]
+#[table_name = "custom_type_arrays"]
+struct HasCustomTypeArrays {
+    id: i32,
+    custom_items: Vec<MyItem>,
+}
+
+#[test]
+fn arrays_of_custom_types_round_trip() {
+    let data = vec![
+        HasCustomTypeArrays {
+            id: 1,
+            custom_items: vec![MyItem::Foo, MyItem::Bar, MyItem::Foo],
+        },
+        HasCustomTypeArrays {
+            id: 2,
+            custom_items: Vec::new(),
+        },
+    ];
+    let connection = connection();
+    connection
+        .batch_execute(
+            r#"
+        CREATE TYPE my_item_type AS ENUM ('foo', 'bar');
+        CREATE TABLE custom_type_arrays (
+            id SERIAL PRIMARY KEY,
+            custom_items my_item_type[] NOT NULL
+        );
+    "#,
+        )
+        .unwrap();
+
+    let inserted = insert(&data)
+        .into(custom_type_arrays::table)
+        .get_results(&connection)
+        .unwrap();
+    assert_eq!(data, inserted);
+
+    let bars = custom_type_arrays::table
+        .select(custom_type_arrays::id)
+        .filter(custom_type_arrays::custom_items.contains(vec![MyItem::Bar]))
+        .load::<i32>(&connection);
+    assert_eq!(Ok(vec![1]), bars);
+
+    let items = custom_type_arrays::table
+        .select(custom_type_arrays::custom_items)
+        .order(custom_type_arrays::id)
+        .load::<Vec<MyItem>>(&connection);
+    assert_eq!(Ok(vec![vec![MyItem::Foo, MyItem::Bar, MyItem::Foo], vec![]]), items);
+}
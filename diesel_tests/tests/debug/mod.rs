@@ -12,6 +12,24 @@ fn test_debug_count_output() {
     }
 }
 
+#[test]
+fn test_debug_count_output_removes_order_clause() {
+    use schema::users::dsl::*;
+    let query = users.filter(name.eq("Sean")).order(id.desc()).count();
+    let sql = debug_query::<TestBackend, _>(&query).to_string();
+    if cfg!(feature = "postgres") {
+        assert_eq!(
+            sql,
+            r#"SELECT COUNT(*) FROM "users" WHERE "users"."name" = $1 -- binds: ["Sean"]"#
+        );
+    } else {
+        assert_eq!(
+            sql,
+            "SELECT COUNT(*) FROM `users` WHERE `users`.`name` = ? -- binds: [\"Sean\"]"
+        );
+    }
+}
+
 #[test]
 fn test_debug_output() {
     use schema::users::dsl::*;
@@ -29,3 +47,188 @@ fn test_debug_output() {
         )
     }
 }
+
+#[test]
+fn test_debug_output_of_explicitly_typed_bind_param() {
+    let query = select(1.into_sql::<types::Integer>());
+    let sql = debug_query::<TestBackend, _>(&query).to_string();
+    if cfg!(feature = "postgres") {
+        assert_eq!(sql, "SELECT $1::integer -- binds: [1]");
+    } else {
+        assert_eq!(sql, "SELECT ? -- binds: [1]");
+    }
+}
+
+#[test]
+fn test_debug_output_of_typed_null() {
+    let query = select(dsl::null::<types::Integer>());
+    let sql = debug_query::<TestBackend, _>(&query).to_string();
+    if cfg!(feature = "postgres") {
+        assert_eq!(sql, "SELECT NULL::integer -- binds: []");
+    } else {
+        assert_eq!(sql, "SELECT NULL -- binds: []");
+    }
+}
+
+#[test]
+fn test_debug_output_of_string_agg() {
+    use schema::users::dsl::*;
+    let query = users.select(dsl::string_agg(name, ", "));
+    let sql = debug_query::<TestBackend, _>(&query).to_string();
+    if cfg!(feature = "postgres") {
+        assert_eq!(
+            sql,
+            r#"SELECT string_agg("users"."name", $1) FROM "users" -- binds: [", "]"#
+        );
+    } else if cfg!(feature = "sqlite") {
+        assert_eq!(
+            sql,
+            r#"SELECT group_concat(`users`.`name`, ?) FROM `users` -- binds: [", "]"#
+        );
+    } else {
+        assert_eq!(
+            sql,
+            r#"SELECT SUBSTRING(GROUP_CONCAT(CONCAT(?, `users`.`name`) SEPARATOR ''), CHAR_LENGTH(?) + 1) FROM `users` -- binds: [", ", ", "]"#
+        );
+    }
+}
+
+#[test]
+#[cfg(not(feature = "sqlite"))]
+fn test_debug_output_of_ordered_string_agg() {
+    use schema::users::dsl::*;
+    let query = users.select(dsl::string_agg(name, "', '").order_by(id.desc()));
+    let sql = debug_query::<TestBackend, _>(&query).to_string();
+    if cfg!(feature = "postgres") {
+        assert_eq!(
+            sql,
+            r#"SELECT string_agg("users"."name", $1 ORDER BY "users"."id" DESC) FROM "users" -- binds: ["', '"]"#
+        );
+    } else {
+        assert_eq!(
+            sql,
+            r#"SELECT SUBSTRING(GROUP_CONCAT(CONCAT(?, `users`.`name`) ORDER BY `users`.`id` DESC SEPARATOR ''), CHAR_LENGTH(?) + 1) FROM `users` -- binds: ["', '", "', '"]"#
+        );
+    }
+}
+
+#[test]
+fn test_debug_output_of_filter_group() {
+    use schema::users::dsl::*;
+    let query = users
+        .select(id)
+        .filter(hair_color.is_null())
+        .filter_group(|q| q.filter(name.eq("Sean")).or_filter(name.eq("Tess")));
+    let sql = debug_query::<TestBackend, _>(&query).to_string();
+    if cfg!(feature = "postgres") {
+        assert_eq!(
+            sql,
+            r#"SELECT "users"."id" FROM "users" WHERE "users"."hair_color" IS NULL AND ("users"."name" = $1 OR "users"."name" = $2) -- binds: ["Sean", "Tess"]"#
+        );
+    } else {
+        assert_eq!(
+            sql,
+            r#"SELECT `users`.`id` FROM `users` WHERE `users`.`hair_color` IS NULL AND (`users`.`name` = ? OR `users`.`name` = ?) -- binds: ["Sean", "Tess"]"#
+        );
+    }
+}
+
+#[test]
+#[cfg(not(feature = "sqlite"))]
+fn test_debug_output_of_truncate() {
+    use schema::users::dsl::*;
+    let command = truncate(users).restart_identity();
+    let sql = debug_query::<TestBackend, _>(&command).to_string();
+    if cfg!(feature = "postgres") {
+        assert_eq!(sql, r#"TRUNCATE TABLE "users" RESTART IDENTITY -- binds: []"#);
+    } else {
+        assert_eq!(sql, "TRUNCATE TABLE `users` -- binds: []");
+    }
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn test_debug_output_of_truncate_cascade() {
+    use schema::users::dsl::*;
+    let command = truncate(users).cascade();
+    let sql = debug_query::<TestBackend, _>(&command).to_string();
+    assert_eq!(sql, r#"TRUNCATE TABLE "users" CASCADE -- binds: []"#);
+}
+
+#[test]
+fn test_debug_output_of_eq_opt() {
+    use schema::users::dsl::*;
+    let some_query = users.select(id).filter(hair_color.eq_opt(Some("black")));
+    let none_query = users.select(id).filter(hair_color.eq_opt(None::<&str>));
+    let some_sql = debug_query::<TestBackend, _>(&some_query).to_string();
+    let none_sql = debug_query::<TestBackend, _>(&none_query).to_string();
+    if cfg!(feature = "postgres") {
+        assert_eq!(
+            some_sql,
+            r#"SELECT "users"."id" FROM "users" WHERE "users"."hair_color" = $1 -- binds: ["black"]"#
+        );
+        assert_eq!(
+            none_sql,
+            r#"SELECT "users"."id" FROM "users" WHERE "users"."hair_color" IS NULL -- binds: []"#
+        );
+    } else {
+        assert_eq!(
+            some_sql,
+            r#"SELECT `users`.`id` FROM `users` WHERE `users`.`hair_color` = ? -- binds: ["black"]"#
+        );
+        assert_eq!(
+            none_sql,
+            "SELECT `users`.`id` FROM `users` WHERE `users`.`hair_color` IS NULL -- binds: []"
+        );
+    }
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn test_debug_output_of_eq_any_chunked() {
+    use schema::users::dsl::*;
+    let query = users.select(id).filter(id.eq_any_chunked(vec![1, 2, 3], 2));
+    let sql = debug_query::<TestBackend, _>(&query).to_string();
+    assert_eq!(
+        sql,
+        r#"SELECT "users"."id" FROM "users" WHERE "users"."id" = ANY($1) -- binds: [[1, 2, 3]]"#
+    );
+}
+
+#[test]
+fn test_debug_output_of_rolling_window() {
+    use schema::posts::dsl::*;
+    use diesel::dsl::{count_star, window};
+    use diesel::expression::window::*;
+
+    let rolling_count = count_star().over(
+        window()
+            .partition_by(user_id)
+            .order_by(id)
+            .frame(Rows::between(Preceding(6), CurrentRow)),
+    );
+    let query = posts.select((id, rolling_count));
+    let sql = debug_query::<TestBackend, _>(&query).to_string();
+    if cfg!(feature = "postgres") {
+        assert_eq!(
+            sql,
+            r#"SELECT "posts"."id", COUNT(*) OVER (PARTITION BY "posts"."user_id" ORDER BY "posts"."id" ROWS BETWEEN 6 PRECEDING AND CURRENT ROW) FROM "posts" -- binds: []"#
+        );
+    } else {
+        assert_eq!(
+            sql,
+            "SELECT `posts`.`id`, COUNT(*) OVER (PARTITION BY `posts`.`user_id` ORDER BY `posts`.`id` ROWS BETWEEN 6 PRECEDING AND CURRENT ROW) FROM `posts` -- binds: []"
+        );
+    }
+
+    let total = count_star().over(
+        window().frame(Range::between(UnboundedPreceding, UnboundedFollowing)),
+    );
+    let query = posts.select(total);
+    let sql = debug_query::<TestBackend, _>(&query).to_string();
+    assert!(
+        sql.contains("COUNT(*) OVER (RANGE BETWEEN UNBOUNDED PRECEDING AND UNBOUNDED FOLLOWING)"),
+        "{}",
+        sql
+    );
+}
@@ -1,4 +1,5 @@
 use diesel::*;
+use diesel::associations::Cascade;
 use schema::*;
 
 #[test]
@@ -42,3 +43,122 @@ fn return_deleted_records() {
     let num_users = users.count().first(&connection);
     assert_eq!(Ok(1), num_users);
 }
+
+#[test]
+#[cfg(not(any(feature = "sqlite", feature = "mysql")))]
+fn return_primary_keys_of_deleted_records() {
+    use schema::users::dsl::*;
+    let connection = connection();
+    let new_users = vec![
+        NewUser::new("Sean", None),
+        NewUser::new("Tess", None),
+        NewUser::new("Jim", None),
+    ];
+    insert(&new_users)
+        .into(users)
+        .execute(&connection)
+        .unwrap();
+    let all_ids = users.select(id).order(id).load::<i32>(&connection).unwrap();
+
+    let mut deleted_ids = delete(users.filter(name.ne("Tess")))
+        .returning(id)
+        .get_results::<i32>(&connection)
+        .unwrap();
+    deleted_ids.sort();
+    assert_eq!(vec![all_ids[0], all_ids[2]], deleted_ids);
+
+    let remaining_ids = users.select(id).load(&connection);
+    assert_eq!(Ok(vec![all_ids[1]]), remaining_ids);
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn delete_rows_matched_against_another_table_with_using() {
+    let connection = connection_with_sean_and_tess_in_users_table();
+    let sean = find_user_by_name("Sean", &connection);
+    let tess = find_user_by_name("Tess", &connection);
+    let new_posts = vec![
+        sean.new_post("Hello", None),
+        tess.new_post("Hi", None),
+        tess.new_post("Draft", None),
+        tess.new_post("Bye", None),
+    ];
+    insert(&new_posts)
+        .into(posts::table)
+        .execute(&connection)
+        .unwrap();
+
+    let query = delete(posts::table.filter(posts::title.ne("Bye")))
+        .using(users::table)
+        .filter(posts::user_id.eq(users::id))
+        .filter(users::name.eq("Tess"));
+    let expected_sql = "DELETE FROM \"posts\" USING \"users\" \
+                        WHERE \"posts\".\"title\" != $1 \
+                        AND \"posts\".\"user_id\" = \"users\".\"id\" \
+                        AND \"users\".\"name\" = $2 \
+                        -- binds: [\"Bye\", \"Tess\"]";
+    assert_eq!(expected_sql, debug_query(&query).to_string());
+    assert_eq!(Ok(2), query.execute(&connection));
+
+    let remaining_posts = posts::table
+        .select((posts::user_id, posts::title))
+        .order(posts::id)
+        .load::<(i32, String)>(&connection);
+    let expected_posts = vec![(sean.id, "Hello".to_string()), (tess.id, "Bye".to_string())];
+    assert_eq!(Ok(expected_posts), remaining_posts);
+}
+
+impl Cascade<TestConnection> for User {
+    fn delete_dependents(&self, conn: &TestConnection) -> QueryResult<()> {
+        for post in try!(Post::belonging_to(self).load::<Post>(conn)) {
+            try!(delete_cascade(&post, conn));
+        }
+        Ok(())
+    }
+}
+
+impl Cascade<TestConnection> for Post {
+    fn delete_dependents(&self, conn: &TestConnection) -> QueryResult<()> {
+        try!(delete(Comment::belonging_to(self)).execute(conn));
+        Ok(())
+    }
+}
+
+#[test]
+fn delete_cascade_deletes_declared_dependents_first() {
+    let connection = connection_with_sean_and_tess_in_users_table();
+    let sean = find_user_by_name("Sean", &connection);
+    let tess = find_user_by_name("Tess", &connection);
+    let new_posts = vec![
+        sean.new_post("Hello", None),
+        sean.new_post("World", None),
+        tess.new_post("Hi", None),
+    ];
+    insert(&new_posts)
+        .into(posts::table)
+        .execute(&connection)
+        .unwrap();
+    let post_ids = posts::table
+        .select(posts::id)
+        .order(posts::id)
+        .load::<i32>(&connection)
+        .unwrap();
+    let new_comments = vec![
+        NewComment(post_ids[0], "First"),
+        NewComment(post_ids[1], "Second"),
+        NewComment(post_ids[2], "Third"),
+    ];
+    insert(&new_comments)
+        .into(comments::table)
+        .execute(&connection)
+        .unwrap();
+
+    assert_eq!(Ok(1), delete_cascade(&sean, &connection));
+
+    let remaining_users = users::table.load(&connection);
+    assert_eq!(Ok(vec![tess]), remaining_users);
+    let remaining_posts = posts::table.select(posts::title).load(&connection);
+    assert_eq!(Ok(vec!["Hi".to_string()]), remaining_posts);
+    let remaining_comments = comments::table.select(comments::text).load(&connection);
+    assert_eq!(Ok(vec!["Third".to_string()]), remaining_comments);
+}
@@ -96,3 +96,121 @@ fn foreign_key_violation_correct_constraint_name() {
         _ => panic!("{:?} did not match Err(DatabaseError(ForeignKeyViolation, e))", failure),
     }
 }
+
+#[test]
+#[cfg(feature = "postgres")]
+fn not_null_violation_reports_column_name() {
+    let connection = connection();
+
+    let failure = connection.execute("INSERT INTO users (name) VALUES (NULL)");
+    match failure {
+        Err(DatabaseError(_, e)) => {
+            assert_eq!(Some("users"), e.table_name());
+            assert_eq!(Some("name"), e.column_name());
+            assert_eq!(None, e.constraint_name());
+        }
+        _ => panic!("{:?} did not match Err(DatabaseError(_, e))", failure),
+    }
+}
+
+#[test]
+fn insert_with_retry_generates_a_new_record_after_a_unique_violation() {
+    let connection = connection();
+    diesel::insert(&User::new(1, "Sean"))
+        .into(users::table)
+        .execute(&connection)
+        .unwrap();
+
+    let mut attempts = 0;
+    let inserted_id = diesel::connection::insert_with_retry(
+        3,
+        || {
+            attempts += 1;
+            let user = User::new(attempts, "Jim");
+            diesel::insert(&user)
+                .into(users::table)
+                .execute(&connection)
+                .map(|_| user.id)
+        },
+        &connection,
+    );
+
+    assert_eq!(Ok(2), inserted_id);
+    assert_eq!(2, attempts);
+    let names = users::table
+        .select(users::name)
+        .order(users::id)
+        .load::<String>(&connection);
+    assert_eq!(Ok(vec!["Sean".to_string(), "Jim".to_string()]), names);
+}
+
+#[test]
+fn insert_with_retry_returns_the_last_unique_violation() {
+    let connection = connection();
+    diesel::insert(&User::new(1, "Sean"))
+        .into(users::table)
+        .execute(&connection)
+        .unwrap();
+
+    let mut attempts = 0;
+    let failure = diesel::connection::insert_with_retry(
+        3,
+        || {
+            attempts += 1;
+            diesel::insert(&User::new(1, "Jim"))
+                .into(users::table)
+                .execute(&connection)
+        },
+        &connection,
+    );
+
+    assert_matches!(failure, Err(DatabaseError(UniqueViolation, _)));
+    assert_eq!(3, attempts);
+}
+
+#[test]
+fn insert_with_retry_does_not_retry_other_errors() {
+    let connection = connection();
+
+    let mut attempts = 0;
+    let failure = diesel::connection::insert_with_retry(
+        3,
+        || {
+            attempts += 1;
+            diesel::insert(&FkTest::new(1, 100))
+                .into(fk_tests::table)
+                .execute(&connection)
+        },
+        &connection,
+    );
+
+    assert_matches!(failure, Err(DatabaseError(ForeignKeyViolation, _)));
+    assert_eq!(1, attempts);
+}
+
+#[test]
+#[should_panic(expected = "at least one attempt")]
+fn insert_with_retry_requires_at_least_one_attempt() {
+    let connection = connection();
+
+    let _ = diesel::connection::insert_with_retry(
+        0,
+        || {
+            diesel::insert(&FkTest::new(1, 100))
+                .into(fk_tests::table)
+                .execute(&connection)
+        },
+        &connection,
+    );
+}
+
+#[test]
+fn identifiers_containing_a_nul_byte_are_rejected() {
+    use diesel::result::Error::QueryBuilderError;
+    use schema_dsl::*;
+
+    let connection = connection();
+    let result = create_table("bad\0name", (integer("id").primary_key(),)).execute(&connection);
+
+    assert_matches!(result, Err(QueryBuilderError(_)));
+}
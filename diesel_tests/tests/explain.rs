@@ -0,0 +1,38 @@
+use schema::*;
+use diesel::*;
+
+#[test]
+fn explain_returns_the_query_plan() {
+    use schema::users::dsl::*;
+
+    let connection = connection();
+    let plan = users.filter(name.eq("Sean")).explain(&connection).unwrap();
+
+    assert!(plan.contains("Seq Scan on users"), "{}", plan);
+    assert!(plan.contains("Filter: ((name)::text = 'Sean'::text)"), "{}", plan);
+}
+
+#[test]
+fn explain_analyze_executes_the_query() {
+    use schema::users::dsl::*;
+
+    let connection = connection_with_sean_and_tess_in_users_table();
+    let plan = users
+        .filter(name.eq("Sean"))
+        .explain_analyze(&connection)
+        .unwrap();
+
+    assert!(plan.contains("actual time="), "{}", plan);
+    assert!(plan.contains("rows=1 loops=1"), "{}", plan);
+}
+
+#[test]
+fn explain_json_returns_the_query_plan_as_json() {
+    use schema::users::dsl::*;
+
+    let connection = connection();
+    let plan = users.filter(name.eq("Sean")).explain_json(&connection).unwrap();
+
+    assert_eq!(Some("Seq Scan"), plan[0]["Plan"]["Node Type"].as_str());
+    assert_eq!(Some("users"), plan[0]["Plan"]["Relation Name"].as_str());
+}
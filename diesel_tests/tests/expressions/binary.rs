@@ -0,0 +1,49 @@
+use schema::*;
+use diesel::*;
+use diesel::dsl::*;
+use diesel::types::Binary;
+
+fn one_megabyte_of_data() -> Vec<u8> {
+    (0..1024 * 1024).map(|i| (i % 251) as u8).collect()
+}
+
+#[test]
+fn large_binary_values_round_trip() {
+    let connection = connection();
+    let data = one_megabyte_of_data();
+
+    let query = select(data.as_slice().into_sql::<Binary>());
+    assert_eq!(Ok(data.clone()), query.get_result::<Vec<u8>>(&connection));
+}
+
+#[test]
+#[cfg(not(feature = "sqlite"))]
+fn octet_length_of_large_binary_value() {
+    let connection = connection();
+    let data = one_megabyte_of_data();
+
+    let query = select(octet_length(data.as_slice().into_sql::<Binary>()));
+    assert_eq!(Ok(1024 * 1024), query.get_result(&connection));
+}
+
+#[test]
+fn substring_of_binary_value() {
+    let connection = connection();
+    let data = vec![0u8, 1, 2, 3, 4, 5];
+
+    let query = select(substring(data.into_sql::<Binary>(), 3, 2));
+    assert_eq!(Ok(vec![2u8, 3]), query.get_result::<Vec<u8>>(&connection));
+}
+
+#[test]
+fn substring_of_text_value() {
+    use schema::users::dsl::*;
+
+    let connection = connection_with_sean_and_tess_in_users_table();
+
+    let query = users.select(substring(name, 2, 2)).order(id);
+    assert_eq!(
+        Ok(vec!["ea".to_string(), "es".to_string()]),
+        query.load::<String>(&connection)
+    );
+}
@@ -0,0 +1,54 @@
+use schema::{connection_with_sean_and_tess_in_users_table, TestBackend};
+use schema::users::dsl::*;
+use diesel::*;
+use diesel::types::Integer;
+
+infix_operator!(Modulo, " % ", Integer, fn modulo(Integer, Integer) in ModuloExpressionMethods);
+
+#[test]
+fn custom_infix_operator_is_rendered_with_binds_and_parentheses() {
+    let query = users.select(id).filter(id.modulo(2).eq(0));
+    let mut expected_sql = "SELECT `users`.`id` FROM `users` \
+                            WHERE (`users`.`id` % ?) = ? \
+                            -- binds: [2, 0]"
+        .to_string();
+    if cfg!(feature = "postgres") {
+        expected_sql = expected_sql
+            .replace('`', "\"")
+            .replace("% ?) = ?", "% $1) = $2");
+    }
+
+    assert_eq!(
+        expected_sql,
+        debug_query::<TestBackend, _>(&query).to_string()
+    );
+}
+
+#[test]
+fn custom_infix_operator_can_be_nested() {
+    let query = users.select(id.modulo(3).modulo(2));
+    let mut expected_sql = "SELECT ((`users`.`id` % ?) % ?) FROM `users` \
+                            -- binds: [3, 2]"
+        .to_string();
+    if cfg!(feature = "postgres") {
+        expected_sql = expected_sql
+            .replace('`', "\"")
+            .replace("% ?) % ?)", "% $1) % $2)");
+    }
+
+    assert_eq!(
+        expected_sql,
+        debug_query::<TestBackend, _>(&query).to_string()
+    );
+}
+
+#[test]
+fn custom_infix_operator_can_be_executed() {
+    let connection = connection_with_sean_and_tess_in_users_table();
+
+    let even_ids = users.select(id).filter(id.modulo(2).eq(0));
+    let remainders = users.select(id.modulo(2)).order(id);
+
+    assert_eq!(Ok(vec![2]), even_ids.load(&connection));
+    assert_eq!(Ok(vec![1, 0]), remainders.load(&connection));
+}
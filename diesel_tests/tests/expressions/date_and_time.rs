@@ -1,3 +1,5 @@
+extern crate chrono;
+
 use schema::{connection, TestConnection};
 use diesel::*;
 use diesel::data_types::*;
@@ -154,6 +156,113 @@ fn date_uses_sql_function_date() {
     assert_eq!(Ok(expected_data), actual_data);
 }
 
+#[test]
+#[cfg(not(feature = "mysql"))] // FIXME: Figure out how to handle tests that modify schema
+fn group_by_extracted_year() {
+    use self::has_timestamps::dsl::*;
+
+    let connection = connection();
+    setup_test_table(&connection);
+    connection
+        .execute(
+            "INSERT INTO has_timestamps (created_at, updated_at) VALUES
+                       ('2015-11-15 06:07:41', '2015-11-15 20:07:41'),
+                       ('2016-03-16 06:07:41', '2016-03-17 20:07:41'),
+                       ('2015-12-31 23:59:59', '2016-01-01 00:00:00')
+                       ",
+        )
+        .unwrap();
+
+    let year = extract(DatePart::Year, created_at);
+    let years = has_timestamps
+        .select(year)
+        .distinct()
+        .order(year)
+        .load::<f64>(&connection);
+    let post_counts = has_timestamps
+        .group_by(year)
+        .select(count_star())
+        .order(year)
+        .load::<i64>(&connection);
+
+    assert_eq!(Ok(vec![2015.0, 2016.0]), years);
+    assert_eq!(Ok(vec![2, 1]), post_counts);
+}
+
+#[test]
+#[cfg(not(feature = "mysql"))] // FIXME: Figure out how to handle tests that modify schema
+fn extract_retrieves_parts_of_timestamps() {
+    use self::nullable_date_and_time::dsl::*;
+
+    let connection = connection();
+    setup_test_table(&connection);
+    connection
+        .execute(
+            "INSERT INTO nullable_date_and_time (timestamp) VALUES
+                       ('2017-07-23 12:34:56.5'), (NULL)",
+        )
+        .unwrap();
+
+    let parts = nullable_date_and_time
+        .select((
+            extract(DatePart::Month, timestamp),
+            extract(DatePart::Day, timestamp),
+            extract(DatePart::Hour, timestamp),
+            extract(DatePart::Minute, timestamp),
+            extract(DatePart::Second, timestamp),
+            extract(DatePart::DayOfWeek, timestamp),
+            extract(DatePart::DayOfYear, timestamp),
+            extract(DatePart::Epoch, timestamp),
+        ))
+        .order(id)
+        .load(&connection);
+    let epoch = if cfg!(feature = "sqlite") {
+        1500813296.0
+    } else {
+        1500813296.5
+    };
+    let expected_parts = vec![
+        (
+            Some(7.0),
+            Some(23.0),
+            Some(12.0),
+            Some(34.0),
+            Some(56.5),
+            Some(0.0),
+            Some(204.0),
+            Some(epoch),
+        ),
+        (None, None, None, None, None, None, None, None),
+    ];
+    assert_eq!(Ok(expected_parts), parts);
+}
+
+#[test]
+fn extract_retrieves_only_the_hour_minute_and_second_of_times() {
+    use diesel::types::Time;
+
+    let connection = connection();
+    let time = if cfg!(feature = "sqlite") {
+        sql::<Time>("'12:34:56'")
+    } else {
+        sql::<Time>("TIME '12:34:56'")
+    };
+
+    let parts = select((
+        extract(DatePart::Hour, time.clone()),
+        extract(DatePart::Minute, time.clone()),
+        extract(DatePart::Second, time.clone()),
+    )).get_result(&connection);
+    assert_eq!(Ok((12.0, 34.0, 56.0)), parts);
+
+    for &part in &[DatePart::Year, DatePart::Month, DatePart::Epoch] {
+        match select(extract(part, time.clone())).get_result::<f64>(&connection) {
+            Err(result::Error::QueryBuilderError(_)) => {}
+            other => panic!("Expected a query builder error, got {:?}", other),
+        }
+    }
+}
+
 #[test]
 #[cfg(feature = "postgres")]
 fn time_is_deserialized_properly() {
@@ -264,6 +373,78 @@ fn adding_interval_to_nullable_things() {
     assert_eq!(expected_data, actual_data);
 }
 
+#[test]
+#[cfg(feature = "postgres")]
+fn left_joining_a_daily_series_counts_rows_of_each_day() {
+    use diesel::dsl::sql;
+    use diesel::expression::dsl::count;
+    use diesel::pg::generate_series::generate_series;
+
+    let connection = connection();
+    setup_test_table(&connection);
+    connection
+        .execute(
+            "INSERT INTO has_timestamps (created_at) VALUES
+                       ('2017-01-01 10:00:00'), ('2017-01-01 15:00:00'),
+                       ('2017-01-03 09:00:00')",
+        )
+        .unwrap();
+
+    let days = generate_series(
+        sql::<types::Timestamp>("'2017-01-01'::timestamp"),
+        sql::<types::Timestamp>("'2017-01-04'::timestamp"),
+        1.day(),
+    );
+    let day = days.value();
+    let created_at = has_timestamps::created_at;
+    let counts = days.left_join(
+        has_timestamps::table.on(created_at.ge(day).and((created_at - 1.day()).lt(day))),
+    ).group_by(day)
+        .select(count(has_timestamps::id.nullable()))
+        .order(day);
+
+    assert_eq!(
+        "SELECT COUNT(\"has_timestamps\".\"id\") \
+         FROM (generate_series('2017-01-01'::timestamp, '2017-01-04'::timestamp, $1) \
+         AS generate_series(value) LEFT OUTER JOIN \"has_timestamps\" \
+         ON \"has_timestamps\".\"created_at\" >= generate_series.value \
+         AND \"has_timestamps\".\"created_at\" - $2 < generate_series.value) \
+         GROUP BY generate_series.value ORDER BY generate_series.value \
+         -- binds: [PgInterval { microseconds: 0, days: 1, months: 0 }, \
+         PgInterval { microseconds: 0, days: 1, months: 0 }]",
+        debug_query(&counts).to_string()
+    );
+    assert_eq!(Ok(vec![2, 0, 1, 0]), counts.load::<i64>(&connection));
+}
+
+#[test]
+#[cfg(not(feature = "mysql"))]
+fn min_and_max_of_timestamps_are_nullable_timestamps() {
+    use self::chrono::{NaiveDate, NaiveDateTime};
+    use self::has_timestamps::dsl::*;
+
+    let connection = connection();
+    setup_test_table(&connection);
+    let earliest = has_timestamps.select(min(created_at));
+    let latest = has_timestamps.select(max(created_at));
+
+    assert_eq!(Ok(None::<NaiveDateTime>), earliest.first(&connection));
+    assert_eq!(Ok(None::<NaiveDateTime>), latest.first(&connection));
+
+    connection
+        .execute(
+            "INSERT INTO has_timestamps (created_at) VALUES
+                       ('2017-01-03 09:00:00'), ('2017-01-01 10:00:00'),
+                       ('2017-01-02 15:00:00')",
+        )
+        .unwrap();
+
+    let expected_first = NaiveDate::from_ymd(2017, 1, 1).and_hms(10, 0, 0);
+    let expected_last = NaiveDate::from_ymd(2017, 1, 3).and_hms(9, 0, 0);
+    assert_eq!(Ok(Some(expected_first)), earliest.first(&connection));
+    assert_eq!(Ok(Some(expected_last)), latest.first(&connection));
+}
+
 #[cfg(not(feature = "mysql"))] // FIXME: Figure out how to handle tests that modify schema
 fn setup_test_table(conn: &TestConnection) {
     use schema_dsl::*;
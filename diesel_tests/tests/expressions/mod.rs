@@ -1,5 +1,8 @@
+mod binary;
+mod custom_operators;
 mod date_and_time;
 mod ops;
+mod string;
 
 use schema::{connection, NewUser, TestBackend};
 use schema::users::dsl::*;
@@ -41,6 +44,33 @@ fn test_count_star() {
     );
 }
 
+#[test]
+fn count_of_a_filtered_and_ordered_query_ignores_the_order() {
+    use schema::connection_with_sean_and_tess_in_users_table;
+
+    let connection = connection_with_sean_and_tess_in_users_table();
+    insert(&NewUser::new("Jim", Some("black")))
+        .into(users)
+        .execute(&connection)
+        .unwrap();
+    let source = users
+        .filter(name.ne("Tess"))
+        .order(name.desc())
+        .count();
+
+    assert_eq!(Ok(2), source.get_result(&connection));
+    assert!(!debug_query::<TestBackend, _>(&source)
+        .to_string()
+        .contains("ORDER BY"));
+
+    let boxed_source = users
+        .filter(name.ne("Tess"))
+        .order(hair_color)
+        .into_boxed()
+        .count();
+    assert_eq!(Ok(2), boxed_source.get_result(&connection));
+}
+
 table! {
     numbers (n) {
         n -> Integer,
@@ -87,6 +117,22 @@ fn max_returns_same_type_as_expression_being_maximized() {
     assert_eq!(Ok(None::<String>), source.first(&connection));
 }
 
+#[test]
+fn max_of_empty_table_is_none_or_deserialization_error() {
+    use diesel::result::Error::DeserializationError;
+
+    let connection = connection();
+
+    assert_eq!(Ok(None::<i32>), users.select(max(id)).first(&connection));
+    assert_eq!(Ok(None::<i32>), users.select(max(id)).get_result(&connection));
+
+    let not_null_max = users.select(sql::<types::Integer>("MAX(id)"));
+    match not_null_max.get_result::<i32>(&connection) {
+        Err(DeserializationError(_)) => {}
+        v => panic!("Expected a deserialization error, got {:?}", v),
+    }
+}
+
 use std::marker::PhantomData;
 
 struct Arbitrary<T> {
@@ -127,6 +173,11 @@ fn max_accepts_all_numeric_string_and_date_types() {
     let _ = users.select(max(arbitrary::<types::VarChar>()));
     let _ = users.select(max(arbitrary::<types::Text>()));
 
+    let _ = users.select(max(arbitrary::<types::Numeric>()));
+    let _ = users.select(max(arbitrary::<types::Date>()));
+    let _ = users.select(max(arbitrary::<types::Time>()));
+    let _ = users.select(max(arbitrary::<types::Timestamp>()));
+
     let _ = users.select(max(arbitrary::<types::Nullable<types::SmallInt>>()));
     let _ = users.select(max(arbitrary::<types::Nullable<types::Integer>>()));
     let _ = users.select(max(arbitrary::<types::Nullable<types::BigInt>>()));
@@ -157,6 +208,21 @@ fn test_min() {
     assert_eq!(Ok(None::<i32>), source.first(&connection));
 }
 
+#[test]
+fn min_returns_same_type_as_expression_being_minimized() {
+    let connection = connection();
+    let source = users.select(min(name));
+
+    assert_eq!(Ok(None::<String>), source.first(&connection));
+    let data: &[_] = &[
+        NewUser::new("B", None),
+        NewUser::new("C", None),
+        NewUser::new("A", None),
+    ];
+    insert(data).into(users).execute(&connection).unwrap();
+    assert_eq!(Ok(Some("A".to_string())), source.first(&connection));
+}
+
 sql_function!(coalesce, coalesce_t, (x: types::Nullable<types::VarChar>, y: types::VarChar) -> types::VarChar);
 
 #[test]
@@ -198,6 +264,100 @@ fn test_sum() {
     assert_eq!(Ok(None::<i64>), source.first(&connection));
 }
 
+#[test]
+fn test_rolling_sum_over_a_window_frame() {
+    use self::numbers::columns::*;
+    use self::numbers::table as numbers;
+    use diesel::expression::window::*;
+
+    let connection = connection();
+    connection
+        .execute("INSERT INTO numbers (n) VALUES (1), (2), (3), (4)")
+        .unwrap();
+    let rolling_sum = sum(n).over(
+        window()
+            .order_by(n)
+            .frame(Rows::between(Preceding(2), CurrentRow)),
+    );
+    let source = numbers.select((n, rolling_sum)).order(n);
+
+    let expected_data = vec![(1, Some(1)), (2, Some(3)), (3, Some(6)), (4, Some(9))];
+    assert_eq!(Ok(expected_data), source.load::<(i32, Option<i64>)>(&connection));
+}
+
+#[test]
+fn sum_over_an_empty_window_frame_is_none() {
+    use self::numbers::columns::*;
+    use self::numbers::table as numbers;
+    use diesel::expression::window::*;
+
+    let connection = connection();
+    connection
+        .execute("INSERT INTO numbers (n) VALUES (1), (2), (3)")
+        .unwrap();
+    let previous = sum(n).over(
+        window()
+            .order_by(n)
+            .frame(Rows::between(Preceding(1), Preceding(1))),
+    );
+    let source = numbers.select(previous).order(n);
+
+    assert_eq!(Ok(vec![None, Some(1), Some(2)]), source.load::<Option<i64>>(&connection));
+}
+
+#[test]
+fn ranking_functions_over_a_window_are_big_integers() {
+    let connection = connection();
+    let new_users = vec![
+        NewUser::new("Sean", Some("black")),
+        NewUser::new("Tess", Some("brown")),
+        NewUser::new("Jim", Some("black")),
+    ];
+    insert(&new_users)
+        .into(users)
+        .execute(&connection)
+        .unwrap();
+
+    let source = users
+        .select((
+            name,
+            row_number().over(window().order_by(id)),
+            rank().over(window().order_by(hair_color)),
+            dense_rank().over(window().order_by(hair_color)),
+        ))
+        .order(id);
+    let expected_data = vec![
+        ("Sean".to_string(), 1, 1, 1),
+        ("Tess".to_string(), 2, 3, 2),
+        ("Jim".to_string(), 3, 1, 1),
+    ];
+
+    assert_eq!(
+        Ok(expected_data),
+        source.load::<(String, i64, i64, i64)>(&connection)
+    );
+}
+
+#[test]
+fn row_number_restarts_in_each_partition() {
+    let connection = connection();
+    let new_users = vec![
+        NewUser::new("Sean", Some("black")),
+        NewUser::new("Tess", Some("brown")),
+        NewUser::new("Jim", Some("black")),
+    ];
+    insert(&new_users)
+        .into(users)
+        .execute(&connection)
+        .unwrap();
+
+    let source = users
+        .select(row_number().over(window().partition_by(hair_color).order_by(id)))
+        .order(id);
+
+    assert_eq!(Ok(vec![1, 1, 2]), source.load::<i64>(&connection));
+}
+
 table! {
     precision_numbers (n) {
         n -> Double,
@@ -224,6 +384,76 @@ fn test_sum_for_double() {
     assert_eq!(Ok(None::<f64>), source.first(&connection));
 }
 
+table! {
+    numeric_columns {
+        id -> Integer,
+        i2 -> SmallInt,
+        i4 -> Integer,
+        i8 -> BigInt,
+        n -> Numeric,
+        f4 -> Float,
+        f8 -> Double,
+    }
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn sum_returns_the_type_postgres_promotes_each_numeric_type_to() {
+    use self::numeric_columns::dsl::*;
+    use diesel::pg::data_types::PgNumeric;
+
+    let connection = connection();
+    connection
+        .execute(
+            "CREATE TABLE numeric_columns (
+        id SERIAL PRIMARY KEY,
+        i2 SMALLINT NOT NULL,
+        i4 INTEGER NOT NULL,
+        i8 BIGINT NOT NULL,
+        n NUMERIC NOT NULL,
+        f4 REAL NOT NULL,
+        f8 DOUBLE PRECISION NOT NULL
+    )",
+        )
+        .unwrap();
+    connection
+        .execute(
+            "INSERT INTO numeric_columns (i2, i4, i8, n, f4, f8) VALUES
+        (32767, 2147483647, 9223372036854775807, 0.25, 1.5, 2.25),
+        (32767, 2147483647, 9223372036854775807, 1.5, 2.5, 3.5)",
+        )
+        .unwrap();
+
+    let sum_of_i2 = numeric_columns.select(sum(i2)).get_result::<Option<i64>>(&connection);
+    let sum_of_i4 = numeric_columns.select(sum(i4)).get_result::<Option<i64>>(&connection);
+    let sum_of_i8 = numeric_columns
+        .select(sum(i8))
+        .get_result::<Option<PgNumeric>>(&connection);
+    let sum_of_n = numeric_columns
+        .select(sum(n))
+        .get_result::<Option<PgNumeric>>(&connection);
+    let sum_of_f4 = numeric_columns.select(sum(f4)).get_result::<Option<f32>>(&connection);
+    let sum_of_f8 = numeric_columns.select(sum(f8)).get_result::<Option<f64>>(&connection);
+
+    assert_eq!(Ok(Some(65534)), sum_of_i2);
+    assert_eq!(Ok(Some(4294967294)), sum_of_i4);
+    // 1844_6744_0737_0955_1614 in base 10000
+    let expected_sum_of_i8 = PgNumeric::Positive {
+        weight: 4,
+        scale: 0,
+        digits: vec![1844, 6744, 737, 955, 1614],
+    };
+    assert_eq!(Ok(Some(expected_sum_of_i8)), sum_of_i8);
+    let expected_sum_of_n = PgNumeric::Positive {
+        weight: 0,
+        scale: 2,
+        digits: vec![1, 7500],
+    };
+    assert_eq!(Ok(Some(expected_sum_of_n)), sum_of_n);
+    assert_eq!(Ok(Some(4.0)), sum_of_f4);
+    assert_eq!(Ok(Some(5.75)), sum_of_f8);
+}
+
 table! {
     nullable_doubles {
         id -> Integer,
@@ -352,3 +582,22 @@ fn test_avg_for_numeric() {
     };
     assert_eq!(Ok(Some(expected_result)), result);
 }
+
+#[test]
+#[cfg(feature = "postgres")]
+fn percentile_cont_interpolates_the_median_of_a_group() {
+    use self::numbers::columns::*;
+    use self::numbers::table as numbers;
+
+    let connection = connection();
+    connection
+        .execute("INSERT INTO numbers (n) VALUES (2), (1), (6), (9)")
+        .unwrap();
+    let median = numbers.select(percentile_cont(0.5).within_group_order_by(n));
+    let discrete_median = numbers.select(percentile_disc(0.5).within_group_order_by(n));
+
+    assert_eq!(Ok(Some(4.0)), median.first(&connection));
+    assert_eq!(Ok(Some(2)), discrete_median.first(&connection));
+    connection.execute("DELETE FROM numbers").unwrap();
+    assert_eq!(Ok(None::<f64>), median.first(&connection));
+}
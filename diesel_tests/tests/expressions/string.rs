@@ -0,0 +1,61 @@
+use schema::*;
+use diesel::*;
+use diesel::dsl::*;
+
+#[test]
+fn filter_by_lower() {
+    use schema::users::dsl::*;
+    let connection = connection_with_sean_and_tess_in_users_table();
+
+    let sean = find_user_by_name("Sean", &connection);
+    let data = users
+        .filter(lower(name).eq("sean"))
+        .load::<User>(&connection);
+    assert_eq!(Ok(vec![sean]), data);
+}
+
+#[test]
+fn select_upper_and_lower() {
+    use schema::users::dsl::*;
+    let connection = connection_with_sean_and_tess_in_users_table();
+
+    let expected_data = vec![
+        ("SEAN".to_string(), "sean".to_string()),
+        ("TESS".to_string(), "tess".to_string()),
+    ];
+    let data = users
+        .select((upper(name), lower(name)))
+        .order(id)
+        .load(&connection);
+    assert_eq!(Ok(expected_data), data);
+}
+
+#[test]
+fn trim_removes_whitespace() {
+    let connection = connection();
+
+    assert_eq!(Ok("Sean".to_string()), select(trim("  Sean  ")).get_result(&connection));
+    assert_eq!(Ok("Sean  ".to_string()), select(ltrim("  Sean  ")).get_result(&connection));
+    assert_eq!(Ok("  Sean".to_string()), select(rtrim("  Sean  ")).get_result(&connection));
+}
+
+#[test]
+fn order_by_length() {
+    use schema::users::dsl::*;
+    let connection = connection_with_sean_and_tess_in_users_table();
+    insert(&NewUser::new("Jim", None))
+        .into(users)
+        .execute(&connection)
+        .unwrap();
+
+    let expected_data = vec![
+        ("Jim".to_string(), 3),
+        ("Sean".to_string(), 4),
+        ("Tess".to_string(), 4),
+    ];
+    let data = users
+        .select((name, length(name)))
+        .order((length(name), name))
+        .load(&connection);
+    assert_eq!(Ok(expected_data), data);
+}
@@ -105,6 +105,29 @@ fn filter_by_is_null_on_nullable_columns() {
     assert_eq!(vec![gordon], source.load(&connection).unwrap());
 }
 
+#[test]
+fn filter_by_eq_opt_on_nullable_columns() {
+    use schema::users::dsl::*;
+
+    let connection = connection();
+    let data = vec![
+        NewUser::new("Derek", Some("red")),
+        NewUser::new("Gordon", None),
+    ];
+    insert(&data).into(users).execute(&connection).unwrap();
+    let data = users.order(id).load::<User>(&connection).unwrap();
+    let derek = data[0].clone();
+    let gordon = data[1].clone();
+
+    let red: Option<&str> = Some("red");
+    let source = users.filter(hair_color.eq_opt(red));
+    assert_eq!(vec![derek], source.load(&connection).unwrap());
+
+    let no_hair_color: Option<&str> = None;
+    let source = users.filter(hair_color.eq_opt(no_hair_color));
+    assert_eq!(vec![gordon], source.load(&connection).unwrap());
+}
+
 #[test]
 fn filter_after_joining() {
     use schema::users::name;
@@ -276,6 +299,95 @@ fn filter_on_column_equality() {
     assert_sets_eq!(expected_data, data);
 }
 
+table! {
+    timestamped_posts {
+        id -> Integer,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+    }
+}
+
+#[test]
+#[cfg(not(feature = "mysql"))] // FIXME: Figure out how to handle tests that modify schema
+fn filter_comparing_two_timestamp_columns() {
+    use self::timestamped_posts::dsl::*;
+
+    let connection = connection();
+    connection
+        .execute(
+            "CREATE TABLE timestamped_posts (
+        id INTEGER PRIMARY KEY,
+        created_at TIMESTAMP NOT NULL,
+        updated_at TIMESTAMP NOT NULL
+    )",
+        )
+        .unwrap();
+    connection
+        .execute(
+            "INSERT INTO timestamped_posts (id, created_at, updated_at) VALUES
+        (1, '2017-01-01 00:00:00', '2017-01-01 00:00:00'),
+        (2, '2017-01-01 00:00:00', '2017-06-01 12:00:00')",
+        )
+        .unwrap();
+
+    let query = timestamped_posts
+        .select(id)
+        .filter(updated_at.gt(created_at));
+    let mut expected_sql = "SELECT `timestamped_posts`.`id` FROM `timestamped_posts` \
+                            WHERE `timestamped_posts`.`updated_at` > `timestamped_posts`.`created_at` \
+                            -- binds: []"
+        .to_string();
+    if cfg!(feature = "postgres") {
+        expected_sql = expected_sql.replace('`', "\"");
+    }
+
+    assert_eq!(expected_sql, debug_query::<TestBackend, _>(&query).to_string());
+    assert_eq!(Ok(vec![2]), query.load::<i32>(&connection));
+}
+
+#[test]
+fn filter_by_icontains_ignores_case() {
+    use schema::users::dsl::*;
+
+    let connection = connection_with_sean_and_tess_in_users_table();
+    let names = users
+        .select(name)
+        .filter(name.icontains("eA"))
+        .load::<String>(&connection);
+
+    assert_eq!(Ok(vec!["Sean".to_string()]), names);
+}
+
+#[test]
+fn icontains_escapes_like_metacharacters() {
+    use schema::users::dsl::*;
+
+    let connection = connection_with_sean_and_tess_in_users_table();
+    insert(&vec![NewUser::new("Jo_hn", None), NewUser::new("Johnny", None)])
+        .into(users)
+        .execute(&connection)
+        .unwrap();
+
+    let query = users.select(name).filter(name.icontains("O_H"));
+    let expected_sql = if cfg!(feature = "postgres") {
+        "SELECT \"users\".\"name\" FROM \"users\" \
+         WHERE \"users\".\"name\" ILIKE $1 ESCAPE $2 \
+         -- binds: [\"%O\\\\_H%\", \"\\\\\"]"
+    } else {
+        "SELECT `users`.`name` FROM `users` \
+         WHERE lower(`users`.`name`) LIKE lower(?) ESCAPE ? \
+         -- binds: [\"%O\\\\_H%\", \"\\\\\"]"
+    };
+    assert_eq!(expected_sql, debug_query::<TestBackend, _>(&query).to_string());
+    assert_eq!(Ok(vec!["Jo_hn".to_string()]), query.load(&connection));
+
+    let names = users
+        .select(name)
+        .filter(name.icontains("%"))
+        .load::<String>(&connection);
+    assert_eq!(Ok(Vec::new()), names);
+}
+
 #[test]
 fn filter_with_or() {
     use schema::users::dsl::*;
@@ -319,6 +431,26 @@ fn or_doesnt_mess_with_precidence_of_previous_statements() {
     assert_eq!(Ok(0), count);
 }
 
+#[test]
+fn filter_group_is_combined_with_previous_filters_as_a_single_condition() {
+    use schema::users::dsl::*;
+
+    let connection = connection_with_sean_and_tess_in_users_table();
+    insert(&NewUser::new("Jim", Some("black")))
+        .into(users)
+        .execute(&connection)
+        .unwrap();
+
+    let data: Vec<String> = users
+        .select(name)
+        .filter(hair_color.is_null())
+        .filter_group(|q| q.filter(name.eq("Tess")).or_filter(name.eq("Jim")))
+        .load(&connection)
+        .unwrap();
+
+    assert_eq!(vec!["Tess".to_string()], data);
+}
+
 #[test]
 fn not_does_not_affect_expressions_other_than_those_passed_to_it() {
     use schema::users::dsl::*;
@@ -368,3 +500,56 @@ fn filter_by_boxed_predicate() {
     assert_eq!(Ok(sean), queried_sean);
     assert_eq!(Ok(tess), queried_tess);
 }
+
+#[test]
+fn folding_predicates_starting_from_true_expr_and_false_expr() {
+    use diesel::dsl::{false_expr, true_expr};
+
+    type Predicate = Box<BoxableExpression<users::table, TestBackend, SqlType = types::Bool>>;
+
+    fn all_of(predicates: Vec<Predicate>) -> Predicate {
+        predicates
+            .into_iter()
+            .fold(Box::new(true_expr()), |all, p| Box::new(all.and(p)))
+    }
+
+    fn any_of(predicates: Vec<Predicate>) -> Predicate {
+        predicates
+            .into_iter()
+            .fold(Box::new(false_expr()), |any, p| Box::new(any.or(p)))
+    }
+
+    let connection = connection_with_sean_and_tess_in_users_table();
+    let sean = User::new(1, "Sean");
+    let tess = User::new(2, "Tess");
+
+    let query = users::table.filter(all_of(Vec::new()));
+    let mut expected_sql = "SELECT `users`.`id`, `users`.`name`, `users`.`hair_color` \
+                            FROM `users` WHERE 1=1 -- binds: []"
+        .to_string();
+    if cfg!(feature = "postgres") {
+        expected_sql = expected_sql.replace('`', "\"");
+    }
+    assert_eq!(expected_sql, debug_query::<TestBackend, _>(&query).to_string());
+    assert_eq!(Ok(vec![sean.clone(), tess.clone()]), query.order(users::id).load(&connection));
+
+    let none = users::table.filter(any_of(Vec::new())).load::<User>(&connection);
+    assert_eq!(Ok(Vec::new()), none);
+
+    let only_tess = users::table
+        .filter(all_of(vec![
+            Box::new(users::id.gt(1)),
+            Box::new(users::name.eq("Tess")),
+        ]))
+        .load(&connection);
+    assert_eq!(Ok(vec![tess.clone()]), only_tess);
+
+    let both = users::table
+        .filter(any_of(vec![
+            Box::new(users::name.eq("Sean")),
+            Box::new(users::name.eq("Tess")),
+        ]))
+        .order(users::id)
+        .load(&connection);
+    assert_eq!(Ok(vec![sean, tess]), both);
+}
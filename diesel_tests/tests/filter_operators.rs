@@ -205,6 +205,44 @@ fn filter_by_ilike() {
     );
 }
 
+#[test]
+#[cfg(feature = "postgres")]
+fn filter_by_regex_match() {
+    use schema::users::dsl::*;
+
+    let connection = connection();
+    let data = vec![
+        NewUser::new("sean@example.com", None),
+        NewUser::new("Tess@Example.org", None),
+        NewUser::new("jim@localhost", None),
+    ];
+    insert(&data).into(users).execute(&connection).unwrap();
+
+    let email_pattern = "^[a-z]+@[a-z]+\\.[a-z]+$";
+    let query = users.select(name).filter(name.regex_match(email_pattern));
+    let expected_sql = "SELECT \"users\".\"name\" FROM \"users\" \
+                        WHERE \"users\".\"name\" ~ $1 \
+                        -- binds: [\"^[a-z]+@[a-z]+\\\\.[a-z]+$\"]";
+    assert_eq!(expected_sql, debug_query(&query).to_string());
+    assert_eq!(Ok(vec!["sean@example.com".to_string()]), query.load(&connection));
+
+    let emails = users
+        .select(name)
+        .filter(name.regex_imatch(email_pattern))
+        .order(id)
+        .load::<String>(&connection);
+    let expected_emails = vec!["sean@example.com".to_string(), "Tess@Example.org".to_string()];
+    assert_eq!(Ok(expected_emails), emails);
+
+    let emails = users
+        .select(name)
+        .filter(name.similar_to("%@(example|localhost)%"))
+        .order(id)
+        .load::<String>(&connection);
+    let expected_emails = vec!["sean@example.com".to_string(), "jim@localhost".to_string()];
+    assert_eq!(Ok(expected_emails), emails);
+}
+
 #[test]
 #[cfg(feature = "postgres")]
 fn filter_by_any() {
@@ -236,6 +274,42 @@ fn filter_by_any() {
     );
 }
 
+#[test]
+#[cfg(feature = "postgres")]
+fn range_overlaps_and_contains() {
+    use std::collections::Bound;
+    use diesel::types::Int4range;
+
+    let connection = connection();
+    let one_to_five = (Bound::Included(1), Bound::Excluded(5));
+    let range = || one_to_five.into_sql::<Int4range>();
+
+    let overlaps = |other| {
+        select(range().overlaps_with(other))
+            .get_result::<bool>(&connection)
+            .unwrap()
+    };
+    assert!(overlaps((Bound::Included(4), Bound::Unbounded)));
+    assert!(!overlaps((Bound::Included(5), Bound::Unbounded)));
+    assert!(overlaps((Bound::Unbounded, Bound::Unbounded)));
+
+    let contains_range = |other| {
+        select(range().contains_range(other))
+            .get_result::<bool>(&connection)
+            .unwrap()
+    };
+    assert!(contains_range((Bound::Included(2), Bound::Included(4))));
+    assert!(!contains_range((Bound::Included(2), Bound::Included(5))));
+
+    let contains_elem = |elem| {
+        select(range().contains_elem(elem))
+            .get_result::<bool>(&connection)
+            .unwrap()
+    };
+    assert!(contains_elem(1));
+    assert!(!contains_elem(5));
+}
+
 #[test]
 fn filter_by_in() {
     use schema::users::dsl::*;
@@ -265,6 +339,209 @@ fn filter_by_in() {
     );
 }
 
+#[test]
+#[cfg(feature = "postgres")]
+fn filter_by_in_chunks() {
+    use schema::users::dsl::*;
+
+    let connection = connection_with_3_users();
+    let sean = User::new(1, "Sean");
+    let jim = User::new(3, "Jim");
+
+    assert_eq!(
+        vec![sean, jim],
+        users
+            .filter(name.eq_any_chunked(vec!["Sean", "Jim", "Bob"], 2))
+            .order(id.asc())
+            .load(&connection)
+            .unwrap()
+    );
+    assert_eq!(
+        Ok(Vec::<User>::new()),
+        users
+            .filter(name.eq_any_chunked(Vec::<String>::new(), 2))
+            .load(&connection)
+    );
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn filter_by_in_chunks_with_more_values_than_bind_parameters_allowed() {
+    use schema::users::dsl::*;
+
+    let connection = connection_with_3_users();
+    let ids = (2..100_002).collect::<Vec<i32>>();
+
+    assert_eq!(
+        Ok(vec!["Tess".to_string(), "Jim".to_string()]),
+        users
+            .select(name)
+            .filter(id.eq_any_chunked(ids, 1_000))
+            .order(id.asc())
+            .load(&connection)
+    );
+}
+
+// This table is never created, so queries which are sent to the database fail
+table! {
+    never_created {
+        id -> Integer,
+        name -> VarChar,
+    }
+}
+
+#[test]
+fn filter_by_composite_key_in_subquery() {
+    use schema::followings::dsl::*;
+
+    let connection = connection();
+    disable_foreign_keys(&connection);
+    let new_followings = vec![
+        Following {
+            user_id: 1,
+            post_id: 1,
+            email_notifications: true,
+        },
+        Following {
+            user_id: 1,
+            post_id: 2,
+            email_notifications: false,
+        },
+        Following {
+            user_id: 2,
+            post_id: 1,
+            email_notifications: false,
+        },
+    ];
+    insert(&new_followings)
+        .into(followings)
+        .execute(&connection)
+        .unwrap();
+    connection
+        .execute("INSERT INTO posts (id, user_id, title) VALUES (1, 1, 'Hi'), (2, 2, 'Hello')")
+        .unwrap();
+
+    let own_posts = posts::table.select((posts::user_id, posts::id));
+    let query = followings
+        .select((user_id, post_id))
+        .filter((user_id, post_id).eq_any(own_posts))
+        .order(user_id);
+    let mut expected_sql = "SELECT `followings`.`user_id`, `followings`.`post_id` \
+                            FROM `followings` \
+                            WHERE (`followings`.`user_id`, `followings`.`post_id`) \
+                            IN (SELECT `posts`.`user_id`, `posts`.`id` FROM `posts`) \
+                            ORDER BY `followings`.`user_id` \
+                            -- binds: []"
+        .to_string();
+    if cfg!(feature = "postgres") {
+        expected_sql = expected_sql.replace('`', "\"");
+    }
+    assert_eq!(expected_sql, debug_query::<TestBackend, _>(&query).to_string());
+    assert_eq!(Ok(vec![(1, 1)]), query.load::<(i32, i32)>(&connection));
+}
+
+#[test]
+fn short_circuit_empty_in_does_not_send_the_query() {
+    use self::never_created::dsl::*;
+
+    let connection = connection();
+    let no_ids = Vec::<i32>::new();
+
+    assert_eq!(
+        Ok(Vec::<String>::new()),
+        never_created
+            .select(name)
+            .filter(id.eq_any(&no_ids))
+            .short_circuit_empty_in()
+            .load(&connection)
+    );
+    assert_eq!(
+        Ok(Vec::<(i32, String)>::new()),
+        never_created
+            .filter(name.eq("Sean"))
+            .filter(id.eq_any(&no_ids))
+            .short_circuit_empty_in()
+            .load(&connection)
+    );
+    assert_eq!(
+        Err(NotFound),
+        never_created
+            .filter(id.eq_any(&no_ids).and(name.eq("Sean")))
+            .limit(1)
+            .short_circuit_empty_in()
+            .get_result::<(i32, String)>(&connection)
+    );
+}
+
+#[test]
+fn short_circuit_empty_in_runs_queries_which_can_match_rows() {
+    use schema::users::dsl::*;
+
+    let connection = connection_with_3_users();
+    let ids = vec![1, 3];
+    let no_ids = Vec::<i32>::new();
+
+    assert_eq!(
+        Ok(vec!["Sean".to_string(), "Jim".to_string()]),
+        users
+            .select(name)
+            .filter(id.eq_any(&ids))
+            .order(id)
+            .short_circuit_empty_in()
+            .load(&connection)
+    );
+    assert_eq!(
+        Ok(vec!["Tess".to_string()]),
+        users
+            .select(name)
+            .filter(id.eq_any(&no_ids).or(name.eq("Tess")))
+            .short_circuit_empty_in()
+            .load(&connection)
+    );
+}
+
+#[test]
+fn filter_by_bit_set_in_integer_column() {
+    use schema::users::dsl::*;
+
+    let connection = connection_with_3_users();
+    let query = users.select(name).filter(id.bitand(2).gt(0)).order(id);
+
+    let mut expected_sql = "SELECT `users`.`name` FROM `users` \
+                            WHERE (`users`.`id` & ?) > ? \
+                            ORDER BY `users`.`id` -- binds: [2, 0]"
+        .to_string();
+    if cfg!(feature = "postgres") {
+        expected_sql = expected_sql
+            .replace('`', "\"")
+            .replace("& ?", "& $1")
+            .replace("> ?", "> $2");
+    }
+    assert_eq!(expected_sql, debug_query::<TestBackend, _>(&query).to_string());
+    assert_eq!(
+        Ok(vec!["Tess".to_string(), "Jim".to_string()]),
+        query.load(&connection)
+    );
+}
+
+#[test]
+fn bitwise_operators_return_the_integer_type() {
+    use schema::users::dsl::*;
+
+    let connection = connection_with_3_users();
+    let data = users
+        .select((
+            id.bitor(4),
+            id.bitxor(3),
+            id.bitshift_left(1),
+            id.bitshift_right(1),
+        ))
+        .order(id)
+        .load::<(i32, i32, i32, i32)>(&connection);
+    let expected = vec![(5, 2, 2, 0), (6, 1, 4, 1), (7, 0, 6, 1)];
+    assert_eq!(Ok(expected), data);
+}
+
 fn connection_with_3_users() -> TestConnection {
     let connection = connection_with_sean_and_tess_in_users_table();
     connection
@@ -46,3 +46,256 @@ fn boxed_queries_have_group_by_method() {
 
     assert_eq!(expected_sql, debug_query(&source).to_string());
 }
+
+#[test]
+fn string_agg_concatenates_values_in_each_group() {
+    use diesel::dsl::string_agg;
+
+    let connection = connection_with_sean_and_tess_in_users_table();
+    let sean = find_user_by_name("Sean", &connection);
+    let tess = find_user_by_name("Tess", &connection);
+    let new_posts = vec![
+        sean.new_post("Hello", None),
+        tess.new_post("Hi", None),
+        tess.new_post("Bye", None),
+    ];
+    insert(&new_posts)
+        .into(posts::table)
+        .execute(&connection)
+        .unwrap();
+
+    let titles = posts::table
+        .group_by(posts::user_id)
+        .select(string_agg(posts::title, "; "))
+        .order(posts::user_id)
+        .load::<Option<String>>(&connection)
+        .unwrap();
+
+    assert_eq!(2, titles.len());
+    assert_eq!(Some("Hello".to_string()), titles[0]);
+    let mut tess_titles = titles[1].as_ref().unwrap().split("; ").collect::<Vec<_>>();
+    tess_titles.sort();
+    assert_eq!(vec!["Bye", "Hi"], tess_titles);
+}
+
+#[test]
+#[cfg(not(feature = "sqlite"))]
+fn string_agg_with_order_by() {
+    use diesel::dsl::string_agg;
+
+    let connection = connection_with_sean_and_tess_in_users_table();
+    let names = users::table
+        .select(string_agg(users::name, ", ").order_by(users::name.desc()))
+        .get_result(&connection);
+
+    assert_eq!(Ok(Some("Tess, Sean".to_string())), names);
+}
+
+table! {
+    team_members {
+        id -> Integer,
+        team -> VarChar,
+        is_admin -> Bool,
+    }
+}
+
+#[test]
+#[cfg(not(feature = "mysql"))] // FIXME: Figure out how to handle tests that modify schema
+fn bool_or_is_true_for_groups_with_any_true_value() {
+    use self::team_members::dsl::*;
+    use diesel::dsl::{bool_and, bool_or};
+
+    let connection = connection();
+    connection
+        .execute(
+            "CREATE TABLE team_members (
+        id INTEGER PRIMARY KEY,
+        team VARCHAR NOT NULL,
+        is_admin BOOLEAN NOT NULL
+    )",
+        )
+        .unwrap();
+    connection
+        .execute(
+            "INSERT INTO team_members (id, team, is_admin) VALUES
+        (1, 'a', TRUE), (2, 'a', FALSE), (3, 'b', FALSE), (4, 'b', FALSE)",
+        )
+        .unwrap();
+
+    let query = team_members
+        .group_by(team)
+        .select(bool_or(is_admin))
+        .order(team);
+    let function = if cfg!(feature = "postgres") {
+        "BOOL_OR"
+    } else {
+        "MAX"
+    };
+    let mut expected_sql = format!(
+        "SELECT {}(`team_members`.`is_admin`) FROM `team_members` \
+         GROUP BY `team_members`.`team` \
+         ORDER BY `team_members`.`team` \
+         -- binds: []",
+        function
+    );
+    if cfg!(feature = "postgres") {
+        expected_sql = expected_sql.replace('`', "\"");
+    }
+
+    assert_eq!(expected_sql, debug_query::<TestBackend, _>(&query).to_string());
+    assert_eq!(Ok(vec![Some(true), Some(false)]), query.load(&connection));
+
+    let all_admins = team_members
+        .group_by(team)
+        .select(bool_and(is_admin))
+        .order(team)
+        .load(&connection);
+    assert_eq!(Ok(vec![Some(false), Some(false)]), all_admins);
+}
+
+#[test]
+fn having_with_an_aliased_aggregate() {
+    use diesel::expression::dsl::count;
+
+    let connection = connection_with_sean_and_tess_in_users_table();
+    let sean = find_user_by_name("Sean", &connection);
+    let tess = find_user_by_name("Tess", &connection);
+    let new_posts = vec![
+        sean.new_post("Hello", None),
+        tess.new_post("Hi", None),
+        tess.new_post("Bye", None),
+    ];
+    insert(&new_posts)
+        .into(posts::table)
+        .execute(&connection)
+        .unwrap();
+
+    let post_count = count(posts::id).aliased("post_count");
+    let source = posts::table
+        .group_by(posts::user_id)
+        .select(post_count)
+        .having(post_count.gt(1));
+
+    assert_eq!(Ok(vec![2]), source.load::<i64>(&connection));
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn having_repeats_aliased_expressions_on_pg() {
+    use diesel::expression::dsl::count;
+
+    let post_count = count(posts::id).aliased("post_count");
+    let source = posts::table
+        .group_by(posts::user_id)
+        .select(post_count)
+        .having(post_count.gt(5));
+    let expected_sql = "SELECT COUNT(\"posts\".\"id\") AS \"post_count\" \
+                        FROM \"posts\" GROUP BY \"posts\".\"user_id\" \
+                        HAVING COUNT(\"posts\".\"id\") > $1 \
+                        -- binds: [5]";
+
+    assert_eq!(expected_sql, debug_query(&source).to_string());
+}
+
+#[test]
+#[cfg(not(feature = "postgres"))]
+fn having_refers_to_aliases_by_name() {
+    use diesel::expression::dsl::count;
+
+    let post_count = count(posts::id).aliased("post_count");
+    let source = posts::table
+        .group_by(posts::user_id)
+        .select(post_count)
+        .having(post_count.gt(5));
+    let expected_sql = "SELECT COUNT(`posts`.`id`) AS `post_count` \
+                        FROM `posts` GROUP BY `posts`.`user_id` \
+                        HAVING `post_count` > ? \
+                        -- binds: [5]";
+
+    assert_eq!(expected_sql, debug_query(&source).to_string());
+}
+
+#[test]
+fn boxed_queries_have_having_method() {
+    use diesel::expression::dsl::count_star;
+
+    let source = users::table
+        .into_boxed::<TestBackend>()
+        .group_by(users::name)
+        .select(users::name)
+        .having(count_star().gt(1));
+    let mut expected_sql = "SELECT `users`.`name` FROM `users` \
+                            GROUP BY `users`.`name` HAVING COUNT(*) > ? \
+                            -- binds: [1]"
+        .to_string();
+    if cfg!(feature = "postgres") {
+        expected_sql = expected_sql.replace('`', "\"").replace('?', "$1");
+    }
+
+    assert_eq!(expected_sql, debug_query(&source).to_string());
+}
+
+#[test]
+fn having_without_group_by_on_a_boxed_query_is_an_error() {
+    use diesel::expression::dsl::count_star;
+    use diesel::result::Error::QueryBuilderError;
+
+    let connection = connection_with_sean_and_tess_in_users_table();
+    let result = users::table
+        .into_boxed()
+        .select(users::name)
+        .having(count_star().gt(1))
+        .load::<String>(&connection);
+
+    match result {
+        Err(QueryBuilderError(_)) => {}
+        other => panic!("expected a query builder error, got {:?}", other),
+    }
+}
+
+#[test]
+fn subqueries_in_having_write_their_aliases_in_full() {
+    use diesel::expression::dsl::{count, exists};
+
+    let post_count = count(posts::id).aliased("post_count");
+    let user_name = users::name.aliased("user_name");
+    let source = posts::table
+        .group_by(posts::user_id)
+        .select(post_count)
+        .having(post_count.gt(1).and(exists(users::table.select(user_name))));
+    let rendered = debug_query::<TestBackend, _>(&source).to_string();
+
+    let expected_subquery = if cfg!(feature = "postgres") {
+        "EXISTS (SELECT \"users\".\"name\" AS \"user_name\" FROM \"users\")"
+    } else {
+        "EXISTS (SELECT `users`.`name` AS `user_name` FROM `users`)"
+    };
+    assert!(
+        rendered.contains(expected_subquery),
+        "{} does not contain {}",
+        rendered,
+        expected_subquery
+    );
+}
+
+#[test]
+fn count_of_left_joined_column_is_zero_for_groups_without_matches() {
+    use diesel::expression::dsl::count;
+
+    let connection = connection_with_sean_and_tess_in_users_table();
+    let sean = find_user_by_name("Sean", &connection);
+    insert(&vec![sean.new_post("Hello", None), sean.new_post("Bye", None)])
+        .into(posts::table)
+        .execute(&connection)
+        .unwrap();
+
+    let post_counts = users::table
+        .left_join(posts::table)
+        .group_by(users::name)
+        .select(count(posts::id))
+        .order(users::name)
+        .load::<i64>(&connection);
+
+    // Sean, then Tess
+    assert_eq!(Ok(vec![2, 0]), post_counts);
+}
@@ -1,5 +1,9 @@
 use super::schema::*;
 use diesel::*;
+use diesel::expression::AsExpression;
+use diesel::expression::bound::Bound;
+use std::error::Error;
+use std::io::Write;
 
 #[test]
 fn insert_records() {
@@ -82,6 +86,73 @@ fn insert_records_with_custom_returning_clause() {
     assert_eq!(expected_users, inserted_users);
 }
 
+#[test]
+#[cfg(not(any(feature = "sqlite", feature = "mysql")))]
+fn returned_rows_can_be_iterated_without_collecting() {
+    use schema::users::dsl::*;
+
+    let connection = connection();
+    let new_users = (0..500)
+        .map(|i| NewUser::new(&format!("User {}", i), None))
+        .collect::<Vec<_>>();
+
+    let mut inserted_ids = insert(&new_users)
+        .into(users)
+        .returning(id)
+        .get_results_iter::<i32>(&connection)
+        .unwrap();
+
+    let first_id = inserted_ids.next().unwrap().unwrap();
+    let remaining_ids = inserted_ids.take(3).collect::<QueryResult<Vec<_>>>();
+    let expected_ids = vec![first_id + 1, first_id + 2, first_id + 3];
+    assert_eq!(Ok(expected_ids), remaining_ids);
+
+    let id_count = insert(&new_users)
+        .into(users)
+        .returning(id)
+        .get_results_iter::<i32>(&connection)
+        .unwrap()
+        .count();
+    assert_eq!(500, id_count);
+}
+
+#[test]
+#[cfg(not(any(feature = "sqlite", feature = "mysql")))]
+fn returned_rows_are_received_one_at_a_time() {
+    use diesel::dsl::sql;
+    use diesel::types::Integer;
+    use schema::users::dsl::*;
+
+    let connection = connection();
+    let new_users = (0..500)
+        .map(|i| NewUser::new(&format!("User {}", i), None))
+        .collect::<Vec<_>>();
+
+    let mut inserted_ids = insert(&new_users)
+        .into(users)
+        .returning(id)
+        .get_results_iter::<i32>(&connection)
+        .unwrap();
+    assert!(inserted_ids.next().unwrap().is_ok());
+    drop(inserted_ids);
+
+    let user_count = users.count().get_result(&connection);
+    assert_eq!(Ok(500), user_count);
+
+    // PostgreSQL computes all rows of a `RETURNING` clause before sending
+    // them, so a `SELECT` is needed to see an error after the first rows
+    let mut selected = users
+        .select(sql::<Integer>(
+            "CASE WHEN name = 'User 499' THEN 1 / (id - id) ELSE 1 END",
+        ))
+        .get_results_iter::<i32>(&connection)
+        .unwrap();
+    assert_eq!(Some(Ok(1)), selected.next());
+    let rest = selected.collect::<Vec<_>>();
+    assert!(rest[..rest.len() - 1].iter().all(|r| *r == Ok(1)));
+    assert!(rest[rest.len() - 1].is_err());
+}
+
 #[test]
 #[cfg(not(feature = "mysql"))] // FIXME: Figure out how to handle tests that modify schema
 fn batch_insert_with_defaults() {
@@ -219,6 +290,244 @@ fn insert_borrowed_content() {
     assert_eq!(expected_users, actual_users);
 }
 
+/// A value which cannot be cloned, to ensure that inserting a struct does not
+/// clone its fields.
+#[derive(Debug)]
+struct MoveOnlyName(String);
+
+impl<DB> types::ToSql<types::Text, DB> for MoveOnlyName
+where
+    DB: backend::Backend + types::HasSqlType<types::Text>,
+    String: types::ToSql<types::Text, DB>,
+{
+    fn to_sql<W: Write>(
+        &self,
+        out: &mut types::ToSqlOutput<W, DB>,
+    ) -> Result<types::IsNull, Box<Error + Send + Sync>> {
+        self.0.to_sql(out)
+    }
+}
+
+impl<'a> AsExpression<types::Text> for &'a MoveOnlyName {
+    type Expression = Bound<types::Text, Self>;
+
+    fn as_expression(self) -> Self::Expression {
+        Bound::new(self)
+    }
+}
+
+#[derive(Insertable)]
+#[table_name = "users"]
+struct UserWithMoveOnlyName {
+    name: MoveOnlyName,
+}
+
+#[test]
+fn insert_binds_fields_by_reference() {
+    use schema::users::table as users;
+    let connection = connection();
+    let new_user = UserWithMoveOnlyName {
+        name: MoveOnlyName("Sean".to_string()),
+    };
+    insert(&new_user).into(users).execute(&connection).unwrap();
+
+    let actual_users = users.load::<User>(&connection).unwrap();
+    let expected_users = vec![User::new(actual_users[0].id, "Sean")];
+
+    assert_eq!(expected_users, actual_users);
+}
+
+#[derive(Debug, PartialEq, Queryable, Insertable)]
+#[table_name = "users"]
+struct UserWithSkippedId {
+    #[diesel(skip_insertion)]
+    id: i32,
+    name: String,
+    hair_color: Option<String>,
+}
+
+#[test]
+fn fields_with_skip_insertion_are_not_inserted() {
+    use schema::users::table as users;
+    let connection = connection();
+    let new_users = vec![
+        UserWithSkippedId {
+            id: 0,
+            name: "Sean".to_string(),
+            hair_color: Some("Black".to_string()),
+        },
+        UserWithSkippedId {
+            id: 0,
+            name: "Tess".to_string(),
+            hair_color: None,
+        },
+    ];
+    insert(&new_users).into(users).execute(&connection).unwrap();
+
+    let actual_users = users.load::<User>(&connection).unwrap();
+    let expected_users = vec![
+        User::with_hair_color(actual_users[0].id, "Sean", "Black"),
+        User::new(actual_users[1].id, "Tess"),
+    ];
+
+    assert_eq!(expected_users, actual_users);
+    assert_ne!(0, actual_users[0].id);
+}
+
+table! {
+    documents {
+        id -> Integer,
+        title -> VarChar,
+        visibility -> SmallInt,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Visibility {
+    Public,
+    Private,
+}
+
+impl From<Visibility> for i16 {
+    fn from(visibility: Visibility) -> Self {
+        match visibility {
+            Visibility::Public => 0,
+            Visibility::Private => 1,
+        }
+    }
+}
+
+impl From<i16> for Visibility {
+    fn from(value: i16) -> Self {
+        match value {
+            0 => Visibility::Public,
+            _ => Visibility::Private,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Queryable, Insertable, AsChangeset)]
+#[table_name = "documents"]
+struct Document {
+    id: i32,
+    title: String,
+    #[diesel(serialize_as = "i16", deserialize_as = "i16")]
+    visibility: Visibility,
+}
+
+#[test]
+#[cfg(not(feature = "mysql"))] // FIXME: Figure out how to handle tests that modify schema
+fn fields_are_converted_with_serialize_as_and_deserialize_as() {
+    use self::documents::dsl::*;
+
+    let connection = connection();
+    connection
+        .execute(
+            "CREATE TABLE documents (
+        id INTEGER PRIMARY KEY,
+        title VARCHAR NOT NULL,
+        visibility SMALLINT NOT NULL
+    )",
+        )
+        .unwrap();
+    let new_documents = vec![
+        Document {
+            id: 1,
+            title: "Public".to_string(),
+            visibility: Visibility::Public,
+        },
+        Document {
+            id: 2,
+            title: "Private".to_string(),
+            visibility: Visibility::Private,
+        },
+    ];
+    insert(&new_documents)
+        .into(documents)
+        .execute(&connection)
+        .unwrap();
+
+    let stored_visibility = documents
+        .select(visibility)
+        .order(id)
+        .load::<i16>(&connection);
+    assert_eq!(Ok(vec![0, 1]), stored_visibility);
+    let loaded_documents = documents.order(id).load::<Document>(&connection);
+    assert_eq!(Ok(new_documents), loaded_documents);
+
+    let changes = Document {
+        id: 1,
+        title: "Now private".to_string(),
+        visibility: Visibility::Private,
+    };
+    update(documents.find(1))
+        .set(&changes)
+        .execute(&connection)
+        .unwrap();
+    assert_eq!(Ok(changes), documents.find(1).first(&connection));
+}
+
+table! {
+    drafts {
+        id -> Integer,
+        visibility -> SmallInt,
+    }
+}
+
+#[derive(Insertable, AsChangeset)]
+#[table_name = "drafts"]
+struct Draft {
+    id: i32,
+    #[diesel(serialize_as = "i16")]
+    visibility: Option<Visibility>,
+}
+
+#[test]
+#[cfg(not(feature = "mysql"))] // FIXME: Figure out how to handle tests that modify schema
+fn option_fields_are_converted_with_serialize_as_unless_they_are_none() {
+    use self::drafts::dsl::*;
+
+    let connection = connection();
+    connection
+        .execute(
+            "CREATE TABLE drafts (
+        id INTEGER PRIMARY KEY,
+        visibility SMALLINT NOT NULL DEFAULT 1
+    )",
+        )
+        .unwrap();
+    let public_draft = Draft {
+        id: 1,
+        visibility: Some(Visibility::Public),
+    };
+    let draft_with_default = Draft {
+        id: 2,
+        visibility: None,
+    };
+    insert(&public_draft)
+        .into(drafts)
+        .execute(&connection)
+        .unwrap();
+    insert(&draft_with_default)
+        .into(drafts)
+        .execute(&connection)
+        .unwrap();
+
+    let stored_visibility = drafts.select(visibility).order(id).load::<i16>(&connection);
+    assert_eq!(Ok(vec![0, 1]), stored_visibility);
+
+    let make_public = Draft {
+        id: 2,
+        visibility: Some(Visibility::Public),
+    };
+    update(drafts.find(2))
+        .set(&make_public)
+        .execute(&connection)
+        .unwrap();
+    let stored_visibility = drafts.select(visibility).order(id).load::<i16>(&connection);
+    assert_eq!(Ok(vec![0, 0]), stored_visibility);
+}
+
 #[test]
 #[cfg(feature = "sqlite")]
 fn insert_on_conflict_replace() {
@@ -334,6 +643,20 @@ fn insert_only_default_values() {
     );
 }
 
+#[test]
+fn insert_only_default_values_sql() {
+    let query = insert(&default_values()).into(users::table);
+    let expected_sql = if cfg!(feature = "postgres") {
+        "INSERT INTO \"users\" DEFAULT VALUES -- binds: []"
+    } else if cfg!(feature = "mysql") {
+        "INSERT INTO `users` () VALUES () -- binds: []"
+    } else {
+        "INSERT INTO `users` DEFAULT VALUES -- binds: []"
+    };
+
+    assert_eq!(expected_sql, debug_query::<TestBackend, _>(&query).to_string());
+}
+
 #[test]
 #[cfg(feature = "postgres")]
 fn insert_only_default_values_with_returning() {
@@ -398,3 +721,28 @@ fn insert_multiple_bare_values() {
     let actual_names = users.select(name).load(&connection);
     assert_eq!(Ok(expected_names), actual_names);
 }
+
+#[test]
+fn insert_and_update_with_explicit_null() {
+    use schema::nullable_table::dsl::*;
+    use diesel::dsl::null;
+    let connection = connection();
+
+    insert(&vec![value.eq(null())])
+        .into(nullable_table)
+        .execute(&connection)
+        .unwrap();
+    let values = nullable_table.select(value).load::<Option<i32>>(&connection);
+    assert_eq!(Ok(vec![None]), values);
+
+    update(nullable_table)
+        .set(value.eq(1))
+        .execute(&connection)
+        .unwrap();
+    update(nullable_table)
+        .set(value.eq(null()))
+        .execute(&connection)
+        .unwrap();
+    let values = nullable_table.select(value).load::<Option<i32>>(&connection);
+    assert_eq!(Ok(vec![None]), values);
+}
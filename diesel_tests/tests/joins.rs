@@ -203,6 +203,59 @@ fn columns_on_right_side_of_left_outer_joins_can_be_used_in_filter() {
     assert_eq!(expected_data, actual_data);
 }
 
+#[test]
+fn columns_on_right_side_of_left_outer_joins_can_be_assumed_not_null() {
+    #[derive(Debug, PartialEq, Queryable)]
+    struct UserWithPostTitle {
+        name: String,
+        title: String,
+    }
+
+    let connection = connection_with_sean_and_tess_in_users_table();
+
+    connection
+        .execute(
+            "INSERT INTO posts (user_id, title) VALUES
+        (1, 'Hello'),
+        (1, 'World')
+    ",
+        )
+        .unwrap();
+
+    let expected_data = vec![
+        UserWithPostTitle {
+            name: "Sean".to_string(),
+            title: "Hello".to_string(),
+        },
+        UserWithPostTitle {
+            name: "Sean".to_string(),
+            title: "World".to_string(),
+        },
+    ];
+    let source = users::table
+        .left_outer_join(posts::table)
+        .filter(posts::id.nullable().is_not_null())
+        .select((users::name, posts::title.nullable().assume_not_null()))
+        .order(posts::title.nullable());
+    let actual_data = source.load(&connection);
+
+    assert_eq!(Ok(expected_data), actual_data);
+}
+
+#[test]
+fn assuming_a_null_column_is_not_null_is_a_deserialization_error() {
+    use diesel::result::Error::DeserializationError;
+
+    let connection = connection_with_sean_and_tess_in_users_table();
+
+    let source = users::table
+        .left_outer_join(posts::table)
+        .select(posts::title.nullable().assume_not_null());
+    let result = source.load::<String>(&connection);
+
+    assert_matches!(result, Err(DeserializationError(_)));
+}
+
 #[test]
 fn select_multiple_from_right_side_returns_optional_tuple_when_nullable_is_called() {
     let connection = connection_with_sean_and_tess_in_users_table();
@@ -480,6 +533,42 @@ fn selecting_parent_child_grandchild() {
     assert_eq!(Ok(expected), data);
 }
 
+#[test]
+fn loading_both_structs_from_an_inner_join() {
+    let (connection, test_data) = connection_with_fixture_data_for_multitable_joins();
+    let TestData {
+        sean,
+        tess,
+        posts,
+        ..
+    } = test_data;
+
+    let data = users::table
+        .inner_join(posts::table)
+        .order(posts::id)
+        .load::<(User, Post)>(&connection);
+    let expected = vec![
+        (sean.clone(), posts[0].clone()),
+        (tess.clone(), posts[1].clone()),
+        (sean.clone(), posts[2].clone()),
+    ];
+    assert_eq!(Ok(expected.clone()), data);
+
+    // With a third table joined, the structs to load are picked by selecting
+    // their `all_columns`
+    let data = users::table
+        .inner_join(posts::table.inner_join(comments::table))
+        .select((users::all_columns, posts::all_columns))
+        .distinct()
+        .order(posts::id)
+        .load::<(User, Post)>(&connection);
+    let expected = vec![
+        (sean.clone(), posts[0].clone()),
+        (sean.clone(), posts[2].clone()),
+    ];
+    assert_eq!(Ok(expected), data);
+}
+
 #[test]
 fn selecting_grandchild_child_parent() {
     let (connection, test_data) = connection_with_fixture_data_for_multitable_joins();
@@ -0,0 +1,87 @@
+use schema::*;
+use diesel::*;
+use diesel::query_source::{lateral, outer};
+
+table! {
+    latest_posts (id) {
+        id -> Integer,
+        title -> VarChar,
+    }
+}
+
+enable_multi_table_joins!(users, latest_posts);
+
+fn connection_with_posts() -> TestConnection {
+    let connection = connection_with_sean_and_tess_in_users_table();
+    let sean = find_user_by_name("Sean", &connection);
+    let new_posts = vec![
+        sean.new_post("Hello", None),
+        sean.new_post("Hello again", None),
+    ];
+    insert(&new_posts)
+        .into(posts::table)
+        .execute(&connection)
+        .unwrap();
+    connection
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn lateral_subqueries_render_binds_in_the_order_of_the_query() {
+    let latest_post = posts::table
+        .select((posts::id, posts::title))
+        .filter(posts::user_id.eq(outer(users::id)))
+        .filter(posts::title.ne("Draft"))
+        .limit(1);
+    let query = users::table
+        .inner_join(lateral(latest_post, latest_posts::table))
+        .select((users::name, latest_posts::title))
+        .filter(users::name.ne("Nobody"));
+
+    assert_eq!(
+        r#"SELECT "users"."name", "latest_posts"."title" FROM ("users" INNER JOIN LATERAL (SELECT "posts"."id", "posts"."title" FROM "posts" WHERE "posts"."user_id" = "users"."id" AND "posts"."title" != $1 LIMIT $2) AS "latest_posts" ON TRUE) WHERE "users"."name" != $3 -- binds: ["Draft", 1, "Nobody"]"#,
+        debug_query::<TestBackend, _>(&query).to_string()
+    );
+}
+
+#[test]
+fn inner_join_lateral_loads_the_latest_post_of_each_user() {
+    let connection = connection_with_posts();
+
+    let latest_post = posts::table
+        .select((posts::id, posts::title))
+        .filter(posts::user_id.eq(outer(users::id)))
+        .order(posts::id.desc())
+        .limit(1);
+    let data = users::table
+        .inner_join(lateral(latest_post, latest_posts::table))
+        .select((users::name, latest_posts::title))
+        .load::<(String, String)>(&connection);
+
+    assert_eq!(
+        Ok(vec![("Sean".to_string(), "Hello again".to_string())]),
+        data
+    );
+}
+
+#[test]
+fn left_join_lateral_keeps_rows_without_a_match() {
+    let connection = connection_with_posts();
+
+    let latest_post = posts::table
+        .select((posts::id, posts::title))
+        .filter(posts::user_id.eq(outer(users::id)))
+        .order(posts::id.desc())
+        .limit(1);
+    let data = users::table
+        .left_join(lateral(latest_post, latest_posts::table))
+        .select((users::name, latest_posts::title.nullable()))
+        .order(users::name)
+        .load::<(String, Option<String>)>(&connection);
+
+    let expected = vec![
+        ("Sean".to_string(), Some("Hello again".to_string())),
+        ("Tess".to_string(), None),
+    ];
+    assert_eq!(Ok(expected), data);
+}
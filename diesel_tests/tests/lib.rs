@@ -13,6 +13,7 @@ extern crate quickcheck;
 mod annotations;
 mod associations;
 mod boxed_queries;
+mod combination;
 mod connection;
 #[cfg(feature = "postgres")]
 mod custom_schemas;
@@ -22,6 +23,8 @@ mod debug;
 mod delete;
 mod deserialization;
 mod errors;
+#[cfg(feature = "postgres")]
+mod explain;
 mod expressions;
 mod filter;
 mod filter_operators;
@@ -30,7 +33,11 @@ mod group_by;
 mod insert;
 mod internal_details;
 mod joins;
+#[cfg(not(feature = "sqlite"))]
+mod lateral;
 mod macros;
+#[cfg(feature = "postgres")]
+mod merge;
 mod order;
 mod perf_details;
 #[cfg(feature = "postgres")]
@@ -41,9 +48,12 @@ mod schema_inference;
 mod select;
 #[cfg(not(feature = "mysql"))] // FIXME: Figure out how to handle tests that modify schema
 mod transactions;
+#[cfg(not(feature = "mysql"))] // TRUNCATE causes an implicit commit on MySQL
+mod truncate;
 mod types;
 mod types_roundtrip;
 mod update;
+mod with_clause;
 
 #[cfg(rustfmt)]
 mod postgres_specific_schema;
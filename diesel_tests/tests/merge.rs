@@ -0,0 +1,88 @@
+use schema::*;
+use diesel::*;
+use diesel::pg::merge::{merge_into, source};
+
+table! {
+    new_users {
+        id -> Integer,
+        name -> VarChar,
+        hair_color -> Nullable<VarChar>,
+    }
+}
+
+enable_multi_table_joins!(users, new_users);
+
+fn connection_with_new_users() -> TestConnection {
+    let connection = connection_with_sean_and_tess_in_users_table();
+    connection
+        .execute(
+            "CREATE TEMPORARY TABLE new_users (
+                id INTEGER PRIMARY KEY,
+                name VARCHAR NOT NULL,
+                hair_color VARCHAR
+            )",
+        )
+        .unwrap();
+    connection
+}
+
+#[test]
+fn merge_renders_binds_in_the_order_of_the_clauses() {
+    let jim = NewUser::new("Jim", Some("brown"));
+    let command = merge_into(users::table)
+        .using(new_users::table)
+        .on(users::id
+            .eq(new_users::id)
+            .and(new_users::name.ne("Nobody")))
+        .when_matched_update(users::hair_color.eq("green"))
+        .when_not_matched_insert(&jim);
+
+    assert_eq!(
+        r#"MERGE INTO "users" USING "new_users" ON "users"."id" = "new_users"."id" AND "new_users"."name" != $1 WHEN MATCHED THEN UPDATE SET "hair_color" = $2 WHEN NOT MATCHED THEN INSERT ("name", "hair_color") VALUES ($3, $4) -- binds: ["Nobody", "green", "Jim", "brown"]"#,
+        debug_query::<TestBackend, _>(&command).to_string()
+    );
+}
+
+#[test]
+fn merge_updates_matched_rows_and_inserts_the_others() {
+    let connection = connection_with_new_users();
+    let sean = find_user_by_name("Sean", &connection);
+    let query = format!(
+        "INSERT INTO new_users (id, name, hair_color) VALUES ({}, 'Sean', 'black'), ({}, 'Jim', 'brown')",
+        sean.id,
+        sean.id + 100
+    );
+    connection.execute(&query).unwrap();
+
+    let merged_rows = merge_into(users::table)
+        .using(new_users::table)
+        .on(users::id.eq(new_users::id))
+        .when_matched_update(users::hair_color.eq(source(new_users::hair_color)))
+        .when_not_matched_insert(&users::name.eq("Someone new"))
+        .execute(&connection);
+    assert_eq!(Ok(2), merged_rows);
+
+    let data = users::table
+        .select((users::name, users::hair_color))
+        .order(users::id)
+        .load(&connection);
+    let expected = vec![
+        ("Sean".to_string(), Some("black".to_string())),
+        ("Tess".to_string(), None),
+        ("Someone new".to_string(), None),
+    ];
+    assert_eq!(Ok(expected), data);
+}
+
+#[test]
+fn merge_without_an_action_is_an_error() {
+    use diesel::result::Error::QueryBuilderError;
+
+    let connection = connection_with_new_users();
+    let result = merge_into(users::table)
+        .using(new_users::table)
+        .on(users::id.eq(new_users::id))
+        .execute(&connection);
+
+    assert_matches!(result, Err(QueryBuilderError(_)));
+}
@@ -78,3 +78,336 @@ fn order_by_descending_column() {
     let data: Vec<_> = users.order(name.desc()).load(&conn).unwrap();
     assert_eq!(expected_data, data);
 }
+
+#[test]
+fn order_by_aggregate_alias() {
+    use schema::posts::dsl::*;
+    use diesel::expression::dsl::count_star;
+
+    let conn = connection_with_sean_and_tess_in_users_table();
+    let sean = find_user_by_name("Sean", &conn);
+    let tess = find_user_by_name("Tess", &conn);
+    let new_posts = vec![
+        sean.new_post("Hi", None),
+        tess.new_post("Hello", None),
+        tess.new_post("Hello again", None),
+    ];
+    insert(&new_posts).into(posts).execute(&conn).unwrap();
+
+    let post_count = count_star().aliased("post_count");
+    let source = posts
+        .group_by(user_id)
+        .select(post_count)
+        .order(post_count.alias().desc());
+    let mut expected_sql = "SELECT COUNT(*) AS `post_count` \
+                            FROM `posts` GROUP BY `posts`.`user_id` \
+                            ORDER BY `post_count` DESC \
+                            -- binds: []"
+        .to_string();
+    if cfg!(feature = "postgres") {
+        expected_sql = expected_sql.replace('`', "\"");
+    }
+
+    assert_eq!(
+        expected_sql,
+        debug_query::<TestBackend, _>(&source).to_string()
+    );
+    assert_eq!(
+        Ok(vec![2, 1]),
+        source.load::<i64>(&conn)
+    );
+}
+
+#[test]
+fn desc_on_a_tuple_applies_to_every_column() {
+    use schema::users::dsl::*;
+
+    let conn = connection();
+    let data = vec![
+        NewUser::new("Sean", Some("black")),
+        NewUser::new("Tess", Some("brown")),
+        NewUser::new("Jim", Some("black")),
+    ];
+    insert(&data).into(users).execute(&conn).unwrap();
+
+    let source = users.select(name).order((hair_color, name).desc());
+    let mut expected_sql = "SELECT `users`.`name` FROM `users` \
+                            ORDER BY `users`.`hair_color` DESC, `users`.`name` DESC \
+                            -- binds: []"
+        .to_string();
+    if cfg!(feature = "postgres") {
+        expected_sql = expected_sql.replace('`', "\"");
+    }
+
+    assert_eq!(
+        expected_sql,
+        debug_query::<TestBackend, _>(&source).to_string()
+    );
+    let expected_data = vec!["Tess", "Sean", "Jim"];
+    assert_eq!(
+        Ok(expected_data.into_iter().map(String::from).collect()),
+        source.load::<String>(&conn)
+    );
+}
+
+#[test]
+fn order_stable_by_appends_the_primary_key() {
+    use schema::users::dsl::*;
+
+    let conn = connection();
+    let data = vec![
+        NewUser::new("Sean", Some("black")),
+        NewUser::new("Tess", Some("brown")),
+        NewUser::new("Jim", Some("black")),
+    ];
+    insert(&data).into(users).execute(&conn).unwrap();
+
+    let source = users.select(name).order_stable_by(hair_color.desc());
+    let mut expected_sql = "SELECT `users`.`name` FROM `users` \
+                            ORDER BY `users`.`hair_color` DESC, `users`.`id` \
+                            -- binds: []"
+        .to_string();
+    if cfg!(feature = "postgres") {
+        expected_sql = expected_sql.replace('`', "\"");
+    }
+
+    assert_eq!(
+        expected_sql,
+        debug_query::<TestBackend, _>(&source).to_string()
+    );
+    let expected_data = vec!["Tess", "Sean", "Jim"];
+    assert_eq!(
+        Ok(expected_data.into_iter().map(String::from).collect()),
+        source.load::<String>(&conn)
+    );
+
+    let boxed_source = users
+        .select(name)
+        .into_boxed::<TestBackend>()
+        .order_stable_by(hair_color.desc());
+    assert_eq!(
+        expected_sql,
+        debug_query::<TestBackend, _>(&boxed_source).to_string()
+    );
+}
+
+#[test]
+fn order_stable_by_appends_every_column_of_a_composite_primary_key() {
+    use schema::followings::dsl::*;
+
+    let source = followings.select(user_id).order_stable_by(email_notifications);
+    let mut expected_sql = "SELECT `followings`.`user_id` FROM `followings` \
+                            ORDER BY `followings`.`email_notifications`, \
+                            `followings`.`user_id`, `followings`.`post_id` \
+                            -- binds: []"
+        .to_string();
+    if cfg!(feature = "postgres") {
+        expected_sql = expected_sql.replace('`', "\"");
+    }
+
+    assert_eq!(
+        expected_sql,
+        debug_query::<TestBackend, _>(&source).to_string()
+    );
+}
+
+#[test]
+fn order_by_values_returns_rows_in_the_order_of_the_values() {
+    use schema::users::dsl::*;
+
+    let conn = connection();
+    let data = vec![
+        NewUser::new("Sean", None),
+        NewUser::new("Tess", None),
+        NewUser::new("Jim", None),
+    ];
+    insert(&data).into(users).execute(&conn).unwrap();
+    let ids = users.select(id).order(id).load::<i32>(&conn).unwrap();
+    let ordered_ids = vec![ids[2], ids[0], ids[1]];
+
+    let names = users
+        .select(name)
+        .filter(id.eq_any(&ordered_ids))
+        .order_by_values(id, &ordered_ids)
+        .load::<String>(&conn);
+    let expected_names = vec!["Jim", "Sean", "Tess"];
+    assert_eq!(
+        Ok(expected_names.into_iter().map(String::from).collect()),
+        names
+    );
+
+    let boxed_names = users
+        .select(name)
+        .into_boxed::<TestBackend>()
+        .order_by_values(id, vec![ids[1], ids[2], ids[0]])
+        .load::<String>(&conn);
+    let expected_names = vec!["Tess", "Jim", "Sean"];
+    assert_eq!(
+        Ok(expected_names.into_iter().map(String::from).collect()),
+        boxed_names
+    );
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn order_by_values_binds_the_values_as_an_array_on_pg() {
+    use schema::users::dsl::*;
+
+    let source = users.select(name).order_by_values(id, vec![3, 1, 2]);
+    let expected_sql = "SELECT \"users\".\"name\" FROM \"users\" \
+                        ORDER BY array_position($1, \"users\".\"id\") \
+                        -- binds: [[3, 1, 2]]";
+
+    assert_eq!(
+        expected_sql,
+        debug_query::<TestBackend, _>(&source).to_string()
+    );
+}
+
+#[test]
+#[cfg(feature = "mysql")]
+fn order_by_values_uses_field_on_mysql() {
+    use schema::users::dsl::*;
+
+    let source = users.select(name).order_by_values(id, vec![3, 1, 2]);
+    let expected_sql = "SELECT `users`.`name` FROM `users` \
+                        ORDER BY FIELD(`users`.`id`, ?, ?, ?) \
+                        -- binds: [3, 1, 2]";
+
+    assert_eq!(
+        expected_sql,
+        debug_query::<TestBackend, _>(&source).to_string()
+    );
+}
+
+#[test]
+#[cfg(feature = "sqlite")]
+fn order_by_values_uses_case_on_sqlite() {
+    use schema::users::dsl::*;
+
+    let source = users.select(name).order_by_values(id, vec![3, 1, 2]);
+    let expected_sql = "SELECT `users`.`name` FROM `users` \
+                        ORDER BY CASE `users`.`id` WHEN ? THEN 1 WHEN ? THEN 2 \
+                        WHEN ? THEN 3 ELSE 4 END \
+                        -- binds: [3, 1, 2]";
+
+    assert_eq!(
+        expected_sql,
+        debug_query::<TestBackend, _>(&source).to_string()
+    );
+}
+
+#[test]
+#[cfg(not(feature = "mysql"))]
+fn order_by_values_sorts_rows_with_values_not_in_the_list_last() {
+    use schema::users::dsl::*;
+
+    let conn = connection_with_sean_and_tess_in_users_table();
+    let tess_id = users
+        .select(id)
+        .filter(name.eq("Tess"))
+        .first::<i32>(&conn)
+        .unwrap();
+
+    let names = users
+        .select(name)
+        .order_by_values(id, vec![tess_id])
+        .load::<String>(&conn);
+    assert_eq!(Ok(vec!["Tess".to_string(), "Sean".to_string()]), names);
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn nulls_last_everywhere_adds_nulls_last_to_every_term() {
+    use schema::users::dsl::*;
+
+    let source = users
+        .select(name)
+        .order((hair_color.desc(), name.asc(), id))
+        .nulls_last_everywhere();
+    let expected_sql = "SELECT \"users\".\"name\" FROM \"users\" \
+                        ORDER BY \"users\".\"hair_color\" DESC NULLS LAST, \
+                        \"users\".\"name\" ASC NULLS LAST, \
+                        \"users\".\"id\" NULLS LAST \
+                        -- binds: []";
+
+    assert_eq!(
+        expected_sql,
+        debug_query::<TestBackend, _>(&source).to_string()
+    );
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn nulls_last_everywhere_keeps_explicit_nulls_first() {
+    use schema::users::dsl::*;
+
+    let source = users
+        .select(name)
+        .order((hair_color.desc().nulls_first(), name))
+        .nulls_last_everywhere();
+    let expected_sql = "SELECT \"users\".\"name\" FROM \"users\" \
+                        ORDER BY \"users\".\"hair_color\" DESC NULLS FIRST, \
+                        \"users\".\"name\" NULLS LAST \
+                        -- binds: []";
+
+    assert_eq!(
+        expected_sql,
+        debug_query::<TestBackend, _>(&source).to_string()
+    );
+}
+
+#[test]
+#[cfg(not(feature = "postgres"))]
+fn nulls_last_everywhere_orders_by_is_null_first() {
+    use schema::users::dsl::*;
+
+    let source = users
+        .select(name)
+        .order((hair_color.asc(), name))
+        .nulls_last_everywhere();
+    let expected_sql = "SELECT `users`.`name` FROM `users` \
+                        ORDER BY `users`.`hair_color` IS NULL, `users`.`hair_color` ASC, \
+                        `users`.`name` IS NULL, `users`.`name` \
+                        -- binds: []";
+
+    assert_eq!(
+        expected_sql,
+        debug_query::<TestBackend, _>(&source).to_string()
+    );
+}
+
+#[test]
+fn nulls_last_everywhere_sorts_nulls_last_in_both_directions() {
+    use schema::users::dsl::*;
+
+    let conn = connection();
+    let data = vec![
+        NewUser::new("Sean", None),
+        NewUser::new("Tess", Some("brown")),
+        NewUser::new("Jim", Some("black")),
+    ];
+    insert(&data).into(users).execute(&conn).unwrap();
+
+    let ascending = users
+        .select(name)
+        .order(hair_color.asc())
+        .nulls_last_everywhere()
+        .load::<String>(&conn);
+    let expected_data = vec!["Jim", "Tess", "Sean"];
+    assert_eq!(
+        Ok(expected_data.into_iter().map(String::from).collect()),
+        ascending
+    );
+
+    let descending = users
+        .select(name)
+        .order(hair_color.desc())
+        .nulls_last_everywhere()
+        .load::<String>(&conn);
+    let expected_data = vec!["Tess", "Jim", "Sean"];
+    assert_eq!(
+        Ok(expected_data.into_iter().map(String::from).collect()),
+        descending
+    );
+}
@@ -17,3 +17,87 @@ fn ci_text_exists_and_coerces_from_text() {
 
     assert_eq!(Ok(expected), data);
 }
+
+table! {
+    json_documents {
+        id -> Integer,
+        data -> Jsonb,
+    }
+}
+
+#[test]
+fn filter_by_text_value_of_json_key() {
+    use self::json_documents::dsl::*;
+
+    let conn = connection();
+    conn.execute("CREATE TABLE json_documents (id SERIAL PRIMARY KEY, data JSONB NOT NULL)")
+        .unwrap();
+    conn.execute(
+        r#"INSERT INTO json_documents (data) VALUES
+            ('{"status": "active", "owner": {"name": "Sean"}}'),
+            ('{"status": "archived", "owner": {"name": "Tess"}}'),
+            ('{"owner": {"name": "Jim"}}'),
+            ('{"status": "active", "owner": {}}')"#,
+    ).unwrap();
+
+    let active_owners = json_documents
+        .filter(data.retrieve_as_text("status").eq("active"))
+        .select(data.retrieve_as_object("owner").retrieve_as_text("name"))
+        .order(id)
+        .load::<Option<String>>(&conn);
+    let expected = vec![Some("Sean".to_string()), None];
+    assert_eq!(Ok(expected), active_owners);
+
+    let owner_names = json_documents
+        .select(data.retrieve_by_path_as_text(vec!["owner", "name"]))
+        .filter(data.retrieve_by_path_as_object(vec!["owner", "name"]).is_not_null())
+        .order(id)
+        .load::<Option<String>>(&conn);
+    let expected = vec![
+        Some("Sean".to_string()),
+        Some("Tess".to_string()),
+        Some("Jim".to_string()),
+    ];
+    assert_eq!(Ok(expected), owner_names);
+}
+
+table! {
+    hstore_documents {
+        id -> Integer,
+        attributes -> Hstore,
+    }
+}
+
+#[test]
+fn hstore_round_trips_null_and_non_null_values() {
+    use self::hstore_documents::dsl::*;
+    use std::collections::HashMap;
+
+    let conn = connection();
+    conn.execute("CREATE EXTENSION IF NOT EXISTS hstore").unwrap();
+    conn.execute(
+        "CREATE TABLE hstore_documents (id SERIAL PRIMARY KEY, attributes HSTORE NOT NULL)",
+    ).unwrap();
+
+    let mut shirt = HashMap::new();
+    shirt.insert("color".to_string(), Some("red".to_string()));
+    shirt.insert("size".to_string(), None);
+    let mut mug = HashMap::new();
+    mug.insert("color".to_string(), Some("blue".to_string()));
+    insert(&vec![attributes.eq(&shirt), attributes.eq(&mug)])
+        .into(hstore_documents)
+        .execute(&conn)
+        .unwrap();
+
+    let data = hstore_documents
+        .select(attributes)
+        .order(id)
+        .load::<HashMap<String, Option<String>>>(&conn);
+    assert_eq!(Ok(vec![shirt, mug]), data);
+
+    let colors_of_sized = hstore_documents
+        .filter(attributes.has_key("size"))
+        .select(attributes.get_value("color"))
+        .load::<Option<String>>(&conn);
+    assert_eq!(Ok(vec![Some("red".to_string())]), colors_of_sized);
+}
@@ -33,6 +33,24 @@ fn selecting_a_struct() {
     assert_eq!(expected_users, actual_users);
 }
 
+#[test]
+fn selecting_a_hash_map_keyed_by_a_column() {
+    use std::collections::HashMap;
+
+    let connection = connection_with_sean_and_tess_in_users_table();
+    let sean = find_user_by_name("Sean", &connection);
+    let tess = find_user_by_name("Tess", &connection);
+
+    let mut expected_users = HashMap::new();
+    expected_users.insert(sean.id, sean);
+    expected_users.insert(tess.id, tess);
+    let actual_users = users::table
+        .select((users::id, users::all_columns))
+        .load_hash_map::<i32, User>(&connection)
+        .unwrap();
+    assert_eq!(expected_users, actual_users);
+}
+
 #[test]
 fn with_safe_select() {
     use schema::users::dsl::*;
@@ -69,6 +87,33 @@ fn with_select_sql() {
     assert_eq!(Ok(3), get_count());
 }
 
+#[test]
+fn sql_literal_with_a_named_parameter_used_twice() {
+    use diesel::dsl::sql;
+    use diesel::result::Error::QueryBuilderError;
+
+    let connection = connection_with_sean_and_tess_in_users_table();
+    let query = sql::<types::Text>(
+        "SELECT name FROM users WHERE name = :name OR (id > :id AND name <> :name) ORDER BY id",
+    ).bind_named::<types::Text, _>("name", "Tess")
+        .bind_named::<types::Integer, _>("id", 0);
+    let mut expected_sql = "SELECT name FROM users WHERE name = ? OR (id > ? AND name <> ?) \
+                            ORDER BY id -- binds: [\"Tess\", 0, \"Tess\"]"
+        .to_string();
+    if cfg!(feature = "postgres") {
+        expected_sql = "SELECT name FROM users WHERE name = $1 OR (id > $2 AND name <> $1) \
+                        ORDER BY id -- binds: [\"Tess\", 0]"
+            .to_string();
+    }
+    assert_eq!(expected_sql, debug_query::<TestBackend, _>(&query).to_string());
+    assert_eq!(Ok(vec!["Sean".to_string(), "Tess".to_string()]), query.load(&connection));
+
+    let missing_value = sql::<types::Text>("SELECT name FROM users WHERE id = :id OR name = :name")
+        .bind_named::<types::Integer, _>("id", 1)
+        .load::<String>(&connection);
+    assert_matches!(missing_value, Err(QueryBuilderError(_)));
+}
+
 #[test]
 fn selecting_nullable_followed_by_non_null() {
     use schema::users::dsl::*;
@@ -159,6 +204,90 @@ fn selecting_columns_with_different_definition_order() {
     assert_eq!(Ok(&expected_user), user_from_select.as_ref());
 }
 
+#[test]
+#[cfg(not(feature = "mysql"))] // FIXME: Figure out how to handle tests that modify schema
+fn selecting_a_tuple_in_a_different_order_than_the_definition_order() {
+    let connection = connection();
+    drop_table_cascade(&connection, "users");
+    create_table(
+        "users",
+        (
+            string("hair_color"),
+            string("name").not_null(),
+            integer("id").primary_key().auto_increment(),
+        ),
+    ).execute(&connection)
+        .unwrap();
+    insert(&NewUser::new("Sean", Some("black")))
+        .into(users::table)
+        .execute(&connection)
+        .unwrap();
+
+    let data = users::table
+        .select((users::id, users::name, users::hair_color))
+        .load::<(i32, String, Option<String>)>(&connection);
+    assert_eq!(Ok(vec![(1, "Sean".to_string(), Some("black".to_string()))]), data);
+
+    let data = users::table
+        .select((users::hair_color, users::id))
+        .load::<(Option<String>, i32)>(&connection);
+    assert_eq!(Ok(vec![(Some("black".to_string()), 1)]), data);
+}
+
+table! {
+    events {
+        id -> Integer,
+        name -> Text,
+    }
+}
+
+#[test]
+#[cfg(not(feature = "mysql"))] // FIXME: Figure out how to handle tests that modify schema
+fn selecting_from_tables_named_at_runtime() {
+    let connection = connection();
+    for &(month, event) in &[(1, "launch"), (2, "landing")] {
+        let table_name = format!("events_2017_{:02}", month);
+        create_table(
+            &table_name,
+            (
+                integer("id").primary_key().auto_increment(),
+                string("name").not_null(),
+            ),
+        ).execute(&connection)
+            .unwrap();
+        connection
+            .execute(&format!(
+                "INSERT INTO events_2017_{:02} (name) VALUES ('{}')",
+                month,
+                event
+            ))
+            .unwrap();
+    }
+
+    let load_names = |table_name: &str| {
+        dynamic_table(events::table, table_name)
+            .select(events::name)
+            .filter(events::id.eq(1))
+            .load::<String>(&connection)
+    };
+    assert_eq!(Ok(vec!["launch".to_string()]), load_names("events_2017_01"));
+    assert_eq!(Ok(vec!["landing".to_string()]), load_names("events_2017_02"));
+
+    let query = dynamic_table(events::table, "events_2017_01").select(events::id);
+    let sql = debug_query::<TestBackend, _>(&query).to_string();
+    if cfg!(feature = "postgres") {
+        assert_eq!(
+            r#"SELECT "events"."id" FROM "events_2017_01" AS "events" -- binds: []"#,
+            sql
+        );
+    } else {
+        assert_eq!(
+            "SELECT `events`.`id` FROM `events_2017_01` AS `events` -- binds: []",
+            sql
+        );
+    }
+}
+
 #[test]
 fn selection_using_subselect() {
     use schema::posts::dsl::*;
@@ -264,3 +393,287 @@ fn select_for_update_locks_selected_rows() {
     // Dropping conn_1 unblocks conn_2
     assert_eq!("Sean", next_selected_name);
 }
+
+#[test]
+#[cfg(feature = "postgres")]
+fn select_for_update_of_one_table_of_a_join_with_skip_locked() {
+    let query = users::table
+        .inner_join(posts::table)
+        .select((users::name, posts::title))
+        .for_update_of(users::table)
+        .skip_locked();
+
+    let expected_sql = "SELECT \"users\".\"name\", \"posts\".\"title\" \
+                        FROM (\"users\" INNER JOIN \"posts\" ON \"posts\".\"user_id\" = \"users\".\"id\") \
+                        FOR UPDATE OF \"users\" SKIP LOCKED -- binds: []";
+    assert_eq!(expected_sql, debug_query::<TestBackend, _>(&query).to_string());
+
+    let query = users::table
+        .inner_join(posts::table)
+        .select(posts::title)
+        .for_update_of((users::table, posts::table));
+    let expected_sql = "SELECT \"posts\".\"title\" \
+                        FROM (\"users\" INNER JOIN \"posts\" ON \"posts\".\"user_id\" = \"users\".\"id\") \
+                        FOR UPDATE OF \"users\", \"posts\" -- binds: []";
+    assert_eq!(expected_sql, debug_query::<TestBackend, _>(&query).to_string());
+}
+
+#[cfg(feature = "postgres")]
+table! {
+    users_skip_locked {
+        id -> Integer,
+        name -> Text,
+    }
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn select_for_update_skip_locked_skips_rows_locked_by_other_transactions() {
+    use self::users_skip_locked::dsl::*;
+
+    let conn_1 = connection_without_transaction();
+    conn_1.execute("DROP TABLE IF EXISTS users_skip_locked").unwrap();
+    conn_1
+        .batch_execute(
+            "CREATE TABLE users_skip_locked (id SERIAL PRIMARY KEY, name TEXT NOT NULL);
+             INSERT INTO users_skip_locked (name) VALUES ('Sean'), ('Tess');",
+        )
+        .unwrap();
+    conn_1.begin_test_transaction().unwrap();
+    let conn_2 = connection_without_transaction();
+    conn_2.begin_test_transaction().unwrap();
+
+    let first_name = users_skip_locked
+        .select(name)
+        .order(id)
+        .for_update()
+        .skip_locked()
+        .first::<String>(&conn_1);
+    let next_name = users_skip_locked
+        .select(name)
+        .order(id)
+        .for_update()
+        .skip_locked()
+        .first::<String>(&conn_2);
+
+    assert_eq!(Ok("Sean".to_string()), first_name);
+    assert_eq!(Ok("Tess".to_string()), next_name);
+    drop(conn_1);
+    drop(conn_2);
+    connection_without_transaction()
+        .execute("DROP TABLE users_skip_locked")
+        .unwrap();
+}
+
+#[test]
+fn selecting_explicitly_typed_bind_param_without_from_clause() {
+    let connection = connection();
+    let query = select(1.into_sql::<types::Integer>());
+
+    assert_eq!(Ok(1), query.get_result::<i32>(&connection));
+}
+
+#[test]
+fn selecting_typed_null_without_from_clause() {
+    use diesel::dsl::null;
+
+    let connection = connection();
+    let query = select((null::<types::Integer>(), 1.into_sql::<types::Integer>()));
+
+    assert_eq!(Ok((None, 1)), query.get_result::<(Option<i32>, i32)>(&connection));
+}
+
+#[test]
+fn selecting_an_aliased_constant_alongside_columns() {
+    use schema::users::dsl::*;
+
+    let connection = connection_with_sean_and_tess_in_users_table();
+    let status = "pending".into_sql::<types::Text>().aliased("status");
+    let query = users.select((name, status)).order(id);
+
+    let expected_data = vec![
+        ("Sean".to_string(), "pending".to_string()),
+        ("Tess".to_string(), "pending".to_string()),
+    ];
+    assert_eq!(Ok(expected_data), query.load::<(String, String)>(&connection));
+
+    let mut expected_sql = "SELECT `users`.`name`, ? AS `status` FROM `users` \
+                            ORDER BY `users`.`id` -- binds: [\"pending\"]"
+        .to_string();
+    if cfg!(feature = "postgres") {
+        expected_sql = expected_sql.replace('`', "\"").replace("?", "$1::text");
+    }
+    assert_eq!(expected_sql, debug_query::<TestBackend, _>(&query).to_string());
+}
+
+#[test]
+fn distinct_queries_can_be_ordered_by_selected_columns() {
+    use schema::users::dsl::*;
+
+    let connection = connection();
+    connection
+        .execute("INSERT INTO users (name) VALUES ('Tess'), ('Sean'), ('Tess')")
+        .unwrap();
+
+    let names = users
+        .select(name)
+        .distinct()
+        .order(name.desc())
+        .load::<String>(&connection);
+    assert_eq!(Ok(vec!["Tess".to_string(), "Sean".to_string()]), names);
+}
+
+#[test]
+#[cfg(feature = "sqlite")]
+fn distinct_queries_can_be_ordered_by_any_column_on_sqlite() {
+    use schema::users::dsl::*;
+
+    let connection = connection();
+    connection
+        .execute("INSERT INTO users (name) VALUES ('Tess'), ('Sean')")
+        .unwrap();
+
+    let names = users
+        .select(name)
+        .distinct()
+        .order(id)
+        .load::<String>(&connection);
+    assert_eq!(Ok(vec!["Tess".to_string(), "Sean".to_string()]), names);
+}
+
+#[test]
+fn sql_of_a_query_with_many_clauses_has_no_double_spaces() {
+    use diesel::dsl::sql;
+    use schema::users::dsl::*;
+
+    let connection = connection_with_sean_and_tess_in_users_table();
+    let query = users
+        .select(name)
+        .distinct()
+        .filter(sql("name IS NOT NULL"))
+        .filter(id.gt(0))
+        .order(name.desc())
+        .limit(1);
+
+    let sql = debug_query::<TestBackend, _>(&query).to_string();
+    assert!(!sql.contains("  "), "Query contains two spaces: {}", sql);
+    let mut expected_sql = "SELECT DISTINCT `users`.`name` FROM `users` \
+                            WHERE name IS NOT NULL AND `users`.`id` > ? \
+                            ORDER BY `users`.`name` DESC LIMIT ? -- binds: [0, 1]"
+        .to_string();
+    if cfg!(feature = "postgres") {
+        expected_sql = expected_sql
+            .replace('`', "\"")
+            .replace("> ?", "> $1")
+            .replace("LIMIT ?", "LIMIT $2");
+    }
+    assert_eq!(expected_sql, sql);
+    assert_eq!(Ok(vec!["Tess".to_string()]), query.load(&connection));
+}
+
+#[test]
+fn whitespace_of_sql_literals_is_not_changed() {
+    use diesel::dsl::sql;
+    use schema::users::dsl::*;
+
+    let query = users.select(id).filter(sql(" name  =  'Sean' "));
+    let sql = debug_query::<TestBackend, _>(&query).to_string();
+    assert!(
+        sql.ends_with(" WHERE  name  =  'Sean'  -- binds: []"),
+        "Whitespace of the literal was changed: {}",
+        sql
+    );
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn selecting_values_and_positions_from_unnest_with_ordinality() {
+    use diesel::pg::unnest::unnest;
+
+    let connection = connection();
+    let numbers = unnest::<types::Integer, _>(vec![30, 10, 20]);
+
+    assert_eq!(
+        Ok(vec![30, 10, 20]),
+        numbers.clone().load::<i32>(&connection)
+    );
+
+    let numbers = numbers.with_ordinality();
+    let value = numbers.value();
+    let position = numbers.ordinality();
+    let query = numbers
+        .select((value, position))
+        .filter(value.gt(10))
+        .order(value.desc());
+
+    assert_eq!(
+        "SELECT unnest.value, unnest.ordinality \
+         FROM unnest($1) WITH ORDINALITY AS unnest(value, ordinality) \
+         WHERE unnest.value > $2 \
+         ORDER BY unnest.value DESC \
+         -- binds: [[30, 10, 20], 10]",
+        debug_query(&query).to_string()
+    );
+    assert_eq!(
+        Ok(vec![(30, 1), (20, 3)]),
+        query.load::<(i32, i64)>(&connection)
+    );
+}
+
+#[test]
+fn loading_a_page_with_the_total_number_of_rows_in_one_query() {
+    use schema::users::dsl::*;
+
+    let connection = connection();
+    connection
+        .execute("INSERT INTO users (name) VALUES ('Sean'), ('Tess'), ('Jim')")
+        .unwrap();
+    let source = users.select(name).filter(name.ne("Jim")).order(name);
+
+    let query = source.with_total().limit(1).offset(1);
+    let mut expected_sql = "SELECT `users`.`name`, \
+                            (SELECT COUNT(*) FROM `users` WHERE `users`.`name` != ?) \
+                            FROM `users` WHERE `users`.`name` != ? \
+                            ORDER BY `users`.`name` LIMIT ? OFFSET ? \
+                            -- binds: [\"Jim\", \"Jim\", 1, 1]"
+        .to_string();
+    if cfg!(feature = "postgres") {
+        expected_sql = expected_sql
+            .replace('`', "\"")
+            .replacen("!= ?", "!= $1", 1)
+            .replacen("!= ?", "!= $2", 1)
+            .replace("LIMIT ? OFFSET ?", "LIMIT $3 OFFSET $4");
+    }
+    assert_eq!(expected_sql, debug_query::<TestBackend, _>(&query).to_string());
+
+    assert_eq!(
+        Ok((vec!["Tess".to_string()], 2)),
+        source.load_page_with_total::<String>(&connection, 2, 1)
+    );
+    assert_eq!(
+        Ok((vec!["Sean".to_string(), "Tess".to_string()], 2)),
+        source.load_page_with_total::<String>(&connection, 1, 10)
+    );
+    assert_eq!(
+        Ok((Vec::new(), 2)),
+        source.load_page_with_total::<String>(&connection, 2, 10)
+    );
+}
+
+#[test]
+fn loading_a_page_before_the_first_or_of_no_rows_is_an_error() {
+    use diesel::result::Error::QueryBuilderError;
+    use schema::users::dsl::*;
+
+    let connection = connection_with_sean_and_tess_in_users_table();
+    let source = users.select(name).order(name);
+
+    assert_matches!(
+        source.load_page_with_total::<String>(&connection, 0, 10),
+        Err(QueryBuilderError(_))
+    );
+    assert_matches!(
+        source.load_page_with_total::<String>(&connection, 1, 0),
+        Err(QueryBuilderError(_))
+    );
+}
@@ -112,6 +112,90 @@ fn test_transaction_panics_on_error() {
     connection.test_transaction::<(), _, _>(|| Err(()));
 }
 
+#[test]
+#[cfg(not(feature = "sqlite"))] // FIXME: This test is only valid when operating on a file and not :memory:
+fn test_transaction_is_rolled_back_when_the_connection_is_dropped() {
+    let conn1 = connection_without_transaction();
+    let test_name = "test_transaction_is_rolled_back_when_the_connection_is_dropped";
+    setup_test_table(&conn1, test_name);
+
+    let conn2 = connection_without_transaction();
+    conn2.begin_test_transaction().unwrap();
+    conn2
+        .execute(&format!("INSERT INTO {} DEFAULT VALUES", test_name))
+        .unwrap();
+    conn2
+        .transaction::<_, Error, _>(|| {
+            conn2.execute(&format!("INSERT INTO {} DEFAULT VALUES", test_name))
+        })
+        .unwrap();
+    assert_eq!(2, count_test_table(&conn2, test_name));
+    assert_eq!(0, count_test_table(&conn1, test_name));
+
+    drop(conn2);
+    assert_eq!(0, count_test_table(&conn1, test_name));
+
+    drop_test_table(&conn1, test_name);
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn deferred_constraints_are_checked_when_the_transaction_commits() {
+    use diesel::connection::SimpleConnection;
+    use diesel::result::DatabaseErrorKind;
+
+    let connection = connection_without_transaction();
+    connection
+        .batch_execute(
+            "CREATE TABLE deferred_parents (id INTEGER PRIMARY KEY, child_id INTEGER NOT NULL);
+             CREATE TABLE deferred_children (id INTEGER PRIMARY KEY,
+                parent_id INTEGER NOT NULL REFERENCES deferred_parents DEFERRABLE);
+             ALTER TABLE deferred_parents ADD FOREIGN KEY (child_id)
+                REFERENCES deferred_children DEFERRABLE;",
+        )
+        .unwrap();
+
+    let result = connection.transaction::<_, Error, _>(|| {
+        try!(connection.defer_constraints());
+        try!(connection.execute("INSERT INTO deferred_parents VALUES (1, 1)"));
+        try!(connection.execute("INSERT INTO deferred_children VALUES (1, 1)"));
+        Ok(())
+    });
+    assert_eq!(Ok(()), result);
+
+    let result = connection.transaction::<_, Error, _>(|| {
+        try!(connection.defer_constraints());
+        try!(connection.execute("INSERT INTO deferred_parents VALUES (2, 2)"));
+        Ok(())
+    });
+    match result {
+        Err(Error::DatabaseError(DatabaseErrorKind::ForeignKeyViolation, _)) => {}
+        other => panic!("expected a foreign key violation, got {:?}", other),
+    }
+
+    let result = connection.transaction::<_, Error, _>(|| {
+        try!(connection.execute("INSERT INTO deferred_parents VALUES (3, 3)"));
+        Ok(())
+    });
+    assert!(result.is_err());
+
+    // The failed commit must not leave the connection inside of a transaction
+    let result = connection.transaction::<_, Error, _>(|| {
+        try!(connection.defer_constraints());
+        try!(connection.execute("INSERT INTO deferred_parents VALUES (4, 4)"));
+        try!(connection.execute("INSERT INTO deferred_children VALUES (4, 4)"));
+        Ok(())
+    });
+    assert_eq!(Ok(()), result);
+
+    let count = connection.execute("SELECT * FROM deferred_parents").unwrap();
+    assert_eq!(2, count);
+
+    connection
+        .batch_execute("DROP TABLE deferred_parents, deferred_children CASCADE")
+        .unwrap();
+}
+
 fn setup_test_table(connection: &TestConnection, table_name: &str) {
     use schema_dsl::*;
     create_table(table_name, (integer("id").primary_key().auto_increment(),))
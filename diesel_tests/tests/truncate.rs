@@ -0,0 +1,62 @@
+use diesel::*;
+use schema::*;
+
+#[cfg(feature = "postgres")]
+fn truncate_users_restarting_identity(connection: &TestConnection) -> QueryResult<usize> {
+    // `posts` references `users`, so PostgreSQL requires `CASCADE`
+    truncate(users::table)
+        .restart_identity()
+        .cascade()
+        .execute(connection)
+}
+
+#[cfg(feature = "sqlite")]
+fn truncate_users_restarting_identity(connection: &TestConnection) -> QueryResult<usize> {
+    truncate(users::table).restart_identity().execute(connection)
+}
+
+#[test]
+fn truncate_removes_all_rows_and_restarts_identity() {
+    use schema::users::dsl::*;
+    let connection = connection_with_sean_and_tess_in_users_table();
+
+    truncate_users_restarting_identity(&connection).unwrap();
+    assert_eq!(Ok(0), users.count().get_result(&connection));
+
+    insert(&NewUser::new("Jim", None))
+        .into(users)
+        .execute(&connection)
+        .unwrap();
+    assert_eq!(Ok(1), users.select(id).first(&connection));
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn truncate_cascade_removes_referencing_rows() {
+    let connection = connection_with_sean_and_tess_in_users_table();
+    let sean = find_user_by_name("Sean", &connection);
+    insert(&sean.new_post("Hello", None))
+        .into(posts::table)
+        .execute(&connection)
+        .unwrap();
+
+    truncate(users::table).cascade().execute(&connection).unwrap();
+
+    assert_eq!(Ok(0), users::table.count().get_result(&connection));
+    assert_eq!(Ok(0), posts::table.count().get_result(&connection));
+}
+
+#[test]
+#[cfg(feature = "sqlite")]
+fn truncate_without_restart_identity_keeps_identity() {
+    use schema::users::dsl::*;
+    let connection = connection_with_sean_and_tess_in_users_table();
+
+    assert_eq!(Ok(2), truncate(users).execute(&connection));
+
+    insert(&NewUser::new("Jim", None))
+        .into(users)
+        .execute(&connection)
+        .unwrap();
+    assert_eq!(Ok(3), users.select(id).first(&connection));
+}
@@ -1119,3 +1119,75 @@ fn test_range_to_sql() {
         (Bound<i32>, Bound<i32>),
     >(expected_value, value));
 }
+
+#[cfg(feature = "postgres")]
+#[test]
+fn tstzrange_round_trips_inclusive_exclusive_and_unbounded_bounds() {
+    use std::collections::Bound;
+    use self::chrono::{DateTime, TimeZone, Utc};
+
+    let connection = connection();
+    let start = Utc.ymd(2017, 8, 1).and_hms(9, 0, 0);
+    let end = Utc.ymd(2017, 8, 1).and_hms(17, 30, 0);
+    let ranges = vec![
+        (Bound::Included(start), Bound::Excluded(end)),
+        (Bound::Excluded(start), Bound::Included(end)),
+        (Bound::Included(start), Bound::Included(end)),
+        (Bound::Included(start), Bound::Unbounded),
+        (Bound::Unbounded, Bound::Excluded(end)),
+        (Bound::Unbounded, Bound::Unbounded),
+    ];
+
+    for range in ranges {
+        let result = select(range.into_sql::<Tstzrange>())
+            .get_result::<(Bound<DateTime<Utc>>, Bound<DateTime<Utc>>)>(&connection);
+        assert_eq!(Ok(range), result);
+    }
+}
+
+#[cfg(feature = "postgres")]
+#[test]
+fn composite_types_round_trip_through_record() {
+    use diesel::types::Record;
+
+    table! {
+        addresses {
+            id -> Integer,
+            address -> Record<(Text, Integer)>,
+        }
+    }
+
+    #[derive(Debug, PartialEq, Queryable)]
+    struct Address {
+        street: String,
+        number: i32,
+    }
+
+    let connection = connection();
+    connection
+        .execute("CREATE TYPE address AS (street TEXT, number INTEGER)")
+        .unwrap();
+    connection
+        .execute("CREATE TABLE addresses (id SERIAL PRIMARY KEY, address address NOT NULL)")
+        .unwrap();
+
+    let new_address = ("Main Street".to_string(), 12);
+    insert(&addresses::address.eq(&new_address))
+        .into(addresses::table)
+        .execute(&connection)
+        .unwrap();
+
+    let address = addresses::table
+        .select(addresses::address)
+        .get_result::<(String, i32)>(&connection);
+    assert_eq!(Ok(new_address), address);
+
+    let address = addresses::table
+        .select(addresses::address)
+        .get_result::<Address>(&connection);
+    let expected_address = Address {
+        street: "Main Street".into(),
+        number: 12,
+    };
+    assert_eq!(Ok(expected_address), address);
+}
@@ -81,6 +81,38 @@ fn test_updating_multiple_columns() {
     assert_eq!(Ok(expected_user), user);
 }
 
+#[cfg(not(feature = "sqlite"))]
+impl diesel::expression::HasDefault for ::schema::followings::email_notifications {}
+
+#[test]
+#[cfg(not(feature = "sqlite"))]
+fn update_column_to_default() {
+    use schema::followings::dsl::*;
+    use diesel::dsl::set_default;
+
+    let connection = connection();
+    disable_foreign_keys(&connection);
+    let following = Following {
+        user_id: 1,
+        post_id: 1,
+        email_notifications: true,
+    };
+    insert(&following)
+        .into(followings)
+        .execute(&connection)
+        .unwrap();
+
+    update(followings)
+        .set(set_default(email_notifications))
+        .execute(&connection)
+        .unwrap();
+
+    assert_eq!(
+        Ok(false),
+        followings.select(email_notifications).first(&connection)
+    );
+}
+
 #[test]
 #[cfg(not(any(feature = "sqlite", feature = "mysql")))]
 fn update_returning_struct() {
@@ -385,6 +417,179 @@ fn upsert_with_sql_literal_for_target() {
     assert_eq!(Ok(expected_data), data);
 }
 
+#[test]
+#[cfg(feature = "postgres")]
+fn upsert_infers_the_conflict_target_from_the_primary_key() {
+    use diesel::pg::upsert::*;
+    use schema::users::dsl::*;
+
+    let connection = connection_with_sean_and_tess_in_users_table();
+    let new_sean = User::with_hair_color(1, "Sean", "Green");
+    insert(&new_sean.on_conflict_do_update(hair_color.eq(excluded(hair_color))))
+        .into(users)
+        .execute(&connection)
+        .unwrap();
+
+    let data = users.select((name, hair_color)).order(id).load(&connection);
+    let expected_data = vec![
+        ("Sean".to_string(), Some("Green".to_string())),
+        ("Tess".to_string(), None),
+    ];
+    assert_eq!(Ok(expected_data), data);
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn upsert_by_a_composite_primary_key_names_every_column() {
+    use diesel::pg::upsert::*;
+
+    let like = Like {
+        user_id: 1,
+        comment_id: 2,
+    };
+    let upsert = like.on_conflict_do_update(likes::comment_id.eq(3));
+    let query = insert(&upsert).into(likes::table);
+
+    assert_eq!(
+        r#"INSERT INTO "likes" ("user_id", "comment_id") VALUES ($1, $2) ON CONFLICT ("comment_id", "user_id") DO UPDATE SET "comment_id" = $3 -- binds: [1, 2, 3]"#,
+        debug_query::<TestBackend, _>(&query).to_string()
+    );
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn upsert_returning_previous_returns_rows_before_and_after_the_upsert() {
+    use diesel::pg::upsert::*;
+    use schema::users::dsl::*;
+
+    let connection = connection_with_sean_and_tess_in_users_table();
+    let new_users = vec![
+        User::with_hair_color(1, "Sean", "Green"),
+        User::with_hair_color(3, "Jim", "Black"),
+    ];
+    let upsert = new_users.on_conflict(id, do_update().set(hair_color.eq(excluded(hair_color))));
+    let query = insert(&upsert)
+        .into(users)
+        .upsert_returning_previous(id.eq_any(vec![1, 3]))
+        .order(id);
+
+    let expected_sql = "WITH \"old\" AS (SELECT \"users\".\"id\", \"users\".\"name\", \"users\".\"hair_color\" \
+                        FROM \"users\" WHERE \"users\".\"id\" IN ($1, $2)), \
+                        \"upserted\" AS (INSERT INTO \"users\" (\"id\", \"name\", \"hair_color\") \
+                        VALUES ($3, $4, $5), ($6, $7, $8) \
+                        ON CONFLICT (\"id\") DO UPDATE SET \"hair_color\" = excluded.\"hair_color\" \
+                        RETURNING \"users\".\"id\", \"users\".\"name\", \"users\".\"hair_color\") \
+                        SELECT \"old\".*, \"users\".* FROM \"upserted\" AS \"users\" \
+                        LEFT JOIN \"old\" USING (\"id\") ORDER BY \"users\".\"id\" \
+                        -- binds: [1, 3, 1, \"Sean\", \"Green\", 3, \"Jim\", \"Black\"]";
+    assert_eq!(expected_sql, debug_query::<TestBackend, _>(&query).to_string());
+
+    let changes = query.load::<(Option<User>, User)>(&connection);
+    let expected_changes = vec![
+        (Some(User::new(1, "Sean")), User::with_hair_color(1, "Sean", "Green")),
+        (None, User::with_hair_color(3, "Jim", "Black")),
+    ];
+    assert_eq!(Ok(expected_changes), changes);
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn upsert_stats_count_inserted_and_updated_rows() {
+    use diesel::pg::upsert::*;
+    use schema::users::dsl::*;
+
+    let connection = connection_with_sean_and_tess_in_users_table();
+    let new_users = vec![
+        User::with_hair_color(1, "Sean", "Green"),
+        User::with_hair_color(2, "Tess", "Brown"),
+        User::with_hair_color(3, "Jim", "Black"),
+    ];
+    let upsert = new_users.on_conflict(id, do_update().set(hair_color.eq(excluded(hair_color))));
+    let stats = insert(&upsert)
+        .into(users)
+        .execute_with_upsert_stats(&connection);
+    assert_eq!(
+        Ok(UpsertStats {
+            inserted: 1,
+            updated: 2,
+        }),
+        stats
+    );
+
+    let hair_colors = users
+        .select(hair_color)
+        .order(id)
+        .load::<Option<String>>(&connection);
+    let expected_hair_colors = vec![
+        Some("Green".to_string()),
+        Some("Brown".to_string()),
+        Some("Black".to_string()),
+    ];
+    assert_eq!(Ok(expected_hair_colors), hair_colors);
+}
+
+#[cfg(feature = "postgres")]
+table! {
+    find_or_create_tags {
+        id -> Integer,
+        name -> VarChar,
+    }
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn find_or_create_returns_the_row_created_by_a_concurrent_transaction() {
+    use self::find_or_create_tags::table as tags;
+    use std::sync::mpsc;
+    use std::thread;
+    use std::time::Duration;
+
+    #[derive(Clone, Copy, Insertable)]
+    #[table_name = "find_or_create_tags"]
+    struct NewTag<'a> {
+        name: &'a str,
+    }
+
+    let connection = connection_without_transaction();
+    connection
+        .execute("DROP TABLE IF EXISTS find_or_create_tags")
+        .unwrap();
+    connection
+        .execute(
+            "CREATE TABLE find_or_create_tags (id SERIAL PRIMARY KEY, name VARCHAR NOT NULL UNIQUE)",
+        )
+        .unwrap();
+
+    let new_tag = NewTag { name: "rust" };
+    let key = find_or_create_tags::name.eq("rust");
+    let (send, recv) = mpsc::channel();
+    let created = connection
+        .transaction(|| {
+            let created = insert(&new_tag)
+                .into(tags)
+                .find_or_create::<_, _, (i32, String)>(key, &connection)?;
+            thread::spawn(move || {
+                let other_connection = connection_without_transaction();
+                let found = insert(&new_tag)
+                    .into(tags)
+                    .find_or_create::<_, _, (i32, String)>(key, &other_connection);
+                send.send(found).unwrap();
+            });
+            // The other connection waits for this transaction to end
+            let timeout = Duration::from_millis(200);
+            assert!(recv.recv_timeout(timeout).is_err());
+            Ok::<_, result::Error>(created)
+        })
+        .unwrap();
+
+    let found = recv.recv_timeout(Duration::from_secs(5)).unwrap();
+    assert_eq!(Ok(created.clone()), found);
+    assert_eq!(Ok(vec![created]), tags.load(&connection));
+    connection
+        .execute("DROP TABLE find_or_create_tags")
+        .unwrap();
+}
+
 #[test]
 fn update_with_custom_pk() {
     #[derive(AsChangeset)]
@@ -434,3 +639,56 @@ fn update_with_custom_composite_pk() {
     let expected_user = User::new(1, "Jim");
     assert_eq!(Ok(expected_user), user);
 }
+
+#[test]
+#[cfg(feature = "mysql")]
+fn updating_a_row_to_its_current_values_counts_it_only_with_found_rows() {
+    use dotenv::dotenv;
+    use std::env;
+
+    let query = update(users::table.find(1)).set(users::name.eq("Sean"));
+
+    let connection = connection_with_sean_and_tess_in_users_table();
+    assert_eq!(Ok(0), query.execute(&connection));
+    drop(connection);
+
+    dotenv().ok();
+    let connection_url = env::var("MYSQL_DATABASE_URL")
+        .or_else(|_| env::var("DATABASE_URL"))
+        .expect("DATABASE_URL must be set in order to run tests");
+    let connection =
+        MysqlConnection::establish(&format!("{}?found_rows=true", connection_url)).unwrap();
+    connection.begin_test_transaction().unwrap();
+    insert_sean_and_tess_into_users_table(&connection);
+    assert_eq!(Ok(1), query.execute(&connection));
+}
+
+#[test]
+#[cfg(feature = "mysql")]
+fn update_with_order_and_limit_updates_only_the_first_rows() {
+    use schema::users::dsl::*;
+
+    let connection = connection_with_sean_and_tess_in_users_table();
+    let query = update(users)
+        .set(hair_color.eq("black"))
+        .order_by(id.desc())
+        .limit(1);
+    let expected_sql = "UPDATE `users` SET `hair_color` = ? \
+                        ORDER BY `users`.`id` DESC LIMIT ? \
+                        -- binds: [\"black\", 1]";
+    assert_eq!(
+        expected_sql,
+        debug_query::<TestBackend, _>(&query).to_string()
+    );
+
+    assert_eq!(Ok(1), query.execute(&connection));
+    let data = users
+        .select((name, hair_color))
+        .order(id)
+        .load::<(String, Option<String>)>(&connection);
+    let expected_data = vec![
+        ("Sean".to_string(), None),
+        ("Tess".to_string(), Some("black".to_string())),
+    ];
+    assert_eq!(Ok(expected_data), data);
+}
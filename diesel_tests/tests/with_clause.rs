@@ -0,0 +1,83 @@
+use schema::*;
+use diesel::*;
+use diesel::query_builder::with;
+
+table! {
+    recent_posts {
+        id -> Integer,
+        title -> VarChar,
+    }
+}
+
+#[test]
+fn queries_can_select_from_a_common_table_expression() {
+    let connection = connection_with_sean_and_tess_in_users_table();
+    let sean = find_user_by_name("Sean", &connection);
+    let tess = find_user_by_name("Tess", &connection);
+    let new_posts = vec![
+        sean.new_post("Hello", None),
+        tess.new_post("Hi", None),
+        tess.new_post("Bye", None),
+    ];
+    insert(&new_posts)
+        .into(posts::table)
+        .execute(&connection)
+        .unwrap();
+
+    let tess_posts = posts::table
+        .select((posts::id, posts::title))
+        .filter(posts::user_id.eq(tess.id));
+    let titles = with(recent_posts::table, tess_posts)
+        .query(
+            recent_posts::table
+                .select(recent_posts::title)
+                .order(recent_posts::id),
+        )
+        .load::<String>(&connection);
+
+    assert_eq!(Ok(vec!["Hi".to_string(), "Bye".to_string()]), titles);
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn materialized_hint_is_rendered_after_as() {
+    let query = with(recent_posts::table, posts::table.select((posts::id, posts::title)))
+        .materialized()
+        .query(recent_posts::table.select(recent_posts::title).filter(recent_posts::id.gt(1)));
+
+    assert_eq!(
+        r#"WITH "recent_posts" AS MATERIALIZED (SELECT "posts"."id", "posts"."title" FROM "posts") SELECT "recent_posts"."title" FROM "recent_posts" WHERE "recent_posts"."id" > $1 -- binds: [1]"#,
+        debug_query::<TestBackend, _>(&query).to_string()
+    );
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn not_materialized_hint_is_rendered_after_as() {
+    let query = with(recent_posts::table, posts::table.select((posts::id, posts::title)))
+        .not_materialized()
+        .query(recent_posts::table.select(recent_posts::title));
+
+    assert_eq!(
+        r#"WITH "recent_posts" AS NOT MATERIALIZED (SELECT "posts"."id", "posts"."title" FROM "posts") SELECT "recent_posts"."title" FROM "recent_posts" -- binds: []"#,
+        debug_query::<TestBackend, _>(&query).to_string()
+    );
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn materialized_common_table_expressions_can_be_loaded() {
+    let connection = connection_with_sean_and_tess_in_users_table();
+    let sean = find_user_by_name("Sean", &connection);
+    insert(&sean.new_post("Hello", None))
+        .into(posts::table)
+        .execute(&connection)
+        .unwrap();
+
+    let titles = with(recent_posts::table, posts::table.select((posts::id, posts::title)))
+        .materialized()
+        .query(recent_posts::table.select(recent_posts::title))
+        .load::<String>(&connection);
+
+    assert_eq!(Ok(vec!["Hello".to_string()]), titles);
+}